@@ -1,10 +1,15 @@
 //! Application struct managing the event loop and windows.
 
+use crate::clock::FrameClock;
+use crate::input::{scroll_delta_pixels, PointerState};
+use crate::window::{WindowConfig, WindowSpawner};
 use crate::GloomyWindow;
+use gloomy_core::SafeArea;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 use winit::event::{ElementState, Event, WindowEvent, MouseButton};
-use winit::event_loop::EventLoop;
+use winit::event_loop::{ControlFlow, EventLoop, EventLoopWindowTarget};
 use winit::keyboard::{Key, NamedKey};
 use winit::window::{WindowBuilder, WindowId};
 
@@ -29,6 +34,19 @@ pub type KeyboardInputFn = Box<dyn FnMut(&mut GloomyWindow, winit::event::KeyEve
 /// Callback for mouse wheel (scroll).
 pub type ScrollFn = Box<dyn FnMut(&mut GloomyWindow, winit::event::MouseScrollDelta, winit::event::TouchPhase)>;
 
+/// Callback for scrolling, with the delta in logical pixels.
+pub type ScrollPixelsFn = Box<dyn FnMut(&mut GloomyWindow, f32, f32, winit::event::TouchPhase)>;
+
+/// Callback for right clicks and double clicks, at the cursor position.
+pub type ClickFn = Box<dyn FnMut(&mut GloomyWindow, f32, f32)>;
+
+/// Callback for a window's scale factor (DPI) changing.
+pub type ScaleFactorFn = Box<dyn FnMut(&mut GloomyWindow, f64)>;
+
+/// Callback run before each frame with the seconds since the previous
+/// one. Returns true to keep animating.
+pub type TickFn = Box<dyn FnMut(&mut GloomyWindow, f32) -> bool>;
+
 /// Callback for modifiers changed.
 pub type ModifiersChangedFn = Box<dyn FnMut(&mut GloomyWindow, winit::event::Modifiers)>;
 
@@ -39,19 +57,32 @@ pub struct GloomyApp {
   mouse_input_fn: Option<MouseInputFn>,
   keyboard_input_fn: Option<KeyboardInputFn>,
   scroll_fn: Option<ScrollFn>,
+  scroll_pixels_fn: Option<ScrollPixelsFn>,
+  right_click_fn: Option<ClickFn>,
+  double_click_fn: Option<ClickFn>,
   modifiers_changed_fn: Option<ModifiersChangedFn>,
+  scale_factor_fn: Option<ScaleFactorFn>,
+  tick_fn: Option<TickFn>,
+  target_fps: Option<u32>,
+  extra_windows: Vec<WindowConfig>,
+  spawner: WindowSpawner,
   width: u32,
   height: u32,
   title: String,
+  transparent: bool,
+  msaa_samples: u32,
+  safe_area: SafeArea,
 }
 
 /// Runtime state during event loop.
 struct AppState {
-  _instance: wgpu::Instance,
-  _adapter: wgpu::Adapter,
+  instance: wgpu::Instance,
+  adapter: wgpu::Adapter,
   device: wgpu::Device,
   queue: wgpu::Queue,
   windows: HashMap<winit::window::WindowId, GloomyWindow>,
+  clocks: HashMap<winit::window::WindowId, FrameClock>,
+  pointers: HashMap<winit::window::WindowId, PointerState>,
 }
 
 impl GloomyApp {
@@ -63,10 +94,21 @@ impl GloomyApp {
       mouse_input_fn: None,
       keyboard_input_fn: None,
       scroll_fn: None,
+      scroll_pixels_fn: None,
+      right_click_fn: None,
+      double_click_fn: None,
       modifiers_changed_fn: None,
+      scale_factor_fn: None,
+      tick_fn: None,
+      target_fps: None,
+      extra_windows: Vec::new(),
+      spawner: WindowSpawner::default(),
       width: 800,
       height: 600,
       title: "Gloomy".to_string(),
+      transparent: false,
+      msaa_samples: 1,
+      safe_area: SafeArea::default(),
     }
   }
 
@@ -83,6 +125,38 @@ impl GloomyApp {
       self
   }
 
+  /// Makes the main window see-through wherever the UI leaves the clear
+  /// color's alpha below 1, for overlays and HUDs. Pair it with
+  /// `GloomyRenderer::set_clear_color` and a translucent color.
+  ///
+  /// Platform requirements: works on Windows, macOS and Wayland; X11
+  /// needs a running compositor. The graphics backend must also offer a
+  /// non-opaque surface alpha mode (Vulkan and Metal usually do, some GL
+  /// drivers don't); otherwise the window stays opaque and a warning is
+  /// logged.
+  pub fn with_transparent(mut self, transparent: bool) -> Self {
+      self.transparent = transparent;
+      self
+  }
+
+  /// Multisamples shapes and images in every window with `samples` (4)
+  /// samples per pixel, for smoother diagonal lines and chart strokes.
+  /// The app's device only has the portable counts 1 and 4, so other
+  /// counts fall back to the next lower of those. Defaults to 1 (off).
+  pub fn with_msaa(mut self, samples: u32) -> Self {
+      self.msaa_samples = samples;
+      self
+  }
+
+  /// Keeps the main window's root out of `safe_area` (logical insets for
+  /// notches, rounded corners or system bars). The app only stores it:
+  /// pass `window.safe_area()` to `compute_root_layout` in `on_draw`, and
+  /// use `GloomyWindow::set_safe_area` when the insets change.
+  pub fn with_safe_area(mut self, safe_area: SafeArea) -> Self {
+      self.safe_area = safe_area;
+      self
+  }
+
   // ... (existing methods)
 
   /// Sets the keyboard input callback.
@@ -103,6 +177,38 @@ impl GloomyApp {
       self
   }
 
+  /// Sets a scroll callback that receives the delta in logical pixels,
+  /// whether the device reports lines or pixels.
+  ///
+  /// Runs alongside `on_scroll`.
+  pub fn on_scroll_pixels<F>(mut self, f: F) -> Self
+  where
+    F: FnMut(&mut GloomyWindow, f32, f32, winit::event::TouchPhase) + 'static,
+  {
+      self.scroll_pixels_fn = Some(Box::new(f));
+      self
+  }
+
+  /// Sets the right-click callback, called on press with the cursor
+  /// position.
+  pub fn on_right_click<F>(mut self, f: F) -> Self
+  where
+    F: FnMut(&mut GloomyWindow, f32, f32) + 'static,
+  {
+      self.right_click_fn = Some(Box::new(f));
+      self
+  }
+
+  /// Sets the double-click callback, called on the second left press with
+  /// the cursor position. `on_mouse_input` still sees both presses.
+  pub fn on_double_click<F>(mut self, f: F) -> Self
+  where
+    F: FnMut(&mut GloomyWindow, f32, f32) + 'static,
+  {
+      self.double_click_fn = Some(Box::new(f));
+      self
+  }
+
   // ... (run loop)
 
 
@@ -142,6 +248,61 @@ impl GloomyApp {
       self
   }
 
+  /// Sets the scale factor changed callback, e.g. for a window moved to a
+  /// monitor with a different DPI.
+  ///
+  /// The renderer already uses the new factor when this runs. Cached
+  /// layouts are still in the old logical size, so call `mark_dirty` on
+  /// your root widget here and lay it out again at `win.renderer.size()`.
+  pub fn on_scale_factor_changed<F>(mut self, f: F) -> Self
+  where
+    F: FnMut(&mut GloomyWindow, f64) + 'static,
+  {
+      self.scale_factor_fn = Some(Box::new(f));
+      self
+  }
+
+  /// Sets the animation tick, called before each frame with the delta
+  /// time in seconds.
+  ///
+  /// Setting a tick switches the app from redrawing continuously to
+  /// redrawing on demand: a window only gets a new frame while its tick
+  /// keeps returning true, or when something calls `request_redraw`
+  /// (input callbacks should, when they change what is shown). Static UIs
+  /// then stay idle, so a tick must also return true while the UI animates
+  /// on its own, e.g. while `InteractionState::has_active_ripples()` or the
+  /// root's `Widget::is_animating()` holds.
+  pub fn on_tick<F>(mut self, f: F) -> Self
+  where
+    F: FnMut(&mut GloomyWindow, f32) -> bool + 'static,
+  {
+      self.tick_fn = Some(Box::new(f));
+      self
+  }
+
+  /// Caps how often animating windows redraw. Without it they redraw as
+  /// fast as the present mode allows (usually the display's refresh
+  /// rate). Only applies with `on_tick`.
+  pub fn with_target_fps(mut self, fps: u32) -> Self {
+      self.target_fps = Some(fps);
+      self
+  }
+
+  /// Opens another window on startup, next to the main one.
+  ///
+  /// All windows share the app's callbacks; check `GloomyWindow::label`
+  /// to decide which root to lay out and draw.
+  pub fn with_window(mut self, config: WindowConfig) -> Self {
+      self.extra_windows.push(config);
+      self
+  }
+
+  /// Returns a handle for opening windows while the app runs, e.g. from
+  /// a button's mouse callback.
+  pub fn spawner(&self) -> WindowSpawner {
+      self.spawner.clone()
+  }
+
   /// Runs the application event loop.
   pub fn run(mut self) -> anyhow::Result<()> {
     let event_loop = EventLoop::new()?;
@@ -151,6 +312,7 @@ impl GloomyApp {
       WindowBuilder::new()
         .with_title(&self.title)
         .with_inner_size(winit::dpi::LogicalSize::new(self.width, self.height))
+        .with_transparent(self.transparent)
         .build(&event_loop)?,
     );
 
@@ -179,29 +341,49 @@ impl GloomyApp {
       None,
     ))?;
 
+    let main_config = WindowConfig {
+      label: crate::MAIN_WINDOW.to_string(),
+      title: self.title.clone(),
+      width: self.width,
+      height: self.height,
+      transparent: self.transparent,
+      msaa_samples: self.msaa_samples,
+      safe_area: self.safe_area,
+    };
     let gloomy_window =
-      GloomyWindow::new(window, &instance, &adapter, &device)?;
+      GloomyWindow::new_with_config(window, &instance, &adapter, &device, &main_config)?;
 
     let mut state = AppState {
-      _instance: instance,
-      _adapter: adapter,
+      instance,
+      adapter,
       device,
       queue,
       windows: HashMap::new(),
+      clocks: HashMap::new(),
+      pointers: HashMap::new(),
     };
 
     let window_id = gloomy_window.id();
     state.windows.insert(window_id, gloomy_window);
 
+    for mut config in std::mem::take(&mut self.extra_windows) {
+      config.msaa_samples = config.msaa_samples.max(self.msaa_samples);
+      state.open_window(&event_loop, config)?;
+    }
+
     #[allow(deprecated)]
     event_loop.run(move |event, elwt| match event {
       Event::WindowEvent { window_id, event } => {
         self.handle_window_event(&mut state, window_id, event, elwt);
       }
       Event::AboutToWait => {
-        for win in state.windows.values() {
-          win.window.request_redraw();
+        for mut config in self.spawner.take() {
+          config.msaa_samples = config.msaa_samples.max(self.msaa_samples);
+          if let Err(e) = state.open_window(elwt, config) {
+            log::error!("Failed to open window: {:?}", e);
+          }
         }
+        self.schedule_redraws(&state, elwt);
       }
       _ => {}
     })?;
@@ -209,6 +391,38 @@ impl GloomyApp {
     Ok(())
   }
 
+  /// Requests the next frames: every window continuously without a tick,
+  /// otherwise only animating windows, paced by the target FPS.
+  fn schedule_redraws(
+    &self,
+    state: &AppState,
+    elwt: &winit::event_loop::EventLoopWindowTarget<()>,
+  ) {
+    if self.tick_fn.is_none() {
+      for win in state.windows.values() {
+        win.window.request_redraw();
+      }
+      return;
+    }
+
+    let now = Instant::now();
+    let mut wake_at: Option<Instant> = None;
+    for (id, win) in &state.windows {
+      let Some(due) = state.clocks.get(id).and_then(|c| c.next_frame(self.target_fps)) else {
+        continue;
+      };
+      if due <= now {
+        win.window.request_redraw();
+      } else {
+        wake_at = Some(wake_at.map_or(due, |w| w.min(due)));
+      }
+    }
+    elwt.set_control_flow(match wake_at {
+      Some(at) => ControlFlow::WaitUntil(at),
+      None => ControlFlow::Wait,
+    });
+  }
+
   fn handle_window_event(
     &mut self,
     state: &mut AppState,
@@ -219,6 +433,8 @@ impl GloomyApp {
     match event {
       WindowEvent::CloseRequested => {
         state.windows.remove(&window_id);
+        state.clocks.remove(&window_id);
+        state.pointers.remove(&window_id);
         if state.windows.is_empty() {
           elwt.exit();
         }
@@ -230,12 +446,24 @@ impl GloomyApp {
         }
       }
 
+      WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+        if let Some(win) = state.windows.get_mut(&window_id) {
+          win.set_scale_factor(&state.queue, scale_factor as f32);
+          if let Some(cb) = self.scale_factor_fn.as_mut() {
+            cb(win, scale_factor);
+          }
+          win.window.request_redraw();
+        }
+      }
+
       WindowEvent::CursorMoved { position, .. } => {
         if let Some(win) = state.windows.get_mut(&window_id) {
+            let scale = win.window.scale_factor();
+            let logical_x = position.x / scale;
+            let logical_y = position.y / scale;
+            state.pointers.entry(window_id).or_default().position =
+                (logical_x as f32, logical_y as f32);
             if let Some(cb) = self.cursor_move_fn.as_mut() {
-                let scale = win.window.scale_factor();
-                let logical_x = position.x / scale;
-                let logical_y = position.y / scale;
                 if false { // Reduced noise, enable if needed
                     log::info!("Input: Phys({:?}) -> Log({:?})", position, (logical_x, logical_y));
                 }
@@ -249,6 +477,26 @@ impl GloomyApp {
               if let Some(cb) = self.mouse_input_fn.as_mut() {
                   cb(win, element_state, button);
               }
+
+              if element_state == ElementState::Pressed {
+                  let pointer = state.pointers.entry(window_id).or_default();
+                  let (x, y) = pointer.position;
+                  match button {
+                      MouseButton::Left => {
+                          if pointer.press(Instant::now()) {
+                              if let Some(cb) = self.double_click_fn.as_mut() {
+                                  cb(win, x, y);
+                              }
+                          }
+                      }
+                      MouseButton::Right => {
+                          if let Some(cb) = self.right_click_fn.as_mut() {
+                              cb(win, x, y);
+                          }
+                      }
+                      _ => {}
+                  }
+              }
           }
       }
 
@@ -257,11 +505,21 @@ impl GloomyApp {
             if let Some(cb) = self.scroll_fn.as_mut() {
                 cb(win, delta, phase);
             }
+            if let Some(cb) = self.scroll_pixels_fn.as_mut() {
+                let (dx, dy) = scroll_delta_pixels(delta, win.window.scale_factor());
+                cb(win, dx, dy, phase);
+            }
         }
       }
 
       WindowEvent::RedrawRequested => {
         if let Some(win) = state.windows.get_mut(&window_id) {
+          if let Some(tick_fn) = self.tick_fn.as_mut() {
+            let clock = state.clocks.entry(window_id).or_default();
+            let dt = clock.tick(Instant::now());
+            clock.animating = tick_fn(win, dt);
+          }
+
           if let Some(draw_fn) = self.draw_fn.as_mut() {
             let ctx =
               DrawContext { device: &state.device, queue: &state.queue };
@@ -309,6 +567,29 @@ impl GloomyApp {
   }
 }
 
+impl AppState {
+  /// Creates a window with its own surface and renderer on the shared
+  /// device.
+  fn open_window(
+    &mut self,
+    elwt: &EventLoopWindowTarget<()>,
+    config: WindowConfig,
+  ) -> anyhow::Result<()> {
+    let window = Arc::new(
+      WindowBuilder::new()
+        .with_title(&config.title)
+        .with_inner_size(winit::dpi::LogicalSize::new(config.width, config.height))
+        .with_transparent(config.transparent)
+        .build(elwt)?,
+    );
+    let win = GloomyWindow::new_with_config(
+      window, &self.instance, &self.adapter, &self.device, &config,
+    )?;
+    self.windows.insert(win.id(), win);
+    Ok(())
+  }
+}
+
 impl Default for GloomyApp {
   fn default() -> Self {
     Self::new()
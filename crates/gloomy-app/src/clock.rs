@@ -0,0 +1,69 @@
+//! Per-window animation clock behind `GloomyApp::on_tick`.
+
+use std::time::{Duration, Instant};
+
+/// Tracks frame times for one window and whether it wants more frames.
+#[derive(Debug, Default)]
+pub(crate) struct FrameClock {
+  last_frame: Option<Instant>,
+  /// The last tick asked for another frame.
+  pub animating: bool,
+}
+
+impl FrameClock {
+  /// Starts a frame and returns the seconds since the previous one.
+  ///
+  /// The first frame, and the first after the window went idle, reports
+  /// 0 so animations don't jump by the time spent waiting.
+  pub fn tick(&mut self, now: Instant) -> f32 {
+    let dt = match self.last_frame {
+      Some(last) if self.animating => now.duration_since(last).as_secs_f32(),
+      _ => 0.0,
+    };
+    self.last_frame = Some(now);
+    dt
+  }
+
+  /// When the next frame is due at `fps`, or `None` if the clock is
+  /// idle. Without a target rate frames are due immediately.
+  pub fn next_frame(&self, fps: Option<u32>) -> Option<Instant> {
+    if !self.animating {
+      return None;
+    }
+    let last = self.last_frame?;
+    Some(match fps {
+      Some(fps) if fps > 0 => last + Duration::from_secs_f64(1.0 / fps as f64),
+      _ => last,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_delta_time_resets_when_idle() {
+    let start = Instant::now();
+    let mut clock = FrameClock::default();
+    assert_eq!(clock.tick(start), 0.0);
+    clock.animating = true;
+    let dt = clock.tick(start + Duration::from_millis(16));
+    assert!((dt - 0.016).abs() < 1e-4);
+
+    clock.animating = false;
+    assert_eq!(clock.next_frame(None), None);
+    // Waking up after a long idle period starts from zero again.
+    assert_eq!(clock.tick(start + Duration::from_secs(5)), 0.0);
+  }
+
+  #[test]
+  fn test_next_frame_follows_target_fps() {
+    let start = Instant::now();
+    let mut clock = FrameClock::default();
+    clock.tick(start);
+    clock.animating = true;
+    assert_eq!(clock.next_frame(None), Some(start));
+    assert_eq!(clock.next_frame(Some(50)), Some(start + Duration::from_millis(20)));
+  }
+}
@@ -0,0 +1,83 @@
+//! Pointer input normalization shared by all windows.
+
+use std::time::{Duration, Instant};
+use winit::event::MouseScrollDelta;
+
+/// Pixels scrolled per wheel line.
+pub const LINE_SCROLL_PIXELS: f32 = 20.0;
+
+/// Maximum time between the presses of a double click.
+pub const DOUBLE_CLICK_TIME: Duration = Duration::from_millis(500);
+
+/// Maximum pointer travel, in logical pixels, between the presses of a
+/// double click.
+pub const DOUBLE_CLICK_DISTANCE: f32 = 4.0;
+
+/// Converts a wheel delta to logical pixels.
+///
+/// Line deltas (most mouse wheels) are scaled by [`LINE_SCROLL_PIXELS`];
+/// pixel deltas (touchpads) arrive in physical pixels and are divided by
+/// the window's scale factor.
+pub fn scroll_delta_pixels(delta: MouseScrollDelta, scale_factor: f64) -> (f32, f32) {
+  match delta {
+    MouseScrollDelta::LineDelta(x, y) => (x * LINE_SCROLL_PIXELS, y * LINE_SCROLL_PIXELS),
+    MouseScrollDelta::PixelDelta(pos) => {
+      ((pos.x / scale_factor) as f32, (pos.y / scale_factor) as f32)
+    }
+  }
+}
+
+/// Per-window pointer state.
+#[derive(Debug, Default)]
+pub(crate) struct PointerState {
+  /// Last cursor position in logical pixels.
+  pub position: (f32, f32),
+  last_press: Option<(Instant, (f32, f32))>,
+}
+
+impl PointerState {
+  /// Records a left-button press at the current position and returns
+  /// true if it completes a double click.
+  pub fn press(&mut self, now: Instant) -> bool {
+    let (x, y) = self.position;
+    let double = self.last_press.is_some_and(|(at, (px, py))| {
+      now.duration_since(at) <= DOUBLE_CLICK_TIME
+        && (x - px).hypot(y - py) <= DOUBLE_CLICK_DISTANCE
+    });
+    // A third press starts a new pair rather than firing again.
+    self.last_press = if double { None } else { Some((now, self.position)) };
+    double
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use winit::dpi::PhysicalPosition;
+
+  #[test]
+  fn test_scroll_deltas_in_pixels() {
+    assert_eq!(scroll_delta_pixels(MouseScrollDelta::LineDelta(0.0, -2.0), 2.0), (0.0, -40.0));
+    let pixels = MouseScrollDelta::PixelDelta(PhysicalPosition::new(10.0, 30.0));
+    assert_eq!(scroll_delta_pixels(pixels, 2.0), (5.0, 15.0));
+  }
+
+  #[test]
+  fn test_double_click_needs_time_and_proximity() {
+    let start = Instant::now();
+    let mut pointer = PointerState::default();
+    assert!(!pointer.press(start));
+    assert!(pointer.press(start + Duration::from_millis(200)));
+    // Third press starts over.
+    assert!(!pointer.press(start + Duration::from_millis(300)));
+
+    // Too slow.
+    assert!(!pointer.press(start + Duration::from_millis(1000)));
+
+    // Too far.
+    let later = start + Duration::from_millis(2000);
+    assert!(!pointer.press(later));
+    pointer.position = (20.0, 0.0);
+    assert!(!pointer.press(later + Duration::from_millis(100)));
+  }
+}
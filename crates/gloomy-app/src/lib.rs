@@ -4,15 +4,30 @@
 //! - Single and multi-window support
 //! - Event loop management
 //! - Keyboard-centric input handling
+//!
+//! # Multiple windows
+//!
+//! Open extra windows with `GloomyApp::with_window`, or at runtime through
+//! a `WindowSpawner`. Callbacks are shared by all windows, so keep app
+//! state in an `Rc<RefCell<_>>` captured by each callback and branch on
+//! `GloomyWindow::label`: each window lays out and draws its own root with
+//! its own renderer, and tracks input in its own `InteractionState`
+//! (hover, focus and scroll offsets are per window). See
+//! `examples/multi_window.rs`.
 
 mod app;
+mod clock;
+mod input;
 mod window;
 
 pub use app::{DrawContext, GloomyApp};
+pub use input::{
+  scroll_delta_pixels, DOUBLE_CLICK_DISTANCE, DOUBLE_CLICK_TIME, LINE_SCROLL_PIXELS,
+};
 pub use gloomy_core::{
-  compute_layout, hit_test, load_ui, parse_ui, render_ui, Align, Container,
+  compute_layout, compute_root_layout, hit_test, load_ui, parse_ui, render_ui, Align, Container,
   Direction, GloomyRenderer, Instance, InteractionState, Justify, Layout,
-  PrimitiveRenderer, Rect, RenderContext, TextRenderer, Vec2, Vec4, Widget,
+  PrimitiveRenderer, Rect, RenderContext, SafeArea, TextRenderer, Vec2, Vec4, Widget,
   WidgetBounds,
 };
-pub use window::GloomyWindow;
+pub use window::{GloomyWindow, WindowConfig, WindowSpawner, MAIN_WINDOW};
@@ -1,15 +1,28 @@
 //! Window wrapper managing wgpu surface and rendering.
 
-use gloomy_core::GloomyRenderer;
+use gloomy_core::{GloomyRenderer, SafeArea};
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::Arc;
 use winit::window::Window;
 
+/// Label of the window `GloomyApp` opens on startup.
+pub const MAIN_WINDOW: &str = "main";
+
 /// A gloomy window with its own rendering context.
+///
+/// Every window has its own surface and `GloomyRenderer`, so each can run
+/// `compute_layout` and `render_ui` on its own root at its own size and
+/// scale factor.
 pub struct GloomyWindow {
   pub window: Arc<Window>,
+  /// Name from the `WindowConfig` that opened it; app callbacks are shared
+  /// by all windows and use it to tell them apart.
+  pub label: String,
   surface: wgpu::Surface<'static>,
   pub config: wgpu::SurfaceConfiguration,
   pub renderer: GloomyRenderer,
+  safe_area: SafeArea,
 }
 
 impl GloomyWindow {
@@ -19,6 +32,24 @@ impl GloomyWindow {
     instance: &wgpu::Instance,
     adapter: &wgpu::Adapter,
     device: &wgpu::Device,
+  ) -> anyhow::Result<Self> {
+    Self::new_with_config(window, instance, adapter, device, &WindowConfig::new(MAIN_WINDOW, ""))
+  }
+
+  /// Creates a window with the surface options from `config`; its size
+  /// and title are taken from the already built `window`.
+  ///
+  /// With `transparent` set the surface blends with the desktop; the
+  /// winit window must have been built with `with_transparent(true)`.
+  /// Falls back to an opaque surface (with a warning) when the backend
+  /// reports no alpha-blended composite mode. Unsupported MSAA sample
+  /// counts fall back the same way.
+  pub fn new_with_config(
+    window: Arc<Window>,
+    instance: &wgpu::Instance,
+    adapter: &wgpu::Adapter,
+    device: &wgpu::Device,
+    options: &WindowConfig,
   ) -> anyhow::Result<Self> {
     let size = window.inner_size();
     let surface = instance.create_surface(window.clone())?;
@@ -31,22 +62,45 @@ impl GloomyWindow {
       .copied()
       .unwrap_or(caps.formats[0]);
 
+    let alpha_mode = if options.transparent {
+      let blended = [
+        wgpu::CompositeAlphaMode::PreMultiplied,
+        wgpu::CompositeAlphaMode::PostMultiplied,
+        wgpu::CompositeAlphaMode::Inherit,
+      ]
+      .into_iter()
+      .find(|mode| caps.alpha_modes.contains(mode));
+      blended.unwrap_or_else(|| {
+        log::warn!("Surface supports no transparent alpha mode ({:?}); window will be opaque", caps.alpha_modes);
+        caps.alpha_modes[0]
+      })
+    } else {
+      caps.alpha_modes[0]
+    };
+
     let config = wgpu::SurfaceConfiguration {
       usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
       format,
       width: size.width.max(1),
       height: size.height.max(1),
       present_mode: wgpu::PresentMode::AutoVsync,
-      alpha_mode: caps.alpha_modes[0],
+      alpha_mode,
       view_formats: vec![],
       desired_maximum_frame_latency: 2,
     };
     surface.configure(device, &config);
 
-    let renderer =
-      GloomyRenderer::new(device, format, config.width, config.height, window.scale_factor() as f32);
+    let renderer = GloomyRenderer::new_multisampled(
+      device,
+      adapter,
+      format,
+      config.width,
+      config.height,
+      window.scale_factor() as f32,
+      options.msaa_samples,
+    );
 
-    Ok(Self { window, surface, config, renderer })
+    Ok(Self { window, label: options.label.clone(), surface, config, renderer, safe_area: options.safe_area })
   }
 
   /// Handles window resize.
@@ -66,6 +120,14 @@ impl GloomyWindow {
     self.renderer.resize(queue, width, height, self.window.scale_factor() as f32);
   }
 
+  /// Applies a new scale factor at the current physical size.
+  ///
+  /// Winit follows a DPI change with a `Resized` event if the physical
+  /// size changes too, which reconfigures the surface.
+  pub fn set_scale_factor(&mut self, queue: &wgpu::Queue, scale_factor: f32) {
+    self.renderer.resize(queue, self.config.width, self.config.height, scale_factor);
+  }
+
   /// Renders a frame.
   pub fn render(
     &mut self,
@@ -102,8 +164,93 @@ impl GloomyWindow {
     Ok(())
   }
 
+  /// Logical insets (notches, rounded corners, system bars) to pass to
+  /// `compute_root_layout` when laying out this window's root.
+  pub fn safe_area(&self) -> SafeArea {
+    self.safe_area
+  }
+
+  /// Replaces the insets, e.g. when the platform reports new ones after
+  /// a rotation. Lay the root out again afterwards.
+  pub fn set_safe_area(&mut self, safe_area: SafeArea) {
+    self.safe_area = safe_area;
+  }
+
   /// Returns the window ID.
   pub fn id(&self) -> winit::window::WindowId {
     self.window.id()
   }
 }
+
+/// Describes an additional window to open.
+#[derive(Debug, Clone)]
+pub struct WindowConfig {
+  /// Passed on as `GloomyWindow::label`.
+  pub label: String,
+  pub title: String,
+  /// Initial logical size.
+  pub width: u32,
+  pub height: u32,
+  /// See `GloomyApp::with_transparent`.
+  pub transparent: bool,
+  /// See `GloomyApp::with_msaa`.
+  pub msaa_samples: u32,
+  /// See `GloomyApp::with_safe_area`.
+  pub safe_area: SafeArea,
+}
+
+impl WindowConfig {
+  pub fn new(label: impl Into<String>, title: impl Into<String>) -> Self {
+    Self {
+      label: label.into(),
+      title: title.into(),
+      width: 800,
+      height: 600,
+      transparent: false,
+      msaa_samples: 1,
+      safe_area: SafeArea::default(),
+    }
+  }
+
+  pub fn with_transparent(mut self, transparent: bool) -> Self {
+    self.transparent = transparent;
+    self
+  }
+
+  pub fn with_msaa(mut self, samples: u32) -> Self {
+    self.msaa_samples = samples;
+    self
+  }
+
+  pub fn with_safe_area(mut self, safe_area: SafeArea) -> Self {
+    self.safe_area = safe_area;
+    self
+  }
+
+  pub fn with_size(mut self, width: u32, height: u32) -> Self {
+    self.width = width;
+    self.height = height;
+    self
+  }
+}
+
+/// Opens windows from inside app callbacks.
+///
+/// Get one from `GloomyApp::spawner` before `run` and move clones into the
+/// callbacks that need it. Requests are handled once the current event
+/// has been processed.
+#[derive(Debug, Clone, Default)]
+pub struct WindowSpawner {
+  pending: Rc<RefCell<Vec<WindowConfig>>>,
+}
+
+impl WindowSpawner {
+  /// Queues a window to be opened.
+  pub fn open(&self, config: WindowConfig) {
+    self.pending.borrow_mut().push(config);
+  }
+
+  pub(crate) fn take(&self) -> Vec<WindowConfig> {
+    std::mem::take(&mut self.pending.borrow_mut())
+  }
+}
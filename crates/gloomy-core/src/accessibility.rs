@@ -0,0 +1,503 @@
+//! Semantic snapshot of a widget tree for assistive technology.
+//!
+//! [`accessibility_tree`] walks a laid-out tree and describes each
+//! meaningful widget as an [`AccessNode`]: role, label, value, absolute
+//! bounds and state. Nothing is rendered. The snapshot is serializable so
+//! an integration layer can translate it into AccessKit nodes (the roles
+//! are named after AccessKit's) or send it to another process.
+//!
+//! Presentational widgets (spacers, dividers, scrollbars, skeletons) and
+//! containers without an `id` are not described; their children move up
+//! to the nearest described ancestor. Bounds follow the same offsets as
+//! rendering and hit testing, including container scroll offsets. Parts
+//! whose geometry is not tracked (tab headers, tree rows, grid headers)
+//! have no bounds. DataGrids describe their visible rows and cells when
+//! given the `DataProvider` they render from.
+//!
+//! Dynamic content is voiced through announcements queued on
+//! `InteractionState`, which the app drains each frame with
+//! `take_announcements` and hands to its screen-reader integration.
+//! Announcements are queued:
+//!
+//! - when the app calls `InteractionState::announce`;
+//! - when a widget's validation errors change to a new, non-empty list
+//!   (`apply_validation` and `validate_triggered`), with the first error,
+//!   assertively;
+//! - by [`update_live_regions`], when the text inside a Container marked
+//!   with `live_region` changes.
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+use crate::data_source::DataProvider;
+use crate::interaction::InteractionState;
+use crate::widget::{Widget, WidgetBounds};
+
+/// What a node is, named after the matching AccessKit role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccessRole {
+  Window,
+  Group,
+  Label,
+  Button,
+  TextInput,
+  SpinButton,
+  ComboBox,
+  DateInput,
+  TimeInput,
+  CheckBox,
+  Switch,
+  RadioButton,
+  Slider,
+  ProgressIndicator,
+  List,
+  ListItem,
+  Grid,
+  ColumnHeader,
+  Row,
+  Cell,
+  Tree,
+  TreeItem,
+  TabList,
+  Tab,
+  TabPanel,
+  MenuBar,
+  MenuItem,
+  Navigation,
+  Link,
+  Image,
+  Figure,
+}
+
+/// How urgently a change is voiced, as in ARIA live regions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LiveRegion {
+  /// Voiced when the user is idle.
+  #[default]
+  Polite,
+  /// Interrupts whatever is being voiced.
+  Assertive,
+}
+
+/// A message for assistive technology to voice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Announcement {
+  pub message: String,
+  pub priority: LiveRegion,
+}
+
+/// One described widget, or part of one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessNode {
+  pub role: AccessRole,
+  /// The widget's id, see [`Widget::id`].
+  pub id: Option<String>,
+  /// Name announced for the node: button text, a label's text, a
+  /// placeholder, a list row.
+  pub label: Option<String>,
+  /// Current value of inputs, sliders and progress indicators.
+  pub value: Option<String>,
+  /// Absolute bounds, or `None` where the geometry is not tracked.
+  pub bounds: Option<WidgetBounds>,
+  pub checked: Option<bool>,
+  pub selected: Option<bool>,
+  pub expanded: Option<bool>,
+  pub disabled: bool,
+  pub focused: bool,
+  /// Set on live region groups.
+  pub live: Option<LiveRegion>,
+  pub children: Vec<AccessNode>,
+}
+
+impl AccessNode {
+  fn new(role: AccessRole) -> Self {
+    Self {
+      role,
+      id: None,
+      label: None,
+      value: None,
+      bounds: None,
+      checked: None,
+      selected: None,
+      expanded: None,
+      disabled: false,
+      focused: false,
+      live: None,
+      children: Vec::new(),
+    }
+  }
+
+  fn labeled(role: AccessRole, label: impl Into<String>) -> Self {
+    Self { label: Some(label.into()), ..Self::new(role) }
+  }
+
+  /// This node and all its descendants, depth first.
+  pub fn descendants(&self) -> Vec<&AccessNode> {
+    let mut nodes = vec![self];
+    for child in &self.children {
+      nodes.extend(child.descendants());
+    }
+    nodes
+  }
+}
+
+/// Describes the laid-out tree under `root` as a `Window` node.
+///
+/// `interaction` supplies focus and scroll offsets; without it nothing is
+/// focused and containers are unscrolled. `data` supplies DataGrid rows;
+/// without it grids describe only their column headers.
+pub fn accessibility_tree(root: &Widget, interaction: Option<&InteractionState>, data: Option<&dyn DataProvider>) -> AccessNode {
+  let mut window = AccessNode::new(AccessRole::Window);
+  window.bounds = Some(root.bounds());
+  describe(root, Vec2::ZERO, interaction, data, &mut window.children);
+  window
+}
+
+/// Appends the nodes describing `widget` to `out`. `offset` is the
+/// absolute position its bounds are relative to.
+fn describe(widget: &Widget, offset: Vec2, interaction: Option<&InteractionState>, data: Option<&dyn DataProvider>, out: &mut Vec<AccessNode>) {
+  use AccessRole::*;
+
+  let b = widget.bounds();
+  let pos = offset + Vec2::new(b.x, b.y);
+  let abs = WidgetBounds { x: pos.x, y: pos.y, width: b.width, height: b.height };
+  let focused_id = interaction.and_then(|state| state.focused_id.as_deref());
+
+  let mut node = match widget {
+    Widget::Container { id, scrollable, overflow, children, live_region, .. } => {
+      let mut child_offset = pos;
+      if overflow.resolve(*scrollable).scrolls() {
+        if let Some(scroll) = id.as_ref().and_then(|id| interaction?.scroll_offsets.get(id)) {
+          child_offset -= *scroll;
+        }
+      }
+      if id.is_none() && live_region.is_none() {
+        for child in crate::widget::z_ordered(children) {
+          describe(child, child_offset, interaction, data, out);
+        }
+        return;
+      }
+      let mut group = AccessNode { live: *live_region, ..AccessNode::new(Group) };
+      for child in crate::widget::z_ordered(children) {
+        describe(child, child_offset, interaction, data, &mut group.children);
+      }
+      group
+    }
+    Widget::Label { text, .. } => AccessNode::labeled(Label, text.clone()),
+    Widget::Button { text, disabled, .. } => AccessNode { disabled: *disabled, ..AccessNode::labeled(Button, text.clone()) },
+    Widget::TextInput { value, placeholder, .. } | Widget::ChipInput { value, placeholder, .. } => {
+      input(TextInput, placeholder, value.clone())
+    }
+    Widget::Autocomplete { value, placeholder, .. } => input(ComboBox, placeholder, value.clone()),
+    Widget::NumberInput { value, precision, number_format, .. } => {
+      AccessNode { value: Some(number_format.format(*value, *precision)), ..AccessNode::new(SpinButton) }
+    }
+    Widget::DatePicker { value, range, start, end, placeholder, format, .. } => {
+      let date = |d: &chrono::NaiveDate| d.format(format).to_string();
+      let text = if *range {
+        match (start, end) {
+          (Some(s), Some(e)) => format!("{} \u{2013} {}", date(s), date(e)),
+          (Some(s), None) => date(s),
+          _ => String::new(),
+        }
+      } else {
+        value.as_ref().map(date).unwrap_or_default()
+      };
+      input(DateInput, placeholder, text)
+    }
+    Widget::TimePicker { value, placeholder, use_24h, .. } => {
+      let pattern = if *use_24h { "%H:%M" } else { "%I:%M %p" };
+      input(TimeInput, placeholder, value.map(|t| t.format(pattern).to_string()).unwrap_or_default())
+    }
+    Widget::Dropdown { options, selected_index, expanded, .. } => AccessNode {
+      value: selected_index.and_then(|i| options.get(i)).cloned(),
+      expanded: Some(*expanded),
+      ..AccessNode::new(ComboBox)
+    },
+    Widget::Checkbox { checked, .. } => AccessNode { checked: Some(*checked), ..AccessNode::new(CheckBox) },
+    Widget::ToggleSwitch { checked, .. } => AccessNode { checked: Some(*checked), ..AccessNode::new(Switch) },
+    Widget::RadioButton { label, selected, .. } => {
+      AccessNode { checked: Some(*selected), ..AccessNode::labeled(RadioButton, label.clone()) }
+    }
+    Widget::Slider { value, .. } => AccessNode { value: Some(value.to_string()), ..AccessNode::new(Slider) },
+    Widget::Rating { value, read_only, .. } => {
+      AccessNode { value: Some(value.to_string()), disabled: *read_only, ..AccessNode::new(Slider) }
+    }
+    Widget::ProgressBar { value, indeterminate, .. } | Widget::CircularProgress { value, indeterminate, .. } => {
+      AccessNode { value: (!indeterminate).then(|| value.to_string()), ..AccessNode::new(ProgressIndicator) }
+    }
+    Widget::ListView { id, items, item_widgets, item_heights, selected_index, multi_select, selected_indices, style, .. } => {
+      let scroll = interaction.and_then(|state| state.scroll_offsets.get(id)).map(|v| v.y).unwrap_or(0.0);
+      let rows = crate::list_view::RowOffsets::new(crate::list_view::row_count(items, item_widgets), style.item_height, item_heights);
+      let mut list = AccessNode::new(List);
+      for i in 0..rows.len() {
+        let mut item = match items.get(i) {
+          Some(text) if item_widgets.is_empty() => AccessNode::labeled(ListItem, text.clone()),
+          _ => AccessNode::new(ListItem),
+        };
+        item.bounds = Some(WidgetBounds { x: pos.x, y: pos.y + rows.top(i) - scroll, width: b.width, height: rows.height(i) });
+        item.selected = Some(if *multi_select { selected_indices.contains(&i) } else { *selected_index == Some(i) });
+        if let Some(row) = item_widgets.get(i) {
+          // Rows were laid out relative to the list's content.
+          describe(row, pos - Vec2::new(0.0, scroll), interaction, data, &mut item.children);
+        }
+        list.children.push(item);
+      }
+      list
+    }
+    Widget::DataGrid {
+      id, columns, data_source_id, header_height, row_height, auto_row_height, fitted_rows,
+      selection_mode, selected_rows, scrollbar, scrollbar_mode, ..
+    } => {
+      let mut grid = AccessNode::new(Grid);
+      grid.children.extend(columns.iter().map(|col| AccessNode::labeled(ColumnHeader, col.header.clone())));
+      if let Some(ds) = data_source_id.as_deref().and_then(|source| data?.get_source(source)) {
+        // Same geometry as rendering: rows scroll under the header and
+        // stop short of the scrollbar gutter.
+        let scroll = id.as_ref().and_then(|id| interaction?.scroll_offsets.get(id)).map(|v| v.y).unwrap_or(0.0);
+        let rows = crate::datagrid::grid_rows(ds.row_count(), *row_height, fitted_rows.as_ref().filter(|_| *auto_row_height));
+        let body_width = b.width - crate::scrollbar::gutter_width(*scrollbar, *scrollbar_mode);
+        let widths = crate::datagrid::column_widths(columns, body_width);
+        let top = pos.y + header_height;
+        for r in rows.visible_range(scroll, b.height - header_height) {
+          let (y, height) = (top + rows.top(r) - scroll, rows.height(r));
+          let mut row = AccessNode {
+            bounds: Some(WidgetBounds { x: pos.x, y, width: body_width, height }),
+            selected: (*selection_mode != crate::datagrid::SelectionMode::Cells).then(|| selected_rows.contains(&r)),
+            ..AccessNode::new(Row)
+          };
+          let mut x = pos.x;
+          for (c, width) in widths.iter().enumerate() {
+            row.children.push(AccessNode {
+              bounds: Some(WidgetBounds { x, y, width: *width, height }),
+              ..AccessNode::labeled(Cell, ds.cell_text(r, c))
+            });
+            x += width;
+          }
+          grid.children.push(row);
+        }
+      }
+      grid
+    }
+    Widget::Tree { root_nodes, selected_id, expanded_ids, .. } => {
+      fn items(nodes: &[crate::tree::TreeNode], selected: Option<&str>, expanded: &std::collections::HashSet<String>) -> Vec<AccessNode> {
+        nodes.iter().map(|node| {
+          let open = expanded.contains(&node.id);
+          AccessNode {
+            id: Some(node.id.clone()),
+            selected: Some(selected == Some(node.id.as_str())),
+            expanded: (!node.leaf && !node.children.is_empty()).then_some(open),
+            children: if open { items(&node.children, selected, expanded) } else { Vec::new() },
+            ..AccessNode::labeled(TreeItem, node.label.clone())
+          }
+        }).collect()
+      }
+      AccessNode { children: items(root_nodes, selected_id.as_deref(), expanded_ids), ..AccessNode::new(Tree) }
+    }
+    Widget::Tab { tabs, selected, .. } => {
+      let mut list = AccessNode::new(TabList);
+      for (i, tab) in tabs.iter().enumerate() {
+        list.children.push(AccessNode { selected: Some(i == *selected), ..AccessNode::labeled(Tab, tab.title.clone()) });
+      }
+      if let Some(tab) = tabs.get(*selected) {
+        let mut panel = AccessNode::labeled(TabPanel, tab.title.clone());
+        describe(&tab.content, offset, interaction, data, &mut panel.children);
+        list.children.push(panel);
+      }
+      list
+    }
+    Widget::MenuBar { menus, open, .. } => AccessNode {
+      children: menus.iter().enumerate().map(|(i, menu)| AccessNode {
+        expanded: Some(*open == Some(i)),
+        ..AccessNode::labeled(MenuItem, menu.label.clone())
+      }).collect(),
+      ..AccessNode::new(MenuBar)
+    },
+    Widget::Breadcrumb { segments, .. } => AccessNode {
+      children: segments.iter().map(|s| AccessNode::labeled(Link, s.clone())).collect(),
+      ..AccessNode::new(Navigation)
+    },
+    Widget::Stepper { steps, current, .. } => AccessNode {
+      children: steps.iter().enumerate().map(|(i, s)| AccessNode {
+        selected: Some(i == *current),
+        ..AccessNode::labeled(ListItem, s.clone())
+      }).collect(),
+      ..AccessNode::new(List)
+    },
+    Widget::KpiCard { title, value, .. } => AccessNode { value: Some(value.clone()), ..AccessNode::labeled(Group, title.clone()) },
+    Widget::Chart { title, .. } => AccessNode::labeled(Figure, title.clone()),
+    Widget::Image { .. } => AccessNode::new(Image),
+    Widget::Icon { icon_name, .. } => AccessNode::labeled(Image, icon_name.clone()),
+    Widget::VirtualFlow { .. } => AccessNode::new(Group),
+    Widget::Spacer { .. }
+    | Widget::Divider { .. }
+    | Widget::Scrollbar { .. }
+    | Widget::Skeleton { .. }
+    | Widget::Include { .. } => return,
+  };
+
+  node.id = node.id.take().or_else(|| widget.id().map(str::to_string));
+  node.bounds = Some(abs);
+  node.focused = focused_id.is_some() && widget.get_focusable_id() == focused_id;
+  out.push(node);
+}
+
+/// Announces live regions whose text changed since the last call.
+///
+/// Call once per frame after the tree is updated. A region's text is the
+/// labels and values of everything described inside it, in order. The
+/// first time a region is seen its text is only recorded, so content
+/// present when a view opens is not read out. Regions need an `id`; text
+/// of regions no longer in the tree is forgotten.
+pub fn update_live_regions(root: &Widget, interaction: &mut InteractionState) {
+  let mut regions = Vec::new();
+  collect_live_regions(root, &mut regions);
+
+  let mut seen = std::collections::HashMap::new();
+  for (id, priority, text) in regions {
+    match interaction.live_region_text.get(&id) {
+      Some(previous) if *previous != text && !text.is_empty() => interaction.announce(text.clone(), priority),
+      _ => {}
+    }
+    seen.insert(id, text);
+  }
+  interaction.live_region_text = seen;
+}
+
+fn collect_live_regions(widget: &Widget, regions: &mut Vec<(String, LiveRegion, String)>) {
+  if let Widget::Container { id: Some(id), live_region: Some(priority), children, .. } = widget {
+    let mut nodes = Vec::new();
+    for child in children {
+      describe(child, Vec2::ZERO, None, None, &mut nodes);
+    }
+    let text: Vec<&str> = nodes
+      .iter()
+      .flat_map(|node| node.descendants())
+      .flat_map(|node| [node.label.as_deref(), node.value.as_deref()])
+      .flatten()
+      .filter(|s| !s.is_empty())
+      .collect();
+    regions.push((id.clone(), *priority, text.join(" ")));
+  }
+  widget.for_each_child(&mut |child| collect_live_regions(child, regions));
+}
+
+/// An editable field: its placeholder names it, `value` is the text.
+fn input(role: AccessRole, placeholder: &str, value: String) -> AccessNode {
+  AccessNode {
+    label: (!placeholder.is_empty()).then(|| placeholder.to_string()),
+    value: Some(value),
+    ..AccessNode::new(role)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ui::parse_ui;
+
+  #[test]
+  fn test_describes_common_widgets() {
+    let mut root = parse_ui(r#"
+      Container(
+        layout: (direction: Column),
+        children: [
+          Container(
+            id: Some("form"),
+            layout: (direction: Column),
+            children: [
+              TextInput(id: "name", placeholder: "Name", value: "Ada", width: 200.0, height: 30.0),
+              Checkbox(id: "terms", checked: true),
+              Button(text: "Save", action: "save", disabled: true, bounds: (x: 0.0, y: 0.0, width: 80.0, height: 30.0)),
+            ],
+          ),
+          ListView(id: "fruit", items: ["Apple", "Pear"], selected_index: Some(1), height: Some(100.0)),
+        ],
+      )
+    "#).unwrap();
+    crate::layout_engine::compute_layout(&mut root, 0.0, 0.0, 400.0, 400.0);
+    let mut state = InteractionState::default();
+    state.set_focus(Some("name".to_string()));
+
+    let tree = accessibility_tree(&root, Some(&state), None);
+    // The anonymous root container is not described.
+    let roles: Vec<AccessRole> = tree.children.iter().map(|n| n.role).collect();
+    assert_eq!(roles, vec![AccessRole::Group, AccessRole::List]);
+
+    let form = &tree.children[0];
+    assert_eq!(form.id.as_deref(), Some("form"));
+    let name = &form.children[0];
+    assert_eq!((name.role, name.label.as_deref(), name.value.as_deref()), (AccessRole::TextInput, Some("Name"), Some("Ada")));
+    assert!(name.focused);
+    assert_eq!((form.children[1].role, form.children[1].checked), (AccessRole::CheckBox, Some(true)));
+    assert_eq!((form.children[2].role, form.children[2].disabled), (AccessRole::Button, true));
+
+    let list = &tree.children[1];
+    let rows: Vec<(Option<&str>, Option<bool>)> = list.children.iter().map(|n| (n.label.as_deref(), n.selected)).collect();
+    assert_eq!(rows, vec![(Some("Apple"), Some(false)), (Some("Pear"), Some(true))]);
+    // Rows sit below the form, in absolute coordinates.
+    let list_y = list.bounds.unwrap().y;
+    assert!(list_y > 0.0);
+    assert_eq!(list.children[0].bounds.unwrap().y, list_y);
+  }
+
+  #[test]
+  fn test_datagrid_describes_visible_rows_and_cells() {
+    use crate::data_source::{CellValue, MapDataProvider, VecDataSource};
+    let root = parse_ui(r#"
+      DataGrid(
+        id: Some("log"),
+        columns: [(header: "Line", field: "line"), (header: "Text", field: "text")],
+        data_source_id: Some("log"),
+        header_height: 20.0,
+        row_height: 10.0,
+        selected_rows: [3],
+        bounds: (x: 0.0, y: 0.0, width: 200.0, height: 50.0),
+      )
+    "#).unwrap();
+    let rows = (0..10).map(|i| vec![CellValue::Integer(i), CellValue::Text(format!("line {i}"))]).collect();
+    let mut provider = MapDataProvider::new();
+    provider.register("log", VecDataSource::new(vec!["line".into(), "text".into()], rows));
+    let mut state = InteractionState::default();
+    state.handle_scroll("log", Vec2::new(0.0, -25.0));
+
+    // Without data only the headers are described.
+    let tree = accessibility_tree(&root, Some(&state), None);
+    assert!(tree.children[0].children.iter().all(|n| n.role == AccessRole::ColumnHeader));
+
+    let tree = accessibility_tree(&root, Some(&state), Some(&provider));
+    let grid = &tree.children[0];
+    let rows: Vec<&AccessNode> = grid.children.iter().filter(|n| n.role == AccessRole::Row).collect();
+    // 30px of body scrolled by 25px shows rows 2 through 5.
+    let first_cells: Vec<Option<&str>> = rows.iter().map(|row| row.children[0].label.as_deref()).collect();
+    assert_eq!(first_cells, vec![Some("2"), Some("3"), Some("4"), Some("5")]);
+    assert_eq!(rows.iter().map(|row| row.selected).collect::<Vec<_>>(), vec![Some(false), Some(true), Some(false), Some(false)]);
+
+    let cells = &rows[1].children;
+    assert_eq!(cells.iter().map(|c| c.role).collect::<Vec<_>>(), vec![AccessRole::Cell, AccessRole::Cell]);
+    assert_eq!(cells[1].label.as_deref(), Some("line 3"));
+    let bounds = cells[1].bounds.unwrap();
+    assert_eq!(bounds.y, 25.0);
+    assert_eq!(bounds.x, cells[0].bounds.unwrap().width);
+  }
+
+  #[test]
+  fn test_live_region_announces_changes() {
+    let results = |text: &str| parse_ui(&format!(r#"
+      Container(children: [
+        Container(id: Some("status"), live_region: Some(Polite), children: [Label(text: "{text}")]),
+      ])
+    "#)).unwrap();
+    let mut state = InteractionState::default();
+
+    // Initial content is not read out.
+    update_live_regions(&results("Searching"), &mut state);
+    assert!(state.take_announcements().is_empty());
+
+    update_live_regions(&results("3 results found"), &mut state);
+    update_live_regions(&results("3 results found"), &mut state);
+    assert_eq!(
+      state.take_announcements(),
+      vec![Announcement { message: "3 results found".to_string(), priority: LiveRegion::Polite }]
+    );
+  }
+}
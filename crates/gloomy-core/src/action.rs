@@ -0,0 +1,506 @@
+//! Structured actions parsed from hit-test action strings.
+//!
+//! `hit_test` reports actions as strings such as `"grid:cell:3:1"` or
+//! `"picker:day:2024-05-01"`. [`Action::parse`] turns them into a typed
+//! enum so apps can pattern-match instead of splitting strings by hand.
+//! The raw string stays available on [`HitTestResult::action`].
+//!
+//! [`collect_actions`] lists the actions a tree offers, e.g. to fill a
+//! command palette.
+//!
+//! [`HitTestResult::action`]: crate::interaction::HitTestResult::action
+
+use crate::widget::Widget;
+use chrono::{NaiveDate, NaiveTime};
+use std::fmt;
+
+/// A parsed widget action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// DataGrid row click (`{grid}:row:{row}`).
+    RowClick { grid: String, row: usize },
+    /// DataGrid cell click (`{grid}:cell:{row}:{col}`).
+    CellClick { grid: String, row: usize, col: usize },
+    /// DataGrid header click, used for sorting (`{grid}:header:{col}`).
+    HeaderSort { grid: String, col: usize },
+    /// DataGrid column resize handle (`{grid}:header_resize:{col}`).
+    HeaderResize { grid: String, col: usize },
+    /// DatePicker day cell (`{picker}:day:{YYYY-MM-DD}`).
+    DaySelect { picker: String, date: NaiveDate },
+    /// TimePicker overlay cell (`{picker}:time:{HH:MM}`).
+    TimeSelect { picker: String, time: NaiveTime },
+    /// DatePicker previous month arrow (`{picker}:prev`).
+    PrevMonth { picker: String },
+    /// DatePicker next month arrow (`{picker}:next`).
+    NextMonth { picker: String },
+    /// Tab header (`{tabs}:tab:{index}`).
+    TabSelect { tabs: String, index: usize },
+    /// Tab header chevrons (`{tabs}:tabs_prev` / `{tabs}:tabs_next`).
+    TabScroll { tabs: String, forward: bool },
+    /// Autocomplete suggestion (`{id}:opt:{index}`). Dropdown options use
+    /// `select_{id}_{index}`, which is ambiguous for ids with underscores,
+    /// and parse as [`Action::Other`].
+    OptionSelect { id: String, index: usize },
+    /// NumberInput up spinner (`{id}:up`).
+    Increment { id: String },
+    /// NumberInput down spinner (`{id}:down`).
+    Decrement { id: String },
+    /// Tree expand/collapse arrow (`{tree}:toggle:{node}`).
+    TreeToggle { tree: String, node: String },
+    /// Tree row selection (`{tree}:select:{node}`).
+    TreeSelect { tree: String, node: String },
+    /// Stepper step circle (`{stepper}:step:{index}`).
+    StepSelect { stepper: String, index: usize },
+    /// Rating star (`{rating}:star:{index}`, or `{rating}:half_star:{index}`
+    /// for the left half of a star).
+    RatingSelect { rating: String, index: usize, half: bool },
+    /// Breadcrumb segment (`{breadcrumb}:crumb:{index}`).
+    CrumbSelect { breadcrumb: String, index: usize },
+    /// ChipInput chip remove button (`{id}:remove:{index}`).
+    ChipRemove { id: String, index: usize },
+    /// ListView or VirtualFlow item (`{list}:{index}`).
+    ListItem { list: String, index: usize },
+    /// ListView row checkbox (`{list}:check:{index}`).
+    ListCheck { list: String, index: usize },
+    /// ListView selection changed (`{list}:selection`).
+    SelectionChange { list: String },
+    /// Scrollbar thumb of a Container, ListView or DataGrid
+    /// (`{id}:scrollthumb`).
+    ScrollThumb { id: String },
+    /// Scrollbar track beside the thumb (`{id}:scrolltrack`).
+    ScrollTrack { id: String },
+    /// Anything else, e.g. a plain button action or widget id.
+    Other(String),
+}
+
+impl Action {
+    /// Parses a raw action string.
+    ///
+    /// Owner ids may themselves contain `:`; the suffix is matched from the
+    /// right. Strings that don't match a known format become
+    /// [`Action::Other`].
+    pub fn parse(raw: &str) -> Action {
+        let parts: Vec<&str> = raw.split(':').collect();
+        let n = parts.len();
+
+        if n >= 4 && parts[n - 3] == "cell" {
+            if let (Ok(row), Ok(col)) = (parts[n - 2].parse(), parts[n - 1].parse()) {
+                let grid = parts[..n - 3].join(":");
+                return Action::CellClick { grid, row, col };
+            }
+        }
+
+        // Checked before the generic suffixes: the time itself contains a
+        // colon, so "09:30" would otherwise read as a ListView index.
+        if n >= 4 && parts[n - 3] == "time" {
+            let time = format!("{}:{}", parts[n - 2], parts[n - 1]);
+            if let Ok(time) = NaiveTime::parse_from_str(&time, "%H:%M") {
+                let picker = parts[..n - 3].join(":");
+                return Action::TimeSelect { picker, time };
+            }
+        }
+
+        if n >= 3 {
+            let owner = parts[..n - 2].join(":");
+            let arg = parts[n - 1];
+            let index = arg.parse::<usize>().ok();
+            let parsed = match (parts[n - 2], index) {
+                ("row", Some(row)) => Some(Action::RowClick { grid: owner, row }),
+                ("header", Some(col)) => Some(Action::HeaderSort { grid: owner, col }),
+                ("header_resize", Some(col)) => {
+                    Some(Action::HeaderResize { grid: owner, col })
+                }
+                ("tab", Some(index)) => Some(Action::TabSelect { tabs: owner, index }),
+                ("opt", Some(index)) => Some(Action::OptionSelect { id: owner, index }),
+                ("step", Some(index)) => Some(Action::StepSelect { stepper: owner, index }),
+                ("crumb", Some(index)) => Some(Action::CrumbSelect { breadcrumb: owner, index }),
+                ("remove", Some(index)) => Some(Action::ChipRemove { id: owner, index }),
+                ("check", Some(index)) => Some(Action::ListCheck { list: owner, index }),
+                ("star", Some(index)) => Some(Action::RatingSelect { rating: owner, index, half: false }),
+                ("half_star", Some(index)) => {
+                    Some(Action::RatingSelect { rating: owner, index, half: true })
+                }
+                ("day", _) => NaiveDate::parse_from_str(arg, "%Y-%m-%d")
+                    .ok()
+                    .map(|date| Action::DaySelect { picker: owner, date }),
+                ("toggle", _) => Some(Action::TreeToggle {
+                    tree: owner,
+                    node: arg.to_string(),
+                }),
+                ("select", _) => Some(Action::TreeSelect {
+                    tree: owner,
+                    node: arg.to_string(),
+                }),
+                _ => None,
+            };
+            if let Some(action) = parsed {
+                return action;
+            }
+        }
+
+        if n >= 2 {
+            let owner = parts[..n - 1].join(":");
+            match parts[n - 1] {
+                "prev" => return Action::PrevMonth { picker: owner },
+                "next" => return Action::NextMonth { picker: owner },
+                "up" => return Action::Increment { id: owner },
+                "down" => return Action::Decrement { id: owner },
+                "tabs_prev" => return Action::TabScroll { tabs: owner, forward: false },
+                "tabs_next" => return Action::TabScroll { tabs: owner, forward: true },
+                "selection" => return Action::SelectionChange { list: owner },
+                "scrollthumb" => return Action::ScrollThumb { id: owner },
+                "scrolltrack" => return Action::ScrollTrack { id: owner },
+                last => {
+                    if let Ok(index) = last.parse() {
+                        return Action::ListItem { list: owner, index };
+                    }
+                }
+            }
+        }
+
+        Action::Other(raw.to_string())
+    }
+
+    /// Returns the id of the widget that produced the action.
+    pub fn widget_id(&self) -> &str {
+        match self {
+            Action::RowClick { grid, .. }
+            | Action::CellClick { grid, .. }
+            | Action::HeaderSort { grid, .. }
+            | Action::HeaderResize { grid, .. } => grid,
+            Action::DaySelect { picker, .. }
+            | Action::TimeSelect { picker, .. }
+            | Action::PrevMonth { picker }
+            | Action::NextMonth { picker } => picker,
+            Action::TabSelect { tabs, .. } | Action::TabScroll { tabs, .. } => tabs,
+            Action::OptionSelect { id, .. }
+            | Action::ChipRemove { id, .. }
+            | Action::Increment { id }
+            | Action::Decrement { id }
+            | Action::ScrollThumb { id }
+            | Action::ScrollTrack { id } => id,
+            Action::TreeToggle { tree, .. } | Action::TreeSelect { tree, .. } => tree,
+            Action::StepSelect { stepper, .. } => stepper,
+            Action::CrumbSelect { breadcrumb, .. } => breadcrumb,
+            Action::RatingSelect { rating, .. } => rating,
+            Action::ListItem { list, .. }
+            | Action::ListCheck { list, .. }
+            | Action::SelectionChange { list } => list,
+            Action::Other(raw) => raw,
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    /// Formats the action back into its raw string form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::RowClick { grid, row } => write!(f, "{}:row:{}", grid, row),
+            Action::CellClick { grid, row, col } => {
+                write!(f, "{}:cell:{}:{}", grid, row, col)
+            }
+            Action::HeaderSort { grid, col } => write!(f, "{}:header:{}", grid, col),
+            Action::HeaderResize { grid, col } => {
+                write!(f, "{}:header_resize:{}", grid, col)
+            }
+            Action::DaySelect { picker, date } => {
+                write!(f, "{}:day:{}", picker, date.format("%Y-%m-%d"))
+            }
+            Action::TimeSelect { picker, time } => {
+                write!(f, "{}:time:{}", picker, time.format("%H:%M"))
+            }
+            Action::PrevMonth { picker } => write!(f, "{}:prev", picker),
+            Action::NextMonth { picker } => write!(f, "{}:next", picker),
+            Action::TabSelect { tabs, index } => write!(f, "{}:tab:{}", tabs, index),
+            Action::TabScroll { tabs, forward } => {
+                write!(f, "{}:{}", tabs, if *forward { "tabs_next" } else { "tabs_prev" })
+            }
+            Action::OptionSelect { id, index } => write!(f, "{}:opt:{}", id, index),
+            Action::Increment { id } => write!(f, "{}:up", id),
+            Action::Decrement { id } => write!(f, "{}:down", id),
+            Action::TreeToggle { tree, node } => write!(f, "{}:toggle:{}", tree, node),
+            Action::TreeSelect { tree, node } => write!(f, "{}:select:{}", tree, node),
+            Action::StepSelect { stepper, index } => write!(f, "{}:step:{}", stepper, index),
+            Action::CrumbSelect { breadcrumb, index } => {
+                write!(f, "{}:crumb:{}", breadcrumb, index)
+            }
+            Action::RatingSelect { rating, index, half } => {
+                write!(f, "{}:{}:{}", rating, if *half { "half_star" } else { "star" }, index)
+            }
+            Action::ChipRemove { id, index } => write!(f, "{}:remove:{}", id, index),
+            Action::ListItem { list, index } => write!(f, "{}:{}", list, index),
+            Action::ListCheck { list, index } => write!(f, "{}:check:{}", list, index),
+            Action::SelectionChange { list } => write!(f, "{}:selection", list),
+            Action::ScrollThumb { id } => write!(f, "{}:scrollthumb", id),
+            Action::ScrollTrack { id } => write!(f, "{}:scrolltrack", id),
+            Action::Other(raw) => f.write_str(raw),
+        }
+    }
+}
+
+/// Receives parsed actions.
+///
+/// Implement this on app state and feed it the raw strings from
+/// `InteractionState::triggered_action` or a `HitTestResult`.
+pub trait ActionHandler {
+    /// Handles a parsed action.
+    fn handle_action(&mut self, action: Action);
+
+    /// Parses `raw` and forwards it to [`ActionHandler::handle_action`].
+    fn dispatch(&mut self, raw: &str) {
+        self.handle_action(Action::parse(raw));
+    }
+}
+
+/// An action a user can trigger, as listed by [`collect_actions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionDescriptor {
+    /// The action string `hit_test` reports. For templated actions it holds
+    /// `{index}` or `{row}`/`{col}` placeholders, see [`ActionTemplate`].
+    pub action: String,
+    /// Human-readable label, e.g. a button's text or a column header.
+    pub label: String,
+    /// Set when `action` stands for a family of per-item actions.
+    pub template: Option<ActionTemplate>,
+}
+
+/// How the placeholders of a templated [`ActionDescriptor`] are filled.
+///
+/// Rows of lists and grids are not enumerated one by one: a list with a
+/// million items is a single descriptor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionTemplate {
+    /// `{index}` ranges over `0..count` (ListView and VirtualFlow items).
+    Index { count: usize },
+    /// `{row}` ranges over the rows of the grid's data source and `{col}`
+    /// over `0..columns` (DataGrid cells).
+    Cell { columns: usize },
+}
+
+impl ActionDescriptor {
+    fn new(action: impl Into<String>, label: impl Into<String>) -> Self {
+        ActionDescriptor { action: action.into(), label: label.into(), template: None }
+    }
+
+    /// Fills the `{index}` placeholder.
+    pub fn with_index(&self, index: usize) -> String {
+        self.action.replace("{index}", &index.to_string())
+    }
+
+    /// Fills the `{row}` and `{col}` placeholders.
+    pub fn with_cell(&self, row: usize, col: usize) -> String {
+        self.action.replace("{row}", &row.to_string()).replace("{col}", &col.to_string())
+    }
+}
+
+/// Lists every action a user could trigger in `root`, in tree order.
+///
+/// Content that is currently out of reach is included, so the list suits a
+/// command palette: the contents of every tab, all tree nodes, dropdown
+/// options and menu items. Disabled buttons and menu items are left out,
+/// as are steps a Stepper won't let the user jump to. Per-row actions of
+/// ListView, VirtualFlow and DataGrid are returned as one templated
+/// descriptor each, see [`ActionTemplate`].
+pub fn collect_actions(root: &Widget) -> Vec<ActionDescriptor> {
+    let mut out = Vec::new();
+    collect(root, &mut out);
+    out
+}
+
+fn collect(widget: &Widget, out: &mut Vec<ActionDescriptor>) {
+    match widget {
+        Widget::Container { children, .. } => {
+            for child in children {
+                collect(child, out);
+            }
+        }
+        Widget::Button { text, action, disabled: false, .. } => {
+            out.push(ActionDescriptor::new(action.clone(), text.clone()));
+        }
+        Widget::Checkbox { id, .. } | Widget::ToggleSwitch { id, .. } => {
+            out.push(ActionDescriptor::new(id.clone(), id.clone()));
+        }
+        Widget::RadioButton { value, label, .. } => {
+            out.push(ActionDescriptor::new(value.clone(), label.clone()));
+        }
+        Widget::NumberInput { id, show_spinner: true, .. } => {
+            out.push(ActionDescriptor::new(format!("{}:up", id), format!("Increase {}", id)));
+            out.push(ActionDescriptor::new(format!("{}:down", id), format!("Decrease {}", id)));
+        }
+        Widget::Dropdown { id, options, .. } => {
+            for (i, option) in options.iter().enumerate() {
+                out.push(ActionDescriptor::new(format!("select_{}_{}", id, i), option.clone()));
+            }
+        }
+        Widget::Tab { id, tabs, .. } => {
+            for (i, tab) in tabs.iter().enumerate() {
+                if let Some(id) = id {
+                    out.push(ActionDescriptor::new(format!("{}:tab:{}", id, i), tab.title.clone()));
+                }
+                collect(&tab.content, out);
+            }
+        }
+        Widget::Tree { id, root_nodes, .. } => {
+            fn tree_actions(tree: &str, list: &[crate::tree::TreeNode], out: &mut Vec<ActionDescriptor>) {
+                for node in list {
+                    out.push(ActionDescriptor::new(format!("{}:select:{}", tree, node.id), node.label.clone()));
+                    if !node.leaf && !node.children.is_empty() {
+                        out.push(ActionDescriptor::new(
+                            format!("{}:toggle:{}", tree, node.id),
+                            format!("Expand {}", node.label),
+                        ));
+                        tree_actions(tree, &node.children, out);
+                    }
+                }
+            }
+            tree_actions(id.as_deref().unwrap_or("tree"), root_nodes, out);
+        }
+        Widget::DataGrid { id: Some(id), columns, .. } => {
+            for (i, col) in columns.iter().enumerate() {
+                out.push(ActionDescriptor::new(format!("{}:header:{}", id, i), col.header.clone()));
+            }
+            out.push(ActionDescriptor {
+                template: Some(ActionTemplate::Cell { columns: columns.len() }),
+                ..ActionDescriptor::new(format!("{}:cell:{{row}}:{{col}}", id), id.clone())
+            });
+        }
+        Widget::ListView { id, items, item_widgets, .. } => {
+            let count = if item_widgets.is_empty() { items.len() } else { item_widgets.len() };
+            out.push(ActionDescriptor {
+                template: Some(ActionTemplate::Index { count }),
+                ..ActionDescriptor::new(format!("{}:{{index}}", id), id.clone())
+            });
+            for row in item_widgets {
+                collect(row, out);
+            }
+        }
+        Widget::VirtualFlow { id, item_count, .. } => {
+            out.push(ActionDescriptor {
+                template: Some(ActionTemplate::Index { count: *item_count }),
+                ..ActionDescriptor::new(format!("{}:{{index}}", id), id.clone())
+            });
+        }
+        Widget::MenuBar { id, menus, .. } => {
+            for (i, menu) in menus.iter().enumerate() {
+                out.push(ActionDescriptor::new(format!("{}:menu:{}", id, i), menu.label.clone()));
+                for item in &menu.items {
+                    if let crate::menu::MenuItem::Item { label, action, disabled: false, .. } = item {
+                        out.push(ActionDescriptor::new(action.clone(), format!("{} > {}", menu.label, label)));
+                    }
+                }
+            }
+        }
+        Widget::Breadcrumb { id, segments, .. } => {
+            for (i, segment) in segments.iter().enumerate() {
+                out.push(ActionDescriptor::new(format!("{}:crumb:{}", id, i), segment.clone()));
+            }
+        }
+        Widget::Stepper { id, steps, current, allow_skip, .. } => {
+            for (i, step) in steps.iter().enumerate() {
+                if i <= *current || *allow_skip {
+                    out.push(ActionDescriptor::new(format!("{}:step:{}", id, i), step.clone()));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_grid_actions() {
+        assert_eq!(
+            Action::parse("main_grid:row:3"),
+            Action::RowClick { grid: "main_grid".into(), row: 3 }
+        );
+        assert_eq!(
+            Action::parse("main_grid:cell:2:5"),
+            Action::CellClick { grid: "main_grid".into(), row: 2, col: 5 }
+        );
+        assert_eq!(
+            Action::parse("main_grid:header_resize:1"),
+            Action::HeaderResize { grid: "main_grid".into(), col: 1 }
+        );
+    }
+
+    #[test]
+    fn test_parse_day_and_ids_with_colons() {
+        assert_eq!(
+            Action::parse("form:dob:day:2024-02-29"),
+            Action::DaySelect {
+                picker: "form:dob".into(),
+                date: NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+            }
+        );
+        assert_eq!(
+            Action::parse("form:alarm:time:07:45"),
+            Action::TimeSelect {
+                picker: "form:alarm".into(),
+                time: NaiveTime::from_hms_opt(7, 45, 0).unwrap(),
+            }
+        );
+        // Invalid date falls through to a catch-all.
+        assert_eq!(
+            Action::parse("dob:day:2024-02-30"),
+            Action::Other("dob:day:2024-02-30".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_other_and_round_trip() {
+        assert_eq!(Action::parse("save"), Action::Other("save".into()));
+        for raw in [
+            "qty:up", "files:3", "t:toggle:n1", "tabs:tab:0", "dd:opt:2", "wiz:step:1",
+            "path:crumb:2", "tabs:tabs_next", "alarm:time:07:45", "review:star:4",
+            "review:half_star:2", "tags:remove:0", "files:check:1", "files:selection",
+            "files:scrollthumb", "grid:scrolltrack",
+        ] {
+            assert_eq!(Action::parse(raw).to_string(), raw);
+        }
+    }
+
+    #[test]
+    fn test_collect_actions() {
+        let root = crate::ui::parse_ui(r#"
+            Container(children: [
+                Button(text: "Save", action: "save"),
+                Button(text: "Delete", action: "delete", disabled: true),
+                Tab(id: Some("tabs"), tabs: [
+                    (title: "Files", content: ListView(id: "files", items: ["a", "b", "c"])),
+                    (title: "Data", content: DataGrid(
+                        id: Some("grid"),
+                        columns: [(header: "Name", field: "name"), (header: "Size", field: "size")],
+                    )),
+                ]),
+                Tree(id: Some("nav"), root_nodes: [
+                    (id: "src", label: "src", icon: None, children: [(id: "main", label: "main.rs", icon: None)]),
+                ]),
+            ])
+        "#).unwrap();
+
+        let actions = collect_actions(&root);
+        let pairs: Vec<(&str, &str)> = actions.iter().map(|a| (a.action.as_str(), a.label.as_str())).collect();
+        assert_eq!(pairs, vec![
+            ("save", "Save"),
+            ("tabs:tab:0", "Files"),
+            ("files:{index}", "files"),
+            ("tabs:tab:1", "Data"),
+            ("grid:header:0", "Name"),
+            ("grid:header:1", "Size"),
+            ("grid:cell:{row}:{col}", "grid"),
+            ("nav:select:src", "src"),
+            ("nav:toggle:src", "Expand src"),
+            ("nav:select:main", "main.rs"),
+        ]);
+
+        let files = &actions[2];
+        assert_eq!(files.template, Some(ActionTemplate::Index { count: 3 }));
+        assert_eq!(Action::parse(&files.with_index(2)), Action::ListItem { list: "files".into(), index: 2 });
+        let cells = &actions[6];
+        assert_eq!(cells.template, Some(ActionTemplate::Cell { columns: 2 }));
+        assert_eq!(Action::parse(&cells.with_cell(40, 1)), Action::CellClick { grid: "grid".into(), row: 40, col: 1 });
+    }
+}
@@ -0,0 +1,214 @@
+//! Declarative value bindings.
+//!
+//! A widget with `bind: Some(key)` shows the value stored under `key` in
+//! a [`ValueStore`] instead of having the app copy it in every frame.
+//! Labels bind their `text`; TextInput, NumberInput, Checkbox,
+//! ToggleSwitch and Slider bind their value and write edits back.
+//!
+//! Each frame:
+//!
+//! 1. After input has been handled, [`commit_bindings`] copies edited
+//!    input values into the store. Edits win over the store.
+//! 2. The app reads and updates the store as it likes.
+//! 3. Before layout, [`resolve_bindings`] copies store values into the
+//!    bound widgets, so label text is current when it is measured.
+//!
+//! Keys missing from the store leave the widget as it is, and widgets
+//! without `bind` are never touched.
+
+use crate::widget::Widget;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A bound value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+}
+
+impl Value {
+    /// The value as a number; text is parsed and booleans are 0 or 1.
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Value::Text(text) => text.trim().parse().ok(),
+            Value::Number(n) => Some(*n),
+            Value::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
+        }
+    }
+
+    /// The value as a boolean; numbers are true when non-zero and text
+    /// when it reads "true".
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Text(text) => text.trim().parse().ok(),
+            Value::Number(n) => Some(*n != 0.0),
+            Value::Bool(b) => Some(*b),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Text(text) => f.write_str(text),
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Bool(b) => write!(f, "{}", b),
+        }
+    }
+}
+
+/// Source and sink of bound values, keyed by the widgets' `bind`.
+pub trait ValueStore {
+    fn get(&self, key: &str) -> Option<Value>;
+    fn set(&mut self, key: &str, value: Value);
+}
+
+/// Simple HashMap-based store.
+impl ValueStore for HashMap<String, Value> {
+    fn get(&self, key: &str) -> Option<Value> {
+        HashMap::get(self, key).cloned()
+    }
+
+    fn set(&mut self, key: &str, value: Value) {
+        self.insert(key.to_string(), value);
+    }
+}
+
+/// Copies store values into bound widgets.
+///
+/// Returns true if any widget changed. The tree is then marked dirty so
+/// the next layout re-measures the new text.
+pub fn resolve_bindings(root: &mut Widget, store: &dyn ValueStore) -> bool {
+    let changed = resolve_widget(root, store);
+    if changed {
+        root.mark_dirty();
+    }
+    changed
+}
+
+fn resolve_widget(widget: &mut Widget, store: &dyn ValueStore) -> bool {
+    let mut changed = false;
+    widget.for_each_child_mut(&mut |child| changed |= resolve_widget(child, store));
+
+    let value = match widget {
+        Widget::Label { bind: Some(key), .. }
+        | Widget::TextInput { bind: Some(key), .. }
+        | Widget::NumberInput { bind: Some(key), .. }
+        | Widget::Checkbox { bind: Some(key), .. }
+        | Widget::ToggleSwitch { bind: Some(key), .. }
+        | Widget::Slider { bind: Some(key), .. } => store.get(key),
+        _ => None,
+    };
+    match value {
+        Some(value) => apply(widget, &value) | changed,
+        None => changed,
+    }
+}
+
+/// Writes `value` into a bound widget, converting as needed. Returns
+/// true if the widget changed.
+fn apply(widget: &mut Widget, value: &Value) -> bool {
+    fn assign<T: PartialEq>(slot: &mut T, new: Option<T>) -> bool {
+        match new {
+            Some(new) if *slot != new => {
+                *slot = new;
+                true
+            }
+            _ => false,
+        }
+    }
+    match widget {
+        Widget::Label { text, .. } | Widget::TextInput { value: text, .. } => {
+            assign(text, Some(value.to_string()))
+        }
+        Widget::NumberInput { value: number, .. } => assign(number, value.as_number()),
+        Widget::Slider { value: number, .. } => assign(number, value.as_number().map(|n| n as f32)),
+        Widget::Checkbox { checked, .. } | Widget::ToggleSwitch { checked, .. } => {
+            assign(checked, value.as_bool())
+        }
+        _ => false,
+    }
+}
+
+/// Copies the values of bound inputs into the store where they differ.
+///
+/// Labels are read-only and never committed. Returns true if the store
+/// changed.
+pub fn commit_bindings(root: &Widget, store: &mut dyn ValueStore) -> bool {
+    let mut changed = false;
+    root.for_each_child(&mut |child| changed |= commit_bindings(child, store));
+
+    let (key, value) = match root {
+        Widget::TextInput { bind: Some(key), value, .. } => (key, Value::Text(value.clone())),
+        Widget::NumberInput { bind: Some(key), value, .. } => (key, Value::Number(*value)),
+        Widget::Slider { bind: Some(key), value, .. } => (key, Value::Number(*value as f64)),
+        Widget::Checkbox { bind: Some(key), checked, .. }
+        | Widget::ToggleSwitch { bind: Some(key), checked, .. } => (key, Value::Bool(*checked)),
+        _ => return changed,
+    };
+    if store.get(key).as_ref() == Some(&value) {
+        return changed;
+    }
+    store.set(key, value);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::parse_ui;
+
+    const FORM: &str = r#"
+        Container(
+            children: [
+                Label(text: "static"),
+                Label(bind: Some("greeting"), text: ""),
+                TextInput(id: "name", bind: Some("name"), value: "Ada"),
+                Checkbox(id: "agree", bind: Some("agree")),
+                Slider(id: "volume", bind: Some("volume"), value: 0.5),
+            ],
+        )
+    "#;
+
+    fn children(root: &Widget) -> &[Widget] {
+        match root {
+            Widget::Container { children, .. } => children,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_fills_bound_widgets_only() {
+        let mut root = parse_ui(FORM).unwrap();
+        let mut store: HashMap<String, Value> = HashMap::new();
+        store.set("greeting", Value::Text("Hello".into()));
+        store.set("agree", Value::Bool(true));
+        store.set("volume", Value::Text("0.8".into()));
+
+        assert!(resolve_bindings(&mut root, &store));
+        let c = children(&root);
+        assert!(matches!(&c[0], Widget::Label { text, .. } if text == "static"));
+        assert!(matches!(&c[1], Widget::Label { text, .. } if text == "Hello"));
+        // No "name" in the store: the input keeps its value.
+        assert!(matches!(&c[2], Widget::TextInput { value, .. } if value == "Ada"));
+        assert!(matches!(&c[3], Widget::Checkbox { checked: true, .. }));
+        assert!(matches!(&c[4], Widget::Slider { value, .. } if (*value - 0.8).abs() < 1e-6));
+
+        assert!(!resolve_bindings(&mut root, &store));
+    }
+
+    #[test]
+    fn test_commit_writes_edits_back() {
+        let root = parse_ui(FORM).unwrap();
+        let mut store: HashMap<String, Value> = HashMap::new();
+        assert!(commit_bindings(&root, &mut store));
+        assert_eq!(store.get("name"), Some(&Value::Text("Ada".into())));
+        assert_eq!(store.get("agree"), Some(&Value::Bool(false)));
+        // Labels are read-only.
+        assert!(!store.contains_key("greeting"));
+        // Nothing changed since the last commit.
+        assert!(!commit_bindings(&root, &mut store));
+    }
+}
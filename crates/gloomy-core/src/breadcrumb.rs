@@ -0,0 +1,136 @@
+//! Breadcrumb segment layout.
+//!
+//! Segment widths come from text measurement, which only happens at render
+//! time. The renderer lays the trail out with [`layout_crumbs`] and stores
+//! the resulting slots on the widget so hit testing can reuse them.
+
+/// What occupies a slot in the breadcrumb trail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrumbPart {
+    /// The segment at this index.
+    Segment(usize),
+    /// Separator between two visible parts.
+    Separator,
+    /// Stand-in for the collapsed middle segments.
+    Ellipsis,
+}
+
+/// A laid-out part, positioned relative to the widget's left edge.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrumbSlot {
+    pub part: CrumbPart,
+    pub x: f32,
+    pub width: f32,
+}
+
+/// Lays out segments left to right with separators between them.
+///
+/// When the full trail is wider than `available`, middle segments collapse
+/// into a single ellipsis. The first segment and as many trailing segments
+/// as fit are kept; the last segment is always shown, even if it alone
+/// overflows (the renderer clips it). Empty input yields no slots.
+pub fn layout_crumbs(
+    widths: &[f32],
+    separator_width: f32,
+    ellipsis_width: f32,
+    available: f32,
+) -> Vec<CrumbSlot> {
+    let n = widths.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let full: f32 = widths.iter().sum::<f32>() + separator_width * (n - 1) as f32;
+    let visible: Vec<CrumbPart> = if full <= available || n == 1 {
+        (0..n).map(CrumbPart::Segment).collect()
+    } else {
+        // Keep the last segment, then add trailing segments while the
+        // first segment and the ellipsis still fit in front of them.
+        let head = widths[0] + ellipsis_width + separator_width * 2.0;
+        let mut tail_start = n - 1;
+        let mut tail_width = widths[n - 1];
+        while tail_start > 1 {
+            let next = widths[tail_start - 1] + separator_width;
+            if head + tail_width + next > available {
+                break;
+            }
+            tail_width += next;
+            tail_start -= 1;
+        }
+
+        let mut parts = Vec::new();
+        if head + tail_width <= available {
+            parts.push(CrumbPart::Segment(0));
+            parts.push(CrumbPart::Ellipsis);
+        } else {
+            parts.push(CrumbPart::Ellipsis);
+        }
+        parts.extend((tail_start..n).map(CrumbPart::Segment));
+        parts
+    };
+
+    let mut slots = Vec::with_capacity(visible.len() * 2);
+    let mut x = 0.0;
+    for (i, part) in visible.into_iter().enumerate() {
+        if i > 0 {
+            slots.push(CrumbSlot { part: CrumbPart::Separator, x, width: separator_width });
+            x += separator_width;
+        }
+        let width = match part {
+            CrumbPart::Segment(idx) => widths[idx],
+            CrumbPart::Ellipsis => ellipsis_width,
+            CrumbPart::Separator => separator_width,
+        };
+        slots.push(CrumbSlot { part, x, width });
+        x += width;
+    }
+    slots
+}
+
+/// Returns the segment under `x` (relative to the widget's left edge).
+pub fn segment_at(slots: &[CrumbSlot], x: f32) -> Option<usize> {
+    slots.iter().find_map(|slot| match slot.part {
+        CrumbPart::Segment(idx) if x >= slot.x && x <= slot.x + slot.width => Some(idx),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(slots: &[CrumbSlot]) -> Vec<CrumbPart> {
+        slots.iter().map(|s| s.part).filter(|p| *p != CrumbPart::Separator).collect()
+    }
+
+    #[test]
+    fn test_empty_and_single() {
+        assert!(layout_crumbs(&[], 10.0, 10.0, 100.0).is_empty());
+
+        let slots = layout_crumbs(&[500.0], 10.0, 10.0, 100.0);
+        assert_eq!(parts(&slots), vec![CrumbPart::Segment(0)]);
+        assert_eq!(segment_at(&slots, 50.0), Some(0));
+    }
+
+    #[test]
+    fn test_fits_without_collapsing() {
+        let slots = layout_crumbs(&[30.0, 30.0, 30.0], 10.0, 10.0, 110.0);
+        assert_eq!(slots.len(), 5);
+        assert_eq!(segment_at(&slots, 55.0), Some(1));
+        assert_eq!(segment_at(&slots, 35.0), None); // separator
+    }
+
+    #[test]
+    fn test_collapses_middle() {
+        // Full width 4 * 30 + 3 * 10 = 150.
+        let slots = layout_crumbs(&[30.0, 30.0, 30.0, 30.0], 10.0, 10.0, 130.0);
+        assert_eq!(
+            parts(&slots),
+            vec![CrumbPart::Segment(0), CrumbPart::Ellipsis, CrumbPart::Segment(2), CrumbPart::Segment(3)]
+        );
+
+        // Too narrow for the first segment: keep only the tail.
+        let slots = layout_crumbs(&[30.0, 30.0, 30.0, 30.0], 10.0, 10.0, 50.0);
+        assert_eq!(parts(&slots), vec![CrumbPart::Ellipsis, CrumbPart::Segment(3)]);
+    }
+}
@@ -0,0 +1,151 @@
+//! Cell-range selection in DataGrids.
+//!
+//! A grid with `selection_mode: Cells` selects cells instead of rows; the
+//! two are mutually exclusive, and such a grid ignores `selected_rows`.
+//! Apps feed cell clicks (`{grid}:cell:{row}:{col}`) to
+//! [`handle_cell_click`]: a plain click selects one cell and Shift+click
+//! extends a rectangle from it. [`copy_cell_selection`] puts the range on
+//! the clipboard as tab-separated values; call it on Ctrl+C. Only one
+//! range is selected at a time, across all grids; it lives in
+//! [`InteractionState::cell_selection`].
+
+use crate::action::Action;
+use crate::clipboard::ClipboardProvider;
+use crate::data_source::{DataProvider, DataSource};
+use crate::datagrid::SelectionMode;
+use crate::interaction::{CellSelection, InteractionState};
+use crate::widget::Widget;
+use crate::widget_index::find_widget;
+use winit::keyboard::ModifiersState;
+
+/// Applies a cell click to the selection if the cell belongs to a grid in
+/// `Cells` mode.
+///
+/// Shift extends the current range when it is in the same grid; anything
+/// else starts a new one. Returns true if the selection changed.
+pub fn handle_cell_click(
+    root: &Widget,
+    state: &mut InteractionState,
+    action: &str,
+    modifiers: ModifiersState,
+) -> bool {
+    let Action::CellClick { grid, row, col } = Action::parse(action) else {
+        return false;
+    };
+    let Some(Widget::DataGrid { selection_mode: SelectionMode::Cells, .. }) = find_widget(root, &grid) else {
+        return false;
+    };
+    let cell = (row, col);
+    let next = match state.cell_selection.take() {
+        Some(current) if modifiers.shift_key() && current.grid == grid => {
+            CellSelection { focus: cell, ..current }
+        }
+        _ => CellSelection { grid, anchor: cell, focus: cell },
+    };
+    let before = state.cell_selection.replace(next);
+    before != state.cell_selection
+}
+
+/// The cells of `selection` as tab-separated values: one line per row,
+/// cells separated by tabs. Tabs and newlines inside cells become spaces
+/// so the shape survives pasting into a spreadsheet.
+pub fn cells_as_tsv(source: &dyn DataSource, selection: &CellSelection) -> String {
+    let rows = selection.rows();
+    let last_row = (*rows.end()).min(source.row_count().saturating_sub(1));
+    let last_col = (*selection.cols().end()).min(source.column_count().saturating_sub(1));
+    (*rows.start()..=last_row)
+        .map(|row| {
+            (*selection.cols().start()..=last_col)
+                .map(|col| source.cell_text(row, col).replace(['\t', '\n', '\r'], " "))
+                .collect::<Vec<_>>()
+                .join("\t")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Copies the selected cell range to `clipboard` as TSV.
+///
+/// Returns false if no range is selected or its grid or data source is
+/// gone.
+pub fn copy_cell_selection(
+    root: &Widget,
+    provider: &dyn DataProvider,
+    state: &InteractionState,
+    clipboard: &mut dyn ClipboardProvider,
+) -> bool {
+    let Some(selection) = &state.cell_selection else {
+        return false;
+    };
+    let Some(Widget::DataGrid { data_source_id: Some(source_id), .. }) = find_widget(root, &selection.grid) else {
+        return false;
+    };
+    let Some(source) = provider.get_source(source_id) else {
+        return false;
+    };
+    if source.row_count() == 0 {
+        return false;
+    }
+    clipboard.set_text(&cells_as_tsv(source, selection));
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard::MemoryClipboard;
+    use crate::data_source::{CellValue, MapDataProvider, VecDataSource};
+    use crate::ui::parse_ui;
+
+    fn grid(mode: &str) -> Widget {
+        parse_ui(&format!(r#"
+            DataGrid(
+                id: Some("people"),
+                columns: [(header: "Name", field: "name"), (header: "Age", field: "age"), (header: "City", field: "city")],
+                data_source_id: Some("people"),
+                selection_mode: {},
+            )
+        "#, mode)).unwrap()
+    }
+
+    fn provider() -> MapDataProvider {
+        let mut provider = MapDataProvider::new();
+        provider.register("people", VecDataSource::new(
+            vec!["name".into(), "age".into(), "city".into()],
+            vec![
+                vec![CellValue::Text("Ada".into()), CellValue::Integer(36), CellValue::Text("London".into())],
+                vec![CellValue::Text("Alan".into()), CellValue::Integer(41), CellValue::Text("Wilmslow\tUK".into())],
+                vec![CellValue::Text("Grace".into()), CellValue::Integer(85), CellValue::Text("Arlington".into())],
+            ],
+        ));
+        provider
+    }
+
+    #[test]
+    fn test_shift_click_selects_range_and_copies_tsv() {
+        let root = grid("Cells");
+        let mut state = InteractionState::default();
+        assert!(handle_cell_click(&root, &mut state, "people:cell:2:2", ModifiersState::empty()));
+        assert!(handle_cell_click(&root, &mut state, "people:cell:1:1", ModifiersState::SHIFT));
+        let selection = state.cell_selection.clone().unwrap();
+        assert_eq!(selection.anchor, (2, 2));
+        assert!(selection.contains(1, 2) && !selection.contains(0, 1));
+
+        let mut clipboard = MemoryClipboard::default();
+        assert!(copy_cell_selection(&root, &provider(), &state, &mut clipboard));
+        assert_eq!(clipboard.contents.as_deref(), Some("41\tWilmslow UK\n85\tArlington"));
+
+        // A plain click starts over.
+        assert!(handle_cell_click(&root, &mut state, "people:cell:0:0", ModifiersState::empty()));
+        assert_eq!(state.cell_selection.unwrap().focus, (0, 0));
+    }
+
+    #[test]
+    fn test_row_selection_grids_ignore_cell_clicks() {
+        let root = grid("Multiple");
+        let mut state = InteractionState::default();
+        assert!(!handle_cell_click(&root, &mut state, "people:cell:0:0", ModifiersState::empty()));
+        assert!(state.cell_selection.is_none());
+        assert!(!copy_cell_selection(&root, &provider(), &state, &mut MemoryClipboard::default()));
+    }
+}
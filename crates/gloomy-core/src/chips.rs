@@ -0,0 +1,120 @@
+//! Chip layout for the ChipInput widget.
+//!
+//! Chips flow left to right and wrap onto new rows, followed by the inline
+//! text field. Widths are estimated from the character count (like the
+//! MenuBar titles) so hit testing agrees with rendering without a text
+//! measurer. Positions are relative to the widget's top-left corner.
+
+pub const PADDING: f32 = 4.0;
+pub const GAP: f32 = 4.0;
+/// Width of the "×" remove button at the right end of a chip.
+pub const REMOVE_WIDTH: f32 = 16.0;
+/// Horizontal padding before a chip's label.
+const LABEL_PADDING: f32 = 8.0;
+/// Room kept for the inline field before it wraps to its own row.
+pub const MIN_INPUT_WIDTH: f32 = 60.0;
+
+/// A laid-out chip.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChipRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl ChipRect {
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    /// True if `x` falls on the remove button.
+    pub fn on_remove(&self, x: f32) -> bool {
+        x >= self.x + self.width - REMOVE_WIDTH
+    }
+}
+
+/// Chip positions plus where the inline text field starts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChipLayout {
+    pub chips: Vec<ChipRect>,
+    pub input_x: f32,
+    pub input_y: f32,
+    /// Total height including padding.
+    pub height: f32,
+}
+
+pub fn chip_height(font_size: f32) -> f32 {
+    font_size + 10.0
+}
+
+pub fn chip_width(label: &str, font_size: f32) -> f32 {
+    LABEL_PADDING + label.chars().count() as f32 * font_size * 0.6 + REMOVE_WIDTH
+}
+
+/// Lays out `chips` within `width`, wrapping as needed.
+///
+/// A chip wider than the row gets a row to itself and overflows.
+pub fn layout_chips(chips: &[String], font_size: f32, width: f32) -> ChipLayout {
+    let row_h = chip_height(font_size);
+    let right = width - PADDING;
+    let (mut x, mut y) = (PADDING, PADDING);
+
+    let rects = chips
+        .iter()
+        .map(|label| {
+            let w = chip_width(label, font_size);
+            if x > PADDING && x + w > right {
+                x = PADDING;
+                y += row_h + GAP;
+            }
+            let rect = ChipRect { x, y, width: w, height: row_h };
+            x += w + GAP;
+            rect
+        })
+        .collect();
+
+    if x > PADDING && x + MIN_INPUT_WIDTH > right {
+        x = PADDING;
+        y += row_h + GAP;
+    }
+    ChipLayout { chips: rects, input_x: x, input_y: y, height: y + row_h + PADDING }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chips(labels: &[&str]) -> Vec<String> {
+        labels.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_chips_wrap_and_input_follows() {
+        // "rust" is 8 + 4 * 6 + 16 = 48 wide at font size 10.
+        let layout = layout_chips(&chips(&["rust", "rust", "rust"]), 10.0, 120.0);
+        assert_eq!(layout.chips[0].x, PADDING);
+        assert_eq!(layout.chips[1].x, PADDING + 48.0 + GAP);
+        // The third chip does not fit on the first row.
+        assert_eq!(layout.chips[2].x, PADDING);
+        assert_eq!(layout.chips[2].y, PADDING + 20.0 + GAP);
+        // The input fits after it.
+        assert_eq!(layout.input_y, layout.chips[2].y);
+        assert_eq!(layout.height, layout.chips[2].y + 20.0 + PADDING);
+    }
+
+    #[test]
+    fn test_empty_input_starts_at_padding() {
+        let layout = layout_chips(&[], 14.0, 200.0);
+        assert!(layout.chips.is_empty());
+        assert_eq!((layout.input_x, layout.input_y), (PADDING, PADDING));
+    }
+
+    #[test]
+    fn test_remove_zone() {
+        let layout = layout_chips(&chips(&["rust"]), 10.0, 200.0);
+        let chip = layout.chips[0];
+        assert!(chip.on_remove(chip.x + chip.width - 2.0));
+        assert!(!chip.on_remove(chip.x + 5.0));
+    }
+}
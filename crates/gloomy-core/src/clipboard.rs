@@ -0,0 +1,27 @@
+//! Clipboard access.
+//!
+//! Core has no platform clipboard; apps implement [`ClipboardProvider`]
+//! over whatever their windowing stack offers and pass it to the copy
+//! helpers.
+
+/// Reads and writes the clipboard's text contents.
+pub trait ClipboardProvider {
+    fn get_text(&mut self) -> Option<String>;
+    fn set_text(&mut self, text: &str);
+}
+
+/// In-process clipboard, for tests and headless use.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryClipboard {
+    pub contents: Option<String>,
+}
+
+impl ClipboardProvider for MemoryClipboard {
+    fn get_text(&mut self) -> Option<String> {
+        self.contents.clone()
+    }
+
+    fn set_text(&mut self, text: &str) {
+        self.contents = Some(text.to_string());
+    }
+}
@@ -34,6 +34,12 @@ pub trait DataSource: Send + Sync {
 
     /// Returns a version identifier that changes whenever data is modified.
     /// This allows the UI to skip expensive updates if the data hasn't changed.
+    ///
+    /// The version must increase with every change to the rows or their
+    /// order, including [`sort`](Self::sort), edits and changes made
+    /// outside this trait; a source that keeps a counter bumps it in each
+    /// mutating method. DataGrids pick up changes through
+    /// [`crate::datagrid::sync_datagrids`].
     fn version(&self) -> u64;
 
     /// Gets the cell value as a formatted string.
@@ -49,6 +55,17 @@ pub trait DataSource: Send + Sync {
         // Default implementation does nothing
     }
 
+    /// Sorts by several columns: rows are ordered by the first key, ties
+    /// by the second, and so on.
+    ///
+    /// The default applies [`sort`](Self::sort) for each key from last to
+    /// first, which is correct when `sort` is stable.
+    fn sort_by_keys(&mut self, keys: &[(usize, SortDirection)]) {
+        for &(col, direction) in keys.iter().rev() {
+            self.sort(col, direction);
+        }
+    }
+
     /// Sets a cell value. Returns true if successful.
     fn set_cell(&mut self, _row: usize, _col: usize, _value: CellValue) -> bool {
         false
@@ -219,7 +236,12 @@ impl DataSource for VecDataSource {
     }
 
     fn sort(&mut self, col: usize, direction: SortDirection) {
-        if col >= self.data.len() { return; }
+        self.sort_by_keys(&[(col, direction)]);
+    }
+
+    fn sort_by_keys(&mut self, keys: &[(usize, SortDirection)]) {
+        let keys: Vec<_> = keys.iter().filter(|(col, _)| *col < self.data.len()).collect();
+        if keys.is_empty() { return; }
         
         let row_count = self.row_count();
         if row_count == 0 { return; }
@@ -227,16 +249,19 @@ impl DataSource for VecDataSource {
         // Create indices
         let mut indices: Vec<usize> = (0..row_count).collect();
 
-        // Sort indices based on the specific column
-        let target_col = &self.data[col];
+        // Sort indices by each key in turn until one tells the rows apart
         indices.sort_by(|&a, &b| {
-            let val_a = &target_col[a];
-            let val_b = &target_col[b];
-            let cmp = val_a.partial_cmp(val_b).unwrap_or(std::cmp::Ordering::Equal);
-            match direction {
-                SortDirection::Ascending => cmp,
-                SortDirection::Descending => cmp.reverse(),
-            }
+            keys.iter()
+                .map(|&&(col, direction)| {
+                    let column = &self.data[col];
+                    let cmp = column[a].partial_cmp(&column[b]).unwrap_or(std::cmp::Ordering::Equal);
+                    match direction {
+                        SortDirection::Ascending => cmp,
+                        SortDirection::Descending => cmp.reverse(),
+                    }
+                })
+                .find(|cmp| cmp.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
         });
 
         // Reorder ALL columns based on new indices
@@ -359,4 +384,17 @@ mod tests {
         assert_eq!(ds.cell_text(0, 0), "Alice");
         assert_eq!(ds.cell_text(1, 1), "25");
     }
+
+    #[test]
+    fn test_sort_by_keys_breaks_ties_with_later_keys() {
+        let row = |city: &str, age: i64| vec![CellValue::Text(city.to_string()), CellValue::Integer(age)];
+        let mut ds = VecDataSource::new(
+            vec!["City".to_string(), "Age".to_string()],
+            vec![row("Oslo", 30), row("Bern", 41), row("Oslo", 52), row("Bern", 25)],
+        );
+        ds.sort_by_keys(&[(0, SortDirection::Ascending), (1, SortDirection::Descending)]);
+        let ages: Vec<_> = (0..4).map(|r| ds.cell_text(r, 1)).collect();
+        assert_eq!(ages, ["41", "25", "52", "30"]);
+        assert_eq!(ds.version(), 1);
+    }
 }
@@ -4,8 +4,16 @@
 //! for sorting, selection, column resizing, and virtual scrolling.
 
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::HashSet;
-use crate::widget::{WidgetBounds, TextAlign, Color};
+use crate::data_source::{CellValue, DataProvider, DataSource};
+use crate::interaction::InteractionState;
+use crate::list_view::RowOffsets;
+use crate::measure::TextMeasurer;
+use crate::theme::{mix, themed_default, with_alpha, Theme, Themed};
+use crate::widget::{Widget, WidgetBounds, TextAlign, Color};
+use crate::widget_index::find_widget;
+use winit::keyboard::{Key, NamedKey};
 
 /// Column width specification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -101,6 +109,9 @@ pub enum SelectionMode {
     Single,
     /// Multiple row selection
     Multiple,
+    /// Rectangular cell ranges instead of rows, see
+    /// [`crate::cell_selection`]. `selected_rows` is ignored.
+    Cells,
 }
 
 impl Default for SelectionMode {
@@ -166,10 +177,51 @@ pub struct DataGridStyle {
     /// Cell padding
     #[serde(default = "default_cell_padding")]
     pub cell_padding: f32,
+
+    /// Width of the sort direction triangle drawn after a header label
+    #[serde(default = "default_sort_indicator_size")]
+    pub sort_indicator_size: f32,
+
+    /// Overlay on a hovered sortable header
+    #[serde(default = "default_header_hover")]
+    pub header_hover_background: Color,
+
+    /// Overlay on a header while it is pressed
+    #[serde(default = "default_header_pressed")]
+    pub header_pressed_background: Color,
 }
 
 impl Default for DataGridStyle {
+    /// Derived from the current theme, see [`crate::theme::set_current_theme`].
     fn default() -> Self {
+        themed_default(Self::fallback)
+    }
+}
+
+impl Themed for DataGridStyle {
+    fn themed(theme: &Theme) -> Self {
+        let c = &theme.colors;
+        Self {
+            header_background: c.surface,
+            header_text_color: c.text,
+            row_background: c.background,
+            alt_row_background: mix(c.background, c.surface, 0.5),
+            row_text_color: c.text,
+            hover_background: c.hover,
+            selected_background: mix(c.background, c.primary, 0.5),
+            grid_line_color: c.divider,
+            grid_line_width: default_grid_line_width(),
+            cell_padding: default_cell_padding(),
+            sort_indicator_size: default_sort_indicator_size(),
+            header_hover_background: with_alpha(c.text, 0.06),
+            header_pressed_background: with_alpha(c.text, 0.12),
+        }
+    }
+}
+
+impl DataGridStyle {
+    /// Built-in dark style, used when no theme is current.
+    fn fallback() -> Self {
         Self {
             header_background: default_header_bg(),
             header_text_color: default_header_text(),
@@ -181,6 +233,9 @@ impl Default for DataGridStyle {
             grid_line_color: default_grid_line(),
             grid_line_width: default_grid_line_width(),
             cell_padding: default_cell_padding(),
+            sort_indicator_size: default_sort_indicator_size(),
+            header_hover_background: default_header_hover(),
+            header_pressed_background: default_header_pressed(),
         }
     }
 }
@@ -321,6 +376,374 @@ impl DataGrid {
     }
 }
 
+// --- Row auto-fit ---
+
+/// Font size of cell text.
+pub const CELL_FONT_SIZE: f32 = 13.0;
+
+/// Column widths as the grid lays them out across `available` pixels:
+/// fixed columns keep their width, flex columns share the rest by weight
+/// and auto columns use their minimum.
+pub fn column_widths(columns: &[ColumnDef], available: f32) -> Vec<f32> {
+    let mut total_fixed = 0.0;
+    let mut total_flex = 0.0;
+    for col in columns {
+        match col.width {
+            ColumnWidth::Fixed(w) => total_fixed += w,
+            ColumnWidth::Flex(f) => total_flex += f,
+            ColumnWidth::Auto => total_fixed += col.min_width,
+        }
+    }
+    let remaining = (available - total_fixed).max(0.0);
+    columns
+        .iter()
+        .map(|col| match col.width {
+            ColumnWidth::Fixed(w) => w,
+            ColumnWidth::Flex(f) if total_flex > 0.0 => (remaining * f / total_flex).max(col.min_width),
+            ColumnWidth::Flex(_) | ColumnWidth::Auto => col.min_width,
+        })
+        .collect()
+}
+
+/// Breaks cell text into lines no wider than `width`, at newlines and
+/// between words. A word wider than `width` gets a line of its own.
+pub fn wrap_cell_text(text: &str, width: f32, size: f32, measurer: &dyn TextMeasurer) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+            if !line.is_empty() && measurer.measure(&candidate, size, None).x > width {
+                lines.push(std::mem::replace(&mut line, word.to_string()));
+            } else {
+                line = candidate;
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Row heights measured by [`fit_datagrid_rows`], with what they were
+/// measured for.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FittedRows {
+    /// Height of each row, between the grid's `row_height` and
+    /// `max_row_height`.
+    pub heights: Vec<f32>,
+    /// Offsets of `heights`, built once per fit so render and hit testing
+    /// don't walk every row each frame.
+    rows: RowOffsets,
+    version: u64,
+    width: f32,
+}
+
+impl FittedRows {
+    /// Row geometry of the fitted heights.
+    pub fn rows(&self) -> &RowOffsets {
+        &self.rows
+    }
+}
+
+/// Row geometry of a grid showing `row_count` rows: `fitted` when given
+/// and fitted for that many rows, uniform `row_height` rows otherwise.
+/// Only the fallback for a stale fit walks the rows.
+pub fn grid_rows(row_count: usize, row_height: f32, fitted: Option<&FittedRows>) -> Cow<'_, RowOffsets> {
+    match fitted {
+        Some(fitted) if fitted.rows.len() == row_count => Cow::Borrowed(&fitted.rows),
+        Some(fitted) => Cow::Owned(RowOffsets::new(row_count, row_height, &fitted.heights)),
+        None => Cow::Owned(RowOffsets::uniform(row_count, row_height)),
+    }
+}
+
+/// Height each row of `source` needs to show its wrapped cell text,
+/// clamped to `min_height..=max_height`.
+pub fn auto_row_heights(
+    source: &dyn DataSource,
+    widths: &[f32],
+    padding: f32,
+    min_height: f32,
+    max_height: f32,
+    measurer: &dyn TextMeasurer,
+) -> Vec<f32> {
+    let line_height = measurer.measure("Ag", CELL_FONT_SIZE, None).y;
+    (0..source.row_count())
+        .map(|row| {
+            let lines = widths
+                .iter()
+                .enumerate()
+                .take(source.column_count())
+                .map(|(col, w)| {
+                    let text = source.cell_text(row, col);
+                    wrap_cell_text(&text, (w - padding * 2.0).max(1.0), CELL_FONT_SIZE, measurer).len()
+                })
+                .max()
+                .unwrap_or(1);
+            (lines as f32 * line_height + padding * 2.0).clamp(min_height, max_height.max(min_height))
+        })
+        .collect()
+}
+
+/// Measures the rows of every DataGrid in the tree that has
+/// `auto_row_height` set.
+///
+/// Call after layout. A grid is re-measured only when its data source's
+/// version or its width changed since the last call. Returns true if any
+/// grid's row heights changed.
+pub fn fit_datagrid_rows(root: &mut Widget, provider: &dyn DataProvider, measurer: &dyn TextMeasurer) -> bool {
+    let mut changed = false;
+    root.for_each_child_mut(&mut |child| changed |= fit_datagrid_rows(child, provider, measurer));
+
+    let Widget::DataGrid {
+        bounds,
+        columns,
+        data_source_id,
+        row_height,
+        auto_row_height: true,
+        max_row_height,
+        style,
+        fitted_rows,
+        scrollbar,
+        scrollbar_mode,
+        ..
+    } = root
+    else {
+        return changed;
+    };
+    let Some(source) = data_source_id.as_deref().and_then(|id| provider.get_source(id)) else {
+        return changed;
+    };
+    let width = bounds.width - crate::scrollbar::gutter_width(*scrollbar, *scrollbar_mode);
+    if fitted_rows.as_ref().is_some_and(|f| f.version == source.version() && f.width == width) {
+        return changed;
+    }
+    let widths = column_widths(columns, width);
+    let heights = auto_row_heights(source, &widths, style.cell_padding, *row_height, *max_row_height, measurer);
+    let rows = RowOffsets::new(heights.len(), *row_height, &heights);
+    let fitted = FittedRows { heights, rows, version: source.version(), width };
+    let rows_changed = fitted_rows.as_ref().map(|f| &f.heights) != Some(&fitted.heights);
+    *fitted_rows = Some(fitted);
+    changed | rows_changed
+}
+
+// --- Sorting ---
+
+/// Sort keys after a click on column `col`'s header.
+///
+/// A plain click sorts by that column alone, flipping its direction if it
+/// already was the only key. With `add` (Shift+click) the column is
+/// appended as the lowest-priority key, or flips direction if it already
+/// is a key.
+pub fn next_sort_keys(
+    keys: &[(usize, crate::data_source::SortDirection)],
+    col: usize,
+    add: bool,
+) -> Vec<(usize, crate::data_source::SortDirection)> {
+    use crate::data_source::SortDirection::{Ascending, Descending};
+    let flip = |direction| match direction {
+        Ascending => Descending,
+        Descending => Ascending,
+    };
+    let current = keys.iter().find(|(c, _)| *c == col).map(|(_, direction)| *direction);
+    if !add {
+        let direction = match (keys.len(), current) {
+            (1, Some(direction)) => flip(direction),
+            _ => Ascending,
+        };
+        return vec![(col, direction)];
+    }
+    let mut keys = keys.to_vec();
+    match keys.iter_mut().find(|(c, _)| *c == col) {
+        Some(key) => key.1 = flip(key.1),
+        None => keys.push((col, Ascending)),
+    }
+    keys
+}
+
+/// Horizontal center of the sort triangle for a column starting at
+/// `col_x`: just after a header label `label_width` wide, but never past
+/// the column's right padding.
+pub fn sort_indicator_x(col_x: f32, col_width: f32, label_width: f32, style: &DataGridStyle) -> f32 {
+    let half = style.sort_indicator_size * 0.5;
+    let after_label = col_x + style.cell_padding + label_width + 6.0 + half;
+    after_label.min(col_x + col_width - style.cell_padding - half)
+}
+
+/// Handles a `{grid}:header:{col}` click: updates the grid's sort keys with
+/// [`next_sort_keys`] (Shift adds a secondary key) and sorts its data
+/// source with [`DataSource::sort_by_keys`].
+///
+/// `sort_column` / `sort_direction` follow the primary key. Clicks on
+/// columns that aren't `sortable` are ignored. Returns true if the grid
+/// was re-sorted.
+pub fn handle_header_sort(
+    root: &mut Widget,
+    action: &str,
+    modifiers: winit::keyboard::ModifiersState,
+    provider: &mut dyn DataProvider,
+) -> bool {
+    let crate::action::Action::HeaderSort { grid, col } = crate::action::Action::parse(action) else {
+        return false;
+    };
+    let Some(Widget::DataGrid { columns, data_source_id, sort_column, sort_direction, sort_keys, .. }) =
+        crate::ui::find_widget_mut(root, &grid)
+    else {
+        return false;
+    };
+    if !columns.get(col).is_some_and(|c| c.sortable) {
+        return false;
+    }
+    let Some(source) = data_source_id.as_deref().and_then(|id| provider.get_source_mut(id)) else {
+        return false;
+    };
+    let current = match (sort_keys.is_empty(), *sort_column, *sort_direction) {
+        (true, Some(c), Some(direction)) => vec![(c, direction)],
+        _ => sort_keys.clone(),
+    };
+    *sort_keys = next_sort_keys(&current, col, modifiers.shift_key());
+    *sort_column = Some(sort_keys[0].0);
+    *sort_direction = Some(sort_keys[0].1);
+    source.sort_by_keys(sort_keys);
+    true
+}
+
+// --- Data changes ---
+
+/// Brings every DataGrid in the tree up to date with its data source.
+///
+/// A grid whose source reports a new [`DataSource::version`] since the
+/// last call drops selected rows past the end, cancels an edit of a row
+/// that no longer exists and clamps its scroll offset to the new content
+/// height. Call once per frame after layout (and after
+/// [`fit_datagrid_rows`]). Returns true if any grid's data changed, so
+/// anything cached from it must be rebuilt.
+pub fn sync_datagrids(root: &mut Widget, provider: &dyn DataProvider, interaction: &mut InteractionState) -> bool {
+    let mut changed = false;
+    root.for_each_child_mut(&mut |child| changed |= sync_datagrids(child, provider, interaction));
+
+    let Widget::DataGrid {
+        id,
+        bounds,
+        data_source_id,
+        header_height,
+        row_height,
+        auto_row_height,
+        fitted_rows,
+        data_version,
+        selected_rows,
+        ..
+    } = root
+    else {
+        return changed;
+    };
+    let Some(source) = data_source_id.as_deref().and_then(|id| provider.get_source(id)) else {
+        return changed;
+    };
+    let version = source.version();
+    if *data_version == Some(version) {
+        return changed;
+    }
+    *data_version = Some(version);
+
+    let row_count = source.row_count();
+    selected_rows.retain(|&row| row < row_count);
+    let Some(id) = id.as_deref() else {
+        return true;
+    };
+    if interaction.editing_grid_cell.as_ref().is_some_and(|(grid, row, _)| grid == id && *row >= row_count) {
+        interaction.cancel_grid_edit();
+    }
+    let fitted = fitted_rows.as_ref().filter(|f| *auto_row_height && f.version == version);
+    let content_height = grid_rows(row_count, *row_height, fitted).total();
+    let max_scroll = (content_height - (bounds.height - *header_height)).max(0.0);
+    if let Some(offset) = interaction.scroll_offsets.get_mut(id) {
+        offset.y = offset.y.clamp(0.0, max_scroll);
+    }
+    true
+}
+
+// --- Cell editing ---
+
+/// Parses edited text into the type the cell held before, falling back to
+/// text when it doesn't parse.
+pub fn parse_like(current: &CellValue, text: &str) -> CellValue {
+    let parsed = match current {
+        CellValue::Integer(_) => text.trim().parse().ok().map(CellValue::Integer),
+        CellValue::Number(_) => text.trim().parse().ok().map(CellValue::Number),
+        CellValue::Boolean(_) => text.trim().parse().ok().map(CellValue::Boolean),
+        _ => None,
+    };
+    parsed.unwrap_or_else(|| CellValue::Text(text.to_string()))
+}
+
+/// Ends the cell edit in progress and writes the buffer to the grid's
+/// data source with [`DataSource::set_cell`], keeping the cell's type
+/// where the text parses as it. A cell the source accepted is marked
+/// dirty.
+///
+/// Returns true if the cell was written. The edit ends either way.
+pub fn commit_cell_edit(root: &Widget, interaction: &mut InteractionState, provider: &mut dyn DataProvider) -> bool {
+    let Some((grid_id, row, col, text)) = interaction.commit_grid_edit() else {
+        return false;
+    };
+    let Some(Widget::DataGrid { data_source_id: Some(source_id), .. }) = find_widget(root, &grid_id) else {
+        return false;
+    };
+    let Some(source) = provider.get_source_mut(source_id) else {
+        return false;
+    };
+    let value = parse_like(&source.cell_value(row, col), &text);
+    if !source.set_cell(row, col, value) {
+        return false;
+    }
+    interaction.mark_dirty(&grid_id, row, col);
+    true
+}
+
+/// Applies a key to the cell edit in progress: characters append to
+/// `grid_edit_buffer`, Backspace deletes the last one, Escape cancels and
+/// Enter commits with [`commit_cell_edit`]. Without a `provider` Enter is
+/// left to the app.
+///
+/// Returns true if the key was consumed; always false when no cell is
+/// being edited.
+pub fn handle_cell_edit_key(
+    root: &Widget,
+    interaction: &mut InteractionState,
+    key: &Key,
+    provider: Option<&mut dyn DataProvider>,
+) -> bool {
+    if interaction.editing_grid_cell.is_none() {
+        return false;
+    }
+    match key {
+        Key::Named(NamedKey::Enter) => match provider {
+            Some(provider) => {
+                commit_cell_edit(root, interaction, provider);
+                true
+            }
+            None => false,
+        },
+        Key::Named(NamedKey::Escape) => {
+            interaction.cancel_grid_edit();
+            true
+        }
+        Key::Named(NamedKey::Backspace) => {
+            interaction.grid_edit_buffer.pop();
+            true
+        }
+        Key::Named(NamedKey::Space) => {
+            interaction.grid_edit_buffer.push(' ');
+            true
+        }
+        Key::Character(c) if !c.chars().any(|ch| ch.is_control()) => {
+            interaction.grid_edit_buffer.push_str(c);
+            true
+        }
+        _ => false,
+    }
+}
+
 // --- Default value functions ---
 
 fn default_true() -> bool {
@@ -379,6 +802,18 @@ fn default_grid_line_width() -> f32 {
     1.0
 }
 
+fn default_sort_indicator_size() -> f32 {
+    8.0
+}
+
+fn default_header_hover() -> Color {
+    (1.0, 1.0, 1.0, 0.06)
+}
+
+fn default_header_pressed() -> Color {
+    (1.0, 1.0, 1.0, 0.12)
+}
+
 fn default_cell_padding() -> f32 {
     8.0
 }
@@ -414,4 +849,197 @@ mod tests {
         assert_eq!(grid.row_height, 32.0);
         assert!(grid.striped);
     }
+
+    #[test]
+    fn test_wrap_cell_text() {
+        use crate::measure::HeuristicMeasurer;
+        // 10px per char at size 10/0.6.
+        let size = 10.0 / 0.6;
+        let lines = wrap_cell_text("one two three\nfour", 75.0, size, &HeuristicMeasurer);
+        assert_eq!(lines, vec!["one two", "three", "four"]);
+        assert_eq!(wrap_cell_text("", 75.0, size, &HeuristicMeasurer), vec![""]);
+        assert_eq!(wrap_cell_text("supercalifragilistic", 75.0, size, &HeuristicMeasurer).len(), 1);
+    }
+
+    #[test]
+    fn test_auto_row_heights_fit_tallest_cell_and_cap() {
+        use crate::data_source::{CellValue, VecDataSource};
+        use crate::measure::HeuristicMeasurer;
+        let long = "word ".repeat(200);
+        let source = VecDataSource::new(
+            vec!["a".into(), "b".into()],
+            vec![
+                vec![CellValue::Text("short".into()), CellValue::Text("x".into())],
+                vec![CellValue::Text("one\ntwo\nthree".into()), CellValue::Text("y".into())],
+                vec![CellValue::Text(long), CellValue::Text("z".into())],
+            ],
+        );
+        // 13px lines, 8px padding: one line fits the 32px minimum.
+        let heights = auto_row_heights(&source, &[200.0, 200.0], 8.0, 32.0, 120.0, &HeuristicMeasurer);
+        assert_eq!(heights, vec![32.0, 3.0 * 13.0 + 16.0, 120.0]);
+
+        // Virtualization over the fitted rows.
+        let rows = RowOffsets::new(heights.len(), 32.0, &heights);
+        assert_eq!(rows.total(), 32.0 + 55.0 + 120.0);
+        assert_eq!(rows.index_at(40.0), Some(1));
+        assert_eq!(rows.index_at(87.0), Some(2));
+        assert_eq!(rows.visible_range(50.0, 20.0), 1..2);
+        assert_eq!(rows.visible_range(80.0, 100.0), 1..3);
+    }
+
+    #[test]
+    fn test_grid_rows_reuse_fit_or_fall_back_to_uniform() {
+        let heights = vec![32.0, 55.0, 120.0];
+        let fitted = FittedRows { rows: RowOffsets::new(3, 32.0, &heights), heights, version: 1, width: 400.0 };
+        assert!(matches!(grid_rows(3, 32.0, Some(&fitted)), Cow::Borrowed(_)));
+        assert_eq!(grid_rows(3, 32.0, Some(&fitted)).total(), 207.0);
+        // A row added since the fit is row_height tall until refitted.
+        assert_eq!(grid_rows(4, 32.0, Some(&fitted)).total(), 239.0);
+        // Uniform rows never walk the rows.
+        let uniform = grid_rows(100_000, 24.0, None);
+        assert_eq!(uniform.total(), 2_400_000.0);
+        assert_eq!(uniform.index_at(2_400.0), Some(100));
+    }
+
+    #[test]
+    fn test_edit_commit_cycle_on_vec_data_source() {
+        use crate::data_source::MapDataProvider;
+        let root = crate::ui::parse_ui(r#"
+            DataGrid(
+                id: Some("stock"),
+                columns: [(header: "Item", field: "item"), (header: "Qty", field: "qty")],
+                data_source_id: Some("stock"),
+            )
+        "#).unwrap();
+        let mut provider = MapDataProvider::new();
+        provider.register("stock", crate::data_source::VecDataSource::new(
+            vec!["item".into(), "qty".into()],
+            vec![vec![CellValue::Text("Bolts".into()), CellValue::Integer(10)]],
+        ));
+        let mut interaction = InteractionState::default();
+        let key = |c: &str| Key::Character(c.into());
+
+        // Not editing: keys are not consumed.
+        assert!(!handle_cell_edit_key(&root, &mut interaction, &key("1"), Some(&mut provider)));
+
+        interaction.start_grid_edit("stock", 0, 1, "10");
+        for k in [Key::Named(NamedKey::Backspace), key("2"), key("5")] {
+            assert!(handle_cell_edit_key(&root, &mut interaction, &k, Some(&mut provider)));
+        }
+        assert_eq!(interaction.grid_edit_buffer, "125");
+        assert!(handle_cell_edit_key(&root, &mut interaction, &Key::Named(NamedKey::Enter), Some(&mut provider)));
+
+        let source = provider.get_source("stock").unwrap();
+        assert_eq!(source.cell_value(0, 1), CellValue::Integer(125));
+        assert!(interaction.editing_grid_cell.is_none());
+        assert!(interaction.is_dirty("stock", 0, 1));
+
+        // Escape discards the edit.
+        interaction.start_grid_edit("stock", 0, 0, "Bolts");
+        handle_cell_edit_key(&root, &mut interaction, &key("!"), Some(&mut provider));
+        assert!(handle_cell_edit_key(&root, &mut interaction, &Key::Named(NamedKey::Escape), Some(&mut provider)));
+        assert_eq!(provider.get_source("stock").unwrap().cell_text(0, 0), "Bolts");
+        assert!(!interaction.is_dirty("stock", 0, 0));
+    }
+
+    #[test]
+    fn test_parse_like_keeps_cell_type() {
+        assert_eq!(parse_like(&CellValue::Number(1.5), " 2.25"), CellValue::Number(2.25));
+        assert_eq!(parse_like(&CellValue::Integer(1), "x"), CellValue::Text("x".into()));
+        assert_eq!(parse_like(&CellValue::Boolean(false), "true"), CellValue::Boolean(true));
+    }
+
+    #[test]
+    fn test_sync_follows_source_version() {
+        use crate::data_source::{MapDataProvider, VecDataSource};
+        let mut root = crate::ui::parse_ui(r#"
+            DataGrid(
+                id: Some("log"),
+                columns: [(header: "Line", field: "line")],
+                data_source_id: Some("log"),
+                header_height: 20.0,
+                row_height: 10.0,
+                selected_rows: [1, 4],
+            )
+        "#).unwrap();
+        if let Widget::DataGrid { bounds, .. } = &mut root {
+            bounds.height = 50.0;
+        }
+        let rows = (0..10).map(|i| vec![CellValue::Integer(i)]).collect();
+        let mut provider = MapDataProvider::new();
+        provider.register("log", VecDataSource::new(vec!["line".into()], rows));
+        let mut interaction = InteractionState::default();
+
+        assert!(sync_datagrids(&mut root, &provider, &mut interaction));
+        assert!(!sync_datagrids(&mut root, &provider, &mut interaction));
+
+        // Scrolled to the bottom, then the source shrinks to three rows.
+        interaction.handle_scroll("log", glam::Vec2::new(0.0, -70.0));
+        interaction.start_grid_edit("log", 4, 0, "4");
+        let source = provider.get_source_mut("log").unwrap();
+        for _ in 0..7 {
+            source.delete_row(0);
+        }
+        assert!(sync_datagrids(&mut root, &provider, &mut interaction));
+        assert_eq!(interaction.scroll_offsets["log"].y, 0.0);
+        assert!(interaction.editing_grid_cell.is_none());
+        let Widget::DataGrid { selected_rows, .. } = &root else { unreachable!() };
+        assert_eq!(selected_rows, &vec![1]);
+    }
+
+    #[test]
+    fn test_next_sort_keys() {
+        use crate::data_source::SortDirection::{Ascending, Descending};
+        // Plain clicks sort by one column, toggling on repeat.
+        assert_eq!(next_sort_keys(&[], 2, false), vec![(2, Ascending)]);
+        assert_eq!(next_sort_keys(&[(2, Ascending)], 2, false), vec![(2, Descending)]);
+        assert_eq!(next_sort_keys(&[(2, Ascending), (0, Ascending)], 2, false), vec![(2, Ascending)]);
+        // Shift adds keys, or flips one already present.
+        let keys = next_sort_keys(&[(2, Ascending)], 0, true);
+        assert_eq!(keys, vec![(2, Ascending), (0, Ascending)]);
+        assert_eq!(next_sort_keys(&keys, 0, true), vec![(2, Ascending), (0, Descending)]);
+    }
+
+    #[test]
+    fn test_shift_click_header_sorts_by_two_columns() {
+        use crate::data_source::{MapDataProvider, SortDirection, VecDataSource};
+        use winit::keyboard::ModifiersState;
+        let mut root = crate::ui::parse_ui(r#"
+            DataGrid(
+                id: Some("staff"),
+                columns: [(header: "Team", field: "team"), (header: "Name", field: "name")],
+                data_source_id: Some("staff"),
+            )
+        "#).unwrap();
+        let text = |s: &str| CellValue::Text(s.into());
+        let mut provider = MapDataProvider::new();
+        provider.register("staff", VecDataSource::new(
+            vec!["team".into(), "name".into()],
+            vec![
+                vec![text("Ops"), text("Zoe")],
+                vec![text("Dev"), text("Yan")],
+                vec![text("Ops"), text("Abe")],
+                vec![text("Dev"), text("Bea")],
+            ],
+        ));
+
+        assert!(handle_header_sort(&mut root, "staff:header:0", ModifiersState::empty(), &mut provider));
+        assert!(handle_header_sort(&mut root, "staff:header:1", ModifiersState::SHIFT, &mut provider));
+        assert!(handle_header_sort(&mut root, "staff:header:1", ModifiersState::SHIFT, &mut provider));
+
+        let source = provider.get_source("staff").unwrap();
+        let names: Vec<_> = (0..4).map(|row| source.cell_text(row, 1)).collect();
+        assert_eq!(names, ["Yan", "Bea", "Zoe", "Abe"]);
+        let Widget::DataGrid { sort_keys, sort_column, .. } = &root else { unreachable!() };
+        assert_eq!(sort_keys, &vec![(0, SortDirection::Ascending), (1, SortDirection::Descending)]);
+        assert_eq!(*sort_column, Some(0));
+    }
+
+    #[test]
+    fn test_sort_indicator_follows_label_within_column() {
+        let style = DataGridStyle { cell_padding: 8.0, sort_indicator_size: 8.0, ..Default::default() };
+        assert_eq!(sort_indicator_x(100.0, 200.0, 40.0, &style), 158.0);
+        // A label wider than the column pins the triangle to the right edge.
+        assert_eq!(sort_indicator_x(100.0, 200.0, 400.0, &style), 288.0);
+    }
 }
@@ -0,0 +1,168 @@
+//! Form serialization.
+//!
+//! [`collect_values`] gathers the value of every TextInput, NumberInput,
+//! Checkbox, Dropdown, DatePicker and Slider in a tree, keyed by id, and
+//! [`apply_values`] puts such a map back. The map serializes with serde,
+//! so saving and restoring form state is a collect, a write and later an
+//! apply.
+
+use crate::widget::Widget;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The value of one form field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FieldValue {
+    /// TextInput text, or the selected Dropdown option.
+    Text(String),
+    /// NumberInput or Slider value.
+    Number(f64),
+    /// Checkbox state.
+    Bool(bool),
+    /// DatePicker date.
+    Date(NaiveDate),
+    /// A Dropdown without a selection or a DatePicker without a date.
+    Empty,
+}
+
+/// Collects the values of all form fields in the tree, keyed by widget
+/// id. When ids repeat, the first widget in depth-first order wins.
+pub fn collect_values(root: &Widget) -> HashMap<String, FieldValue> {
+    let mut values = HashMap::new();
+    collect_into(root, &mut values);
+    values
+}
+
+fn collect_into(widget: &Widget, values: &mut HashMap<String, FieldValue>) {
+    let field = match widget {
+        Widget::TextInput { id, value, .. } => Some((id, FieldValue::Text(value.clone()))),
+        Widget::NumberInput { id, value, .. } => Some((id, FieldValue::Number(*value))),
+        Widget::Slider { id, value, .. } => Some((id, FieldValue::Number(*value as f64))),
+        Widget::Checkbox { id, checked, .. } => Some((id, FieldValue::Bool(*checked))),
+        Widget::Dropdown { id, options, selected_index, .. } => {
+            let value = selected_index
+                .and_then(|i| options.get(i))
+                .map(|option| FieldValue::Text(option.clone()))
+                .unwrap_or(FieldValue::Empty);
+            Some((id, value))
+        }
+        Widget::DatePicker { id, value, .. } => {
+            Some((id, value.map(FieldValue::Date).unwrap_or(FieldValue::Empty)))
+        }
+        _ => None,
+    };
+    if let Some((id, value)) = field {
+        values.entry(id.clone()).or_insert(value);
+    }
+    widget.for_each_child(&mut |child| collect_into(child, values));
+}
+
+/// Writes `values` back into the fields with matching ids.
+///
+/// Ids missing from the map, and values of the wrong kind for a field
+/// (text for a checkbox, an option a dropdown doesn't have), leave the
+/// field unchanged. Returns true if any field changed.
+pub fn apply_values(root: &mut Widget, values: &HashMap<String, FieldValue>) -> bool {
+    let mut changed = false;
+    root.for_each_child_mut(&mut |child| changed |= apply_values(child, values));
+
+    let field_changed = match root {
+        Widget::TextInput { id, value, .. } => match values.get(id.as_str()) {
+            Some(FieldValue::Text(text)) => replace(value, text.clone()),
+            _ => false,
+        },
+        Widget::NumberInput { id, value, .. } => match values.get(id.as_str()) {
+            Some(FieldValue::Number(n)) => replace(value, *n),
+            _ => false,
+        },
+        Widget::Slider { id, value, .. } => match values.get(id.as_str()) {
+            Some(FieldValue::Number(n)) => replace(value, *n as f32),
+            _ => false,
+        },
+        Widget::Checkbox { id, checked, .. } => match values.get(id.as_str()) {
+            Some(FieldValue::Bool(b)) => replace(checked, *b),
+            _ => false,
+        },
+        Widget::Dropdown { id, options, selected_index, .. } => match values.get(id.as_str()) {
+            Some(FieldValue::Text(text)) => match options.iter().position(|o| o == text) {
+                Some(index) => replace(selected_index, Some(index)),
+                None => false,
+            },
+            Some(FieldValue::Empty) => replace(selected_index, None),
+            _ => false,
+        },
+        Widget::DatePicker { id, value, .. } => match values.get(id.as_str()) {
+            Some(FieldValue::Date(date)) => replace(value, Some(*date)),
+            Some(FieldValue::Empty) => replace(value, None),
+            _ => false,
+        },
+        _ => false,
+    };
+    changed | field_changed
+}
+
+/// Sets `slot` to `new`, returning true if that changed it.
+fn replace<T: PartialEq>(slot: &mut T, new: T) -> bool {
+    if *slot == new {
+        return false;
+    }
+    *slot = new;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::parse_ui;
+
+    const FORM: &str = r#"
+        Container(
+            children: [
+                TextInput(id: "name", value: "Ada"),
+                NumberInput(id: "age", value: 36.0),
+                Checkbox(id: "subscribe", checked: true),
+                Dropdown(id: "plan", options: ["Free", "Pro"], selected_index: Some(1)),
+                DatePicker(id: "start", value: Some("2024-05-15")),
+                DatePicker(id: "end"),
+                Container(children: [Slider(id: "volume", value: 0.25)]),
+            ],
+        )
+    "#;
+
+    #[test]
+    fn test_collect_then_apply_round_trips() {
+        let filled = parse_ui(FORM).unwrap();
+        let values = collect_values(&filled);
+        assert_eq!(values.len(), 7);
+        assert_eq!(values["plan"], FieldValue::Text("Pro".into()));
+        assert_eq!(values["end"], FieldValue::Empty);
+        assert_eq!(values["volume"], FieldValue::Number(0.25));
+
+        // Survives serialization.
+        let saved = ron::to_string(&values).unwrap();
+        let loaded: HashMap<String, FieldValue> = ron::from_str(&saved).unwrap();
+
+        let mut blank = parse_ui(FORM).unwrap();
+        let mut clear = values.clone();
+        clear.insert("name".into(), FieldValue::Text(String::new()));
+        clear.insert("plan".into(), FieldValue::Empty);
+        clear.insert("start".into(), FieldValue::Empty);
+        assert!(apply_values(&mut blank, &clear));
+        assert_ne!(collect_values(&blank), values);
+
+        assert!(apply_values(&mut blank, &loaded));
+        assert_eq!(collect_values(&blank), values);
+        assert!(!apply_values(&mut blank, &loaded));
+    }
+
+    #[test]
+    fn test_mismatched_values_are_ignored() {
+        let mut form = parse_ui(FORM).unwrap();
+        let values = HashMap::from([
+            ("subscribe".to_string(), FieldValue::Text("yes".into())),
+            ("plan".to_string(), FieldValue::Text("Enterprise".into())),
+        ]);
+        assert!(!apply_values(&mut form, &values));
+    }
+}
@@ -0,0 +1,171 @@
+//! Fuzzy matching for suggestion lists.
+//!
+//! [`fuzzy_match`] scores a candidate against a typed query and reports
+//! which characters matched, so the Autocomplete dropdown can highlight
+//! exactly what the ranking was based on. Apps sorting their own lists
+//! should use [`rank`] to stay consistent with the highlight.
+
+use crate::rich_text::{TextSpan, TextStyle};
+
+/// Result of matching a query against a candidate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Higher is better. Only meaningful relative to other scores for the
+    /// same query.
+    pub score: i32,
+    /// Char indices (not byte offsets) of the matched characters, ascending.
+    pub indices: Vec<usize>,
+}
+
+const MATCH: i32 = 1;
+const CONSECUTIVE_BONUS: i32 = 5;
+const WORD_START_BONUS: i32 = 8;
+const SUBSTRING_BONUS: i32 = 20;
+const LEADING_GAP_PENALTY_MAX: i32 = 5;
+
+/// Matches `query` against `candidate`, ignoring case.
+///
+/// Every query character must appear in the candidate in order. A
+/// contiguous occurrence is preferred (earliest one starting a word, else
+/// the earliest); otherwise characters are matched greedily. Consecutive
+/// matches and matches at word starts score higher, characters skipped
+/// before the first match lower it. An empty query matches everything
+/// with score 0.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch { score: 0, indices: Vec::new() });
+    }
+    let original: Vec<char> = candidate.chars().collect();
+    // Lowercasing can change the char count (e.g. 'İ'); compare per char
+    // on the first lowercase char so indices stay aligned with `original`.
+    let chars: Vec<char> = original
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    if query.len() > chars.len() {
+        return None;
+    }
+
+    let starts: Vec<usize> = (0..=chars.len() - query.len())
+        .filter(|&i| chars[i..i + query.len()] == query[..])
+        .collect();
+    let indices: Vec<usize> = match starts
+        .iter()
+        .find(|&&i| is_word_start(&original, i))
+        .or(starts.first())
+    {
+        Some(&start) => (start..start + query.len()).collect(),
+        None => {
+            let mut indices = Vec::with_capacity(query.len());
+            let mut pos = 0;
+            for q in &query {
+                let found = chars[pos..].iter().position(|c| c == q)?;
+                indices.push(pos + found);
+                pos += found + 1;
+            }
+            indices
+        }
+    };
+
+    let mut score = 0;
+    for (n, &i) in indices.iter().enumerate() {
+        score += MATCH;
+        if n > 0 && indices[n - 1] + 1 == i {
+            score += CONSECUTIVE_BONUS;
+        }
+        if is_word_start(&original, i) {
+            score += WORD_START_BONUS;
+        }
+    }
+    if !starts.is_empty() {
+        score += SUBSTRING_BONUS;
+    }
+    score -= (indices[0] as i32).min(LEADING_GAP_PENALTY_MAX);
+
+    Some(FuzzyMatch { score, indices })
+}
+
+/// True at the first char, after a non-alphanumeric char, or at a
+/// lower-to-upper case change ("fooBar").
+fn is_word_start(chars: &[char], i: usize) -> bool {
+    if i == 0 {
+        return true;
+    }
+    let prev = chars[i - 1];
+    !prev.is_alphanumeric() || (prev.is_lowercase() && chars[i].is_uppercase())
+}
+
+/// Matches every candidate and returns `(index, match)` pairs for those
+/// that matched, best first. Ties keep the original order.
+pub fn rank<S: AsRef<str>>(query: &str, candidates: &[S]) -> Vec<(usize, FuzzyMatch)> {
+    let mut ranked: Vec<(usize, FuzzyMatch)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| fuzzy_match(query, c.as_ref()).map(|m| (i, m)))
+        .collect();
+    ranked.sort_by_key(|(_, m)| std::cmp::Reverse(m.score));
+    ranked
+}
+
+/// Splits `candidate` into spans, giving the matched characters
+/// `highlight` and the rest `base`.
+pub fn highlight_spans(
+    candidate: &str,
+    indices: &[usize],
+    base: &TextStyle,
+    highlight: &TextStyle,
+) -> Vec<TextSpan> {
+    let mut spans: Vec<TextSpan> = Vec::new();
+    let mut matched = indices.iter().peekable();
+    for (i, c) in candidate.chars().enumerate() {
+        let is_match = matched.next_if_eq(&&i).is_some();
+        let style = if is_match { highlight } else { base };
+        match spans.last_mut() {
+            Some(span) if span.style == *style => span.text.push(c),
+            _ => spans.push(TextSpan { text: c.to_string(), style: style.clone() }),
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_ignores_case_and_requires_order() {
+        let m = fuzzy_match("BER", "Berlin").unwrap();
+        assert_eq!(m.indices, vec![0, 1, 2]);
+        assert_eq!(fuzzy_match("nb", "Berlin"), None);
+        assert_eq!(fuzzy_match("", "Berlin").unwrap().score, 0);
+    }
+
+    #[test]
+    fn test_prefers_word_start_substring() {
+        // "ber" occurs inside "Cumberland" and at the start of "Bern".
+        let m = fuzzy_match("ber", "Cumberland Bern").unwrap();
+        assert_eq!(m.indices, vec![11, 12, 13]);
+    }
+
+    #[test]
+    fn test_subsequence_highlight_and_rank() {
+        let m = fuzzy_match("nyc", "New York City").unwrap();
+        assert_eq!(m.indices, vec![0, 4, 9]);
+
+        let ranked = rank("ber", &["Hamburg", "Cumberland", "Berlin"]);
+        let order: Vec<usize> = ranked.iter().map(|(i, _)| *i).collect();
+        // Hamburg has no 'e' after its 'b', so it drops out.
+        assert_eq!(order, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_highlight_spans_merge_runs() {
+        let base = TextStyle::default();
+        let hl = TextStyle { bold: true, ..TextStyle::default() };
+        let spans = highlight_spans("Berlin", &[0, 1, 2], &base, &hl);
+        let texts: Vec<&str> = spans.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["Ber", "lin"]);
+        assert!(spans[0].style.bold && !spans[1].style.bold);
+    }
+}
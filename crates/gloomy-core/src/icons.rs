@@ -0,0 +1,142 @@
+//! Icon registry for scalable, tintable `Icon` widgets.
+//!
+//! Apps register icons by name once, either as SVG documents or as glyphs
+//! of an icon font, and `Icon` widgets refer to them by `icon_name`:
+//!
+//! - SVG icons are rasterized as a coverage mask at the size they are drawn
+//!   (in device pixels) and cached per size, so they stay sharp at any
+//!   scale and take the widget's `color` whatever fills the file uses.
+//! - Glyph icons are drawn as text with the registered font (added with
+//!   `GloomyRenderer::add_font`), so they scale like any other text.
+//!
+//! An icon set is registered in one call with [`IconRegistry::register_glyph_set`]
+//! for fonts, or one [`IconRegistry::register_svg`] per file.
+//!
+//! `Icon` resolves its name in this order: the registry, then textures
+//! registered with `GloomyRenderer::register_texture` (the original
+//! per-image icons), then the registry's fallback icon. If none match,
+//! nothing is drawn.
+
+use std::collections::HashMap;
+use crate::texture::Texture;
+
+/// How a registered icon is drawn.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IconSource {
+    /// SVG document bytes.
+    Svg(Vec<u8>),
+    /// A codepoint in an icon font; `None` uses the default font.
+    Glyph { font: Option<String>, codepoint: char },
+}
+
+/// Named icons available to `Icon` widgets.
+#[derive(Default)]
+pub struct IconRegistry {
+    icons: HashMap<String, IconSource>,
+    fallback: Option<String>,
+    /// Rasterized SVG masks keyed by icon name and pixel size.
+    masks: HashMap<(String, u32), Texture>,
+}
+
+impl IconRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an SVG icon, replacing any icon of the same name.
+    /// Fails if the document does not parse.
+    pub fn register_svg(&mut self, name: impl Into<String>, svg_data: &[u8]) -> anyhow::Result<()> {
+        crate::svg_loader::validate_svg(svg_data)?;
+        self.register(name.into(), IconSource::Svg(svg_data.to_vec()));
+        Ok(())
+    }
+
+    /// Registers a single glyph of an icon font.
+    pub fn register_glyph(&mut self, name: impl Into<String>, font: Option<&str>, codepoint: char) {
+        let font = font.map(str::to_string);
+        self.register(name.into(), IconSource::Glyph { font, codepoint });
+    }
+
+    /// Registers every `(name, codepoint)` pair of an icon font.
+    pub fn register_glyph_set<'n>(&mut self, font: &str, glyphs: impl IntoIterator<Item = (&'n str, char)>) {
+        for (name, codepoint) in glyphs {
+            self.register_glyph(name, Some(font), codepoint);
+        }
+    }
+
+    /// Icon drawn in place of names that are not registered.
+    pub fn set_fallback(&mut self, name: Option<&str>) {
+        self.fallback = name.map(str::to_string);
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.icons.contains_key(name)
+    }
+
+    /// The icon registered as `name`, without the fallback.
+    pub fn get(&self, name: &str) -> Option<&IconSource> {
+        self.icons.get(name)
+    }
+
+    /// The fallback icon, if one is set and registered.
+    pub fn fallback(&self) -> Option<(&str, &IconSource)> {
+        let name = self.fallback.as_deref()?;
+        self.icons.get(name).map(|source| (name, source))
+    }
+
+    /// Mask texture for the SVG icon `name` at `px` pixels square,
+    /// rasterizing it on first use. `None` for glyph or unknown icons.
+    pub fn svg_mask(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, name: &str, px: u32) -> Option<&Texture> {
+        let px = px.max(1);
+        let key = (name.to_string(), px);
+        if !self.masks.contains_key(&key) {
+            let IconSource::Svg(data) = self.icons.get(name)? else { return None };
+            match crate::svg_loader::load_svg_mask(device, queue, data, px, px) {
+                Ok(texture) => { self.masks.insert(key.clone(), texture); }
+                Err(err) => {
+                    log::warn!("Failed to rasterize icon '{}': {}", name, err);
+                    return None;
+                }
+            }
+        }
+        self.masks.get(&key)
+    }
+
+    fn register(&mut self, name: String, source: IconSource) {
+        self.masks.retain(|(cached, _), _| *cached != name);
+        self.icons.insert(name, source);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glyph_set_and_fallback() {
+        let mut icons = IconRegistry::new();
+        icons.register_glyph_set("Material Icons", [("home", '\u{e88a}'), ("help", '\u{e887}')]);
+        assert_eq!(
+            icons.get("home"),
+            Some(&IconSource::Glyph { font: Some("Material Icons".to_string()), codepoint: '\u{e88a}' })
+        );
+        assert!(icons.get("missing").is_none());
+
+        // A fallback naming an unregistered icon resolves to nothing.
+        icons.set_fallback(Some("unknown"));
+        assert!(icons.fallback().is_none());
+        icons.set_fallback(Some("help"));
+        assert_eq!(icons.fallback().map(|(name, _)| name), Some("help"));
+    }
+
+    #[test]
+    fn test_register_svg_rejects_invalid_documents() {
+        let mut icons = IconRegistry::new();
+        assert!(icons.register_svg("broken", b"not svg").is_err());
+        assert!(!icons.contains("broken"));
+
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><rect width="24" height="24"/></svg>"#;
+        icons.register_svg("square", svg).unwrap();
+        assert!(matches!(icons.get("square"), Some(IconSource::Svg(_))));
+    }
+}
@@ -1,5 +1,6 @@
 use wgpu::util::DeviceExt;
 use crate::texture::Texture;
+use crate::primitives::RoundedClip;
 use bytemuck::{Pod, Zeroable};
 use glam::{Vec2, Vec4};
 
@@ -9,6 +10,12 @@ struct ImageInstance {
     pos: Vec2,
     size: Vec2,
     color: Vec4,
+    /// Rounded clip center (xy) and half-extents (zw); zero disables it.
+    clip_rect: Vec4,
+    clip_radii: [f32; 4],
+    /// Times the texture wraps across the quad; one stretches it.
+    uv_scale: Vec2,
+    _pad: Vec2,
 }
 
 #[repr(C)]
@@ -30,10 +37,15 @@ pub struct ImageRenderer {
     globals_buffer: wgpu::Buffer,
     globals_bind_group: wgpu::BindGroup,
     texture_bind_group_layout: wgpu::BindGroupLayout,
+    /// Wrapping sampler for tiled draws; textures carry a clamping one.
+    repeat_sampler: wgpu::Sampler,
     instances: Vec<ImageInstance>,
     instance_buffer: wgpu::Buffer,
     batches: Vec<Batch>,
     current_scissor: Option<(u32, u32, u32, u32)>,
+    current_clip: Option<RoundedClip>,
+    /// Alpha multiplier applied to queued images.
+    opacity: f32,
     screen_size: Vec2, 
 }
 
@@ -43,6 +55,18 @@ impl ImageRenderer {
         format: wgpu::TextureFormat,
         width: u32,
         height: u32,
+    ) -> Self {
+        Self::new_multisampled(device, format, width, height, 1)
+    }
+
+    /// Creates an image renderer for render passes with `sample_count`
+    /// samples per pixel.
+    pub fn new_multisampled(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        width: u32,
+        height: u32,
+        sample_count: u32,
     ) -> Self {
         let globals_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("GloomyImageGlobalsLayout"),
@@ -113,6 +137,21 @@ impl ImageRenderer {
                             shader_location: 2,
                             format: wgpu::VertexFormat::Float32x4,
                         },
+                        wgpu::VertexAttribute {
+                            offset: 32,
+                            shader_location: 3,
+                            format: wgpu::VertexFormat::Float32x4,
+                        },
+                        wgpu::VertexAttribute {
+                            offset: 48,
+                            shader_location: 4,
+                            format: wgpu::VertexFormat::Float32x4,
+                        },
+                        wgpu::VertexAttribute {
+                            offset: 64,
+                            shader_location: 5,
+                            format: wgpu::VertexFormat::Float32x2,
+                        },
                     ],
                 }],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
@@ -134,7 +173,10 @@ impl ImageRenderer {
                 ..Default::default()
             },
             depth_stencil: None,
-            multisample: wgpu::MultisampleState::default(),
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                ..Default::default()
+            },
             multiview: None,
         });
 
@@ -162,15 +204,29 @@ impl ImageRenderer {
             mapped_at_creation: false,
         });
 
+        let repeat_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("GloomyImageRepeatSampler"),
+            address_mode_u: wgpu::AddressMode::Repeat,
+            address_mode_v: wgpu::AddressMode::Repeat,
+            address_mode_w: wgpu::AddressMode::Repeat,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
         Self {
             pipeline,
             globals_buffer,
             globals_bind_group,
             texture_bind_group_layout: texture_layout,
+            repeat_sampler,
             instances: Vec::new(),
             instance_buffer,
             batches: Vec::new(),
             current_scissor: None,
+            current_clip: None,
+            opacity: 1.0,
             screen_size: Vec2::new(width as f32, height as f32),
         }
     }
@@ -182,6 +238,18 @@ impl ImageRenderer {
         old
     }
 
+    /// Sets the alpha multiplier for subsequent draws.
+    /// Returns the previous value.
+    pub fn set_opacity(&mut self, opacity: f32) -> f32 {
+        std::mem::replace(&mut self.opacity, opacity)
+    }
+
+    /// Sets the rounded clip mask for subsequent draws.
+    /// Returns the previous value.
+    pub fn set_rounded_clip(&mut self, clip: Option<RoundedClip>) -> Option<RoundedClip> {
+        std::mem::replace(&mut self.current_clip, clip)
+    }
+
     pub fn resize(&self, queue: &wgpu::Queue, width: u32, height: u32) {
         queue.write_buffer(
             &self.globals_buffer,
@@ -200,7 +268,18 @@ impl ImageRenderer {
         size: Vec2,
         color: Vec4,
     ) {
-        let instance = ImageInstance { pos, size, color };
+        let mut color = color;
+        color.w *= self.opacity;
+        let (clip_rect, clip_radii) = RoundedClip::to_instance(self.current_clip);
+        let instance = ImageInstance {
+            pos,
+            size,
+            color,
+            clip_rect,
+            clip_radii,
+            uv_scale: Vec2::ONE,
+            _pad: Vec2::ZERO,
+        };
         
         // Simple batching: if last batch used SAME texture, append.
         // But we store BindGroups. We can't easily check sameness of BG.
@@ -238,6 +317,54 @@ impl ImageRenderer {
         });
     }
 
+    /// Draws `texture` over the quad at `top_left` of `size`, wrapping it
+    /// `uv_scale` times across each axis. Any number of tiles is one
+    /// instance.
+    pub fn draw_tiled(
+        &mut self,
+        device: &wgpu::Device,
+        texture: &Texture,
+        top_left: Vec2,
+        size: Vec2,
+        uv_scale: Vec2,
+        color: Vec4,
+    ) {
+        let mut color = color;
+        color.w *= self.opacity;
+        let (clip_rect, clip_radii) = RoundedClip::to_instance(self.current_clip);
+        let bg = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("ImageTiledBG"),
+            layout: &self.texture_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&texture.view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&self.repeat_sampler),
+                },
+            ],
+        });
+
+        let start_index = self.instances.len() as u32;
+        self.instances.push(ImageInstance {
+            pos: top_left + size * 0.5,
+            size,
+            color,
+            clip_rect,
+            clip_radii,
+            uv_scale,
+            _pad: Vec2::ZERO,
+        });
+        self.batches.push(Batch {
+            bind_group: std::sync::Arc::new(bg),
+            start_index,
+            count: 1,
+            scissor: self.current_scissor,
+        });
+    }
+
     pub fn prepare(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
         if self.instances.is_empty() {
             return;
@@ -294,6 +421,8 @@ impl ImageRenderer {
             rpass.draw(0..6, batch.start_index..(batch.start_index + batch.count));
         }
         self.current_scissor = None;
+        self.current_clip = None;
+        self.opacity = 1.0;
     }
     
     pub fn clear(&mut self) {
@@ -333,6 +462,10 @@ impl ImageRenderer {
         for instance in &snapshot.instances {
             let mut i = *instance;
             i.pos += offset;
+            if i.clip_rect.z > 0.0 {
+                i.clip_rect.x += offset.x;
+                i.clip_rect.y += offset.y;
+            }
             self.instances.push(i);
         }
 
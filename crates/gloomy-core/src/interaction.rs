@@ -1,16 +1,36 @@
 //! Interaction state management for UI.
 
+use crate::accessibility::{Announcement, LiveRegion};
+use crate::action::Action;
+use crate::spinner::SpinRepeat;
+use crate::validation::ValidationTrigger;
 use crate::widget::Widget;
 use glam::Vec2;
 use chrono::{Datelike, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Tracks the state of user interaction (mouse, hover, active).
+///
+/// Most fields are transient: pointer, hover and press state, the action
+/// triggered this frame, drags in progress, pending announcements. The
+/// fields worth keeping across sessions (focus, scroll offsets, calendar
+/// months, cell and label selections, dirty cells, released focus traps)
+/// are captured by [`InteractionState::snapshot`] and put back by
+/// [`InteractionState::restore`].
 #[derive(Debug, Default, Clone)]
 pub struct InteractionState {
   /// Current mouse position.
   pub mouse_pos: Vec2,
   /// Is the mouse button currently pressed?
   pub is_pressed: bool,
+  /// Set when the button goes down, until [`InteractionState::handle_hit`]
+  /// reports that press; dragging onto other targets while held doesn't
+  /// count as pressing them.
+  pub press_pending: bool,
+  /// Action the current press started on; releasing over it emits
+  /// [`UiEvent::Action`].
+  pub pressed_action: Option<String>,
   /// ID Action of the widget currently being hovered.
   pub hovered_action: Option<String>,
   /// ID Action of the widget actively being pressed.
@@ -25,6 +45,13 @@ pub struct InteractionState {
   pub scroll_offsets: std::collections::HashMap<String, Vec2>,
   /// Validation errors for widgets (ID -> List of errors).
   pub validation_errors: std::collections::HashMap<String, Vec<String>>,
+  /// When input widgets are validated, see [`crate::validation`].
+  pub validation_trigger: ValidationTrigger,
+  /// Widget that lost focus since validation last ran; set by
+  /// [`InteractionState::set_focus`].
+  pub blurred_id: Option<String>,
+  /// Spinner arrow held down, see [`crate::spinner`].
+  pub spin_repeat: Option<SpinRepeat>,
   /// Calendar view state for DatePicker widgets (ID -> (Month, Year)).
   pub calendar_view_state: std::collections::HashMap<String, (u32, i32)>,
   /// Last click time (for double-click detection) - milliseconds since epoch.
@@ -37,6 +64,116 @@ pub struct InteractionState {
   pub grid_edit_buffer: String,
   /// Set of dirty/modified cells: (grid_id, row, col).
   pub dirty_cells: std::collections::HashSet<(String, usize, usize)>,
+  /// Text selected in a selectable label, see [`crate::selection`].
+  pub label_selection: Option<LabelSelection>,
+  /// Cell range selected in a DataGrid, see [`crate::cell_selection`].
+  pub cell_selection: Option<CellSelection>,
+  /// Scrollbar thumb being dragged, see [`crate::scrollbar`].
+  pub scrollbar_drag: Option<crate::scrollbar::ScrollbarDrag>,
+  /// IDs of focus traps released with Escape; remove an ID to re-arm
+  /// its trap. See [`crate::ui::get_tab_order`].
+  pub released_traps: std::collections::HashSet<String>,
+  /// Messages for assistive technology, oldest first; drain them with
+  /// [`InteractionState::take_announcements`].
+  pub announcements: Vec<Announcement>,
+  /// Last text seen in each live region (ID -> text), see
+  /// [`crate::accessibility::update_live_regions`].
+  pub live_region_text: std::collections::HashMap<String, String>,
+  /// Margin around small controls (buttons, checkboxes, radio buttons,
+  /// switches, sliders) that still hits them, for touch input. Widgets
+  /// can override it with their own `hit_padding`. 0 by default.
+  pub hit_padding: f32,
+  /// Feedback callback set with [`InteractionState::on_ui_event`].
+  pub ui_event_hook: Option<UiEventHook>,
+  /// Latest press on each action, for button ripples (action -> press).
+  pub ripples: std::collections::HashMap<String, Ripple>,
+  /// Area popovers were placed within on the last rendered frame, see
+  /// [`crate::popover`]. Render records it so hit testing flips popovers
+  /// near the edges the same way.
+  pub overlay_area: std::cell::Cell<Option<crate::rect::Rect>>,
+}
+
+/// How long a button ripple takes to expand and fade out.
+pub const RIPPLE_SECONDS: f32 = 0.3;
+
+/// Where and when an action was pressed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ripple {
+  /// Mouse position at the press, in the same coordinates as hit testing.
+  pub origin: Vec2,
+  /// Frame clock time of the press.
+  pub start: f32,
+}
+
+/// Interactions apps may give audible or haptic feedback for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiEvent {
+  /// The pointer moved onto a new hit target.
+  HoverEnter,
+  /// The mouse was pressed on a target.
+  Press,
+  /// A target fired: released over the target it was pressed on, or a
+  /// button activated from the keyboard.
+  Action,
+}
+
+/// Callback receiving each [`UiEvent`] with the action id it concerns.
+#[derive(Clone)]
+pub struct UiEventHook(std::sync::Arc<dyn Fn(UiEvent, &str) + Send + Sync>);
+
+impl std::fmt::Debug for UiEventHook {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("UiEventHook")
+  }
+}
+
+/// A character range selected in a label, by char index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelSelection {
+  /// The label's `id`.
+  pub id: String,
+  /// Where the drag started.
+  pub anchor: usize,
+  /// Where the drag is now; may be before `anchor`.
+  pub focus: usize,
+}
+
+impl LabelSelection {
+  pub fn range(&self) -> std::ops::Range<usize> {
+    self.anchor.min(self.focus)..self.anchor.max(self.focus)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.anchor == self.focus
+  }
+}
+
+/// A rectangular cell range selected in a DataGrid.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CellSelection {
+  /// The grid's `id`.
+  pub grid: String,
+  /// The first clicked cell, as (row, col).
+  pub anchor: (usize, usize),
+  /// The Shift-clicked corner opposite `anchor`; equal to it for a
+  /// single cell.
+  pub focus: (usize, usize),
+}
+
+impl CellSelection {
+  /// Selected rows, top to bottom.
+  pub fn rows(&self) -> std::ops::RangeInclusive<usize> {
+    self.anchor.0.min(self.focus.0)..=self.anchor.0.max(self.focus.0)
+  }
+
+  /// Selected columns, left to right.
+  pub fn cols(&self) -> std::ops::RangeInclusive<usize> {
+    self.anchor.1.min(self.focus.1)..=self.anchor.1.max(self.focus.1)
+  }
+
+  pub fn contains(&self, row: usize, col: usize) -> bool {
+    self.rows().contains(&row) && self.cols().contains(&col)
+  }
 }
 
 impl InteractionState {
@@ -51,11 +188,45 @@ impl InteractionState {
   }
 
   /// Update state with mouse press/release.
+  ///
+  /// Releasing over the action that was pressed emits [`UiEvent::Action`].
   pub fn set_pressed(&mut self, pressed: bool) {
+    self.press_pending = pressed && (self.press_pending || !self.is_pressed);
     self.is_pressed = pressed;
     if !pressed {
       // release
       self.active_action = None;
+      if let Some(pressed) = self.pressed_action.take() {
+        if self.hovered_action.as_deref() == Some(pressed.as_str()) {
+          self.emit(UiEvent::Action, &pressed);
+        }
+      }
+    }
+  }
+
+  /// Calls `hook` on hover-enter, press and action, e.g. to play UI sounds
+  /// or trigger haptics. Hover-enter fires only when the hovered action
+  /// changes, so moving within one target is a single event.
+  pub fn on_ui_event(&mut self, hook: impl Fn(UiEvent, &str) + Send + Sync + 'static) {
+    self.ui_event_hook = Some(UiEventHook(std::sync::Arc::new(hook)));
+  }
+
+  /// True while a ripple is still animating; keep requesting redraws
+  /// until it returns false.
+  pub fn has_active_ripples(&self) -> bool {
+    let now = crate::ui::frame_clock();
+    self.ripples.values().any(|r| now - r.start < RIPPLE_SECONDS)
+  }
+
+  fn start_ripple(&mut self, action: String) {
+    let now = crate::ui::frame_clock();
+    self.ripples.retain(|_, r| now - r.start < RIPPLE_SECONDS);
+    self.ripples.insert(action, Ripple { origin: self.mouse_pos, start: now });
+  }
+
+  pub(crate) fn emit(&self, event: UiEvent, action: &str) {
+    if let Some(UiEventHook(hook)) = &self.ui_event_hook {
+      hook(event, action);
     }
   }
 
@@ -79,56 +250,92 @@ impl InteractionState {
     self.active_action.as_deref() == Some(action)
   }
 
+  /// Moves focus to `id`, recording the widget that loses it in
+  /// `blurred_id`. Prefer this over assigning `focused_id` so blur
+  /// validation sees the change.
+  pub fn set_focus(&mut self, id: Option<String>) {
+    if self.focused_id == id {
+        return;
+    }
+    if let Some(previous) = self.focused_id.take() {
+        self.blurred_id = Some(previous);
+    }
+    self.focused_id = id;
+  }
+
+  /// Queues `message` for assistive technology.
+  pub fn announce(&mut self, message: impl Into<String>, priority: LiveRegion) {
+    self.announcements.push(Announcement { message: message.into(), priority });
+  }
+
+  /// Returns the queued announcements and clears the queue.
+  pub fn take_announcements(&mut self) -> Vec<Announcement> {
+    std::mem::take(&mut self.announcements)
+  }
+
   /// Cycles focus to the next element in the list.
   pub fn focus_next(&mut self, focusable_ids: &[String]) {
     if focusable_ids.is_empty() {
-        self.focused_id = None;
+        self.set_focus(None);
         return;
     }
 
     if let Some(current) = &self.focused_id {
         if let Some(idx) = focusable_ids.iter().position(|id| id == current) {
             let next_idx = (idx + 1) % focusable_ids.len();
-            self.focused_id = Some(focusable_ids[next_idx].clone());
+            self.set_focus(Some(focusable_ids[next_idx].clone()));
         } else {
-            self.focused_id = Some(focusable_ids[0].clone());
+            self.set_focus(Some(focusable_ids[0].clone()));
         }
     } else {
-        self.focused_id = Some(focusable_ids[0].clone());
+        self.set_focus(Some(focusable_ids[0].clone()));
     }
   }
 
   /// Cycles focus to the previous element in the list.
   pub fn focus_prev(&mut self, focusable_ids: &[String]) {
     if focusable_ids.is_empty() {
-        self.focused_id = None;
+        self.set_focus(None);
         return;
     }
 
     if let Some(current) = &self.focused_id {
         if let Some(idx) = focusable_ids.iter().position(|id| id == current) {
             let prev_idx = if idx == 0 { focusable_ids.len() - 1 } else { idx - 1 };
-            self.focused_id = Some(focusable_ids[prev_idx].clone());
+            self.set_focus(Some(focusable_ids[prev_idx].clone()));
         } else {
-            self.focused_id = Some(focusable_ids.last().unwrap().clone());
+            self.set_focus(Some(focusable_ids.last().unwrap().clone()));
         }
     } else {
-        self.focused_id = Some(focusable_ids.last().unwrap().clone());
+        self.set_focus(Some(focusable_ids.last().unwrap().clone()));
     }
   }
 
   /// Update state based on hit test result.
   pub fn handle_hit(&mut self, action_id: Option<String>) {
       if let Some(act) = action_id {
+          if self.hovered_action.as_deref() != Some(act.as_str()) {
+              self.emit(UiEvent::HoverEnter, &act);
+          }
           self.hovered_action = Some(act);
           if self.is_pressed {
+              if std::mem::take(&mut self.press_pending) {
+                  let pressed = self.hovered_action.clone().unwrap_or_default();
+                  self.emit(UiEvent::Press, &pressed);
+                  self.pressed_action = Some(pressed.clone());
+                  self.start_ripple(pressed);
+              }
               self.active_action = self.hovered_action.clone();
-              self.focused_id = self.hovered_action.clone();
+              self.set_focus(self.hovered_action.clone());
           }
       } else {
           self.hovered_action = None;
           if self.is_pressed {
-              self.focused_id = None;
+              // A press on bare background presses nothing.
+              if std::mem::take(&mut self.press_pending) {
+                  self.pressed_action = None;
+              }
+              self.set_focus(None);
           }
       }
   }
@@ -231,10 +438,215 @@ impl InteractionState {
   pub fn is_dirty(&self, grid_id: &str, row: usize, col: usize) -> bool {
       self.dirty_cells.contains(&(grid_id.to_string(), row, col))
   }
+
+  /// Captures the persistent part of the state, e.g. to save on exit.
+  pub fn snapshot(&self) -> InteractionSnapshot {
+    InteractionSnapshot {
+      focused_id: self.focused_id.clone(),
+      scroll_offsets: self.scroll_offsets.iter().map(|(id, v)| (id.clone(), (v.x, v.y))).collect(),
+      calendar_view_state: self.calendar_view_state.iter().map(|(id, v)| (id.clone(), *v)).collect(),
+      label_selection: self.label_selection.clone(),
+      cell_selection: self.cell_selection.clone(),
+      dirty_cells: self.dirty_cells.iter().cloned().collect(),
+      released_traps: self.released_traps.iter().cloned().collect(),
+    }
+  }
+
+  /// Replaces the persistent part of the state with `snapshot`. Transient
+  /// fields are left as they are.
+  pub fn restore(&mut self, snapshot: InteractionSnapshot) {
+    self.focused_id = snapshot.focused_id;
+    self.scroll_offsets = snapshot.scroll_offsets.into_iter().map(|(id, (x, y))| (id, Vec2::new(x, y))).collect();
+    self.calendar_view_state = snapshot.calendar_view_state.into_iter().collect();
+    self.label_selection = snapshot.label_selection;
+    self.cell_selection = snapshot.cell_selection;
+    self.dirty_cells = snapshot.dirty_cells.into_iter().collect();
+    self.released_traps = snapshot.released_traps.into_iter().collect();
+  }
+}
+
+/// The persistent part of an [`InteractionState`], for session restore and
+/// test fixtures.
+///
+/// Serializes with serde; [`InteractionSnapshot::to_ron`] and
+/// [`InteractionSnapshot::from_ron`] cover the common case. Maps are
+/// ordered so the output is stable. Tree expansion and list selection are
+/// not here: they live on the `Tree` and `ListView` widgets themselves.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InteractionSnapshot {
+  #[serde(default)]
+  pub focused_id: Option<String>,
+  /// Scroll offsets as (x, y), by scrollable id.
+  #[serde(default)]
+  pub scroll_offsets: BTreeMap<String, (f32, f32)>,
+  /// Shown (month, year), by DatePicker id.
+  #[serde(default)]
+  pub calendar_view_state: BTreeMap<String, (u32, i32)>,
+  #[serde(default)]
+  pub label_selection: Option<LabelSelection>,
+  #[serde(default)]
+  pub cell_selection: Option<CellSelection>,
+  /// Edited cells as (grid_id, row, col).
+  #[serde(default)]
+  pub dirty_cells: BTreeSet<(String, usize, usize)>,
+  #[serde(default)]
+  pub released_traps: BTreeSet<String>,
+}
+
+impl InteractionSnapshot {
+  /// Serializes the snapshot to pretty-printed RON.
+  pub fn to_ron(&self) -> anyhow::Result<String> {
+    Ok(ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?)
+  }
+
+  /// Parses a snapshot written by [`InteractionSnapshot::to_ron`].
+  pub fn from_ron(s: &str) -> anyhow::Result<Self> {
+    Ok(ron::from_str(s)?)
+  }
+}
+
+/// Mouse cursor suggested for the region under the pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorHint {
+  #[default]
+  Default,
+  /// Clickable: buttons, options, tabs, day cells.
+  Pointer,
+  /// Editable text.
+  Text,
+  /// Horizontal resize handle, e.g. a DataGrid column edge.
+  EwResize,
+  /// Vertical resize handle.
+  NsResize,
+}
+
+impl From<CursorHint> for winit::window::CursorIcon {
+  fn from(hint: CursorHint) -> Self {
+    match hint {
+      CursorHint::Default => Self::Default,
+      CursorHint::Pointer => Self::Pointer,
+      CursorHint::Text => Self::Text,
+      CursorHint::EwResize => Self::EwResize,
+      CursorHint::NsResize => Self::NsResize,
+    }
+  }
 }
 
 /// Hit test result.
 pub struct HitTestResult<'a> {
   pub widget: &'a Widget,
   pub action: String,
+  /// Cursor for the hit region; apps pass it to `Window::set_cursor_icon`.
+  pub cursor: CursorHint,
+}
+
+impl HitTestResult<'_> {
+  /// Parses the raw action string into a structured [`Action`].
+  pub fn parse_action(&self) -> Action {
+    Action::parse(&self.action)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::{Arc, Mutex};
+
+  #[test]
+  fn test_ui_events_hover_once_then_press_and_action() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut state = InteractionState::new();
+    let sink = events.clone();
+    state.on_ui_event(move |event, action| sink.lock().unwrap().push((event, action.to_string())));
+
+    // Moving within the button reports one hover-enter.
+    state.handle_hit(Some("save".to_string()));
+    state.handle_hit(Some("save".to_string()));
+    state.set_pressed(true);
+    state.handle_hit(Some("save".to_string()));
+    state.handle_hit(Some("save".to_string()));
+    state.set_pressed(false);
+
+    let expected = [UiEvent::HoverEnter, UiEvent::Press, UiEvent::Action];
+    assert_eq!(*events.lock().unwrap(), expected.map(|e| (e, "save".to_string())).to_vec());
+  }
+
+  #[test]
+  fn test_dragging_onto_another_target_is_not_a_press() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut state = InteractionState::new();
+    let sink = events.clone();
+    state.on_ui_event(move |event, action| sink.lock().unwrap().push((event, action.to_string())));
+
+    state.set_pressed(true);
+    state.handle_hit(Some("save".to_string()));
+    state.set_pressed(true);
+    state.handle_hit(Some("open".to_string()));
+
+    let presses: Vec<String> = events.lock().unwrap().iter()
+      .filter(|(event, _)| *event == UiEvent::Press)
+      .map(|(_, action)| action.clone())
+      .collect();
+    assert_eq!(presses, ["save"]);
+    assert!(!state.ripples.contains_key("open"));
+  }
+
+  #[test]
+  fn test_release_over_another_target_is_not_an_action() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut state = InteractionState::new();
+    let sink = events.clone();
+    state.on_ui_event(move |event, action| sink.lock().unwrap().push((event, action.to_string())));
+
+    // Press on "save", drag onto "open" and release there.
+    state.set_pressed(true);
+    state.handle_hit(Some("save".to_string()));
+    state.handle_hit(Some("open".to_string()));
+    state.set_pressed(false);
+    // Press on "save", drag off and back, release over it.
+    state.set_pressed(true);
+    state.handle_hit(Some("save".to_string()));
+    state.handle_hit(Some("open".to_string()));
+    state.handle_hit(Some("save".to_string()));
+    state.set_pressed(false);
+
+    let actions: Vec<String> = events.lock().unwrap().iter()
+      .filter(|(event, _)| *event == UiEvent::Action)
+      .map(|(_, action)| action.clone())
+      .collect();
+    assert_eq!(actions, ["save"]);
+    assert!(state.pressed_action.is_none());
+  }
+
+  #[test]
+  fn test_press_records_ripple_origin() {
+    let mut state = InteractionState::new();
+    state.update_mouse(Vec2::new(12.0, 8.0));
+    state.set_pressed(true);
+    state.handle_hit(Some("save".to_string()));
+
+    assert_eq!(state.ripples["save"].origin, Vec2::new(12.0, 8.0));
+  }
+
+  #[test]
+  fn test_snapshot_round_trip_skips_transient_state() {
+    let mut state = InteractionState::new();
+    state.set_focus(Some("name".to_string()));
+    state.handle_scroll("files", Vec2::new(0.0, 120.0));
+    state.calendar_view_state.insert("dob".to_string(), (2, 2024));
+    state.cell_selection = Some(CellSelection { grid: "grid".to_string(), anchor: (1, 0), focus: (3, 2) });
+    state.mark_dirty("grid", 4, 1);
+    state.update_mouse(Vec2::new(50.0, 60.0));
+    state.set_pressed(true);
+
+    let ron = state.snapshot().to_ron().unwrap();
+    let mut restored = InteractionState::new();
+    restored.restore(InteractionSnapshot::from_ron(&ron).unwrap());
+
+    assert_eq!(restored.snapshot(), state.snapshot());
+    assert_eq!(restored.scroll_offsets["files"], state.scroll_offsets["files"]);
+    assert!(restored.is_dirty("grid", 4, 1));
+    assert_eq!(restored.mouse_pos, Vec2::ZERO);
+    assert!(!restored.is_pressed);
+  }
 }
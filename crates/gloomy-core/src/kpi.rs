@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::widget::{Color, WidgetBounds};
+use crate::theme::{themed_default, Theme, Themed};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct KpiCardStyle {
@@ -17,7 +18,34 @@ pub struct KpiCardStyle {
 }
 
 impl Default for KpiCardStyle {
+    /// Derived from the current theme, see [`crate::theme::set_current_theme`].
     fn default() -> Self {
+        themed_default(Self::fallback)
+    }
+}
+
+impl Themed for KpiCardStyle {
+    fn themed(theme: &Theme) -> Self {
+        let c = &theme.colors;
+        Self {
+            background: c.surface,
+            border_color: c.border,
+            border_width: 1.0,
+            corner_radius: 8.0,
+            label_color: c.text_secondary,
+            label_size: 14.0,
+            value_color: c.text,
+            value_size: 24.0,
+            trend_up_color: c.success,
+            trend_down_color: c.error,
+            trend_neutral_color: c.text_secondary,
+        }
+    }
+}
+
+impl KpiCardStyle {
+    /// Built-in dark style, used when no theme is current.
+    fn fallback() -> Self {
         Self {
             background: (0.15, 0.15, 0.2, 1.0),
             border_color: (0.3, 0.3, 0.3, 1.0),
@@ -1,6 +1,7 @@
 //! Layout primitives for Flexbox-style positioning.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Layout direction for containers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
@@ -40,6 +41,32 @@ impl Default for Align {
   }
 }
 
+/// Per-child override of the container's alignment, see `align_self` and
+/// `justify_self` on widgets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub enum AlignSelf {
+  /// Use the container's setting.
+  #[default]
+  Auto,
+  Start,
+  Center,
+  End,
+  Stretch,
+}
+
+impl AlignSelf {
+  /// The alignment to use in a container aligning with `container`.
+  pub fn resolve(self, container: Align) -> Align {
+    match self {
+      AlignSelf::Auto => container,
+      AlignSelf::Start => Align::Start,
+      AlignSelf::Center => Align::Center,
+      AlignSelf::End => Align::End,
+      AlignSelf::Stretch => Align::Stretch,
+    }
+  }
+}
+
 /// Justification of content along the main axis.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 pub enum Justify {
@@ -78,17 +105,215 @@ impl Default for TrackSize {
     }
 }
 
+/// Space between a container's edges and its content.
+///
+/// In RON either a single number, the same on every side, or
+/// `[top, right, bottom, left]`:
+///
+/// ```ignore
+/// Container(padding: 8.0, ...)
+/// Container(padding: [4.0, 12.0, 4.0, 12.0], ...)
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Padding {
+  pub top: f32,
+  pub right: f32,
+  pub bottom: f32,
+  pub left: f32,
+}
+
+impl Padding {
+  /// The same padding on every side.
+  pub fn uniform(padding: f32) -> Self {
+    Self { top: padding, right: padding, bottom: padding, left: padding }
+  }
+
+  /// Padding given in CSS order.
+  pub fn sides(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+    Self { top, right, bottom, left }
+  }
+
+  /// Left plus right padding.
+  pub fn horizontal(&self) -> f32 {
+    self.left + self.right
+  }
+
+  /// Top plus bottom padding.
+  pub fn vertical(&self) -> f32 {
+    self.top + self.bottom
+  }
+}
+
+impl From<f32> for Padding {
+  fn from(padding: f32) -> Self {
+    Self::uniform(padding)
+  }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum PaddingRepr {
+  Uniform(f32),
+  Sides([f32; 4]),
+}
+
+impl Serialize for Padding {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let repr = if *self == Self::uniform(self.top) {
+      PaddingRepr::Uniform(self.top)
+    } else {
+      PaddingRepr::Sides([self.top, self.right, self.bottom, self.left])
+    };
+    repr.serialize(serializer)
+  }
+}
+
+impl<'de> Deserialize<'de> for Padding {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    Ok(match PaddingRepr::deserialize(deserializer)? {
+      PaddingRepr::Uniform(padding) => Self::uniform(padding),
+      PaddingRepr::Sides([top, right, bottom, left]) => Self::sides(top, right, bottom, left),
+    })
+  }
+}
+
+/// Edges of the window covered by decorations, notches or system bars.
+///
+/// [`crate::layout_engine::compute_root_layout`] places the root inside the
+/// remaining area. Insets sit outside the root, so its own padding is
+/// applied on top: content starts `inset + padding` from the window edge.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+pub struct SafeArea {
+  pub top: f32,
+  pub right: f32,
+  pub bottom: f32,
+  pub left: f32,
+}
+
+impl SafeArea {
+  /// The same inset on every edge.
+  pub fn uniform(inset: f32) -> Self {
+    Self { top: inset, right: inset, bottom: inset, left: inset }
+  }
+
+  /// The `(x, y, width, height)` left of a `width` x `height` window.
+  pub fn inset(&self, width: f32, height: f32) -> (f32, f32, f32, f32) {
+    (
+      self.left,
+      self.top,
+      (width - self.left - self.right).max(0.0),
+      (height - self.top - self.bottom).max(0.0),
+    )
+  }
+}
+
 /// complete Layout configuration for a container.
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct Layout {
   #[serde(default)]
   pub direction: Direction,
+  /// Gap between items on both axes, unless `row_gap`/`column_gap` is set.
   #[serde(default)]
   pub spacing: f32,
+  /// Gap between rows: between items of a `Column`, between grid rows.
+  #[serde(default)]
+  pub row_gap: Option<f32>,
+  /// Gap between columns: between items of a `Row`, between grid columns.
+  #[serde(default)]
+  pub column_gap: Option<f32>,
   #[serde(default)]
   pub align_items: Align,
   #[serde(default)]
   pub justify_content: Justify,
   #[serde(default)]
   pub template_columns: Vec<TrackSize>,
+  /// Alternative layouts for wider containers, see [`Layout::resolve`].
+  #[serde(default)]
+  pub breakpoints: Vec<Breakpoint>,
+  /// Named cell ranges of a `Grid`. Children placed with
+  /// `grid_area: Some("name")` take the area's position and spans in place
+  /// of their own `grid_col`/`grid_row`/`col_span`/`row_span`.
+  #[serde(default)]
+  pub areas: HashMap<String, GridArea>,
+}
+
+/// A named range of grid cells, see [`Layout::areas`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GridArea {
+  pub col: usize,
+  pub row: usize,
+  #[serde(default = "default_span")]
+  pub col_span: usize,
+  #[serde(default = "default_span")]
+  pub row_span: usize,
+}
+
+fn default_span() -> usize {
+  1
+}
+
+/// Layout a container switches to once it is at least `min_width` wide.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Breakpoint {
+  pub min_width: f32,
+  pub layout: Layout,
+}
+
+impl Layout {
+  /// Whether `name` is an area of this layout or of one of its breakpoints.
+  pub fn has_area(&self, name: &str) -> bool {
+    self.areas.contains_key(name) || self.breakpoints.iter().any(|bp| bp.layout.areas.contains_key(name))
+  }
+
+  /// Gap between grid rows and between the items of a `Column`.
+  pub fn row_gap(&self) -> f32 {
+    self.row_gap.unwrap_or(self.spacing)
+  }
+
+  /// Gap between grid columns and between the items of a `Row`.
+  pub fn column_gap(&self) -> f32 {
+    self.column_gap.unwrap_or(self.spacing)
+  }
+
+  /// Gap between consecutive items along the flex direction.
+  ///
+  /// Gaps always separate items. `justify_content` only distributes the
+  /// space left after them, so `SpaceBetween` with a gap never packs
+  /// items closer than the gap.
+  pub fn main_gap(&self) -> f32 {
+    match self.direction {
+      Direction::Row => self.column_gap(),
+      _ => self.row_gap(),
+    }
+  }
+
+  /// Index of the breakpoint active at `width`: the one with the largest
+  /// `min_width` not above `width`, the first of equal ones winning.
+  /// `None` means the base layout applies.
+  pub fn active_breakpoint(&self, width: f32) -> Option<usize> {
+    let mut active: Option<(usize, f32)> = None;
+    for (i, bp) in self.breakpoints.iter().enumerate() {
+      let wider = match active {
+        Some((_, min_width)) => bp.min_width > min_width,
+        None => true,
+      };
+      if bp.min_width <= width && wider {
+        active = Some((i, bp.min_width));
+      }
+    }
+    active.map(|(i, _)| i)
+  }
+
+  /// The layout used by a container `width` wide.
+  ///
+  /// Breakpoints are min-width thresholds, like CSS `min-width` media
+  /// queries applied to the container: the base layout covers widths below
+  /// every threshold. Breakpoint layouts are used as-is; their own
+  /// `breakpoints` are ignored.
+  pub fn resolve(&self, width: f32) -> &Layout {
+    match self.active_breakpoint(width) {
+      Some(i) => &self.breakpoints[i].layout,
+      None => self,
+    }
+  }
 }
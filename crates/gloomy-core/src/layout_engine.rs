@@ -1,9 +1,42 @@
 //! Layout engine for recalculating widget bounds.
 
-use crate::layout::{Align, Direction, Justify, TrackSize};
-use crate::widget::{Widget, Orientation};
+use crate::layout::{Align, AlignSelf, Direction, Justify, Layout, SafeArea, TrackSize};
+use crate::measure::{measure_tree, HeuristicMeasurer, MeasuredText, TextMeasurer, BUTTON_PADDING, BUTTON_TEXT_SIZE};
+use crate::widget::{Widget, Orientation, SkeletonShape};
+
+/// Computes the layout for a widget tree, measuring text with `measurer`.
+///
+/// Labels and buttons without an explicit size get their intrinsic size
+/// from `measurer` (see [`measure_tree`]) instead of the estimate used by
+/// [`compute_layout`]. Pass the app's `TextRenderer` for real glyph metrics.
+pub fn compute_layout_with(
+  widget: &mut Widget,
+  parent_x: f32,
+  parent_y: f32,
+  parent_width: f32,
+  parent_height: f32,
+  measurer: &dyn TextMeasurer,
+) {
+  measure_tree(widget, measurer);
+  compute_layout(widget, parent_x, parent_y, parent_width, parent_height);
+}
+
+/// Lays out `root` as the root of a `width` x `height` window, inside the
+/// window's `safe_area`.
+///
+/// Sizes and positions the root to the safe area, then runs
+/// [`compute_layout`]. With `SafeArea::default()` the root fills the
+/// window. The root's padding applies within the safe area.
+pub fn compute_root_layout(root: &mut Widget, width: f32, height: f32, safe_area: SafeArea) {
+  let (x, y, w, h) = safe_area.inset(width, height);
+  set_pos(root, x, y);
+  set_size(root, w, h);
+  compute_layout(root, x, y, w, h);
+}
 
 /// Computes the layout for a widget tree.
+///
+/// Unmeasured text is sized with [`HeuristicMeasurer`].
 pub fn compute_layout(
   widget: &mut Widget,
   _parent_x: f32,
@@ -18,12 +51,21 @@ pub fn compute_layout(
       padding,
       children,
       layout_cache,
+      scrollable,
+      overflow,
+      scrollbar,
+      scrollbar_mode,
       ..
     } => {
+        // Breakpoints switch on the container's own width.
+        let breakpoint = layout.active_breakpoint(bounds.width);
+        let layout = layout.resolve(bounds.width);
+
         // --- LAYOUT CACHING START ---
         // Check if we can skip layout calculation
         if let Some(cache) = layout_cache {
             if cache.valid && 
+               cache.breakpoint == breakpoint &&
                (cache.input_width - _parent_width).abs() < 0.001 &&
                (cache.input_height - _parent_height).abs() < 0.001 &&
                (cache.parent_x - _parent_x).abs() < 0.001 &&
@@ -37,8 +79,14 @@ pub fn compute_layout(
         // --- LAYOUT CACHING END ---
 
       // Effective content area
-      let content_width = (bounds.width - *padding * 2.0).max(0.0);
-      let content_height = (bounds.height - *padding * 2.0).max(0.0);
+      // A scrollbar gutter narrows the content of scrolling containers.
+      let gutter = if overflow.resolve(*scrollable).scrolls() {
+        crate::scrollbar::gutter_width(*scrollbar, *scrollbar_mode)
+      } else {
+        0.0
+      };
+      let content_width = (bounds.width - padding.horizontal() - gutter).max(0.0);
+      let content_height = (bounds.height - padding.vertical()).max(0.0);
 
       // Check if we have an active layout
       match layout.direction {
@@ -65,7 +113,7 @@ pub fn compute_layout(
           }
 
           let spacing_total = if count > 1 {
-            (count - 1) as f32 * layout.spacing
+            (count - 1) as f32 * layout.main_gap()
           } else {
             0.0
           };
@@ -111,7 +159,11 @@ pub fn compute_layout(
             current_main += step_extra / 2.0;
           }
 
-          for child in children.iter_mut() {
+          // Children are placed in `order`; declaration order breaks ties.
+          let mut placement: Vec<usize> = (0..children.len()).collect();
+          placement.sort_by_key(|&i| get_order(&children[i]));
+          for i in placement {
+            let child = &mut children[i];
             let child_flex = get_flex(child);
             let (mut child_w, mut child_h) = get_fixed_size(child); // Start with desired/fixed size
 
@@ -132,8 +184,9 @@ pub fn compute_layout(
               Direction::Column => content_width,
               _ => 0.0,
             };
+            let align = get_align_self(child).resolve(layout.align_items);
 
-            match layout.align_items {
+            match align {
               Align::Stretch => {
                 match layout.direction {
                   Direction::Row => child_h = cross_axis_size,
@@ -150,31 +203,30 @@ pub fn compute_layout(
             // Set position
             match layout.direction {
               Direction::Row => {
-                let cross_pos = match layout.align_items {
+                let cross_pos = match align {
                   Align::Start | Align::Stretch => 0.0,
                   Align::Center => (content_height - child_h) / 2.0,
                   Align::End => content_height - child_h,
                 };
                 set_size(child, child_w, child_h);
-                set_pos(child, *padding + current_main, *padding + cross_pos);
+                set_pos(child, padding.left + current_main, padding.top + cross_pos);
                 current_main += child_w;
               }
               Direction::Column => {
-                let cross_pos = match layout.align_items {
+                let cross_pos = match align {
                   Align::Start | Align::Stretch => 0.0,
                   Align::Center => (content_width - child_w) / 2.0,
                   Align::End => content_width - child_w,
                 };
                 set_size(child, child_w, child_h);
-                set_pos(child, *padding + cross_pos, *padding + current_main);
-                set_pos(child, *padding + cross_pos, *padding + current_main);
+                set_pos(child, padding.left + cross_pos, padding.top + current_main);
                 current_main += child_h;
               }
               _ => {}
             }
 
-            // Add spacing (and extra justification space)
-            current_main += layout.spacing + step_extra;
+            // Add the gap (and extra justification space)
+            current_main += layout.main_gap() + step_extra;
 
             // Recurse
             compute_layout(
@@ -211,10 +263,7 @@ pub fn compute_layout(
           let mut current_col = 0usize;
           
           for child in children.iter() {
-              let explicit_col = get_explicit_grid_col(child);
-              let explicit_row = get_explicit_grid_row(child);
-              let c_span = get_col_span(child);
-              let r_span = get_row_span(child);
+              let (explicit_col, explicit_row, c_span, r_span) = grid_placement(child, layout);
               
               let (c, r) = if explicit_col.is_some() && explicit_row.is_some() {
                   // Explicit position
@@ -309,7 +358,7 @@ pub fn compute_layout(
                  .sum();
              
              let spacing_total = if cols > 1 {
-                 (cols - 1) as f32 * layout.spacing
+                 (cols - 1) as f32 * layout.column_gap()
              } else {
                  0.0
              };
@@ -326,7 +375,7 @@ pub fn compute_layout(
               // Auto-sizing based on content
               for (idx, child) in children.iter().enumerate() {
                   let (c, _r) = assigned_positions[idx];
-                  let c_span = get_col_span(child);
+                  let (_, _, c_span, _) = grid_placement(child, layout);
                   let (w, _h) = get_fixed_size(child);
                   if c_span == 1 && c < cols {
                       col_widths[c] = col_widths[c].max(w);
@@ -337,7 +386,7 @@ pub fn compute_layout(
           // --- ROW SIZING ---
           for (idx, child) in children.iter().enumerate() {
               let (_c, r) = assigned_positions[idx];
-              let r_span = get_row_span(child);
+              let (_, _, _, r_span) = grid_placement(child, layout);
               let (_w, h) = get_fixed_size(child);
               if r_span == 1 && r < rows {
                   row_heights[r] = row_heights[r].max(h);
@@ -348,23 +397,22 @@ pub fn compute_layout(
           let mut col_offsets = vec![0.0; cols + 1];
           let mut row_offsets = vec![0.0; rows + 1];
           
-          let mut current_offset = *padding;
+          let mut current_offset = padding.left;
           for i in 0..cols {
               col_offsets[i] = current_offset;
-              current_offset += col_widths[i] + layout.spacing;
+              current_offset += col_widths[i] + layout.column_gap();
           }
 
-          current_offset = *padding;
+          current_offset = padding.top;
           for i in 0..rows {
               row_offsets[i] = current_offset;
-              current_offset += row_heights[i] + layout.spacing;
+              current_offset += row_heights[i] + layout.row_gap();
           }
 
           // --- POSITION CHILDREN ---
           for (idx, child) in children.iter_mut().enumerate() {
               let (c, r) = assigned_positions[idx];
-              let c_span = get_col_span(child);
-              let r_span = get_row_span(child);
+              let (_, _, c_span, r_span) = grid_placement(child, layout);
 
               if c < cols && r < rows {
                   let x = col_offsets[c];
@@ -374,29 +422,43 @@ pub fn compute_layout(
                   let mut width = 0.0;
                   for i in c..std::cmp::min(c + c_span, cols) {
                       width += col_widths[i];
-                      if i > c { width += layout.spacing; }
+                      if i > c { width += layout.column_gap(); }
                   }
 
                   let mut height = 0.0;
                   for i in r..std::cmp::min(r + r_span, rows) {
                       height += row_heights[i];
-                      if i > r { height += layout.spacing; }
+                      if i > r { height += layout.row_gap(); }
                   }
                   
                   let (fw, fh) = get_fixed_size(child);
                   
-                  let (final_w, x_off) = if matches!(layout.align_items, Align::Stretch) {
-                      (width, 0.0)
-                  } else {
-                       let w = fw.min(width);
-                       match layout.justify_content {
-                           Justify::Center => (w, (width - w) / 2.0),
-                           Justify::End => (w, width - w),
-                           _ => (w, 0.0)
-                       }
+                  // Without `justify_self`, cells stretch along the row
+                  // with `align_items: Stretch` and otherwise follow
+                  // `justify_content`.
+                  let justify = match get_justify_self(child) {
+                      AlignSelf::Auto if matches!(layout.align_items, Align::Stretch) => Align::Stretch,
+                      AlignSelf::Auto => match layout.justify_content {
+                          Justify::Center => Align::Center,
+                          Justify::End => Align::End,
+                          _ => Align::Start,
+                      },
+                      explicit => explicit.resolve(Align::Start),
+                  };
+                  let (final_w, x_off) = match justify {
+                      Align::Stretch => (width, 0.0),
+                      Align::Center => {
+                          let w = fw.min(width);
+                          (w, (width - w) / 2.0)
+                      }
+                      Align::End => {
+                          let w = fw.min(width);
+                          (w, width - w)
+                      }
+                      Align::Start => (fw.min(width), 0.0),
                   };
 
-                  let (final_h, y_off) = match layout.align_items {
+                  let (final_h, y_off) = match get_align_self(child).resolve(layout.align_items) {
                       Align::Stretch => (height, 0.0),
                       Align::Center => {
                           let h = fh.min(height);
@@ -437,6 +499,7 @@ pub fn compute_layout(
           parent_x: _parent_x,
           parent_y: _parent_y,
           result_bounds: *bounds,
+          breakpoint,
           valid: true,
       }));
     }
@@ -486,6 +549,28 @@ pub fn compute_layout(
              );
         }
     }
+    Widget::VirtualFlow { bounds, item_size, spacing, realized, .. } => {
+        // Re-places already built items, e.g. when a resize changes the
+        // number of columns.
+        crate::virtual_flow::layout_items(bounds, *item_size, *spacing, realized);
+    }
+    Widget::ListView {
+        bounds, item_widgets, item_heights, style, multi_select, show_checkboxes,
+        scrollbar, scrollbar_mode, ..
+    } if !item_widgets.is_empty() => {
+        // Rows are positioned relative to the list's content, which
+        // render and hit-test offset by the scroll position.
+        let rows = crate::list_view::RowOffsets::new(item_widgets.len(), style.item_height, item_heights);
+        let inset = crate::list_view::row_inset(*multi_select, *show_checkboxes);
+        let gutter = crate::scrollbar::gutter_width(*scrollbar, *scrollbar_mode);
+        let width = (bounds.width - inset - gutter).max(0.0);
+        for (i, row) in item_widgets.iter_mut().enumerate() {
+            let (top, height) = (rows.top(i), rows.height(i));
+            set_pos(row, inset, top);
+            set_size(row, width, height);
+            compute_layout(row, inset, top, width, height);
+        }
+    }
     _ => {
       // Leaf widgets
     }
@@ -502,7 +587,8 @@ fn get_flex(widget: &Widget) -> f32 {
     Widget::NumberInput { flex, .. } => *flex,
     Widget::Autocomplete { flex, .. } => *flex,
     Widget::DatePicker { flex, .. } => *flex,
-    Widget::Spacer { .. } => 0.0,
+    Widget::TimePicker { flex, .. } => *flex,
+    Widget::Spacer { flex, .. } => *flex,
     Widget::Divider { .. } => 0.0,
     Widget::Scrollbar { .. } => 0.0,
     Widget::DataGrid { flex, .. } => *flex,
@@ -519,9 +605,24 @@ fn get_flex(widget: &Widget) -> f32 {
     Widget::ListView { flex, .. } => *flex,
     Widget::Tab { flex, .. } => *flex,
     Widget::Chart { flex, .. } => *flex,
+    Widget::VirtualFlow { flex, .. } => *flex,
+    Widget::Skeleton { flex, .. } => *flex,
+    Widget::ChipInput { flex, .. } => *flex,
+    Widget::Rating { flex, .. } => *flex,
+    Widget::MenuBar { flex, .. } => *flex,
+    Widget::Breadcrumb { flex, .. } => *flex,
+    Widget::Stepper { flex, .. } => *flex,
+    Widget::CircularProgress { flex, .. } => *flex,
+    Widget::Include { .. } => 0.0,
   }
 }
 
+/// Height of a horizontal divider between its margins: the line, or the
+/// label if that is taller.
+fn divider_band(thickness: f32, label: &Option<String>, label_size: f32) -> f32 {
+  if label.is_some() { thickness.max(label_size) } else { thickness }
+}
+
 fn calculate_tree_height(nodes: &[crate::tree::TreeNode], expanded: &std::collections::HashSet<String>, row_height: f32) -> f32 {
     let mut count = 0;
     for node in nodes {
@@ -546,28 +647,36 @@ fn calculate_tree_height_recursive(nodes: &[crate::tree::TreeNode], expanded: &s
 
 
 // Helper to get fixed/intrinsic size
-fn get_fixed_size(widget: &Widget) -> (f32, f32) {
+pub(crate) fn get_fixed_size(widget: &Widget) -> (f32, f32) {
   match widget {
-    Widget::Container { bounds, width, height, padding, children, .. } => {
+    Widget::Container { bounds, width, height, padding, layout, children, .. } => {
         let mut w = width.unwrap_or(0.0);
         let mut h = height.unwrap_or(0.0);
         
-        if w <= 0.0 && !children.is_empty() {
-            let mut max_w = 0.0f32;
+        if (w <= 0.0 || h <= 0.0) && !children.is_empty() {
+            // Rows and columns need their children side by side along the
+            // main axis; other layouts need the largest child.
+            let layout = layout.resolve(bounds.width);
+            let gaps = (children.len() - 1) as f32 * layout.main_gap();
+            let (mut sum_w, mut sum_h, mut max_w, mut max_h) = (0.0f32, 0.0f32, 0.0f32, 0.0f32);
             for child in children {
-                let (cw, _) = get_fixed_size(child);
+                let (cw, ch) = get_fixed_size(child);
+                sum_w += cw;
+                sum_h += ch;
                 max_w = max_w.max(cw);
-            }
-            w = max_w + padding * 2.0;
-        }
-        
-        if h <= 0.0 && !children.is_empty() {
-            let mut max_h = 0.0f32;
-            for child in children {
-                let (_, ch) = get_fixed_size(child);
                 max_h = max_h.max(ch);
             }
-            h = max_h + padding * 2.0;
+            let (content_w, content_h) = match layout.direction {
+                Direction::Row => (sum_w + gaps, max_h),
+                Direction::Column => (max_w, sum_h + gaps),
+                _ => (max_w, max_h),
+            };
+            if w <= 0.0 {
+                w = content_w + padding.horizontal();
+            }
+            if h <= 0.0 {
+                h = content_h + padding.vertical();
+            }
         }
         
         if w <= 0.0 { w = bounds.width; }
@@ -581,25 +690,24 @@ fn get_fixed_size(widget: &Widget) -> (f32, f32) {
         (w, h)
     },
     Widget::Icon { size, .. } => (*size, *size),
-    Widget::Button { bounds, text, font, .. } => {
+    Widget::Button { bounds, text, font, measured, .. } => {
         if bounds.width > 0.0 && bounds.height > 0.0 {
             (bounds.width, bounds.height)
         } else {
-             // We don't have a renderer here, so we'll use a slightly better estimation
-             // or ideally we should pass a measurement closure or have a global font cache access.
-             // For now, let's keep the estimation but acknowledge the font.
-             let w = text.len() as f32 * 10.0 + 20.0;
-             let h = 30.0;
-             (w, h)
+            let extent = MeasuredText::get(measured, text, Some(BUTTON_TEXT_SIZE), font.as_deref())
+                .unwrap_or_else(|| HeuristicMeasurer.measure(text, BUTTON_TEXT_SIZE, font.as_deref()));
+            (extent.x + BUTTON_PADDING * 2.0, 30.0)
         }
     },
-    Widget::Label { width, height, size, text, font, .. } => {
+    Widget::Label { width, height, size, text, font, measured, .. } => {
         if *width > 0.0 && *height > 0.0 {
            (*width, *height)
         } else {
-           // Improved estimation: average character width is roughly 0.6 * size
-           let w = text.len() as f32 * size * 0.6;
-           (w, *size)
+           let extent = MeasuredText::get(measured, text, *size, font.as_deref()).unwrap_or_else(|| {
+               let size = size.unwrap_or_else(crate::widget::default_font_size);
+               HeuristicMeasurer.measure(text, size, font.as_deref())
+           });
+           (extent.x, extent.y)
         }
     },
     Widget::TextInput { width, height, .. } => {
@@ -622,10 +730,15 @@ fn get_fixed_size(widget: &Widget) -> (f32, f32) {
         let h = if *height > 0.0 { *height } else { 32.0 };
         (w, h)
     },
+    Widget::TimePicker { width, height, .. } => {
+        let w = if *width > 0.0 { *width } else { 150.0 };
+        let h = if *height > 0.0 { *height } else { 32.0 };
+        (w, h)
+    },
     Widget::Spacer { size, .. } => (*size, *size),
-    Widget::Divider { orientation, thickness, margin, .. } => {
+    Widget::Divider { orientation, thickness, margin, label, label_size, .. } => {
       match orientation {
-        Orientation::Horizontal => (0.0, thickness + margin * 2.0),
+        Orientation::Horizontal => (0.0, divider_band(*thickness, label, *label_size) + margin * 2.0),
         Orientation::Vertical => (thickness + margin * 2.0, 0.0),
       }
     }
@@ -647,8 +760,11 @@ fn get_fixed_size(widget: &Widget) -> (f32, f32) {
         let h = if style.track_height > 0.0 { style.track_height.max(style.thumb_radius * 2.0) } else { 20.0 };
         (w, h)
     },
-    Widget::ListView { items, style, width, height, .. } => {
-        let h = height.unwrap_or(items.len() as f32 * style.item_height);
+    Widget::ListView { items, item_widgets, item_heights, style, width, height, .. } => {
+        let h = height.unwrap_or_else(|| {
+            let count = crate::list_view::row_count(items, item_widgets);
+            crate::list_view::RowOffsets::new(count, style.item_height, item_heights).total()
+        });
         let w = width.unwrap_or(200.0); // Default width if not flexible
         (w, h)
     },
@@ -674,11 +790,55 @@ fn get_fixed_size(widget: &Widget) -> (f32, f32) {
         let h = if *height > 0.0 { *height } else { 300.0 };
         (w, h)
     },
+    Widget::VirtualFlow { width, height, .. } => (width.unwrap_or(400.0), height.unwrap_or(300.0)),
+    Widget::Skeleton { shape, width, height, .. } => {
+        let default_h = if *shape == SkeletonShape::Line { 12.0 } else { 48.0 };
+        let h = height.unwrap_or(default_h);
+        let default_w = if *shape == SkeletonShape::Circle { h } else { 200.0 };
+        (width.unwrap_or(default_w), h)
+    },
+    Widget::ChipInput { chips, style, width, height, .. } => {
+        let w = width.unwrap_or(240.0);
+        (w, height.unwrap_or_else(|| crate::chips::layout_chips(chips, style.text_size(), w).height))
+    },
+    Widget::Rating { max, style, width, height, .. } => {
+        let n = (*max).max(1) as f32;
+        (
+            width.unwrap_or(n * style.size() + (n - 1.0) * style.gap()),
+            height.unwrap_or(style.size()),
+        )
+    },
+    Widget::MenuBar { menus, style, width, height, .. } => {
+        let titles: f32 = menus.iter().map(|m| crate::menu::title_width(&m.label, style.text_size())).sum();
+        (width.unwrap_or(titles), height.unwrap_or(style.text_size() + 14.0))
+    },
+    Widget::Breadcrumb { style, width, height, .. } => {
+        let size = if style.font_size > 0.0 { style.font_size } else { 14.0 };
+        (width.unwrap_or(300.0), height.unwrap_or(size + 10.0))
+    },
+    Widget::Stepper { steps, orientation, style, width, height, .. } => {
+        let n = steps.len().max(1) as f32;
+        match orientation {
+            Orientation::Horizontal => (
+                width.unwrap_or(n * 120.0),
+                height.unwrap_or(style.radius() * 2.0 + style.text_size() + 12.0),
+            ),
+            Orientation::Vertical => (
+                width.unwrap_or(200.0),
+                height.unwrap_or(n * (style.radius() * 2.0 + 24.0)),
+            ),
+        }
+    },
+    Widget::CircularProgress { size, .. } => {
+        let d = size.unwrap_or(48.0);
+        (d, d)
+    },
+    Widget::Include { .. } => (0.0, 0.0),
   }
 }
 
 // Helper to set widget size
-fn set_size(widget: &mut Widget, w: f32, h: f32) {
+pub(crate) fn set_size(widget: &mut Widget, w: f32, h: f32) {
   match widget {
     Widget::Container { bounds, .. } => {
       bounds.width = w;
@@ -720,15 +880,19 @@ fn set_size(widget: &mut Widget, w: f32, h: f32) {
       bounds.width = w;
       bounds.height = h;
     }
+    Widget::TimePicker { bounds, .. } => {
+      bounds.width = w;
+      bounds.height = h;
+    }
     Widget::Spacer { size, .. } => {
       *size = w.max(h);
     }
-    Widget::Divider { bounds, orientation, thickness, margin, .. } => {
+    Widget::Divider { bounds, orientation, thickness, margin, label, label_size, .. } => {
       // Set bounds based on orientation
       match orientation {
         Orientation::Horizontal => {
           bounds.width = w;
-          bounds.height = *thickness + *margin * 2.0;
+          bounds.height = divider_band(*thickness, label, *label_size) + *margin * 2.0;
         }
         Orientation::Vertical => {
           bounds.width = *thickness + *margin * 2.0;
@@ -792,11 +956,47 @@ fn set_size(widget: &mut Widget, w: f32, h: f32) {
         bounds.width = w;
         bounds.height = h;
     }
+    Widget::VirtualFlow { bounds, .. } => {
+        bounds.width = w;
+        bounds.height = h;
+    }
+    Widget::Skeleton { bounds, .. } => {
+        bounds.width = w;
+        bounds.height = h;
+    }
+    Widget::ChipInput { bounds, .. } => {
+        bounds.width = w;
+        bounds.height = h;
+    }
+    Widget::Rating { bounds, .. } => {
+        bounds.width = w;
+        bounds.height = h;
+    }
+    Widget::MenuBar { bounds, .. } => {
+        bounds.width = w;
+        bounds.height = h;
+    }
+    Widget::Breadcrumb { bounds, .. } => {
+        bounds.width = w;
+        bounds.height = h;
+    }
+    Widget::Stepper { bounds, .. } => {
+        bounds.width = w;
+        bounds.height = h;
+    }
+    Widget::CircularProgress { bounds, .. } => {
+        bounds.width = w;
+        bounds.height = h;
+    }
+    Widget::Include { .. } => {}
   }
 }
 
-// Helper to set widget position relative to parent
-fn set_pos(widget: &mut Widget, x: f32, y: f32) {
+/// Moves a widget to `x`, `y` relative to its parent.
+///
+/// Only sticks inside containers with `Direction::None`; other layouts
+/// overwrite it on the next pass.
+pub fn set_pos(widget: &mut Widget, x: f32, y: f32) {
   match widget {
     Widget::Container { bounds, .. } => {
       bounds.x = x;
@@ -834,6 +1034,10 @@ fn set_pos(widget: &mut Widget, x: f32, y: f32) {
       bounds.x = x;
       bounds.y = y;
     }
+    Widget::TimePicker { bounds, .. } => {
+      bounds.x = x;
+      bounds.y = y;
+    }
     Widget::KpiCard { bounds, .. } => {
         bounds.x = x;
         bounds.y = y;
@@ -891,6 +1095,39 @@ fn set_pos(widget: &mut Widget, x: f32, y: f32) {
         bounds.x = x;
         bounds.y = y;
     }
+    Widget::VirtualFlow { bounds, .. } => {
+        bounds.x = x;
+        bounds.y = y;
+    }
+    Widget::Skeleton { bounds, .. } => {
+        bounds.x = x;
+        bounds.y = y;
+    }
+    Widget::ChipInput { bounds, .. } => {
+        bounds.x = x;
+        bounds.y = y;
+    }
+    Widget::Rating { bounds, .. } => {
+        bounds.x = x;
+        bounds.y = y;
+    }
+    Widget::MenuBar { bounds, .. } => {
+        bounds.x = x;
+        bounds.y = y;
+    }
+    Widget::Breadcrumb { bounds, .. } => {
+        bounds.x = x;
+        bounds.y = y;
+    }
+    Widget::Stepper { bounds, .. } => {
+        bounds.x = x;
+        bounds.y = y;
+    }
+    Widget::CircularProgress { bounds, .. } => {
+        bounds.x = x;
+        bounds.y = y;
+    }
+    Widget::Include { .. } => {}
   }
 }
 
@@ -904,6 +1141,7 @@ fn get_grid_col(widget: &Widget) -> usize {
     Widget::NumberInput { grid_col, .. } => grid_col.unwrap_or(0),
     Widget::Autocomplete { grid_col, .. } => grid_col.unwrap_or(0),
     Widget::DatePicker { grid_col, .. } => grid_col.unwrap_or(0),
+    Widget::TimePicker { grid_col, .. } => grid_col.unwrap_or(0),
     Widget::Spacer { grid_col, .. } => grid_col.unwrap_or(0),
     Widget::Divider { grid_col, .. } => grid_col.unwrap_or(0),
     Widget::Scrollbar { grid_col, .. } => grid_col.unwrap_or(0),
@@ -922,6 +1160,15 @@ fn get_grid_col(widget: &Widget) -> usize {
     Widget::ListView { grid_col, .. } => grid_col.unwrap_or(0),
     Widget::Tab { grid_col, .. } => grid_col.unwrap_or(0),
     Widget::Chart { grid_col, .. } => grid_col.unwrap_or(0),
+    Widget::VirtualFlow { grid_col, .. } => grid_col.unwrap_or(0),
+    Widget::Skeleton { grid_col, .. } => grid_col.unwrap_or(0),
+    Widget::ChipInput { grid_col, .. } => grid_col.unwrap_or(0),
+    Widget::Rating { grid_col, .. } => grid_col.unwrap_or(0),
+    Widget::MenuBar { grid_col, .. } => grid_col.unwrap_or(0),
+    Widget::Breadcrumb { grid_col, .. } => grid_col.unwrap_or(0),
+    Widget::Stepper { grid_col, .. } => grid_col.unwrap_or(0),
+    Widget::CircularProgress { grid_col, .. } => grid_col.unwrap_or(0),
+    Widget::Include { .. } => 0,
   }
 }
 
@@ -937,6 +1184,7 @@ fn get_grid_row(widget: &Widget) -> usize {
     Widget::NumberInput { grid_row, .. } => grid_row.unwrap_or(0),
     Widget::Autocomplete { grid_row, .. } => grid_row.unwrap_or(0),
     Widget::DatePicker { grid_row, .. } => grid_row.unwrap_or(0),
+    Widget::TimePicker { grid_row, .. } => grid_row.unwrap_or(0),
     Widget::Spacer { grid_row, .. } => grid_row.unwrap_or(0),
     Widget::Divider { grid_row, .. } => grid_row.unwrap_or(0),
     Widget::Scrollbar { grid_row, .. } => grid_row.unwrap_or(0),
@@ -952,6 +1200,15 @@ fn get_grid_row(widget: &Widget) -> usize {
     Widget::ListView { grid_row, .. } => grid_row.unwrap_or(0),
     Widget::Tab { grid_row, .. } => grid_row.unwrap_or(0),
     Widget::Chart { grid_row, .. } => grid_row.unwrap_or(0),
+    Widget::VirtualFlow { grid_row, .. } => grid_row.unwrap_or(0),
+    Widget::Skeleton { grid_row, .. } => grid_row.unwrap_or(0),
+    Widget::ChipInput { grid_row, .. } => grid_row.unwrap_or(0),
+    Widget::Rating { grid_row, .. } => grid_row.unwrap_or(0),
+    Widget::MenuBar { grid_row, .. } => grid_row.unwrap_or(0),
+    Widget::Breadcrumb { grid_row, .. } => grid_row.unwrap_or(0),
+    Widget::Stepper { grid_row, .. } => grid_row.unwrap_or(0),
+    Widget::CircularProgress { grid_row, .. } => grid_row.unwrap_or(0),
+    Widget::Include { .. } => 0,
   }
 }
 
@@ -966,6 +1223,7 @@ fn get_explicit_grid_col(widget: &Widget) -> Option<usize> {
     Widget::NumberInput { grid_col, .. } => *grid_col,
     Widget::Autocomplete { grid_col, .. } => *grid_col,
     Widget::DatePicker { grid_col, .. } => *grid_col,
+    Widget::TimePicker { grid_col, .. } => *grid_col,
     Widget::Spacer { grid_col, .. } => *grid_col,
     Widget::Divider { grid_col, .. } => *grid_col,
     Widget::Scrollbar { grid_col, .. } => *grid_col,
@@ -983,7 +1241,214 @@ fn get_explicit_grid_col(widget: &Widget) -> Option<usize> {
     Widget::ListView { grid_col, .. } => *grid_col,
     Widget::Tab { grid_col, .. } => *grid_col,
     Widget::Chart { grid_col, .. } => *grid_col,
+    Widget::VirtualFlow { grid_col, .. } => *grid_col,
+    Widget::Skeleton { grid_col, .. } => *grid_col,
+    Widget::ChipInput { grid_col, .. } => *grid_col,
+    Widget::Rating { grid_col, .. } => *grid_col,
+    Widget::MenuBar { grid_col, .. } => *grid_col,
+    Widget::Breadcrumb { grid_col, .. } => *grid_col,
+    Widget::Stepper { grid_col, .. } => *grid_col,
+    Widget::CircularProgress { grid_col, .. } => *grid_col,
+    Widget::Include { .. } => None,
+  }
+}
+
+// Helper to get the named grid area a widget is placed in
+pub(crate) fn get_grid_area(widget: &Widget) -> Option<&str> {
+  match widget {
+    Widget::Container { grid_area, .. } => grid_area.as_deref(),
+    Widget::Button { grid_area, .. } => grid_area.as_deref(),
+    Widget::Label { grid_area, .. } => grid_area.as_deref(),
+    Widget::TextInput { grid_area, .. } => grid_area.as_deref(),
+    Widget::NumberInput { grid_area, .. } => grid_area.as_deref(),
+    Widget::Autocomplete { grid_area, .. } => grid_area.as_deref(),
+    Widget::DatePicker { grid_area, .. } => grid_area.as_deref(),
+    Widget::TimePicker { grid_area, .. } => grid_area.as_deref(),
+    Widget::Spacer { grid_area, .. } => grid_area.as_deref(),
+    Widget::Divider { grid_area, .. } => grid_area.as_deref(),
+    Widget::Scrollbar { grid_area, .. } => grid_area.as_deref(),
+    Widget::DataGrid { grid_area, .. } => grid_area.as_deref(),
+    Widget::Checkbox { grid_area, .. } => grid_area.as_deref(),
+    Widget::Slider { grid_area, .. } => grid_area.as_deref(),
+    Widget::Image { grid_area, .. } => grid_area.as_deref(),
+    Widget::Icon { grid_area, .. } => grid_area.as_deref(),
+    Widget::ToggleSwitch { grid_area, .. } => grid_area.as_deref(),
+    Widget::ProgressBar { grid_area, .. } => grid_area.as_deref(),
+    Widget::RadioButton { grid_area, .. } => grid_area.as_deref(),
+    Widget::Dropdown { grid_area, .. } => grid_area.as_deref(),
+    Widget::Tree { grid_area, .. } => grid_area.as_deref(),
+    Widget::KpiCard { grid_area, .. } => grid_area.as_deref(),
+    Widget::ListView { grid_area, .. } => grid_area.as_deref(),
+    Widget::Tab { grid_area, .. } => grid_area.as_deref(),
+    Widget::Chart { grid_area, .. } => grid_area.as_deref(),
+    Widget::VirtualFlow { grid_area, .. } => grid_area.as_deref(),
+    Widget::Skeleton { grid_area, .. } => grid_area.as_deref(),
+    Widget::ChipInput { grid_area, .. } => grid_area.as_deref(),
+    Widget::Rating { grid_area, .. } => grid_area.as_deref(),
+    Widget::MenuBar { grid_area, .. } => grid_area.as_deref(),
+    Widget::Breadcrumb { grid_area, .. } => grid_area.as_deref(),
+    Widget::Stepper { grid_area, .. } => grid_area.as_deref(),
+    Widget::CircularProgress { grid_area, .. } => grid_area.as_deref(),
+    Widget::Include { .. } => None,
+  }
+}
+
+// Helper to get a widget's `align_self` override
+fn get_align_self(widget: &Widget) -> AlignSelf {
+  match widget {
+    Widget::Container { align_self, .. } => *align_self,
+    Widget::Button { align_self, .. } => *align_self,
+    Widget::Label { align_self, .. } => *align_self,
+    Widget::TextInput { align_self, .. } => *align_self,
+    Widget::NumberInput { align_self, .. } => *align_self,
+    Widget::Autocomplete { align_self, .. } => *align_self,
+    Widget::DatePicker { align_self, .. } => *align_self,
+    Widget::TimePicker { align_self, .. } => *align_self,
+    Widget::Spacer { align_self, .. } => *align_self,
+    Widget::Divider { align_self, .. } => *align_self,
+    Widget::Scrollbar { align_self, .. } => *align_self,
+    Widget::DataGrid { align_self, .. } => *align_self,
+    Widget::Checkbox { align_self, .. } => *align_self,
+    Widget::Slider { align_self, .. } => *align_self,
+    Widget::Image { align_self, .. } => *align_self,
+    Widget::Icon { align_self, .. } => *align_self,
+    Widget::ToggleSwitch { align_self, .. } => *align_self,
+    Widget::ProgressBar { align_self, .. } => *align_self,
+    Widget::RadioButton { align_self, .. } => *align_self,
+    Widget::Dropdown { align_self, .. } => *align_self,
+    Widget::Tree { align_self, .. } => *align_self,
+    Widget::KpiCard { align_self, .. } => *align_self,
+    Widget::ListView { align_self, .. } => *align_self,
+    Widget::Tab { align_self, .. } => *align_self,
+    Widget::Chart { align_self, .. } => *align_self,
+    Widget::VirtualFlow { align_self, .. } => *align_self,
+    Widget::Skeleton { align_self, .. } => *align_self,
+    Widget::ChipInput { align_self, .. } => *align_self,
+    Widget::Rating { align_self, .. } => *align_self,
+    Widget::MenuBar { align_self, .. } => *align_self,
+    Widget::Breadcrumb { align_self, .. } => *align_self,
+    Widget::Stepper { align_self, .. } => *align_self,
+    Widget::CircularProgress { align_self, .. } => *align_self,
+    Widget::Include { .. } => AlignSelf::Auto,
+  }
+}
+
+// Helper to get a widget's flex `order`
+fn get_order(widget: &Widget) -> i32 {
+  match widget {
+    Widget::Container { order, .. } => *order,
+    Widget::Button { order, .. } => *order,
+    Widget::Label { order, .. } => *order,
+    Widget::TextInput { order, .. } => *order,
+    Widget::NumberInput { order, .. } => *order,
+    Widget::Autocomplete { order, .. } => *order,
+    Widget::DatePicker { order, .. } => *order,
+    Widget::TimePicker { order, .. } => *order,
+    Widget::Spacer { order, .. } => *order,
+    Widget::Divider { order, .. } => *order,
+    Widget::Scrollbar { order, .. } => *order,
+    Widget::DataGrid { order, .. } => *order,
+    Widget::Checkbox { order, .. } => *order,
+    Widget::Slider { order, .. } => *order,
+    Widget::Image { order, .. } => *order,
+    Widget::Icon { order, .. } => *order,
+    Widget::ToggleSwitch { order, .. } => *order,
+    Widget::ProgressBar { order, .. } => *order,
+    Widget::RadioButton { order, .. } => *order,
+    Widget::Dropdown { order, .. } => *order,
+    Widget::Tree { order, .. } => *order,
+    Widget::KpiCard { order, .. } => *order,
+    Widget::ListView { order, .. } => *order,
+    Widget::Tab { order, .. } => *order,
+    Widget::Chart { order, .. } => *order,
+    Widget::VirtualFlow { order, .. } => *order,
+    Widget::Skeleton { order, .. } => *order,
+    Widget::ChipInput { order, .. } => *order,
+    Widget::Rating { order, .. } => *order,
+    Widget::MenuBar { order, .. } => *order,
+    Widget::Breadcrumb { order, .. } => *order,
+    Widget::Stepper { order, .. } => *order,
+    Widget::CircularProgress { order, .. } => *order,
+    Widget::Include { .. } => 0,
+  }
+}
+
+// Helper to get a widget's `justify_self` override
+fn get_justify_self(widget: &Widget) -> AlignSelf {
+  match widget {
+    Widget::Container { justify_self, .. } => *justify_self,
+    Widget::Button { justify_self, .. } => *justify_self,
+    Widget::Label { justify_self, .. } => *justify_self,
+    Widget::TextInput { justify_self, .. } => *justify_self,
+    Widget::NumberInput { justify_self, .. } => *justify_self,
+    Widget::Autocomplete { justify_self, .. } => *justify_self,
+    Widget::DatePicker { justify_self, .. } => *justify_self,
+    Widget::TimePicker { justify_self, .. } => *justify_self,
+    Widget::Spacer { justify_self, .. } => *justify_self,
+    Widget::Divider { justify_self, .. } => *justify_self,
+    Widget::Scrollbar { justify_self, .. } => *justify_self,
+    Widget::DataGrid { justify_self, .. } => *justify_self,
+    Widget::Checkbox { justify_self, .. } => *justify_self,
+    Widget::Slider { justify_self, .. } => *justify_self,
+    Widget::Image { justify_self, .. } => *justify_self,
+    Widget::Icon { justify_self, .. } => *justify_self,
+    Widget::ToggleSwitch { justify_self, .. } => *justify_self,
+    Widget::ProgressBar { justify_self, .. } => *justify_self,
+    Widget::RadioButton { justify_self, .. } => *justify_self,
+    Widget::Dropdown { justify_self, .. } => *justify_self,
+    Widget::Tree { justify_self, .. } => *justify_self,
+    Widget::KpiCard { justify_self, .. } => *justify_self,
+    Widget::ListView { justify_self, .. } => *justify_self,
+    Widget::Tab { justify_self, .. } => *justify_self,
+    Widget::Chart { justify_self, .. } => *justify_self,
+    Widget::VirtualFlow { justify_self, .. } => *justify_self,
+    Widget::Skeleton { justify_self, .. } => *justify_self,
+    Widget::ChipInput { justify_self, .. } => *justify_self,
+    Widget::Rating { justify_self, .. } => *justify_self,
+    Widget::MenuBar { justify_self, .. } => *justify_self,
+    Widget::Breadcrumb { justify_self, .. } => *justify_self,
+    Widget::Stepper { justify_self, .. } => *justify_self,
+    Widget::CircularProgress { justify_self, .. } => *justify_self,
+    Widget::Include { .. } => AlignSelf::Auto,
+  }
+}
+
+/// Grid column, row and spans of `child` in a grid laid out with `layout`.
+///
+/// A child in a named area of `layout` takes the area's cells; one whose
+/// area isn't defined there (see [`validate_grid_areas`]) is auto-placed.
+fn grid_placement(child: &Widget, layout: &Layout) -> (Option<usize>, Option<usize>, usize, usize) {
+  let area = get_grid_area(child).map(|name| layout.areas.get(name));
+  match area {
+    Some(Some(area)) => (Some(area.col), Some(area.row), area.col_span.max(1), area.row_span.max(1)),
+    Some(None) => (None, None, 1, 1),
+    None => (get_explicit_grid_col(child), get_explicit_grid_row(child), get_col_span(child), get_row_span(child)),
+  }
+}
+
+/// Checks that every `grid_area` in the tree names an area of its parent
+/// container's layout (or of one of the layout's breakpoints).
+///
+/// Called by [`crate::ui::parse_ui`] and [`crate::ui::load_ui`]; the error
+/// lists every unknown name.
+pub fn validate_grid_areas(root: &Widget) -> anyhow::Result<()> {
+  fn collect(widget: &Widget, unknown: &mut Vec<String>) {
+    if let Widget::Container { layout, children, .. } = widget {
+      for name in children.iter().filter_map(get_grid_area) {
+        if !layout.has_area(name) && !unknown.iter().any(|u| u == name) {
+          unknown.push(name.to_string());
+        }
+      }
+    }
+    widget.for_each_child(&mut |child| collect(child, unknown));
+  }
+
+  let mut unknown = Vec::new();
+  collect(root, &mut unknown);
+  if !unknown.is_empty() {
+    anyhow::bail!("Unknown grid areas: {}", unknown.join(", "));
   }
+  Ok(())
 }
 
 // Helper to get explicit grid row (returns None if auto-flow should apply)
@@ -998,6 +1463,7 @@ fn get_explicit_grid_row(widget: &Widget) -> Option<usize> {
     Widget::NumberInput { grid_row, .. } => *grid_row,
     Widget::Autocomplete { grid_row, .. } => *grid_row,
     Widget::DatePicker { grid_row, .. } => *grid_row,
+    Widget::TimePicker { grid_row, .. } => *grid_row,
     Widget::Spacer { grid_row, .. } => *grid_row,
     Widget::Divider { grid_row, .. } => *grid_row,
     Widget::Scrollbar { grid_row, .. } => *grid_row,
@@ -1013,6 +1479,15 @@ fn get_explicit_grid_row(widget: &Widget) -> Option<usize> {
     Widget::ListView { grid_row, .. } => *grid_row,
     Widget::Tab { grid_row, .. } => *grid_row,
     Widget::Chart { grid_row, .. } => *grid_row,
+    Widget::VirtualFlow { grid_row, .. } => *grid_row,
+    Widget::Skeleton { grid_row, .. } => *grid_row,
+    Widget::ChipInput { grid_row, .. } => *grid_row,
+    Widget::Rating { grid_row, .. } => *grid_row,
+    Widget::MenuBar { grid_row, .. } => *grid_row,
+    Widget::Breadcrumb { grid_row, .. } => *grid_row,
+    Widget::Stepper { grid_row, .. } => *grid_row,
+    Widget::CircularProgress { grid_row, .. } => *grid_row,
+    Widget::Include { .. } => None,
   }
 }
 
@@ -1029,6 +1504,7 @@ fn get_col_span(widget: &Widget) -> usize {
     Widget::NumberInput { col_span, .. } => *col_span,
     Widget::Autocomplete { col_span, .. } => *col_span,
     Widget::DatePicker { col_span, .. } => *col_span,
+    Widget::TimePicker { col_span, .. } => *col_span,
     Widget::Spacer { col_span, .. } => *col_span,
     Widget::Divider { col_span, .. } => *col_span,
     Widget::Scrollbar { col_span, .. } => *col_span,
@@ -1044,6 +1520,15 @@ fn get_col_span(widget: &Widget) -> usize {
     Widget::ListView { col_span, .. } => *col_span,
     Widget::Tab { col_span, .. } => *col_span,
     Widget::Chart { col_span, .. } => *col_span,
+    Widget::VirtualFlow { col_span, .. } => *col_span,
+    Widget::Skeleton { col_span, .. } => *col_span,
+    Widget::ChipInput { col_span, .. } => *col_span,
+    Widget::Rating { col_span, .. } => *col_span,
+    Widget::MenuBar { col_span, .. } => *col_span,
+    Widget::Breadcrumb { col_span, .. } => *col_span,
+    Widget::Stepper { col_span, .. } => *col_span,
+    Widget::CircularProgress { col_span, .. } => *col_span,
+    Widget::Include { .. } => 1,
   }
 }
 
@@ -1058,6 +1543,7 @@ fn get_row_span(widget: &Widget) -> usize {
     Widget::NumberInput { row_span, .. } => *row_span,
     Widget::Autocomplete { row_span, .. } => *row_span,
     Widget::DatePicker { row_span, .. } => *row_span,
+    Widget::TimePicker { row_span, .. } => *row_span,
     Widget::Spacer { row_span, .. } => *row_span,
     Widget::Divider { row_span, .. } => *row_span,
     Widget::Scrollbar { row_span, .. } => *row_span,
@@ -1074,5 +1560,14 @@ fn get_row_span(widget: &Widget) -> usize {
     Widget::ListView { row_span, .. } => *row_span,
     Widget::Tab { row_span, .. } => *row_span,
     Widget::Chart { row_span, .. } => *row_span,
+    Widget::VirtualFlow { row_span, .. } => *row_span,
+    Widget::Skeleton { row_span, .. } => *row_span,
+    Widget::ChipInput { row_span, .. } => *row_span,
+    Widget::Rating { row_span, .. } => *row_span,
+    Widget::MenuBar { row_span, .. } => *row_span,
+    Widget::Breadcrumb { row_span, .. } => *row_span,
+    Widget::Stepper { row_span, .. } => *row_span,
+    Widget::CircularProgress { row_span, .. } => *row_span,
+    Widget::Include { .. } => 1,
   }
 }
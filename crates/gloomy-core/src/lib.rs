@@ -9,6 +9,8 @@
 //! - Interactive UI elements (buttons)
 //! - GPU context management
 
+pub mod accessibility;
+pub mod action;
 pub mod container;
 pub mod interaction;
 pub mod image_renderer;
@@ -22,6 +24,7 @@ pub mod texture;
 pub mod ui;
 pub mod widget;
 pub mod svg_loader;
+pub mod icons;
 pub mod theme;
 pub mod style;
 pub mod style_context;
@@ -32,26 +35,72 @@ pub mod validation;
 pub mod rich_text;
 pub mod widget_state;
 pub mod kpi;
+pub mod widget_index;
+pub mod breadcrumb;
+pub mod menu;
+pub mod shortcut;
+pub mod suggestion;
+pub mod fuzzy;
+pub mod time_picker;
+pub mod chips;
+pub mod measure;
+pub mod lru;
+pub mod clipboard;
+pub mod selection;
+pub mod cell_selection;
+pub mod list_view;
+pub mod virtual_flow;
+pub mod binding;
+pub mod form;
+pub mod mask;
+pub mod number_format;
+pub mod spinner;
+pub mod paged_source;
+pub mod popover;
+pub mod scroll;
+pub mod scrollbar;
 
 #[cfg(test)]
 mod tests;
 
 pub use container::Container;
 pub use glam::{Vec2, Vec4};
-pub use interaction::InteractionState;
-pub use layout::{Align, Direction, Justify, Layout};
-pub use layout_engine::compute_layout;
-pub use primitives::{Instance, PrimitiveRenderer};
+pub use accessibility::{accessibility_tree, update_live_regions, AccessNode, AccessRole, Announcement, LiveRegion};
+pub use action::{collect_actions, Action, ActionDescriptor, ActionHandler, ActionTemplate};
+pub use interaction::{CursorHint, InteractionSnapshot, InteractionState, UiEvent};
+pub use layout::{Align, AlignSelf, Direction, GridArea, Justify, Layout, Padding, SafeArea};
+pub use layout_engine::{compute_layout, compute_layout_with, compute_root_layout, validate_grid_areas};
+pub use number_format::NumberFormat;
+pub use measure::{measure_tree, HeuristicMeasurer, TextMeasurer};
+pub use primitives::{Instance, PrimitiveRenderer, RoundedClip};
+pub use popover::{Placement, Popover};
+pub use scroll::{route_scroll, scroll_target_at};
+pub use scrollbar::{
+    begin_scrollbar_drag, drag_scrollbar, end_scrollbar_drag, widget_scrollbar, ScrollbarDrag, ScrollbarGeometry,
+    ScrollbarMode, ScrollbarPolicy,
+};
+pub use clipboard::{ClipboardProvider, MemoryClipboard};
+pub use shortcut::{Shortcut, ShortcutMap};
+pub use spinner::{step_number_input, tick_spinners, SpinRepeatConfig};
+pub use suggestion::{FuzzySuggestions, SuggestionProvider, SuggestionProviders};
+pub use virtual_flow::{refresh_virtual_flows, ItemBuilder, ItemBuilders};
+pub use binding::{commit_bindings, resolve_bindings, Value, ValueStore};
+pub use form::{apply_values, collect_values, FieldValue};
+pub use validation::{apply_validation, is_valid, validate_all, validate_triggered, ValidationTrigger};
 pub use rect::Rect;
-pub use renderer::GloomyRenderer;
-pub use text::TextRenderer;
+pub use renderer::{supported_sample_count, GloomyRenderer};
+pub use text::{FontMetrics, TextRenderer, PREWARM_ASCII};
 pub use ui::{
-  hit_test, load_ui, parse_ui, render_ui, RenderContext,
+  hit_test, hit_test_all, load_ui, parse_ui, parse_ui_template, parse_ui_with_base,
+  render_ui, render_ui_styled, RenderContext, RenderContextBuilder,
 };
-pub use widget::{Widget, WidgetBounds};
+pub use widget::{Overflow, Widget, WidgetBounds};
+pub use widget_index::WidgetIndex;
 pub use theme::{Theme, ColorPalette};
-pub use style::{GlobalStyle, BoxStyle, ButtonStyle, TextInputStyle, Shadow, Gradient, Border, BorderStyle};
-pub use style_context::StyleContext;
+pub use style::{GlobalStyle, BoxStyle, ButtonStyle, TextInputStyle, Shadow, Gradient, Border, BorderStyle, TextDefaults};
+pub use style_context::{ClassStyle, StyleContext};
+pub use icons::{IconRegistry, IconSource};
 pub use data_source::{DataSource, CellValue, VecDataSource};
-pub use datagrid::{ColumnDef, ColumnWidth, DataGrid, DataGridStyle, SelectionMode, SortDirection};
+pub use paged_source::{PageFetcher, PagedDataSource};
+pub use datagrid::{fit_datagrid_rows, handle_header_sort, sync_datagrids, ColumnDef, ColumnWidth, DataGrid, DataGridStyle, SelectionMode, SortDirection};
 pub use kpi::{KpiCard, KpiCardStyle, KpiTrend, TrendDirection};
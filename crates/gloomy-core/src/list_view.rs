@@ -0,0 +1,360 @@
+//! Row geometry for `ListView`.
+//!
+//! Items are `style.item_height` tall unless `item_heights` gives them
+//! their own height. [`RowOffsets`] keeps a prefix sum of those heights
+//! so virtualization, scrolling and hit-testing can find rows by y with
+//! a binary search; rows past `item_heights` are found by dividing by
+//! the uniform height, so lists without it cost O(1) per frame.
+//!
+//! Rows are plain strings (`items`, the fast default) or widgets
+//! (`item_widgets`). Item widgets are laid out in their row slot,
+//! relative to the list's content, and only the rows in view are drawn
+//! and hit-tested. A click on a row's interactive child (a button, a
+//! checkbox) yields that child's action; anywhere else in the row it
+//! yields `{list}:{index}` as for string items.
+//!
+//! Focus flows into rows like into a container's children: Tab moves to
+//! the list itself, then through the focusable widgets of each row in
+//! row order, including rows scrolled out of view.
+//!
+//! Lists select one row (`selected_index`) unless `multi_select` is set.
+//! Apps feed row clicks to [`handle_list_click`] with the current
+//! modifiers: a plain click selects one row, Ctrl toggles a row, Shift
+//! selects the range from the last clicked row, and with
+//! `show_checkboxes` the checkbox column toggles like Ctrl. Space on a
+//! focused list toggles the last clicked row. Every change reports
+//! `{list}:selection` ([`Action::SelectionChange`]).
+
+use crate::action::Action;
+use crate::widget::Widget;
+use std::collections::HashSet;
+use std::ops::Range;
+use winit::keyboard::ModifiersState;
+
+/// Width of the checkbox column, when shown.
+pub const CHECKBOX_COLUMN: f32 = 32.0;
+
+/// Side of a row checkbox.
+pub const CHECKBOX_SIZE: f32 = 16.0;
+
+/// How far row content is pushed right to make room for checkboxes.
+pub fn row_inset(multi_select: bool, show_checkboxes: bool) -> f32 {
+    if multi_select && show_checkboxes {
+        CHECKBOX_COLUMN
+    } else {
+        0.0
+    }
+}
+
+/// Number of rows in a list: its item widgets if it has any, otherwise
+/// its string items.
+pub fn row_count(items: &[String], item_widgets: &[Widget]) -> usize {
+    if item_widgets.is_empty() {
+        items.len()
+    } else {
+        item_widgets.len()
+    }
+}
+
+/// Cumulative top offsets of a list's rows.
+///
+/// Only rows with their own height are stored; the rest are
+/// `default_height` tall and found by arithmetic, so a list without
+/// `item_heights` costs nothing to build however long it is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowOffsets {
+    /// `offsets[i]` is the top of row `i` for the rows with their own
+    /// height; the last entry is the bottom of the last of them.
+    offsets: Vec<f32>,
+    count: usize,
+    default_height: f32,
+}
+
+impl RowOffsets {
+    /// Builds offsets for `count` rows. Row `i` is `heights[i]` tall when
+    /// present, otherwise `default_height`.
+    pub fn new(count: usize, default_height: f32, heights: &[f32]) -> Self {
+        let listed = heights.len().min(count);
+        let mut offsets = Vec::with_capacity(listed + 1);
+        let mut y = 0.0;
+        offsets.push(y);
+        for height in &heights[..listed] {
+            y += height.max(0.0);
+            offsets.push(y);
+        }
+        Self { offsets, count, default_height: default_height.max(0.0) }
+    }
+
+    /// `count` rows of `height` each.
+    pub fn uniform(count: usize, height: f32) -> Self {
+        Self::new(count, height, &[])
+    }
+
+    /// Number of rows.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Rows with their own height, which come first.
+    fn listed(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Top of row `index`, relative to the top of the content.
+    pub fn top(&self, index: usize) -> f32 {
+        let index = index.min(self.count);
+        match self.offsets.get(index) {
+            Some(top) => *top,
+            None => self.offsets[self.listed()] + (index - self.listed()) as f32 * self.default_height,
+        }
+    }
+
+    /// Height of row `index`.
+    pub fn height(&self, index: usize) -> f32 {
+        self.top(index + 1) - self.top(index)
+    }
+
+    /// Height of all rows together.
+    pub fn total(&self) -> f32 {
+        self.top(self.count)
+    }
+
+    /// Number of row tops (counting the bottom of the last row as a top)
+    /// at or above `y`, or strictly above it when `strict`.
+    fn tops_before(&self, y: f32, strict: bool) -> usize {
+        let listed = self.listed();
+        let found = self.offsets.partition_point(|&top| if strict { top < y } else { top <= y });
+        if found <= listed {
+            return found;
+        }
+        let rest = self.count - listed;
+        if self.default_height <= 0.0 {
+            return listed + 1 + rest;
+        }
+        // Tops past the listed rows are `base + k * default_height`, k >= 1.
+        let steps = (y - self.offsets[listed]) / self.default_height;
+        let more = if strict { (steps.ceil() as usize).saturating_sub(1) } else { steps.floor() as usize };
+        listed + 1 + more.min(rest)
+    }
+
+    /// Row containing content y `y`, or `None` past either end.
+    pub fn index_at(&self, y: f32) -> Option<usize> {
+        if y < 0.0 || y >= self.total() {
+            return None;
+        }
+        // Tops at or above y, minus one, is the row y falls in.
+        Some(self.tops_before(y, false) - 1)
+    }
+
+    /// Rows intersecting a viewport `height` tall scrolled to `scroll`.
+    pub fn visible_range(&self, scroll: f32, height: f32) -> Range<usize> {
+        if self.is_empty() {
+            return 0..0;
+        }
+        let start = self.tops_before(scroll, false).saturating_sub(1);
+        let end = self.tops_before(scroll + height, true).min(self.len());
+        start..end.max(start)
+    }
+}
+
+impl Default for RowOffsets {
+    fn default() -> Self {
+        Self::uniform(0, 0.0)
+    }
+}
+
+/// Applies a click on row `index` to a multi-row selection.
+///
+/// A plain click selects only that row. `toggle` (Ctrl, or a row
+/// checkbox) flips the row and keeps the rest. `extend` (Shift) selects
+/// the range from the anchor, replacing the selection unless `toggle` is
+/// held too; without an anchor it acts like a plain click. Returns true
+/// if the selection changed.
+pub fn apply_click(
+    selected: &mut HashSet<usize>,
+    anchor: &mut Option<usize>,
+    index: usize,
+    toggle: bool,
+    extend: bool,
+) -> bool {
+    let before = selected.clone();
+    match *anchor {
+        Some(from) if extend => {
+            if !toggle {
+                selected.clear();
+            }
+            // The anchor stays put so further Shift-clicks pivot on it.
+            selected.extend(from.min(index)..=from.max(index));
+        }
+        _ if toggle => {
+            if !selected.remove(&index) {
+                selected.insert(index);
+            }
+            *anchor = Some(index);
+        }
+        _ => {
+            selected.clear();
+            selected.insert(index);
+            *anchor = Some(index);
+        }
+    }
+    *selected != before
+}
+
+/// Applies a ListView row action from `hit_test` (`{list}:{index}` or
+/// `{list}:check:{index}`) to the list in `root`.
+///
+/// Returns the `{list}:selection` action if the selection changed, and
+/// `None` for actions that aren't ListView rows.
+pub fn handle_list_click(root: &mut Widget, action: &str, modifiers: ModifiersState) -> Option<String> {
+    let (list, index, checkbox) = match Action::parse(action) {
+        Action::ListItem { list, index } => (list, index, false),
+        Action::ListCheck { list, index } => (list, index, true),
+        _ => return None,
+    };
+    let Some(Widget::ListView {
+        items,
+        item_widgets,
+        selected_index,
+        multi_select,
+        selected_indices,
+        selection_anchor,
+        ..
+    }) = crate::ui::find_widget_mut(root, &list)
+    else {
+        return None;
+    };
+    if index >= row_count(items, item_widgets) {
+        return None;
+    }
+
+    let changed = if *multi_select {
+        let toggle = checkbox || modifiers.control_key();
+        apply_click(selected_indices, selection_anchor, index, toggle, modifiers.shift_key())
+    } else {
+        *selection_anchor = Some(index);
+        selected_index.replace(index) != Some(index)
+    };
+    changed.then(|| Action::SelectionChange { list }.to_string())
+}
+
+/// Toggles the last clicked row of a focused list, or selects it in
+/// single-select mode. Starts at the first row if nothing was clicked.
+///
+/// Returns true if the selection changed.
+pub(crate) fn toggle_anchor_row(widget: &mut Widget) -> bool {
+    let Widget::ListView {
+        items,
+        item_widgets,
+        selected_index,
+        multi_select,
+        selected_indices,
+        selection_anchor,
+        ..
+    } = widget
+    else {
+        return false;
+    };
+    if row_count(items, item_widgets) == 0 {
+        return false;
+    }
+    let index = *selection_anchor.get_or_insert(0);
+    if *multi_select {
+        apply_click(selected_indices, selection_anchor, index, true, false)
+    } else {
+        selected_index.replace(index) != Some(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uniform_rows_match_division() {
+        let rows = RowOffsets::new(10, 20.0, &[]);
+        assert_eq!(rows.total(), 200.0);
+        assert_eq!(rows.index_at(45.0), Some(2));
+        assert_eq!(rows.index_at(200.0), None);
+        assert_eq!(rows.visible_range(30.0, 50.0), 1..4);
+    }
+
+    #[test]
+    fn test_mixed_heights() {
+        let rows = RowOffsets::new(4, 20.0, &[10.0, 40.0, 20.0, 5.0]);
+        assert_eq!(rows.top(2), 50.0);
+        assert_eq!(rows.height(1), 40.0);
+        assert_eq!(rows.index_at(9.9), Some(0));
+        assert_eq!(rows.index_at(10.0), Some(1));
+        assert_eq!(rows.index_at(72.0), Some(3));
+        assert_eq!(rows.visible_range(15.0, 40.0), 1..3);
+        assert!(RowOffsets::new(0, 20.0, &[]).visible_range(0.0, 100.0).is_empty());
+    }
+
+    #[test]
+    fn test_ctrl_and_shift_clicks() {
+        let mut selected = HashSet::new();
+        let mut anchor = None;
+        assert!(apply_click(&mut selected, &mut anchor, 2, false, false));
+        // Ctrl adds without clearing.
+        assert!(apply_click(&mut selected, &mut anchor, 5, true, false));
+        assert_eq!(selected, HashSet::from([2, 5]));
+        // Shift replaces with the range from the last clicked row.
+        assert!(apply_click(&mut selected, &mut anchor, 3, false, true));
+        assert_eq!(selected, HashSet::from([3, 4, 5]));
+        // Ctrl+Shift adds the range instead.
+        assert!(apply_click(&mut selected, &mut anchor, 7, true, true));
+        assert_eq!(selected, HashSet::from([3, 4, 5, 6, 7]));
+        // Ctrl on a selected row removes it.
+        assert!(apply_click(&mut selected, &mut anchor, 4, true, false));
+        assert!(!selected.contains(&4));
+        // Clicking the only selected row changes nothing.
+        let mut only = HashSet::from([1]);
+        assert!(!apply_click(&mut only, &mut Some(1), 1, false, false));
+    }
+
+    #[test]
+    fn test_list_click_reports_selection_change() {
+        let mut root = crate::ui::parse_ui(r#"
+            ListView(id: "files", items: ["a", "b", "c"], multi_select: true, show_checkboxes: true)
+        "#).unwrap();
+        let none = ModifiersState::empty();
+        assert_eq!(handle_list_click(&mut root, "files:0", none).as_deref(), Some("files:selection"));
+        assert_eq!(handle_list_click(&mut root, "files:check:2", none).as_deref(), Some("files:selection"));
+        assert_eq!(handle_list_click(&mut root, "files:9", none), None);
+        assert_eq!(handle_list_click(&mut root, "save", none), None);
+        let Widget::ListView { selected_indices, .. } = &mut root else { unreachable!() };
+        assert_eq!(*selected_indices, HashSet::from([0, 2]));
+
+        // Space toggles the last clicked row.
+        assert!(toggle_anchor_row(&mut root));
+        let Widget::ListView { selected_indices, .. } = &root else { unreachable!() };
+        assert_eq!(*selected_indices, HashSet::from([0]));
+    }
+
+    #[test]
+    fn test_missing_heights_use_default() {
+        let rows = RowOffsets::new(3, 20.0, &[50.0]);
+        assert_eq!(rows.total(), 90.0);
+        assert_eq!(rows.index_at(60.0), Some(1));
+    }
+
+    #[test]
+    fn test_uniform_rows_match_listed_heights() {
+        let uniform = RowOffsets::uniform(100_000, 24.0);
+        let listed = RowOffsets::new(50, 24.0, &[24.0; 50]);
+        for y in [0.0, 23.9, 24.0, 500.0, 1000.0] {
+            assert_eq!(uniform.index_at(y), listed.index_at(y));
+            assert_eq!(uniform.visible_range(y, 100.0), listed.visible_range(y, 100.0));
+        }
+        assert_eq!(uniform.total(), 2_400_000.0);
+        assert_eq!(uniform.index_at(2_399_999.0), Some(99_999));
+        assert_eq!(uniform.index_at(2_400_000.0), None);
+        assert_eq!(uniform.visible_range(48.0, 48.0), 2..4);
+        assert_eq!(uniform.top(10), 240.0);
+    }
+}
@@ -0,0 +1,144 @@
+//! Input masks for `TextInput`.
+//!
+//! A mask is a pattern of slots and literals: `#` takes a digit, `A` a
+//! letter and `*` a letter or digit; any other character is a literal
+//! shown as-is. `"(###) ###-####"` is a US phone number, `"$###,###.##"`
+//! a fixed-width amount.
+//!
+//! A masked input stores only what was typed into the slots (the raw
+//! value, `"5551234567"`) and displays it through the mask
+//! (`"(555) 123-4567"`). Literals appear as soon as the slot before them
+//! is filled, so typing never has to enter them.
+//!
+//! - Typed or pasted text fills the next free slots. Characters the next
+//!   slot doesn't accept, such as the mask's punctuation, are skipped, so pasting
+//!   `"(555) 123-4567"`, `"555-123-4567"` or `"5551234567"` all give the
+//!   same value. Text beyond the last slot is dropped.
+//! - Backspace removes the last typed character, together with the
+//!   literals displayed after it. There is no way to stop on a literal.
+
+/// True if `mask_char` is a slot rather than a literal.
+fn is_slot(mask_char: char) -> bool {
+    matches!(mask_char, '#' | 'A' | '*')
+}
+
+/// True if slot `mask_char` accepts `c`.
+fn accepts(mask_char: char, c: char) -> bool {
+    match mask_char {
+        '#' => c.is_ascii_digit(),
+        'A' => c.is_alphabetic(),
+        '*' => c.is_alphanumeric(),
+        _ => false,
+    }
+}
+
+/// Number of slots in `mask`, i.e. the longest raw value.
+pub fn slot_count(mask: &str) -> usize {
+    mask.chars().filter(|&c| is_slot(c)).count()
+}
+
+/// Displays `raw` through `mask`: filled slots, the literals between
+/// them, and the literals following the last filled slot. Empty raw
+/// values display as empty so the placeholder shows.
+pub fn format(mask: &str, raw: &str) -> String {
+    let mut out = String::new();
+    let mut raw = raw.chars().peekable();
+    if raw.peek().is_none() {
+        return out;
+    }
+    for m in mask.chars() {
+        if !is_slot(m) {
+            out.push(m);
+            continue;
+        }
+        match raw.next() {
+            Some(c) => out.push(c),
+            None => break,
+        }
+    }
+    out
+}
+
+/// Appends `text` to `raw` slot by slot. Returns true if anything was
+/// added.
+pub fn insert(mask: &str, raw: &mut String, text: &str) -> bool {
+    let before = raw.chars().count();
+    let mut slots = mask.chars().filter(|&m| is_slot(m)).skip(before);
+    let mut next = slots.next();
+    for c in text.chars() {
+        let Some(m) = next else {
+            break;
+        };
+        if accepts(m, c) {
+            raw.push(c);
+            next = slots.next();
+        }
+    }
+    raw.chars().count() != before
+}
+
+/// The raw value for formatted `text`: the characters that fill the
+/// mask's slots, in order.
+pub fn strip(mask: &str, text: &str) -> String {
+    let mut raw = String::new();
+    insert(mask, &mut raw, text);
+    raw
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::Widget;
+
+    const PHONE: &str = "(###) ###-####";
+
+    #[test]
+    fn test_literals_appear_while_typing() {
+        let mut raw = String::new();
+        assert_eq!(format(PHONE, &raw), "");
+        for c in "555".chars() {
+            insert(PHONE, &mut raw, &c.to_string());
+        }
+        assert_eq!(format(PHONE, &raw), "(555) ");
+        assert!(!insert(PHONE, &mut raw, "x"));
+        insert(PHONE, &mut raw, "1234567");
+        assert_eq!(raw, "5551234567");
+        assert_eq!(format(PHONE, &raw), "(555) 123-4567");
+        // Full: more digits are dropped.
+        assert!(!insert(PHONE, &mut raw, "8"));
+        assert_eq!(slot_count(PHONE), 10);
+    }
+
+    #[test]
+    fn test_backspace_removes_trailing_literals() {
+        let mut raw = "5551".to_string();
+        assert_eq!(format(PHONE, &raw), "(555) 1");
+        raw.pop();
+        assert_eq!(format(PHONE, &raw), "(555) ");
+        raw.pop();
+        assert_eq!(format(PHONE, &raw), "(55");
+    }
+
+    #[test]
+    fn test_paste_of_formatted_text() {
+        for pasted in ["(555) 123-4567", "555-123-4567", "555.123.4567 ext 9"] {
+            assert_eq!(strip(PHONE, pasted), "5551234567");
+        }
+        // Pasting continues from where typing left off.
+        let mut raw = "55".to_string();
+        insert(PHONE, &mut raw, "5) 12");
+        assert_eq!(format(PHONE, &raw), "(555) 12");
+        assert_eq!(strip("AA-##", "ab12"), "ab12");
+        assert_eq!(strip("AA-##", "1a"), "a");
+    }
+
+    #[test]
+    fn test_paste_into_masked_input() {
+        let mut root = crate::ui::parse_ui(r#"TextInput(id: "phone", mask: Some("(###) ###-####"))"#).unwrap();
+        let mut interaction = crate::InteractionState::default();
+        interaction.focused_id = Some("phone".into());
+        assert!(crate::ui::paste_text(&mut root, &interaction, "(555) 123-4567"));
+        let Widget::TextInput { value, .. } = &root else { unreachable!() };
+        assert_eq!(value, "5551234567");
+    }
+}
@@ -0,0 +1,232 @@
+//! Intrinsic size measurement with real glyph metrics.
+//!
+//! The layout engine has no access to fonts, so auto-sized labels and
+//! buttons fall back to [`HeuristicMeasurer`]. [`measure_tree`] walks a
+//! widget tree with a [`TextMeasurer`] (usually the [`TextRenderer`]) and
+//! stores the measured text extent on each text-sized widget; layout then
+//! prefers it over the estimate. The stored extent remembers what it was
+//! measured for, so unchanged widgets are skipped on the next call and
+//! edited ones are re-measured.
+//!
+//! Text is measured with the properties inherited from enclosing
+//! containers (see [`TextDefaults`]), so it lays out at the size it is
+//! drawn with.
+//!
+//! `compute_layout_with` does both steps in one call.
+
+use crate::style::TextDefaults;
+use crate::text::TextRenderer;
+use crate::widget::Widget;
+use glam::Vec2;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Font size buttons render their label at.
+pub(crate) const BUTTON_TEXT_SIZE: f32 = 16.0;
+/// Horizontal padding around a button label, per side.
+pub(crate) const BUTTON_PADDING: f32 = 10.0;
+
+/// Measures text for layout.
+///
+/// Implemented by [`TextRenderer`]; the trait keeps layout usable headless,
+/// e.g. in tests or the driver.
+pub trait TextMeasurer {
+    /// Returns the width and line height of `text` rendered at `size`.
+    fn measure(&self, text: &str, size: f32, font: Option<&str>) -> Vec2;
+}
+
+impl TextMeasurer for TextRenderer {
+    fn measure(&self, text: &str, size: f32, font: Option<&str>) -> Vec2 {
+        TextRenderer::measure(self, text, size, font)
+    }
+}
+
+/// Estimates the average glyph as 0.6 × the font size wide.
+///
+/// Used whenever no real measurer was supplied.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeuristicMeasurer;
+
+impl TextMeasurer for HeuristicMeasurer {
+    fn measure(&self, text: &str, size: f32, _font: Option<&str>) -> Vec2 {
+        Vec2::new(text.chars().count() as f32 * size * 0.6, size)
+    }
+}
+
+/// Text extent cached on a widget by [`measure_tree`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeasuredText {
+    /// Hash of the widget's own text, size and font.
+    key: u64,
+    /// Hash of what was measured, after inheritance.
+    resolved: u64,
+    pub extent: Vec2,
+}
+
+impl MeasuredText {
+    fn key(text: &str, size: Option<f32>, font: Option<&str>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        (text, size.map(f32::to_bits), font).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// The cached extent if it was measured for this text, size and font.
+    /// `size` is the widget's own, `None` when it inherits one.
+    pub fn get(measured: &Option<Self>, text: &str, size: Option<f32>, font: Option<&str>) -> Option<Vec2> {
+        measured
+            .filter(|m| m.key == Self::key(text, size, font))
+            .map(|m| m.extent)
+    }
+}
+
+/// Measures `text` at the inherited `resolved` size and font into `slot`,
+/// keyed by the widget's own `size` and `font`, unless it already holds a
+/// matching extent.
+fn measure_into(
+    slot: &mut Option<MeasuredText>,
+    measurer: &dyn TextMeasurer,
+    text: &str,
+    (size, font): (Option<f32>, Option<&str>),
+    resolved: (f32, Option<&str>),
+) {
+    let key = MeasuredText::key(text, size, font);
+    let resolved_key = MeasuredText::key(text, Some(resolved.0), resolved.1);
+    if slot.is_some_and(|m| m.key == key && m.resolved == resolved_key) {
+        return;
+    }
+    *slot = Some(MeasuredText { key, resolved: resolved_key, extent: measurer.measure(text, resolved.0, resolved.1) });
+}
+
+/// Measures every label and button in the tree and returns the intrinsic
+/// size of `root`.
+///
+/// Call it before `compute_layout` whenever text may have changed (or use
+/// `compute_layout_with`). The
+/// returned size is what the root would occupy if shrunk to fit its
+/// content, e.g. for sizing a window.
+pub fn measure_tree(root: &mut Widget, measurer: &dyn TextMeasurer) -> Vec2 {
+    measure_widget(root, measurer, &TextDefaults::default());
+    let (w, h) = crate::layout_engine::get_fixed_size(root);
+    Vec2::new(w, h)
+}
+
+fn measure_widget(widget: &mut Widget, measurer: &dyn TextMeasurer, inherited: &TextDefaults) {
+    match widget {
+        Widget::Container { children, text_defaults, .. } => {
+            let inherited = inherited.inherit(text_defaults);
+            for child in children {
+                measure_widget(child, measurer, &inherited);
+            }
+        }
+        Widget::Tab { tabs, .. } => {
+            for tab in tabs {
+                measure_widget(&mut tab.content, measurer, inherited);
+            }
+        }
+        Widget::ListView { item_widgets, .. } => {
+            for row in item_widgets {
+                measure_widget(row, measurer, inherited);
+            }
+        }
+        Widget::Label { text, size, color, font, measured, .. } => {
+            let (resolved_size, _, resolved_font) = crate::widget::label_text(*size, *color, font, inherited);
+            measure_into(measured, measurer, text, (*size, font.as_deref()), (resolved_size, resolved_font.as_deref()));
+        }
+        Widget::Button { text, font, measured, .. } => {
+            let resolved_font = font.as_deref().or(inherited.font.as_deref());
+            measure_into(measured, measurer, text, (Some(BUTTON_TEXT_SIZE), font.as_deref()), (BUTTON_TEXT_SIZE, resolved_font));
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_extent_only_matches_its_input() {
+        let measured = Some(MeasuredText {
+            key: MeasuredText::key("Save", Some(16.0), None),
+            resolved: MeasuredText::key("Save", Some(16.0), None),
+            extent: Vec2::new(40.0, 16.0),
+        });
+        assert_eq!(MeasuredText::get(&measured, "Save", Some(16.0), None), Some(Vec2::new(40.0, 16.0)));
+        assert_eq!(MeasuredText::get(&measured, "Save as", Some(16.0), None), None);
+        assert_eq!(MeasuredText::get(&measured, "Save", Some(18.0), None), None);
+        assert_eq!(MeasuredText::get(&measured, "Save", Some(16.0), Some("Bold")), None);
+        assert_eq!(MeasuredText::get(&None, "Save", Some(16.0), None), None);
+    }
+    #[test]
+    fn test_measure_tree_caches_extents() {
+        struct Fixed;
+        impl TextMeasurer for Fixed {
+            fn measure(&self, text: &str, size: f32, _font: Option<&str>) -> Vec2 {
+                Vec2::new(text.len() as f32 * 5.0, size)
+            }
+        }
+
+        let mut root = crate::ui::parse_ui(r#"
+            Container(
+                layout: (direction: Row),
+                children: [
+                    Label(text: "Name", size: 20.0),
+                    Button(text: "Go", action: "go"),
+                ],
+            )
+        "#).unwrap();
+
+        // Label 4 * 5 wide next to the button, 2 * 5 plus padding on both
+        // sides.
+        let size = measure_tree(&mut root, &Fixed);
+        assert_eq!(size, Vec2::new(50.0, 30.0));
+        let Widget::Container { children, .. } = &root else { unreachable!() };
+        let Widget::Label { measured, .. } = &children[0] else { unreachable!() };
+        assert_eq!(MeasuredText::get(measured, "Name", Some(20.0), None), Some(Vec2::new(20.0, 20.0)));
+    }
+
+    #[test]
+    fn test_nested_label_inherits_ancestor_text() {
+        use std::cell::RefCell;
+
+        /// Records the font and size each text was measured with.
+        struct Recording(RefCell<Vec<(String, f32, Option<String>)>>);
+        impl TextMeasurer for Recording {
+            fn measure(&self, text: &str, size: f32, font: Option<&str>) -> Vec2 {
+                self.0.borrow_mut().push((text.to_string(), size, font.map(String::from)));
+                Vec2::new(10.0, size)
+            }
+        }
+
+        let mut root = crate::ui::parse_ui(r#"
+            Container(
+                text_defaults: (font: Some("Mono"), font_size: Some(20.0)),
+                children: [
+                    Container(
+                        text_defaults: (font_size: Some(12.0)),
+                        children: [
+                            Label(text: "nested"),
+                            Label(text: "own", font: Some("Serif"), size: 24.0),
+                            // The built-in size still counts as set.
+                            Label(text: "pinned", size: 16.0),
+                            Button(text: "go", action: "go"),
+                        ],
+                    ),
+                    Label(text: "outer"),
+                ],
+            )
+        "#).unwrap();
+        let recording = Recording(RefCell::new(Vec::new()));
+        measure_tree(&mut root, &recording);
+
+        let calls = recording.0.into_inner();
+        let mono = Some("Mono".to_string());
+        assert_eq!(calls, vec![
+            ("nested".to_string(), 12.0, mono.clone()),
+            ("own".to_string(), 24.0, Some("Serif".to_string())),
+            ("pinned".to_string(), 16.0, mono.clone()),
+            ("go".to_string(), BUTTON_TEXT_SIZE, mono.clone()),
+            ("outer".to_string(), 20.0, mono),
+        ]);
+    }
+}
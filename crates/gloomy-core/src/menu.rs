@@ -0,0 +1,166 @@
+//! Menus for the MenuBar widget.
+
+use crate::popover::Popover;
+use crate::rect::Rect;
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+/// A top-level menu: a title in the bar and the items it drops down.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Menu {
+    pub label: String,
+    #[serde(default)]
+    pub items: Vec<MenuItem>,
+}
+
+/// An entry in a dropped-down menu.
+///
+/// Only a single level is supported for now; submenus are not.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MenuItem {
+    /// Clickable entry that emits `action` when chosen.
+    Item {
+        label: String,
+        action: String,
+        /// Hint drawn right-aligned, e.g. "Ctrl+S". Display only.
+        #[serde(default)]
+        shortcut: Option<String>,
+        #[serde(default)]
+        disabled: bool,
+    },
+    /// Thin line between groups of items.
+    Separator,
+}
+
+impl MenuItem {
+    /// Convenience constructor for an enabled item.
+    pub fn item(label: impl Into<String>, action: impl Into<String>) -> Self {
+        MenuItem::Item {
+            label: label.into(),
+            action: action.into(),
+            shortcut: None,
+            disabled: false,
+        }
+    }
+
+    /// Returns the action if this item can be chosen.
+    pub fn action(&self) -> Option<&str> {
+        match self {
+            MenuItem::Item { action, disabled: false, .. } => Some(action),
+            _ => None,
+        }
+    }
+
+    /// Height of the row in the dropped-down panel.
+    pub fn height(&self) -> f32 {
+        match self {
+            MenuItem::Item { .. } => ITEM_HEIGHT,
+            MenuItem::Separator => SEPARATOR_HEIGHT,
+        }
+    }
+}
+
+pub const ITEM_HEIGHT: f32 = 28.0;
+pub const SEPARATOR_HEIGHT: f32 = 9.0;
+
+/// Width of a title in the bar.
+///
+/// Estimated from the character count (like the layout engine does for
+/// labels) so hit testing agrees with rendering without a text measurer.
+pub fn title_width(label: &str, font_size: f32) -> f32 {
+    label.chars().count() as f32 * font_size * 0.6 + 24.0
+}
+
+/// `(x, width)` of each title in the bar, relative to the bar's left edge.
+pub fn title_spans(menus: &[Menu], font_size: f32) -> Vec<(f32, f32)> {
+    let mut x = 0.0;
+    menus
+        .iter()
+        .map(|menu| {
+            let w = title_width(&menu.label, font_size);
+            let span = (x, w);
+            x += w;
+            span
+        })
+        .collect()
+}
+
+/// Index of the item whose row contains `y`, measured from the panel top.
+pub fn item_at(items: &[MenuItem], y: f32) -> Option<usize> {
+    let mut top = 0.0;
+    for (i, item) in items.iter().enumerate() {
+        let bottom = top + item.height();
+        if y >= top && y < bottom {
+            return Some(i);
+        }
+        top = bottom;
+    }
+    None
+}
+
+/// Total height of the dropped-down panel.
+pub fn panel_height(items: &[MenuItem]) -> f32 {
+    items.iter().map(MenuItem::height).sum()
+}
+
+/// Top-left corner of the dropped-down panel of a menu whose title spans
+/// `title` (absolute), `width` wide. The panel opens below the title, or
+/// above when it does not fit in `area` (see [`crate::popover`]).
+pub fn panel_origin(title: Rect, width: f32, items: &[MenuItem], area: Option<&Rect>) -> Vec2 {
+    Popover::new(title, Vec2::new(width, panel_height(items))).origin(area)
+}
+
+/// Moves the keyboard highlight to the next choosable item, wrapping.
+///
+/// Separators and disabled items are skipped. Returns `None` if nothing in
+/// the menu can be chosen.
+pub fn step_highlight(items: &[MenuItem], from: Option<usize>, forward: bool) -> Option<usize> {
+    let n = items.len();
+    if n == 0 {
+        return None;
+    }
+    let mut i = match (from, forward) {
+        (Some(i), _) => i,
+        (None, true) => n - 1,
+        (None, false) => 0,
+    };
+    for _ in 0..n {
+        i = if forward { (i + 1) % n } else { (i + n - 1) % n };
+        if items[i].action().is_some() {
+            return Some(i);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<MenuItem> {
+        vec![
+            MenuItem::item("Open", "open"),
+            MenuItem::Separator,
+            MenuItem::Item { label: "Save".into(), action: "save".into(), shortcut: None, disabled: true },
+            MenuItem::item("Quit", "quit"),
+        ]
+    }
+
+    #[test]
+    fn test_step_highlight_skips_separators_and_disabled() {
+        let items = items();
+        assert_eq!(step_highlight(&items, None, true), Some(0));
+        assert_eq!(step_highlight(&items, Some(0), true), Some(3));
+        assert_eq!(step_highlight(&items, Some(3), true), Some(0));
+        assert_eq!(step_highlight(&items, None, false), Some(3));
+        assert_eq!(step_highlight(&[MenuItem::Separator], None, true), None);
+    }
+
+    #[test]
+    fn test_item_at() {
+        let items = items();
+        assert_eq!(item_at(&items, 10.0), Some(0));
+        assert_eq!(item_at(&items, ITEM_HEIGHT + 2.0), Some(1));
+        assert_eq!(item_at(&items, panel_height(&items) + 1.0), None);
+    }
+}
@@ -0,0 +1,114 @@
+//! Number display for `NumberInput`.
+//!
+//! [`NumberFormat`] adds optional thousands grouping and a choice of
+//! decimal separator to the input's fixed `precision`, e.g. `1,234.50`
+//! or `1.234,50`. [`NumberFormat::parse`] reads such strings back, so
+//! text typed or pasted in the displayed style round-trips.
+
+use serde::{Deserialize, Serialize};
+
+/// Separators used to display a number.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NumberFormat {
+    /// Inserted between groups of three integer digits; `None` (the
+    /// default) leaves them ungrouped.
+    pub thousands_separator: Option<char>,
+    /// Separates the integer and fractional digits.
+    pub decimal_separator: char,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self { thousands_separator: None, decimal_separator: '.' }
+    }
+}
+
+impl NumberFormat {
+    /// `1,234.5` style.
+    pub fn grouped() -> Self {
+        Self { thousands_separator: Some(','), ..Self::default() }
+    }
+
+    /// Formats `value` with `precision` fractional digits.
+    pub fn format(&self, value: f64, precision: usize) -> String {
+        let plain = format!("{:.1$}", value, precision);
+        let (sign, digits) = match plain.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", plain.as_str()),
+        };
+        let (int, frac) = match digits.split_once('.') {
+            Some((int, frac)) => (int, Some(frac)),
+            None => (digits, None),
+        };
+
+        let mut out = String::from(sign);
+        match self.thousands_separator {
+            Some(sep) => {
+                for (i, c) in int.chars().enumerate() {
+                    if i > 0 && (int.len() - i) % 3 == 0 {
+                        out.push(sep);
+                    }
+                    out.push(c);
+                }
+            }
+            None => out.push_str(int),
+        }
+        if let Some(frac) = frac {
+            out.push(self.decimal_separator);
+            out.push_str(frac);
+        }
+        out
+    }
+
+    /// Parses a number written in this format. Thousands separators are
+    /// ignored wherever they appear; surrounding whitespace is trimmed.
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        let normalized: String = text
+            .trim()
+            .chars()
+            .filter(|&c| Some(c) != self.thousands_separator)
+            .map(|c| if c == self.decimal_separator { '.' } else { c })
+            .collect();
+        normalized.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_by_default() {
+        let f = NumberFormat::default();
+        assert_eq!(f.format(1234567.891, 2), "1234567.89");
+        assert_eq!(f.format(-3.0, 0), "-3");
+    }
+
+    #[test]
+    fn test_grouping_at_various_magnitudes() {
+        let f = NumberFormat::grouped();
+        assert_eq!(f.format(0.5, 2), "0.50");
+        assert_eq!(f.format(999.0, 0), "999");
+        assert_eq!(f.format(1000.0, 0), "1,000");
+        assert_eq!(f.format(1234.5, 2), "1,234.50");
+        assert_eq!(f.format(123456.0, 1), "123,456.0");
+        assert_eq!(f.format(1234567.0, 0), "1,234,567");
+        assert_eq!(f.format(-9876543.21, 2), "-9,876,543.21");
+        // Rounding can add a digit and a group.
+        assert_eq!(f.format(999.999, 2), "1,000.00");
+    }
+
+    #[test]
+    fn test_formatted_text_parses_back() {
+        let european = NumberFormat { thousands_separator: Some('.'), decimal_separator: ',' };
+        assert_eq!(european.format(1234.5, 2), "1.234,50");
+        for f in [NumberFormat::default(), NumberFormat::grouped(), european] {
+            for value in [0.0, 12.25, -1234.5, 1234567.75] {
+                assert_eq!(f.parse(&f.format(value, 2)), Some(value));
+            }
+        }
+        assert_eq!(NumberFormat::grouped().parse(" 12,000 "), Some(12000.0));
+        assert_eq!(NumberFormat::grouped().parse("12a"), None);
+    }
+}
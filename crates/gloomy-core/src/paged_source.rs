@@ -0,0 +1,187 @@
+//! Paged data source for datasets too large to hold in memory.
+//!
+//! [`PagedDataSource`] implements [`DataSource`] over a [`PageFetcher`]
+//! that loads fixed-size pages of rows on demand. The grid only asks for
+//! the rows it draws, so scrolling fetches the pages coming into view.
+//! Loaded pages are cached; once more than `max_pages` are held, the pages
+//! furthest from the one just requested are evicted.
+
+use crate::data_source::{CellValue, DataSource};
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Loads rows for a [`PagedDataSource`].
+pub trait PageFetcher: Send + Sync {
+    /// Total number of rows in the dataset.
+    fn total_rows(&self) -> usize;
+
+    /// Number of columns in every row.
+    fn column_count(&self) -> usize;
+
+    /// Loads up to `len` rows starting at row `offset`. Called from the
+    /// render thread, so a remote fetcher should answer from its own
+    /// buffer or block only briefly.
+    fn fetch_page(&self, offset: usize, len: usize) -> Vec<Vec<CellValue>>;
+}
+
+/// [`DataSource`] that fetches rows page by page and caches a bounded
+/// number of pages.
+pub struct PagedDataSource<F: PageFetcher> {
+    fetcher: F,
+    page_size: usize,
+    max_pages: usize,
+    /// Page index -> rows. Behind a lock because cells are read through
+    /// `&self`.
+    pages: Mutex<BTreeMap<usize, Vec<Vec<CellValue>>>>,
+    version: u64,
+}
+
+impl<F: PageFetcher> PagedDataSource<F> {
+    /// Creates a source reading `page_size` rows at a time and keeping at
+    /// most `max_pages` pages (both at least 1).
+    pub fn new(fetcher: F, page_size: usize, max_pages: usize) -> Self {
+        Self {
+            fetcher,
+            page_size: page_size.max(1),
+            max_pages: max_pages.max(1),
+            pages: Mutex::new(BTreeMap::new()),
+            version: 0,
+        }
+    }
+
+    /// The page fetcher.
+    pub fn fetcher(&self) -> &F {
+        &self.fetcher
+    }
+
+    /// Indices of the pages currently cached, in order.
+    pub fn cached_pages(&self) -> Vec<usize> {
+        self.lock().keys().copied().collect()
+    }
+
+    /// Drops all cached pages so they are fetched again, e.g. after the
+    /// remote data changed. Bumps the version.
+    pub fn invalidate(&mut self) {
+        self.lock().clear();
+        self.version += 1;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<usize, Vec<Vec<CellValue>>>> {
+        // A panic mid-fetch leaves at worst a missing page.
+        self.pages.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Reads a cell, fetching its page if it isn't cached.
+    fn with_cell<T>(&self, row: usize, col: usize, read: impl FnOnce(Option<&CellValue>) -> T) -> T {
+        if row >= self.fetcher.total_rows() {
+            return read(None);
+        }
+        let page = row / self.page_size;
+        let mut pages = self.lock();
+        if let Entry::Vacant(slot) = pages.entry(page) {
+            slot.insert(self.fetcher.fetch_page(page * self.page_size, self.page_size));
+            while pages.len() > self.max_pages {
+                let first = *pages.keys().next().unwrap();
+                let last = *pages.keys().next_back().unwrap();
+                let furthest = if page - first >= last - page { first } else { last };
+                pages.remove(&furthest);
+            }
+        }
+        read(pages[&page].get(row % self.page_size).and_then(|cells| cells.get(col)))
+    }
+}
+
+impl<F: PageFetcher> DataSource for PagedDataSource<F> {
+    fn row_count(&self) -> usize {
+        self.fetcher.total_rows()
+    }
+
+    fn column_count(&self) -> usize {
+        self.fetcher.column_count()
+    }
+
+    fn version(&self) -> u64 {
+        self.version
+    }
+
+    fn cell_text(&self, row: usize, col: usize) -> String {
+        self.with_cell(row, col, |cell| cell.map(|v| v.to_string()).unwrap_or_default())
+    }
+
+    fn cell_value(&self, row: usize, col: usize) -> CellValue {
+        self.with_cell(row, col, |cell| cell.cloned().unwrap_or(CellValue::None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Rows of `[row]`, counting fetches.
+    struct Numbers {
+        total: usize,
+        fetches: AtomicUsize,
+    }
+
+    impl PageFetcher for Numbers {
+        fn total_rows(&self) -> usize {
+            self.total
+        }
+
+        fn column_count(&self) -> usize {
+            1
+        }
+
+        fn fetch_page(&self, offset: usize, len: usize) -> Vec<Vec<CellValue>> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            (offset..(offset + len).min(self.total)).map(|i| vec![CellValue::Integer(i as i64)]).collect()
+        }
+    }
+
+    fn source(total: usize, max_pages: usize) -> PagedDataSource<Numbers> {
+        PagedDataSource::new(Numbers { total, fetches: AtomicUsize::new(0) }, 10, max_pages)
+    }
+
+    #[test]
+    fn test_fetches_pages_on_demand_and_caches_them() {
+        let ds = source(1_000_000, 4);
+        assert_eq!(ds.row_count(), 1_000_000);
+        assert_eq!(ds.cell_value(123_456, 0), CellValue::Integer(123_456));
+        assert_eq!(ds.cell_text(123_459, 0), "123459");
+        assert_eq!(ds.fetcher().fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(ds.cached_pages(), vec![12_345]);
+
+        // Rows past the end are empty without a fetch; so are missing columns.
+        assert_eq!(ds.cell_value(1_000_000, 0), CellValue::None);
+        assert_eq!(ds.fetcher().fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(ds.cell_text(0, 5), "");
+        assert_eq!(ds.fetcher().fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_evicts_pages_furthest_from_the_request() {
+        let ds = source(1000, 3);
+        for row in [0, 10, 20] {
+            ds.cell_text(row, 0);
+        }
+        // Scrolling down drops the top page.
+        ds.cell_text(30, 0);
+        assert_eq!(ds.cached_pages(), vec![1, 2, 3]);
+        // Jumping back to the top drops the bottom one.
+        ds.cell_text(5, 0);
+        assert_eq!(ds.cached_pages(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_invalidate_refetches_and_bumps_version() {
+        let mut ds = source(100, 2);
+        ds.cell_text(0, 0);
+        ds.invalidate();
+        assert_eq!(ds.version(), 1);
+        assert!(ds.cached_pages().is_empty());
+        ds.cell_text(0, 0);
+        assert_eq!(ds.fetcher().fetches.load(Ordering::SeqCst), 2);
+    }
+}
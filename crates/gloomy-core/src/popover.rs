@@ -0,0 +1,173 @@
+//! Shared placement for popovers: the Dropdown list, the Autocomplete
+//! suggestions and the DatePicker calendar.
+//!
+//! A [`Popover`] opens against an anchor rect (the widget that owns it) on
+//! a preferred side. When it does not fit between the anchor and the edge
+//! of the overlay area on that side, it flips to the opposite side if
+//! there is more room there, and is then clamped so it stays inside the
+//! area.
+//!
+//! The area is the surface while rendering. `render_root` records it in
+//! [`InteractionState::overlay_area`](crate::interaction::InteractionState::overlay_area)
+//! and hit testing places popovers against that same area, so the drawn
+//! overlay and its hit region agree. Before the first frame there is no
+//! area and popovers open on their preferred side, unclamped.
+
+use crate::rect::Rect;
+use crate::ui::RenderContext;
+use crate::widget::Widget;
+use glam::Vec2;
+
+/// Side of the anchor a popover opens on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+  #[default]
+  Below,
+  Above,
+  Left,
+  Right,
+}
+
+impl Placement {
+  pub fn opposite(self) -> Self {
+    match self {
+      Placement::Below => Placement::Above,
+      Placement::Above => Placement::Below,
+      Placement::Left => Placement::Right,
+      Placement::Right => Placement::Left,
+    }
+  }
+}
+
+/// A popover of `size` opening next to `anchor`, in absolute coordinates.
+#[derive(Debug, Clone, Copy)]
+pub struct Popover {
+  pub anchor: Rect,
+  pub size: Vec2,
+  pub placement: Placement,
+  /// Space between the anchor and the popover.
+  pub gap: f32,
+}
+
+impl Popover {
+  /// A popover opening below `anchor`, flush with it.
+  pub fn new(anchor: Rect, size: Vec2) -> Self {
+    Self { anchor, size, placement: Placement::Below, gap: 0.0 }
+  }
+
+  pub fn placement(mut self, placement: Placement) -> Self {
+    self.placement = placement;
+    self
+  }
+
+  pub fn gap(mut self, gap: f32) -> Self {
+    self.gap = gap;
+    self
+  }
+
+  /// Side the popover opens on within `area`.
+  pub fn resolve(&self, area: Option<&Rect>) -> Placement {
+    let Some(area) = area else { return self.placement };
+    let room = |placement| self.room(placement, area);
+    let needed = match self.placement {
+      Placement::Below | Placement::Above => self.size.y,
+      Placement::Left | Placement::Right => self.size.x,
+    };
+    let flipped = self.placement.opposite();
+    if room(self.placement) < needed && room(flipped) > room(self.placement) {
+      flipped
+    } else {
+      self.placement
+    }
+  }
+
+  /// Absolute top-left corner of the popover within `area`.
+  pub fn origin(&self, area: Option<&Rect>) -> Vec2 {
+    let a = &self.anchor;
+    let origin = match self.resolve(area) {
+      Placement::Below => Vec2::new(a.x, a.y + a.height + self.gap),
+      Placement::Above => Vec2::new(a.x, a.y - self.gap - self.size.y),
+      Placement::Left => Vec2::new(a.x - self.gap - self.size.x, a.y),
+      Placement::Right => Vec2::new(a.x + a.width + self.gap, a.y),
+    };
+    match area {
+      // Popovers larger than the area stay pinned to its top-left corner.
+      Some(area) => Vec2::new(
+        origin.x.min(area.x + area.width - self.size.x).max(area.x),
+        origin.y.min(area.y + area.height - self.size.y).max(area.y),
+      ),
+      None => origin,
+    }
+  }
+
+  /// The placed popover as a rect.
+  pub fn rect(&self, area: Option<&Rect>) -> Rect {
+    let origin = self.origin(area);
+    Rect::new(origin.x, origin.y, self.size.x, self.size.y)
+  }
+
+  /// Queues `draw` as a deferred overlay draw, called with the popover's
+  /// origin once the rest of the frame is drawn. Returns the origin.
+  pub fn enqueue<F>(&self, ctx: &mut RenderContext, draw: F) -> Vec2
+  where
+    F: FnOnce(&mut crate::renderer::GloomyRenderer, &wgpu::Device, &wgpu::Queue, Vec2) + 'static,
+  {
+    let origin = self.origin(Some(&ctx.overlay_area()));
+    if let Some(deferred) = ctx.deferred_draws.as_mut() {
+      deferred.push(Box::new(move |renderer, device, queue| draw(renderer, device, queue, origin)));
+    }
+    origin
+  }
+
+  /// Queues `widget` on the overlay queue at the popover's origin.
+  /// Returns the origin.
+  pub fn enqueue_widget(&self, ctx: &mut RenderContext, widget: Widget) -> Vec2 {
+    let origin = self.origin(Some(&ctx.overlay_area()));
+    ctx.overlay_queue.push((widget, origin));
+    origin
+  }
+
+  /// Space between the anchor and the area's edge on `placement`'s side.
+  fn room(&self, placement: Placement, area: &Rect) -> f32 {
+    let a = &self.anchor;
+    match placement {
+      Placement::Below => area.y + area.height - (a.y + a.height) - self.gap,
+      Placement::Above => a.y - area.y - self.gap,
+      Placement::Left => a.x - area.x - self.gap,
+      Placement::Right => area.x + area.width - (a.x + a.width) - self.gap,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const SURFACE: Rect = Rect { x: 0.0, y: 0.0, width: 800.0, height: 600.0 };
+
+  #[test]
+  fn test_flips_above_near_bottom_edge() {
+    let anchor = Rect::new(100.0, 540.0, 200.0, 30.0);
+    let popover = Popover::new(anchor, Vec2::new(250.0, 200.0)).gap(2.0);
+
+    assert_eq!(popover.resolve(Some(&SURFACE)), Placement::Above);
+    assert_eq!(popover.origin(Some(&SURFACE)), Vec2::new(100.0, 338.0));
+
+    // With room below it stays put.
+    let popover = Popover { anchor: Rect::new(100.0, 100.0, 200.0, 30.0), ..popover };
+    assert_eq!(popover.origin(Some(&SURFACE)), Vec2::new(100.0, 132.0));
+  }
+
+  #[test]
+  fn test_clamps_when_neither_side_fits() {
+    // Flipping would not help: more room below, so it stays below and
+    // is pushed back up inside the surface.
+    let anchor = Rect::new(700.0, 250.0, 200.0, 30.0);
+    let popover = Popover::new(anchor, Vec2::new(250.0, 400.0));
+    assert_eq!(popover.resolve(Some(&SURFACE)), Placement::Below);
+    assert_eq!(popover.origin(Some(&SURFACE)), Vec2::new(550.0, 200.0));
+
+    // Without an area nothing is adjusted.
+    assert_eq!(popover.origin(None), Vec2::new(700.0, 280.0));
+  }
+}
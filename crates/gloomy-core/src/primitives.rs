@@ -7,7 +7,7 @@ use glam::{Vec2, Vec4};
 
 /// Instance data for a single primitive.
 ///
-/// Packed for GPU buffer layout with 48 bytes per instance.
+/// Packed for GPU buffer layout with 112 bytes per instance.
 #[repr(C)]
 #[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct Instance {
@@ -21,7 +21,7 @@ pub struct Instance {
   pub color_end: Vec4,
   /// Corner radii: [TopRight, BottomRight, TopLeft, BottomLeft]
   pub radii: [f32; 4],
-  /// Primitive type: 0=Rect, 1=Circle, 2=Line
+  /// Primitive type: 0=Rect, 1=Circle, 2=Line, 3=Border ring, 4=Arc, 5=Star
   pub prim_type: u32,
   /// Stroke width
   pub stroke_width: f32,
@@ -29,6 +29,80 @@ pub struct Instance {
   pub softness: f32,
   /// Padding
   pub _pad: u32,
+  /// Rounded clip: center (xy) and half-extents (zw). Disabled when zw is zero.
+  pub clip_rect: Vec4,
+  /// Rounded clip corner radii, same order as `radii`.
+  pub clip_radii: [f32; 4],
+}
+
+/// A rounded-rect mask applied per fragment in addition to the scissor.
+///
+/// Costs one extra SDF evaluation per fragment for every instance drawn
+/// while the clip is active, plus 32 bytes per instance regardless.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RoundedClip {
+  /// Center in screen coordinates
+  pub center: Vec2,
+  /// Half-extents (width/2, height/2)
+  pub half_size: Vec2,
+  /// Corner radii, same order as the box being clipped to
+  pub radii: [f32; 4],
+}
+
+impl RoundedClip {
+  /// Packs an optional clip into the per-instance `clip_rect`/`clip_radii` form.
+  pub(crate) fn to_instance(clip: Option<RoundedClip>) -> (Vec4, [f32; 4]) {
+    match clip {
+      Some(c) => (Vec4::new(c.center.x, c.center.y, c.half_size.x, c.half_size.y), c.radii),
+      None => (Vec4::ZERO, [0.0; 4]),
+    }
+  }
+}
+
+/// Rounds a rect's or border ring's edges, or an axis-aligned line's
+/// position and thickness, to device pixels at `scale` device pixels per
+/// logical one.
+fn snap_to_pixels(instance: &mut Instance, scale: f32) {
+  let to_device = |v: f32| (v * scale).round() / scale;
+  match instance.prim_type {
+    // Rect and border ring: center and full size. A ring's stroke is
+    // rounded too so its inner edge lands on a pixel as well.
+    0 | 3 => {
+      if instance.prim_type == 3 {
+        instance.stroke_width = to_device(instance.stroke_width).max(1.0 / scale);
+      }
+      let min = instance.pos_a - instance.pos_b * 0.5;
+      let max = instance.pos_a + instance.pos_b * 0.5;
+      let min = Vec2::new(to_device(min.x), to_device(min.y));
+      let max = Vec2::new(to_device(max.x), to_device(max.y));
+      instance.pos_a = (min + max) * 0.5;
+      instance.pos_b = max - min;
+    }
+    // Line: endpoints, half thickness in radii[0].
+    2 => {
+      let (a, b) = (instance.pos_a, instance.pos_b);
+      let horizontal = a.y == b.y;
+      if !horizontal && a.x != b.x {
+        return;
+      }
+      let thickness = ((instance.radii[0] * 2.0 * scale).round()).max(1.0);
+      instance.radii[0] = thickness / scale * 0.5;
+      // An odd number of device pixels is centered on a pixel center,
+      // an even number on a pixel edge.
+      let center_offset = if thickness as u32 % 2 == 1 { 0.5 } else { 0.0 };
+      let across = |v: f32| ((v * scale - center_offset).round() + center_offset) / scale;
+      if horizontal {
+        let y = across(a.y);
+        instance.pos_a = Vec2::new(to_device(a.x), y);
+        instance.pos_b = Vec2::new(to_device(b.x), y);
+      } else {
+        let x = across(a.x);
+        instance.pos_a = Vec2::new(x, to_device(a.y));
+        instance.pos_b = Vec2::new(x, to_device(b.y));
+      }
+    }
+    _ => {}
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -46,6 +120,12 @@ pub struct PrimitiveRenderer {
   instances: Vec<Instance>,
   batches: Vec<Batch>,
   current_scissor: Option<(u32, u32, u32, u32)>,
+  current_clip: Option<RoundedClip>,
+  /// Alpha multiplier applied to every queued instance.
+  opacity: f32,
+  /// Round rect edges and straight lines to device pixels.
+  pixel_snap: bool,
+  scale_factor: f32,
   screen_size: Vec2, // Logical
   width: u32, // Physical
   height: u32, // Physical
@@ -59,6 +139,18 @@ impl PrimitiveRenderer {
     format: wgpu::TextureFormat,
     width: u32,
     height: u32,
+  ) -> Self {
+    Self::new_multisampled(device, format, width, height, 1)
+  }
+
+  /// Creates a primitive renderer for render passes with `sample_count`
+  /// samples per pixel.
+  pub fn new_multisampled(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    width: u32,
+    height: u32,
+    sample_count: u32,
   ) -> Self {
     let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
       label: Some("GloomyPrimitiveShader"),
@@ -124,7 +216,9 @@ impl PrimitiveRenderer {
               5 => Uint32,    // prim_type
               6 => Float32,   // stroke_width
               7 => Float32,   // softness
-              8 => Uint32     // pad
+              8 => Uint32,    // pad
+              9 => Float32x4, // clip_rect
+              10 => Float32x4 // clip_radii
             ],
           }],
           compilation_options: wgpu::PipelineCompilationOptions::default(),
@@ -141,7 +235,10 @@ impl PrimitiveRenderer {
         }),
         primitive: wgpu::PrimitiveState::default(),
         depth_stencil: None,
-        multisample: wgpu::MultisampleState::default(),
+        multisample: wgpu::MultisampleState {
+          count: sample_count,
+          ..Default::default()
+        },
         multiview: None,
       });
 
@@ -161,6 +258,10 @@ impl PrimitiveRenderer {
       instances: Vec::with_capacity(initial_capacity),
       batches: Vec::new(),
       current_scissor: None,
+      current_clip: None,
+      opacity: 1.0,
+      pixel_snap: false,
+      scale_factor: 1.0,
       screen_size: Vec2::new(width as f32, height as f32),
       width,
       height,
@@ -172,6 +273,7 @@ impl PrimitiveRenderer {
   pub fn resize(&mut self, queue: &wgpu::Queue, width: u32, height: u32, scale_factor: f32) {
     self.width = width;
     self.height = height;
+    self.scale_factor = scale_factor;
     self.screen_size = Vec2::new((width as f32).max(1.0) / scale_factor, (height as f32).max(1.0) / scale_factor);
     queue.write_buffer(
       &self.uniform_buffer,
@@ -191,7 +293,35 @@ impl PrimitiveRenderer {
       old
   }
 
-  fn push_instance(&mut self, instance: Instance) {
+  /// Sets the alpha multiplier for subsequent draws.
+  /// Returns the previous value.
+  pub fn set_opacity(&mut self, opacity: f32) -> f32 {
+      std::mem::replace(&mut self.opacity, opacity)
+  }
+
+  /// Sets the rounded clip mask for subsequent draws.
+  /// Returns the previous value.
+  pub fn set_rounded_clip(&mut self, clip: Option<RoundedClip>) -> Option<RoundedClip> {
+      std::mem::replace(&mut self.current_clip, clip)
+  }
+
+  /// Enables rounding of rect and border edges and horizontal/vertical
+  /// lines to whole device pixels, so hairlines and 1px borders at
+  /// fractional positions stay crisp instead of smearing over two pixels. Curved
+  /// shapes and diagonal lines are left alone. Off by default.
+  pub fn set_pixel_snap(&mut self, enabled: bool) {
+      self.pixel_snap = enabled;
+  }
+
+  fn push_instance(&mut self, mut instance: Instance) {
+      if self.pixel_snap {
+          snap_to_pixels(&mut instance, self.scale_factor);
+      }
+      if self.opacity < 1.0 {
+          instance.color.w *= self.opacity;
+          instance.color_end.w *= self.opacity;
+      }
+      (instance.clip_rect, instance.clip_radii) = RoundedClip::to_instance(self.current_clip);
       let instance_idx = self.instances.len() as u32;
       self.instances.push(instance);
 
@@ -237,6 +367,8 @@ impl PrimitiveRenderer {
       stroke_width,
       softness: 0.0,
       _pad: 0,
+      clip_rect: Vec4::ZERO,
+      clip_radii: [0.0; 4],
     });
   }
 
@@ -261,9 +393,51 @@ impl PrimitiveRenderer {
           stroke_width,
           softness,
           _pad: 0,
+          clip_rect: Vec4::ZERO,
+          clip_radii: [0.0; 4],
       });
   }
 
+  /// Draws a rounded-rect border as a ring.
+  ///
+  /// Unlike `draw_rect` with a stroke width, the ring's inner edge is a
+  /// true inset rounded box (half-size and radii shrunk by `width`) and
+  /// coverage is multiplied rather than subtracted, so anti-aliasing stays
+  /// clean on rounded corners. Pair it with a fill inset by `width` to
+  /// keep semi-transparent borders from blending over the background.
+  ///
+  /// # Arguments
+  /// * `pos` - Center position in screen coordinates
+  /// * `size` - Outer half-extents (width/2, height/2)
+  /// * `radii` - Outer corner radii [TR, BR, TL, BL]
+  /// * `width` - Border width, measured inwards from the outer edge
+  /// * `color` - RGBA color
+  pub fn draw_border(
+    &mut self,
+    pos: Vec2,
+    size: Vec2,
+    radii: [f32; 4],
+    width: f32,
+    color: Vec4,
+  ) {
+    if width <= 0.0 {
+      return;
+    }
+    self.push_instance(Instance {
+      pos_a: pos,
+      pos_b: size * 2.0,
+      color,
+      color_end: color,
+      radii,
+      prim_type: 3,
+      stroke_width: width,
+      softness: 0.0,
+      _pad: 0,
+      clip_rect: Vec4::ZERO,
+      clip_radii: [0.0; 4],
+    });
+  }
+
   /// Draws a filled rectangle (convenience method).
   pub fn fill_rect(&mut self, pos: Vec2, size: Vec2, color: Vec4) {
     self.draw_rect(pos, size, color, [0.0; 4], 0.0);
@@ -293,6 +467,8 @@ impl PrimitiveRenderer {
       stroke_width,
       softness: 0.0,
       _pad: 0,
+      clip_rect: Vec4::ZERO,
+      clip_radii: [0.0; 4],
     });
   }
 
@@ -320,6 +496,117 @@ impl PrimitiveRenderer {
       stroke_width: 0.0,
       softness: 0.0,
       _pad: 0,
+      clip_rect: Vec4::ZERO,
+      clip_radii: [0.0; 4],
+    });
+  }
+
+  /// Draws a circular arc with round caps.
+  ///
+  /// Angles are in radians, measured clockwise from 12 o'clock. A sweep
+  /// of `TAU` or more draws the full ring.
+  ///
+  /// # Arguments
+  /// * `center` - Center position
+  /// * `radius` - Radius of the arc's centerline
+  /// * `start_angle` - Where the arc begins
+  /// * `sweep` - Angular length, clockwise
+  /// * `thickness` - Stroke thickness
+  /// * `color` - RGBA color
+  pub fn draw_arc(
+    &mut self,
+    center: Vec2,
+    radius: f32,
+    start_angle: f32,
+    sweep: f32,
+    thickness: f32,
+    color: Vec4,
+  ) {
+    if sweep <= 0.0 || thickness <= 0.0 {
+      return;
+    }
+    self.push_instance(Instance {
+      pos_a: center,
+      pos_b: Vec2::ZERO,
+      color,
+      color_end: color,
+      radii: [radius, start_angle.rem_euclid(std::f32::consts::TAU), sweep, 0.0],
+      prim_type: 4,
+      stroke_width: thickness,
+      softness: 0.0,
+      _pad: 0,
+      clip_rect: Vec4::ZERO,
+      clip_radii: [0.0; 4],
+    });
+  }
+
+  /// Draws a five-pointed star with one point facing up.
+  ///
+  /// Only the left `fill` fraction (0..=1) of the star is drawn, so a
+  /// half star is an empty star overdrawn with `fill = 0.5`. A positive
+  /// `stroke_width` draws the outline instead of filling.
+  ///
+  /// # Arguments
+  /// * `center` - Center position
+  /// * `radius` - Distance from the center to the tips
+  /// * `fill` - Horizontal fraction to draw, from the left
+  /// * `stroke_width` - Outline width, or 0.0 to fill
+  /// * `color` - RGBA color
+  pub fn draw_star(
+    &mut self,
+    center: Vec2,
+    radius: f32,
+    fill: f32,
+    stroke_width: f32,
+    color: Vec4,
+  ) {
+    if fill <= 0.0 || radius <= 0.0 {
+      return;
+    }
+    self.push_instance(Instance {
+      pos_a: center,
+      pos_b: Vec2::ZERO,
+      color,
+      color_end: color,
+      radii: [radius, 0.45, fill.min(1.0), 0.0],
+      prim_type: 5,
+      stroke_width,
+      softness: 0.0,
+      _pad: 0,
+      clip_rect: Vec4::ZERO,
+      clip_radii: [0.0; 4],
+    });
+  }
+
+  /// Draws a triangle through three points, in any winding order.
+  ///
+  /// # Arguments
+  /// * `a`, `b`, `c` - Corner positions
+  /// * `color` - RGBA color
+  /// * `stroke_width` - Outline width, or 0.0 to fill
+  pub fn draw_triangle(
+    &mut self,
+    a: Vec2,
+    b: Vec2,
+    c: Vec2,
+    color: Vec4,
+    stroke_width: f32,
+  ) {
+    // Corners are passed relative to the bounding box center.
+    let center = (a.min(b).min(c) + a.max(b).max(c)) * 0.5;
+    let (a, b, c) = (a - center, b - center, c - center);
+    self.push_instance(Instance {
+      pos_a: center,
+      pos_b: c,
+      color,
+      color_end: color,
+      radii: [a.x, a.y, b.x, b.y],
+      prim_type: 6,
+      stroke_width,
+      softness: 0.0,
+      _pad: 0,
+      clip_rect: Vec4::ZERO,
+      clip_radii: [0.0; 4],
     });
   }
 
@@ -383,6 +670,8 @@ impl PrimitiveRenderer {
     self.batches.clear();
     // Keep current_scissor or reset? Reset is safer per frame.
     self.current_scissor = None;
+    self.opacity = 1.0;
+    self.current_clip = None;
   }
 
   // --- CAPTURE / REPLAY ---
@@ -421,6 +710,10 @@ impl PrimitiveRenderer {
           if i.prim_type == 2 { // Line
               i.pos_b += offset;
           }
+          if i.clip_rect.z > 0.0 {
+              i.clip_rect.x += offset.x;
+              i.clip_rect.y += offset.y;
+          }
           self.instances.push(i);
       }
 
@@ -438,3 +731,60 @@ pub struct PrimitiveSnapshot {
     pub instances: Vec<Instance>,
     pub batches: Vec<Batch>,
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn instance(prim_type: u32, pos_a: Vec2, pos_b: Vec2, half_thickness: f32) -> Instance {
+    Instance {
+      pos_a,
+      pos_b,
+      color: Vec4::ONE,
+      color_end: Vec4::ONE,
+      radii: [half_thickness, 0.0, 0.0, 0.0],
+      prim_type,
+      stroke_width: 0.0,
+      softness: 0.0,
+      _pad: 0,
+      clip_rect: Vec4::ZERO,
+      clip_radii: [0.0; 4],
+    }
+  }
+
+  #[test]
+  fn test_rect_edges_snap_to_device_pixels() {
+    // Edges at 10.3..50.6 logical, 1.5x scale: 15.45..75.9 device.
+    let mut rect = instance(0, Vec2::new(30.45, 20.0), Vec2::new(40.3, 10.0), 0.0);
+    snap_to_pixels(&mut rect, 1.5);
+    let min = (rect.pos_a - rect.pos_b * 0.5) * 1.5;
+    let max = (rect.pos_a + rect.pos_b * 0.5) * 1.5;
+    assert!((min.x - 15.0).abs() < 1e-4 && (max.x - 76.0).abs() < 1e-4);
+  }
+
+  #[test]
+  fn test_border_ring_snaps_like_a_rect() {
+    let mut ring = instance(3, Vec2::new(30.45, 20.0), Vec2::new(40.3, 10.0), 0.0);
+    ring.stroke_width = 0.9;
+    snap_to_pixels(&mut ring, 1.5);
+    let min = (ring.pos_a - ring.pos_b * 0.5) * 1.5;
+    let max = (ring.pos_a + ring.pos_b * 0.5) * 1.5;
+    assert!((min.x - 15.0).abs() < 1e-4 && (max.x - 76.0).abs() < 1e-4);
+    // 1.35 device pixels rounds to one.
+    assert!((ring.stroke_width * 1.5 - 1.0).abs() < 1e-4);
+  }
+
+  #[test]
+  fn test_hairline_lands_on_pixel_center() {
+    let mut line = instance(2, Vec2::new(0.2, 10.0), Vec2::new(100.7, 10.0), 0.5);
+    snap_to_pixels(&mut line, 1.0);
+    assert_eq!(line.pos_a, Vec2::new(0.0, 10.5));
+    assert_eq!(line.pos_b, Vec2::new(101.0, 10.5));
+    assert_eq!(line.radii[0], 0.5);
+
+    // Diagonal lines are untouched.
+    let mut diagonal = instance(2, Vec2::new(0.2, 0.2), Vec2::new(10.7, 10.7), 0.5);
+    snap_to_pixels(&mut diagonal, 1.0);
+    assert_eq!(diagonal.pos_a, Vec2::new(0.2, 0.2));
+  }
+}
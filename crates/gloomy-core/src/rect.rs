@@ -3,7 +3,7 @@
 use glam::Vec2;
 
 /// A rectangle defined by position and size.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct Rect {
   /// X position (left edge)
   pub x: f32,
@@ -1,5 +1,6 @@
 //! GPU context and rendering orchestration.
 
+use crate::icons::IconRegistry;
 use crate::image_renderer::ImageRenderer;
 use crate::primitives::PrimitiveRenderer;
 use crate::text::TextRenderer;
@@ -35,6 +36,7 @@ pub struct GloomyRenderer {
   overlay_text: TextRenderer,
   images: ImageRenderer,
   textures: HashMap<String, Texture>,
+  icons: IconRegistry,
   // mpl-wgpu renderers
   pub chart_primitives: mpl_wgpu::primitives::PrimitiveRenderer,
   pub chart_text: mpl_wgpu::text::TextRenderer,
@@ -42,6 +44,43 @@ pub struct GloomyRenderer {
   height: u32,
   pub scale_factor: f32,
   clear_color: wgpu::Color,
+  format: wgpu::TextureFormat,
+  /// Samples per pixel for the primitive and image pass; 1 is no MSAA.
+  sample_count: u32,
+  /// Multisampled color target, recreated when the size changes.
+  msaa_target: Option<MsaaTarget>,
+}
+
+struct MsaaTarget {
+  view: wgpu::TextureView,
+  width: u32,
+  height: u32,
+}
+
+/// Returns the highest MSAA sample count up to `requested` that a device
+/// with `features` on `adapter` can render `format` with. Always at least 1.
+///
+/// Counts other than 1 and 4 need `TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES`
+/// on the device; without it wgpu rejects them even if the adapter
+/// reports support.
+pub fn supported_sample_count(
+  adapter: &wgpu::Adapter,
+  features: wgpu::Features,
+  format: wgpu::TextureFormat,
+  requested: u32,
+) -> u32 {
+  let flags = adapter.get_texture_format_features(format).flags;
+  let candidates: &[u32] = if features.contains(wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES) {
+    &[8, 4, 2]
+  } else {
+    &[4]
+  };
+  candidates
+    .iter()
+    .copied()
+    .filter(|&count| count <= requested)
+    .find(|&count| flags.sample_count_supported(count))
+    .unwrap_or(1)
 }
 
 impl GloomyRenderer {
@@ -59,7 +98,42 @@ impl GloomyRenderer {
     height: u32,
     scale_factor: f32,
   ) -> Self {
-    let primitives = PrimitiveRenderer::new(device, format, width, height);
+    Self::build(device, format, width, height, scale_factor, 1)
+  }
+
+  /// Creates a renderer that multisamples shapes and images with up to
+  /// `sample_count` samples per pixel, smoothing line and triangle edges
+  /// the SDF shader can't.
+  ///
+  /// Falls back to the highest count the adapter and `device` support for
+  /// `format`, down to 1 (no MSAA); see [`supported_sample_count`]. Text is drawn after the samples are resolved,
+  /// since glyphs are already anti-aliased. With MSAA on, chart shapes are
+  /// drawn after images instead of before.
+  pub fn new_multisampled(
+    device: &wgpu::Device,
+    adapter: &wgpu::Adapter,
+    format: wgpu::TextureFormat,
+    width: u32,
+    height: u32,
+    scale_factor: f32,
+    sample_count: u32,
+  ) -> Self {
+    let supported = supported_sample_count(adapter, device.features(), format, sample_count);
+    if supported != sample_count.max(1) {
+      log::warn!("{}x MSAA is not supported for {:?}, using {}x", sample_count, format, supported);
+    }
+    Self::build(device, format, width, height, scale_factor, supported)
+  }
+
+  fn build(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    width: u32,
+    height: u32,
+    scale_factor: f32,
+    sample_count: u32,
+  ) -> Self {
+    let primitives = PrimitiveRenderer::new_multisampled(device, format, width, height, sample_count);
     let overlay_primitives = PrimitiveRenderer::new(device, format, width, height);
     
     // Load all available font families (10 fonts total)
@@ -89,7 +163,7 @@ impl GloomyRenderer {
       DEFAULT_FONT,
     );
     
-    let images = ImageRenderer::new(device, format, width, height);
+    let images = ImageRenderer::new_multisampled(device, format, width, height, sample_count);
 
     let chart_primitives = mpl_wgpu::primitives::PrimitiveRenderer::new(device, format, width, height);
     let chart_text = mpl_wgpu::text::TextRenderer::new(device, format, width, height, DEFAULT_FONT);
@@ -103,10 +177,14 @@ impl GloomyRenderer {
       chart_primitives,
       chart_text,
       textures: HashMap::new(),
+      icons: IconRegistry::new(),
       width,
       height,
       scale_factor,
       clear_color: wgpu::Color { r: 0.1, g: 0.1, b: 0.12, a: 1.0 },
+      format,
+      sample_count,
+      msaa_target: None,
     }
   }
 
@@ -137,18 +215,62 @@ impl GloomyRenderer {
       chart_primitives,
       chart_text,
       textures: HashMap::new(),
+      icons: IconRegistry::new(),
       width,
       height,
       scale_factor,
       clear_color: wgpu::Color { r: 0.1, g: 0.1, b: 0.12, a: 1.0 },
+      format,
+      sample_count: 1,
+      msaa_target: None,
     }
   }
 
+  /// Snaps rect edges and straight lines to device pixels (using the
+  /// scale factor) in both the main and overlay layers. Text is not
+  /// affected. Off by default.
+  pub fn set_pixel_snap(&mut self, enabled: bool) {
+    self.primitives.set_pixel_snap(enabled);
+    self.overlay_primitives.set_pixel_snap(enabled);
+  }
+
+  /// Samples per pixel used for shapes and images; 1 means no MSAA.
+  pub fn sample_count(&self) -> u32 {
+    self.sample_count
+  }
+
+  /// Creates the multisampled target if missing or the wrong size.
+  fn ensure_msaa_target(&mut self, device: &wgpu::Device) {
+    if self.msaa_target.as_ref().is_some_and(|t| t.width == self.width && t.height == self.height) {
+      return;
+    }
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+      label: Some("GloomyMsaaTarget"),
+      size: wgpu::Extent3d { width: self.width.max(1), height: self.height.max(1), depth_or_array_layers: 1 },
+      mip_level_count: 1,
+      sample_count: self.sample_count,
+      dimension: wgpu::TextureDimension::D2,
+      format: self.format,
+      usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+      view_formats: &[],
+    });
+    self.msaa_target = Some(MsaaTarget {
+      view: texture.create_view(&wgpu::TextureViewDescriptor::default()),
+      width: self.width,
+      height: self.height,
+    });
+  }
+
   /// Registers a texture with a given name.
   pub fn register_texture(&mut self, name: String, texture: Texture) {
       self.textures.insert(name, texture);
   }
 
+  /// Icons available to `Icon` widgets by name; see [`crate::icons`].
+  pub fn icons(&mut self) -> &mut IconRegistry {
+      &mut self.icons
+  }
+
   /// Handles viewport resize.
   pub fn resize(&mut self, queue: &wgpu::Queue, width: u32, height: u32, scale_factor: f32) {
     log::info!("GloomyRenderer::resize: {}x{} @ {}", width, height, scale_factor);
@@ -197,9 +319,25 @@ impl GloomyRenderer {
 
   /// Splits the renderer into mutable references components.
 
-  /// Adds a new font to the renderer.
-  pub fn add_font(&mut self, name: &str, font_bytes: &[u8]) {
-    self.text.add_font(name, font_bytes);
+  /// Adds a new font to the renderer, including overlay text.
+  pub fn add_font(&mut self, device: &wgpu::Device, name: &str, font_bytes: &[u8]) -> anyhow::Result<()> {
+    self.text.add_font(device, name, font_bytes)?;
+    self.overlay_text.add_font(device, name, font_bytes)
+  }
+
+  /// Appends a font to the fallback chain of both the main and overlay
+  /// text, so dropdowns, calendars and menus get its glyphs too; see
+  /// [`TextRenderer::register_fallback`].
+  pub fn register_fallback(&mut self, device: &wgpu::Device, name: &str, font_bytes: &[u8]) -> anyhow::Result<()> {
+    self.text.register_fallback(device, name, font_bytes)?;
+    self.overlay_text.register_fallback(device, name, font_bytes)
+  }
+
+  /// Prewarms the glyph atlases of both the main and overlay text, see
+  /// [`TextRenderer::prewarm`].
+  pub fn prewarm_glyphs(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, chars: &str, sizes: &[f32], fonts: &[Option<&str>]) {
+    self.text.prewarm(device, queue, chars, sizes, fonts);
+    self.overlay_text.prewarm(device, queue, chars, sizes, fonts);
   }
 
   /// Draws text at the specified position with an optional font.
@@ -229,9 +367,21 @@ impl GloomyRenderer {
     self.text.draw(device, queue, text, pos, size, color, wgpu_text::glyph_brush::HorizontalAlign::Left, None);
   }
 
-  /// Sets the clear color for the background.
-  pub fn set_clear_color(&mut self, r: f64, g: f64, b: f64, a: f64) {
-    self.clear_color = wgpu::Color { r, g, b, a };
+  /// Sets the color each frame starts from. Defaults to an opaque dark
+  /// gray.
+  ///
+  /// An alpha below 1 only shows what is behind the window if it was
+  /// opened transparent (`GloomyApp::with_transparent`). Compositors
+  /// usually expect premultiplied alpha, so scale rgb by alpha: 50%
+  /// black is `Vec4::new(0.0, 0.0, 0.0, 0.5)`, 50% white
+  /// `Vec4::new(0.5, 0.5, 0.5, 0.5)`.
+  pub fn set_clear_color(&mut self, color: Vec4) {
+    self.clear_color = wgpu::Color {
+      r: color.x as f64,
+      g: color.y as f64,
+      b: color.z as f64,
+      a: color.w as f64,
+    };
   }
 
   /// Prepares all draw commands for submission.
@@ -250,6 +400,72 @@ impl GloomyRenderer {
     view: &wgpu::TextureView,
     device: &wgpu::Device,
     queue: &wgpu::Queue,
+  ) {
+    if self.sample_count > 1 {
+      self.render_multisampled(encoder, view, device);
+    } else {
+      self.render_shapes_and_images(encoder, view);
+    }
+
+    // Text pass (now manages its own passes)
+    self.text.render(encoder, view, device, queue);
+    
+    self.render_overlays(encoder, view, device, queue);
+  }
+
+  /// Shapes and images into a multisampled target, resolved into `view`,
+  /// then charts on top (their pipelines are single-sampled).
+  fn render_multisampled(
+    &mut self,
+    encoder: &mut wgpu::CommandEncoder,
+    view: &wgpu::TextureView,
+    device: &wgpu::Device,
+  ) {
+    self.ensure_msaa_target(device);
+    if let Some(target) = &self.msaa_target {
+      let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("GloomyMsaaPass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+          view: &target.view,
+          resolve_target: Some(view),
+          ops: wgpu::Operations {
+            load: wgpu::LoadOp::Clear(self.clear_color),
+            // Only the resolved image is needed afterwards.
+            store: wgpu::StoreOp::Discard,
+          },
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+      });
+      self.primitives.render(&mut rp);
+      self.images.render(&mut rp);
+    }
+    self.images.clear();
+
+    {
+      let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("GloomyChartPass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+          view,
+          resolve_target: None,
+          ops: wgpu::Operations {
+            load: wgpu::LoadOp::Load,
+            store: wgpu::StoreOp::Store,
+          },
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+      });
+      self.chart_primitives.render(&mut rp);
+    }
+  }
+
+  fn render_shapes_and_images(
+    &mut self,
+    encoder: &mut wgpu::CommandEncoder,
+    view: &wgpu::TextureView,
   ) {
     // Primitives pass
     {
@@ -292,12 +508,16 @@ impl GloomyRenderer {
         self.images.render(&mut rp);
     }
     self.images.clear(); // Clear instance list after render
+  }
 
-    // Text pass (now manages its own passes)
-    // Text pass (now manages its own passes)
-    // Text pass (now manages its own passes)
-    self.text.render(encoder, view, device, queue);
-    
+  /// Chart text, then dropdowns/tooltips on top of everything.
+  fn render_overlays(
+    &mut self,
+    encoder: &mut wgpu::CommandEncoder,
+    view: &wgpu::TextureView,
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+  ) {
     // mpl-wgpu Text Render (manages its own pass? check implementation)
     // mpl-wgpu text renderer render() takes &mut RenderPass in its source?
     // Let's check primitives.rs (step 18). primitives::PrimitiveRenderer::render(&self, &mut RenderPass).
@@ -352,10 +572,11 @@ impl GloomyRenderer {
       &mut TextRenderer,
       &mut ImageRenderer,
       &mut HashMap<String, Texture>,
+      &mut IconRegistry,
       &mut mpl_wgpu::primitives::PrimitiveRenderer,
       &mut mpl_wgpu::text::TextRenderer
   ) {
-      (&mut self.primitives, &mut self.text, &mut self.images, &mut self.textures, &mut self.chart_primitives, &mut self.chart_text)
+      (&mut self.primitives, &mut self.text, &mut self.images, &mut self.textures, &mut self.icons, &mut self.chart_primitives, &mut self.chart_text)
   }
 
   /// Splits renderer to access overlay layers mutably
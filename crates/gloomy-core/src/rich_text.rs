@@ -99,6 +99,41 @@ impl RichText {
     }
 }
 
+/// Spacing of tab stops in a label.
+///
+/// A `\t` advances to the next multiple of the width, measured from the
+/// start of the text, so tab-separated columns line up across labels.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub enum TabWidth {
+    /// Fixed distance in pixels.
+    Px(f32),
+    /// Multiple of the x-height, taken as half the font size.
+    Ex(f32),
+}
+
+impl Default for TabWidth {
+    fn default() -> Self {
+        TabWidth::Ex(8.0)
+    }
+}
+
+impl TabWidth {
+    pub fn to_px(self, font_size: f32) -> f32 {
+        match self {
+            TabWidth::Px(px) => px,
+            TabWidth::Ex(ex) => ex * font_size * 0.5,
+        }
+    }
+}
+
+/// Returns the first tab stop after `x` for stops every `tab` pixels.
+pub fn next_tab_stop(x: f32, tab: f32) -> f32 {
+    if tab <= 0.0 {
+        return x;
+    }
+    ((x / tab).floor() + 1.0) * tab
+}
+
 /// HTML-like markup parser.
 struct MarkupParser {
     input: Vec<char>,
@@ -545,4 +580,14 @@ mod tests {
             (0.0, 0.0, 1.0, 1.0)
         );
     }
+    #[test]
+    fn test_tab_stops() {
+        assert_eq!(TabWidth::Ex(8.0).to_px(20.0), 80.0);
+        assert_eq!(TabWidth::Px(50.0).to_px(20.0), 50.0);
+        assert_eq!(next_tab_stop(0.0, 50.0), 50.0);
+        assert_eq!(next_tab_stop(49.0, 50.0), 50.0);
+        // Text ending exactly on a stop still moves to the next one.
+        assert_eq!(next_tab_stop(50.0, 50.0), 100.0);
+        assert_eq!(next_tab_stop(12.0, 0.0), 12.0);
+    }
 }
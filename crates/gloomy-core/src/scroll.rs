@@ -0,0 +1,191 @@
+//! Mouse wheel routing for nested scrollable widgets.
+//!
+//! Scrollables are Containers whose overflow scrolls (they need an `id`),
+//! ListViews, VirtualFlows and DataGrids. [`scroll_target_at`] finds the
+//! innermost one under the pointer; [`route_scroll`] applies a wheel
+//! delta to it, passing the delta on to the enclosing scrollable when the
+//! inner one is already at its limit in that direction.
+//!
+//! A DataGrid's row count is only known to its data source, so a grid
+//! passes the wheel on at its top edge but never at its bottom;
+//! `sync_datagrids` clamps its offset afterwards.
+
+use crate::interaction::InteractionState;
+use crate::layout::Padding;
+use crate::widget::Widget;
+use glam::Vec2;
+
+/// A scrollable under the pointer and how far it can scroll.
+struct Scrollable {
+    id: String,
+    max: Vec2,
+    /// The pointer in the coordinates of the scrollable's bounds.
+    point: Vec2,
+}
+
+/// Id of the innermost scrollable under `point`, in the same coordinates
+/// as [`crate::ui::hit_test`].
+pub fn scroll_target_at(root: &Widget, point: Vec2, interaction: Option<&InteractionState>) -> Option<String> {
+    let mut chain = Vec::new();
+    collect_scrollables(root, point, interaction, &mut chain);
+    chain.pop().map(|s| s.id)
+}
+
+/// `point` in the coordinates of the bounds of scrollable `id`, which
+/// must be under it.
+pub(crate) fn point_in_scrollable(
+    root: &Widget,
+    id: &str,
+    point: Vec2,
+    interaction: Option<&InteractionState>,
+) -> Option<Vec2> {
+    let mut chain = Vec::new();
+    collect_scrollables(root, point, interaction, &mut chain);
+    chain.into_iter().find(|s| s.id == id).map(|s| s.point)
+}
+
+/// Scrolls the innermost scrollable under `point` that can still move by
+/// `delta` (a wheel delta, as passed to [`InteractionState::handle_scroll`]).
+/// The new offset is clamped to the scrollable's content. Returns the id
+/// of the widget that scrolled, or `None` if every scrollable under the
+/// pointer is at its limit.
+pub fn route_scroll(root: &Widget, point: Vec2, delta: Vec2, state: &mut InteractionState) -> Option<String> {
+    let mut chain = Vec::new();
+    collect_scrollables(root, point, Some(&*state), &mut chain);
+    let target = chain.into_iter().rev().find(|s| {
+        let offset = state.scroll_offsets.get(&s.id).copied().unwrap_or(Vec2::ZERO);
+        can_scroll(offset.x, delta.x, s.max.x) || can_scroll(offset.y, delta.y, s.max.y)
+    })?;
+
+    state.handle_scroll(&target.id, delta);
+    if let Some(offset) = state.scroll_offsets.get_mut(&target.id) {
+        *offset = offset.clamp(Vec2::ZERO, target.max);
+    }
+    Some(target.id)
+}
+
+/// Size of a scrolling container's content: the extent of its children
+/// plus the trailing padding.
+pub(crate) fn content_size(children: &[Widget], padding: &Padding) -> Vec2 {
+    children.iter().fold(Vec2::ZERO, |extent, child| {
+        let b = child.bounds();
+        extent.max(Vec2::new(b.x + b.width, b.y + b.height))
+    }) + Vec2::new(padding.right, padding.bottom)
+}
+
+/// Whether an axis at `offset` moves when scrolled by `delta`. A positive
+/// wheel delta scrolls back towards 0.
+fn can_scroll(offset: f32, delta: f32, max: f32) -> bool {
+    (delta > 0.0 && offset > 0.0) || (delta < 0.0 && offset < max)
+}
+
+/// Pushes the scrollables containing `point` (in the parent's coordinates)
+/// outermost first. Returns whether `widget` contains the point.
+fn collect_scrollables(
+    widget: &Widget,
+    point: Vec2,
+    interaction: Option<&InteractionState>,
+    out: &mut Vec<Scrollable>,
+) -> bool {
+    let bounds = widget.bounds();
+    let inside = point.x >= bounds.x && point.x <= bounds.x + bounds.width
+        && point.y >= bounds.y && point.y <= bounds.y + bounds.height;
+    let scroll_of = |id: &str| {
+        interaction
+            .and_then(|state| state.scroll_offsets.get(id))
+            .copied()
+            .unwrap_or(Vec2::ZERO)
+    };
+
+    match widget {
+        Widget::Container { id, scrollable, overflow, padding, children, .. } => {
+            let scrolls = overflow.resolve(*scrollable).scrolls();
+            if scrolls && !inside {
+                return false;
+            }
+            let mut local = point - Vec2::new(bounds.x, bounds.y);
+            if scrolls {
+                if let Some(id) = id {
+                    let content = content_size(children, padding);
+                    let max = (content - Vec2::new(bounds.width, bounds.height)).max(Vec2::ZERO);
+                    out.push(Scrollable { id: id.clone(), max, point });
+                    local += scroll_of(id);
+                }
+            }
+            let hit_child = crate::widget::z_ordered(children)
+                .rev()
+                .any(|child| collect_scrollables(child, local, interaction, out));
+            inside || hit_child
+        }
+        Widget::Tab { tabs, selected, .. } if inside => {
+            if let Some(tab) = tabs.get(*selected) {
+                collect_scrollables(&tab.content, point, interaction, out);
+            }
+            true
+        }
+        Widget::ListView { id, items, item_widgets, item_heights, style, .. } if inside => {
+            let count = crate::list_view::row_count(items, item_widgets);
+            let content = crate::list_view::RowOffsets::new(count, style.item_height, item_heights).total();
+            out.push(Scrollable { id: id.clone(), max: Vec2::new(0.0, (content - bounds.height).max(0.0)), point });
+            true
+        }
+        Widget::VirtualFlow { id, item_count, item_size, spacing, .. } if inside => {
+            let geometry = crate::virtual_flow::FlowGeometry::new(bounds.width, Vec2::from(*item_size), *spacing);
+            let content = geometry.content_height(*item_count);
+            out.push(Scrollable { id: id.clone(), max: Vec2::new(0.0, (content - bounds.height).max(0.0)), point });
+            true
+        }
+        Widget::DataGrid { id: Some(id), .. } if inside => {
+            out.push(Scrollable { id: id.clone(), max: Vec2::new(0.0, f32::INFINITY), point });
+            true
+        }
+        _ => inside,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::parse_ui;
+
+    const NESTED: &str = r#"
+Container(
+    id: Some("outer"),
+    scrollable: true,
+    bounds: (x: 0.0, y: 0.0, width: 300.0, height: 200.0),
+    children: [
+        Container(
+            id: Some("inner"),
+            scrollable: true,
+            bounds: (x: 0.0, y: 0.0, width: 300.0, height: 100.0),
+            children: [
+                Container(bounds: (x: 0.0, y: 0.0, width: 300.0, height: 150.0)),
+            ],
+        ),
+        Container(bounds: (x: 0.0, y: 100.0, width: 300.0, height: 300.0)),
+    ],
+)
+"#;
+
+    #[test]
+    fn test_wheel_targets_inner_scroll_then_bubbles_at_limit() {
+        let root = parse_ui(NESTED).unwrap();
+        let mut state = InteractionState::default();
+        let over_inner = Vec2::new(150.0, 50.0);
+
+        assert_eq!(scroll_target_at(&root, over_inner, Some(&state)).as_deref(), Some("inner"));
+        assert_eq!(scroll_target_at(&root, Vec2::new(150.0, 150.0), Some(&state)).as_deref(), Some("outer"));
+
+        // The inner container scrolls until its 50px of overflow run out...
+        assert_eq!(route_scroll(&root, over_inner, Vec2::new(0.0, -40.0), &mut state).as_deref(), Some("inner"));
+        assert_eq!(route_scroll(&root, over_inner, Vec2::new(0.0, -40.0), &mut state).as_deref(), Some("inner"));
+        assert_eq!(state.scroll_offsets["inner"].y, 50.0);
+
+        // ...then the wheel moves the outer one.
+        assert_eq!(route_scroll(&root, over_inner, Vec2::new(0.0, -40.0), &mut state).as_deref(), Some("outer"));
+        assert_eq!(state.scroll_offsets["outer"].y, 40.0);
+
+        // Scrolling back up goes to the inner container first again.
+        assert_eq!(route_scroll(&root, over_inner, Vec2::new(0.0, 40.0), &mut state).as_deref(), Some("inner"));
+    }
+}
@@ -0,0 +1,394 @@
+//! Vertical scrollbars of scrolling Containers, ListViews and DataGrids.
+//!
+//! Each of these widgets has a `scrollbar: ScrollbarPolicy` deciding when
+//! the bar is drawn and a `scrollbar_mode: ScrollbarMode` deciding whether
+//! it floats over the content or sits in a gutter the content is laid out
+//! around. A gutter is reserved whenever the policy can show a bar, even
+//! while the content fits, so content doesn't reflow when it starts to
+//! overflow.
+//!
+//! [`ScrollbarGeometry`] places the track and thumb; render draws it and
+//! hit testing uses the same rects, reporting `{id}:scrollthumb` and
+//! `{id}:scrolltrack`. Only widgets with an `id` have hit-testable bars.
+//!
+//! Dragging the thumb is a session on
+//! [`InteractionState::scrollbar_drag`]: [`begin_scrollbar_drag`] on mouse
+//! press starts it (or pages when the press is on the track),
+//! [`drag_scrollbar`] on mouse move maps the thumb's travel back to a
+//! content offset, and [`end_scrollbar_drag`] on release ends it.
+
+use crate::interaction::InteractionState;
+use crate::rect::Rect;
+use crate::ui::{hit_test, RenderContext};
+use crate::widget::Widget;
+use glam::{Vec2, Vec4};
+use serde::{Deserialize, Serialize};
+
+/// Width of the scrollbar track.
+pub const SCROLLBAR_WIDTH: f32 = 10.0;
+
+/// Smallest thumb height, so very long content keeps a grabbable thumb.
+const MIN_THUMB: f32 = 20.0;
+
+const TRACK_COLOR: Vec4 = Vec4::new(0.0, 0.0, 0.0, 0.2);
+const THUMB_COLOR: Vec4 = Vec4::new(0.5, 0.5, 0.5, 0.8);
+
+/// When a scrollbar is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Default)]
+pub enum ScrollbarPolicy {
+    /// Only while the content overflows.
+    #[default]
+    Auto,
+    /// Always; the thumb fills the track when nothing overflows.
+    AlwaysOn,
+    /// Never. The widget still scrolls with the wheel and keyboard.
+    Hidden,
+}
+
+impl ScrollbarPolicy {
+    /// Whether the bar is drawn for `content` scrolled in `viewport`.
+    pub fn shows(self, content: f32, viewport: f32) -> bool {
+        match self {
+            ScrollbarPolicy::Auto => content > viewport,
+            ScrollbarPolicy::AlwaysOn => true,
+            ScrollbarPolicy::Hidden => false,
+        }
+    }
+}
+
+/// Where a scrollbar sits relative to the content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Default)]
+pub enum ScrollbarMode {
+    /// Drawn over the right edge of the content.
+    #[default]
+    Overlay,
+    /// Content is laid out [`SCROLLBAR_WIDTH`] narrower so the bar never
+    /// covers it.
+    Gutter,
+}
+
+/// Width taken from the right of the content for the scrollbar.
+pub fn gutter_width(policy: ScrollbarPolicy, mode: ScrollbarMode) -> f32 {
+    if mode == ScrollbarMode::Gutter && policy != ScrollbarPolicy::Hidden {
+        SCROLLBAR_WIDTH
+    } else {
+        0.0
+    }
+}
+
+/// Track and thumb of a vertical scrollbar, in the coordinates of the
+/// viewport it was placed in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollbarGeometry {
+    pub track: Rect,
+    pub thumb: Rect,
+    /// Largest scroll offset of the content.
+    pub max_scroll: f32,
+}
+
+impl ScrollbarGeometry {
+    /// Scrollbar along the right edge of `viewport` for `content` pixels
+    /// scrolled by `offset`, or `None` if `policy` hides it.
+    pub fn vertical(policy: ScrollbarPolicy, viewport: Rect, content: f32, offset: f32) -> Option<Self> {
+        if !policy.shows(content, viewport.height) || viewport.height <= 0.0 {
+            return None;
+        }
+        let track = track_rect(viewport);
+        let max_scroll = (content - viewport.height).max(0.0);
+        let thumb_h = if content > 0.0 {
+            (viewport.height / content * track.height).clamp(MIN_THUMB.min(track.height), track.height)
+        } else {
+            track.height
+        };
+        let ratio = if max_scroll > 0.0 { (offset / max_scroll).clamp(0.0, 1.0) } else { 0.0 };
+        let thumb = Rect::new(track.x, track.y + ratio * (track.height - thumb_h), track.width, thumb_h);
+        Some(Self { track, thumb, max_scroll })
+    }
+
+    /// Content offset covered by moving the thumb one pixel.
+    pub fn offset_per_pixel(&self) -> f32 {
+        let travel = self.track.height - self.thumb.height;
+        if travel > 0.0 { self.max_scroll / travel } else { 0.0 }
+    }
+
+    fn translated(mut self, by: Vec2) -> Self {
+        self.track.x += by.x;
+        self.track.y += by.y;
+        self.thumb.x += by.x;
+        self.thumb.y += by.y;
+        self
+    }
+
+    fn draw(&self, ctx: &mut RenderContext) {
+        let track_half = Vec2::new(self.track.width, self.track.height) * 0.5;
+        ctx.primitives.draw_rect(
+            Vec2::new(self.track.x, self.track.y) + track_half,
+            track_half,
+            TRACK_COLOR,
+            [4.0; 4],
+            0.0,
+        );
+        let thumb_half = Vec2::new(self.thumb.width * 0.5 - 2.0, self.thumb.height * 0.5);
+        ctx.primitives.draw_rect(
+            Vec2::new(self.thumb.x + self.thumb.width * 0.5, self.thumb.y + thumb_half.y),
+            thumb_half,
+            THUMB_COLOR,
+            [3.0; 4],
+            0.0,
+        );
+    }
+}
+
+/// The strip along the right edge of `viewport` a scrollbar track covers.
+fn track_rect(viewport: Rect) -> Rect {
+    Rect::new(viewport.x + viewport.width - SCROLLBAR_WIDTH, viewport.y, SCROLLBAR_WIDTH, viewport.height)
+}
+
+/// The rect a widget's scrollbar runs along: its bounds, or a DataGrid's
+/// body below the header. `None` for widgets that don't scroll.
+fn bar_viewport(widget: &Widget) -> Option<Rect> {
+    let b = widget.bounds();
+    match widget {
+        Widget::Container { scrollable, overflow, .. } if overflow.resolve(*scrollable).scrolls() => {
+            Some(Rect::new(b.x, b.y, b.width, b.height))
+        }
+        Widget::ListView { .. } => Some(Rect::new(b.x, b.y, b.width, b.height)),
+        Widget::DataGrid { header_height, .. } => {
+            Some(Rect::new(b.x, b.y + header_height, b.width, (b.height - header_height).max(0.0)))
+        }
+        _ => None,
+    }
+}
+
+fn scroll_offset(id: Option<&str>, interaction: Option<&InteractionState>) -> f32 {
+    id.and_then(|id| interaction?.scroll_offsets.get(id)).map_or(0.0, |offset| offset.y)
+}
+
+/// Scrollbar of a scrolling Container, ListView or DataGrid, in the
+/// coordinates of the widget's bounds.
+pub fn widget_scrollbar(widget: &Widget, interaction: Option<&InteractionState>) -> Option<ScrollbarGeometry> {
+    let viewport = bar_viewport(widget)?;
+    match widget {
+        Widget::Container { id, padding, children, scrollbar, .. } => {
+            let content = crate::scroll::content_size(children, padding).y;
+            ScrollbarGeometry::vertical(*scrollbar, viewport, content, scroll_offset(id.as_deref(), interaction))
+        }
+        Widget::ListView { id, items, item_widgets, item_heights, style, scrollbar, .. } => {
+            let count = crate::list_view::row_count(items, item_widgets);
+            let content = crate::list_view::RowOffsets::new(count, style.item_height, item_heights).total();
+            ScrollbarGeometry::vertical(*scrollbar, viewport, content, scroll_offset(Some(id), interaction))
+        }
+        Widget::DataGrid { id, scrollbar, content_height, .. } => {
+            ScrollbarGeometry::vertical(*scrollbar, viewport, content_height.get(), scroll_offset(id.as_deref(), interaction))
+        }
+        _ => None,
+    }
+}
+
+/// Draws `widget`'s scrollbar, if it has one, at the context's offset.
+pub(crate) fn draw_scrollbar(widget: &Widget, ctx: &mut RenderContext) {
+    if let Some(bar) = widget_scrollbar(widget, ctx.interaction) {
+        bar.translated(ctx.offset).draw(ctx);
+    }
+}
+
+/// `{id}:scrollthumb` or `{id}:scrolltrack` when `point` (in the
+/// coordinates of the widget's bounds) is on `widget`'s scrollbar.
+///
+/// Points off the track strip return before the content is measured.
+/// ListViews are left to [`list_hit_action`], which reuses the row offsets
+/// of their own hit test.
+pub(crate) fn hit_action(widget: &Widget, point: Vec2, interaction: Option<&InteractionState>) -> Option<String> {
+    let id = match widget {
+        Widget::Container { id: Some(id), .. } | Widget::DataGrid { id: Some(id), .. } => id,
+        _ => return None,
+    };
+    if !track_rect(bar_viewport(widget)?).contains(point) {
+        return None;
+    }
+    bar_action(id, widget_scrollbar(widget, interaction)?, point)
+}
+
+/// [`hit_action`] for a ListView whose rows are already laid out.
+pub(crate) fn list_hit_action(
+    widget: &Widget,
+    rows: &crate::list_view::RowOffsets,
+    point: Vec2,
+    interaction: Option<&InteractionState>,
+) -> Option<String> {
+    let Widget::ListView { id, scrollbar, .. } = widget else {
+        return None;
+    };
+    let viewport = bar_viewport(widget)?;
+    if !track_rect(viewport).contains(point) {
+        return None;
+    }
+    let bar = ScrollbarGeometry::vertical(*scrollbar, viewport, rows.total(), scroll_offset(Some(id), interaction))?;
+    bar_action(id, bar, point)
+}
+
+fn bar_action(id: &str, bar: ScrollbarGeometry, point: Vec2) -> Option<String> {
+    if bar.thumb.contains(point) {
+        Some(format!("{}:scrollthumb", id))
+    } else if bar.track.contains(point) {
+        Some(format!("{}:scrolltrack", id))
+    } else {
+        None
+    }
+}
+
+/// A scrollbar thumb being dragged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrollbarDrag {
+    /// The scrolled widget's `id`.
+    pub id: String,
+    start_y: f32,
+    start_offset: f32,
+    offset_per_pixel: f32,
+    max_scroll: f32,
+}
+
+/// Starts dragging the thumb under `point`, or scrolls one page towards
+/// `point` when it is on the track. Call on mouse press.
+///
+/// Returns true if the press was on a scrollbar.
+pub fn begin_scrollbar_drag(root: &Widget, state: &mut InteractionState, point: Vec2) -> bool {
+    let Some(hit) = hit_test(root, point, Some(&*state)) else {
+        return false;
+    };
+    let (id, on_thumb) = if let Some(id) = hit.action.strip_suffix(":scrollthumb") {
+        (id, true)
+    } else if let Some(id) = hit.action.strip_suffix(":scrolltrack") {
+        (id, false)
+    } else {
+        return false;
+    };
+    let Some(bar) = widget_scrollbar(hit.widget, Some(&*state)) else {
+        return false;
+    };
+    let offset = state.scroll_offsets.get(id).map_or(0.0, |offset| offset.y);
+
+    if on_thumb {
+        state.scrollbar_drag = Some(ScrollbarDrag {
+            id: id.to_string(),
+            start_y: point.y,
+            start_offset: offset,
+            offset_per_pixel: bar.offset_per_pixel(),
+            max_scroll: bar.max_scroll,
+        });
+    } else if let Some(local) = crate::scroll::point_in_scrollable(root, id, point, Some(&*state)) {
+        // A page is the visible height, which the track spans.
+        let page = if local.y < bar.thumb.y { -bar.track.height } else { bar.track.height };
+        set_offset(state, id, (offset + page).clamp(0.0, bar.max_scroll));
+    }
+    true
+}
+
+/// Moves the dragged thumb to follow `point`. Call on mouse move.
+///
+/// Returns true if a drag is in progress.
+pub fn drag_scrollbar(state: &mut InteractionState, point: Vec2) -> bool {
+    let Some(drag) = state.scrollbar_drag.clone() else {
+        return false;
+    };
+    let offset = drag.start_offset + (point.y - drag.start_y) * drag.offset_per_pixel;
+    set_offset(state, &drag.id, offset.clamp(0.0, drag.max_scroll));
+    true
+}
+
+/// Ends a thumb drag. Call on mouse release.
+///
+/// Returns true if a drag was in progress.
+pub fn end_scrollbar_drag(state: &mut InteractionState) -> bool {
+    state.scrollbar_drag.take().is_some()
+}
+
+fn set_offset(state: &mut InteractionState, id: &str, y: f32) {
+    state.scroll_offsets.entry(id.to_string()).or_insert(Vec2::ZERO).y = y;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_and_thumb_geometry() {
+        let viewport = Rect::new(0.0, 50.0, 200.0, 100.0);
+
+        // Auto only shows while overflowing; Hidden never.
+        assert!(ScrollbarGeometry::vertical(ScrollbarPolicy::Auto, viewport, 80.0, 0.0).is_none());
+        assert!(ScrollbarGeometry::vertical(ScrollbarPolicy::Hidden, viewport, 400.0, 0.0).is_none());
+
+        // AlwaysOn without overflow: the thumb fills the track.
+        let bar = ScrollbarGeometry::vertical(ScrollbarPolicy::AlwaysOn, viewport, 80.0, 0.0).unwrap();
+        assert_eq!(bar.thumb, bar.track);
+        assert_eq!(bar.track, Rect::new(190.0, 50.0, SCROLLBAR_WIDTH, 100.0));
+
+        // Scrolled to the end, the thumb sits at the bottom of the track.
+        let bar = ScrollbarGeometry::vertical(ScrollbarPolicy::Auto, viewport, 400.0, 300.0).unwrap();
+        assert_eq!(bar.max_scroll, 300.0);
+        assert_eq!(bar.thumb.height, 25.0);
+        assert_eq!(bar.thumb.y + bar.thumb.height, 150.0);
+
+        assert_eq!(gutter_width(ScrollbarPolicy::Auto, ScrollbarMode::Gutter), SCROLLBAR_WIDTH);
+        assert_eq!(gutter_width(ScrollbarPolicy::Hidden, ScrollbarMode::Gutter), 0.0);
+        assert_eq!(gutter_width(ScrollbarPolicy::AlwaysOn, ScrollbarMode::Overlay), 0.0);
+    }
+
+    #[test]
+    fn test_thumb_drag_and_track_paging() {
+        // 400px of rows in a 100px list at (50, 50): a 25px thumb with 75px
+        // of travel, so each pixel of drag scrolls 4px.
+        let root = crate::ui::parse_ui(r#"
+            Container(
+                bounds: (x: 0.0, y: 0.0, width: 300.0, height: 300.0),
+                children: [
+                    ListView(
+                        id: "list",
+                        items: ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j"],
+                        bounds: (x: 50.0, y: 50.0, width: 200.0, height: 100.0),
+                    ),
+                ],
+            )
+        "#).unwrap();
+        let mut state = InteractionState::default();
+        let action_at = |state: &InteractionState, x: f32, y: f32| {
+            hit_test(&root, Vec2::new(x, y), Some(state)).unwrap().action
+        };
+        assert_eq!(action_at(&state, 245.0, 60.0), "list:scrollthumb");
+        assert_eq!(action_at(&state, 245.0, 130.0), "list:scrolltrack");
+        assert_eq!(action_at(&state, 100.0, 60.0), "list:0");
+
+        assert!(begin_scrollbar_drag(&root, &mut state, Vec2::new(245.0, 60.0)));
+        assert!(drag_scrollbar(&mut state, Vec2::new(245.0, 80.0)));
+        assert_eq!(state.scroll_offsets["list"].y, 80.0);
+        assert!(drag_scrollbar(&mut state, Vec2::new(245.0, 500.0)));
+        assert_eq!(state.scroll_offsets["list"].y, 300.0);
+        assert!(end_scrollbar_drag(&mut state));
+        assert!(!drag_scrollbar(&mut state, Vec2::new(245.0, 60.0)));
+
+        // The thumb now sits at the bottom; a press above it pages up.
+        assert!(begin_scrollbar_drag(&root, &mut state, Vec2::new(245.0, 60.0)));
+        assert_eq!(state.scroll_offsets["list"].y, 200.0);
+        assert!(state.scrollbar_drag.is_none());
+
+        assert!(!begin_scrollbar_drag(&root, &mut state, Vec2::new(100.0, 60.0)));
+    }
+
+    #[test]
+    fn test_hit_action_only_on_track_strip() {
+        let root = crate::ui::parse_ui(r#"
+            Container(
+                id: Some("scroller"),
+                scrollable: true,
+                bounds: (x: 0.0, y: 0.0, width: 100.0, height: 100.0),
+                children: [
+                    Container(bounds: (x: 0.0, y: 0.0, width: 50.0, height: 400.0)),
+                ],
+            )
+        "#).unwrap();
+        assert_eq!(hit_action(&root, Vec2::new(95.0, 5.0), None).as_deref(), Some("scroller:scrollthumb"));
+        assert_eq!(hit_action(&root, Vec2::new(95.0, 90.0), None).as_deref(), Some("scroller:scrolltrack"));
+        assert_eq!(hit_action(&root, Vec2::new(50.0, 5.0), None), None);
+    }
+}
@@ -0,0 +1,158 @@
+//! Read-only text selection in labels.
+//!
+//! A `Label` with an `id` and `selectable: true` records where each
+//! character boundary was drawn (`char_stops`, absolute x) during render.
+//! Pressing on it starts a selection, dragging moves its end, and
+//! [`copy_label_selection`] puts the selected text on the clipboard. Only
+//! one label holds a selection at a time; it lives in
+//! [`InteractionState::label_selection`].
+
+use crate::clipboard::ClipboardProvider;
+use crate::interaction::{InteractionState, LabelSelection};
+use crate::ui::hit_test;
+use crate::widget::Widget;
+use glam::Vec2;
+
+/// Index of the character boundary nearest to `x`.
+///
+/// `stops` holds the x of every boundary, one more than the char count.
+pub fn char_index_at(stops: &[f32], x: f32) -> usize {
+    stops
+        .windows(2)
+        .position(|pair| x < (pair[0] + pair[1]) * 0.5)
+        .unwrap_or(stops.len().saturating_sub(1))
+}
+
+/// Starts a selection if `point` is on a selectable label, otherwise
+/// clears any existing one. Call on mouse press.
+///
+/// Returns true if the selection changed.
+pub fn begin_label_selection(root: &Widget, state: &mut InteractionState, point: Vec2) -> bool {
+    let hit = hit_test(root, point, Some(state)).map(|hit| hit.widget);
+    match hit {
+        Some(Widget::Label { id: Some(id), selectable: true, char_stops, .. }) => {
+            let index = char_index_at(&char_stops.borrow(), point.x);
+            state.label_selection = Some(LabelSelection { id: id.clone(), anchor: index, focus: index });
+            true
+        }
+        _ => state.label_selection.take().is_some(),
+    }
+}
+
+/// Moves the end of the current selection to `point`. Call on mouse move
+/// while the button is held; the pointer may leave the label.
+///
+/// Returns true if the selection changed.
+pub fn drag_label_selection(root: &Widget, state: &mut InteractionState, point: Vec2) -> bool {
+    let Some(selection) = state.label_selection.as_mut() else {
+        return false;
+    };
+    let Some(Widget::Label { char_stops, .. }) = find_label(root, &selection.id) else {
+        return false;
+    };
+    let focus = char_index_at(&char_stops.borrow(), point.x);
+    if focus == selection.focus {
+        return false;
+    }
+    selection.focus = focus;
+    true
+}
+
+/// The currently selected label text, if any is selected.
+pub fn selected_label_text(root: &Widget, state: &InteractionState) -> Option<String> {
+    let selection = state.label_selection.as_ref().filter(|s| !s.is_empty())?;
+    let Some(Widget::Label { text, .. }) = find_label(root, &selection.id) else {
+        return None;
+    };
+    let range = selection.range();
+    Some(text.chars().skip(range.start).take(range.len()).collect())
+}
+
+/// Copies the selected label text to `clipboard`. Call on Ctrl+C.
+///
+/// Returns false if nothing is selected.
+pub fn copy_label_selection(
+    root: &Widget,
+    state: &InteractionState,
+    clipboard: &mut dyn ClipboardProvider,
+) -> bool {
+    match selected_label_text(root, state) {
+        Some(text) => {
+            clipboard.set_text(&text);
+            true
+        }
+        None => false,
+    }
+}
+
+fn find_label<'a>(root: &'a Widget, label_id: &str) -> Option<&'a Widget> {
+    match root {
+        Widget::Label { id: Some(id), .. } if id == label_id => Some(root),
+        Widget::Container { children, .. } => children.iter().find_map(|child| find_label(child, label_id)),
+        Widget::Tab { tabs, selected, .. } => tabs
+            .get(*selected)
+            .and_then(|tab| find_label(&tab.content, label_id)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard::MemoryClipboard;
+    use crate::ui::parse_ui;
+
+    /// A selectable label as the last render left it: "Hello" drawn from
+    /// x = 10 with 10px per character.
+    fn rendered_label() -> Widget {
+        let root = parse_ui(r#"
+            Label(
+                id: Some("err"),
+                text: "Hello",
+                selectable: true,
+                x: 10.0, y: 0.0, width: 100.0, height: 20.0,
+            )
+        "#).unwrap();
+        if let Widget::Label { char_stops, .. } = &root {
+            *char_stops.borrow_mut() = (0..=5).map(|i| 10.0 + i as f32 * 10.0).collect();
+        }
+        root
+    }
+
+    #[test]
+    fn test_char_index_rounds_to_nearest_boundary() {
+        let stops = [0.0, 10.0, 20.0];
+        assert_eq!(char_index_at(&stops, -5.0), 0);
+        assert_eq!(char_index_at(&stops, 4.0), 0);
+        assert_eq!(char_index_at(&stops, 6.0), 1);
+        assert_eq!(char_index_at(&stops, 50.0), 2);
+        assert_eq!(char_index_at(&[], 5.0), 0);
+    }
+
+    #[test]
+    fn test_drag_selects_and_copies() {
+        let root = rendered_label();
+        let mut state = InteractionState::default();
+
+        assert!(begin_label_selection(&root, &mut state, Vec2::new(21.0, 10.0)));
+        // Dragging past the end clamps to the last boundary.
+        assert!(drag_label_selection(&root, &mut state, Vec2::new(200.0, 10.0)));
+        assert_eq!(selected_label_text(&root, &state).as_deref(), Some("ello"));
+
+        let mut clipboard = MemoryClipboard::default();
+        assert!(copy_label_selection(&root, &state, &mut clipboard));
+        assert_eq!(clipboard.contents.as_deref(), Some("ello"));
+
+        // Pressing elsewhere clears it.
+        assert!(begin_label_selection(&root, &mut state, Vec2::new(300.0, 10.0)));
+        assert!(state.label_selection.is_none());
+    }
+
+    #[test]
+    fn test_plain_labels_are_not_selectable() {
+        let root = parse_ui(r#"Label(text: "Hello", x: 0.0, y: 0.0, width: 100.0, height: 20.0)"#).unwrap();
+        let mut state = InteractionState::default();
+        assert!(!begin_label_selection(&root, &mut state, Vec2::new(10.0, 10.0)));
+        assert!(hit_test(&root, Vec2::new(10.0, 10.0), None).is_none());
+    }
+}
@@ -6,12 +6,18 @@ struct VertexInput {
     @location(0) pos: vec2<f32>,
     @location(1) size: vec2<f32>, // Full size
     @location(2) color: vec4<f32>, // Tint
+    @location(3) clip_rect: vec4<f32>, // Rounded clip: center.xy, half-extents.zw
+    @location(4) clip_radii: vec4<f32>,
+    @location(5) uv_scale: vec2<f32>, // Texture repeats across the quad
 };
 
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) uv: vec2<f32>,
     @location(1) color: vec4<f32>,
+    @location(2) screen_pos: vec2<f32>,
+    @location(3) clip_rect: vec4<f32>,
+    @location(4) clip_radii: vec4<f32>,
 };
 
 struct GlobalUniforms {
@@ -49,8 +55,11 @@ fn vs_main(in: VertexInput) -> VertexOutput {
     
     let ndc = (screen_pos / globals.screen_size) * 2.0 - 1.0;
     out.position = vec4<f32>(ndc.x, -ndc.y, 0.0, 1.0);
-    out.uv = vec2<f32>(u, v);
+    out.uv = vec2<f32>(u, v) * in.uv_scale;
     out.color = in.color;
+    out.screen_pos = screen_pos;
+    out.clip_rect = in.clip_rect;
+    out.clip_radii = in.clip_radii;
     
     return out;
 }
@@ -58,8 +67,31 @@ fn vs_main(in: VertexInput) -> VertexOutput {
 @group(1) @binding(0) var t_diffuse: texture_2d<f32>;
 @group(1) @binding(1) var s_diffuse: sampler;
 
+// Same rounded-box SDF as primitives.wgsl. Radii: TR, BR, TL, BL.
+fn sd_rounded_box_varying(p: vec2<f32>, b: vec2<f32>, r: vec4<f32>) -> f32 {
+    var rx: f32;
+    if (p.x > 0.0) {
+        if (p.y > 0.0) { rx = r.y; } else { rx = r.x; }
+    } else {
+        if (p.y > 0.0) { rx = r.w; } else { rx = r.z; }
+    }
+    let q = abs(p) - b + rx;
+    return min(max(q.x, q.y), 0.0) + length(max(q, vec2<f32>(0.0))) - rx;
+}
+
+// Coverage of the rounded clip mask at `p`; 1.0 when no clip is set.
+fn clip_coverage(p: vec2<f32>, rect: vec4<f32>, radii: vec4<f32>) -> f32 {
+    if (rect.z <= 0.0 || rect.w <= 0.0) {
+        return 1.0;
+    }
+    let d = sd_rounded_box_varying(p - rect.xy, rect.zw, radii);
+    return 1.0 - smoothstep(-0.5, 0.5, d);
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let tex = textureSample(t_diffuse, s_diffuse, in.uv);
-    return tex * in.color;
+    var color = tex * in.color;
+    color.a = color.a * clip_coverage(in.screen_pos, in.clip_rect, in.clip_radii);
+    return color;
 }
@@ -1,5 +1,5 @@
 // Gloomy SDF Primitives Shader
-// Renders rectangles, circles, and lines using signed distance fields.
+// Renders rectangles, circles, lines and triangles using signed distance fields.
 
 struct VertexInput {
     @builtin(vertex_index) vertex_index: u32,
@@ -14,6 +14,9 @@ struct VertexInput {
     @location(6) stroke_width: f32,
     @location(7) softness: f32,
     @location(8) _pad: u32,
+    // Rounded clip: center.xy, half-extents.zw (zero = disabled)
+    @location(9) clip_rect: vec4<f32>,
+    @location(10) clip_radii: vec4<f32>,
 };
 
 struct VertexOutput {
@@ -25,6 +28,11 @@ struct VertexOutput {
     // Params: type, stroke_width, softness, unused
     @location(4) params: vec4<f32>,
     @location(5) radii: vec4<f32>,
+    @location(6) screen_pos: vec2<f32>,
+    @location(7) clip_rect: vec4<f32>,
+    @location(8) clip_radii: vec4<f32>,
+    // Triangle: third corner (the first two are in radii)
+    @location(9) corner: vec2<f32>,
 };
 
 struct GlobalUniforms {
@@ -57,12 +65,16 @@ fn vs_main(in: VertexInput) -> VertexOutput {
     let stroke = in.stroke_width;
     let soft = in.softness;
     
-    if (in.prim_type == 1u) {
-        // Circle
+    if (in.prim_type == 1u || in.prim_type == 4u || in.prim_type == 5u) {
+        // Circle / Arc / Star: radii.x is the radius, the stroke may extend past it
         let r = in.radii.x;
         // Padding for AA, stroke and softness
         let padding = stroke + soft + 2.0; 
         size = vec2<f32>((r + padding) * 2.0, (r + padding) * 2.0);
+    } else if (in.prim_type == 6u) {
+        // Triangle: corners relative to center in radii.xy, radii.zw, pos_b
+        let extent = max(max(abs(in.radii.xy), abs(in.radii.zw)), abs(in.pos_b));
+        size = (extent + vec2<f32>(stroke + 2.0)) * 2.0;
     } else if (in.prim_type == 2u) {
         // Line logic (unchanged mostly, ignore soft for now)
         let delta = in.pos_b - in.pos_a;
@@ -89,6 +101,9 @@ fn vs_main(in: VertexInput) -> VertexOutput {
         out.color_end = in.color_end;
         out.params = vec4<f32>(f32(in.prim_type), in.stroke_width, in.softness, 0.0);
         out.radii = in.radii;
+        out.screen_pos = screen_pos;
+        out.clip_rect = in.clip_rect;
+        out.clip_radii = in.clip_radii;
         return out;
     } else {
        // Rect: Expand size to account for softness blur
@@ -114,6 +129,9 @@ fn vs_main(in: VertexInput) -> VertexOutput {
        out.color_end = in.color_end;
        out.params = vec4<f32>(f32(in.prim_type), in.stroke_width, in.softness, 0.0);
        out.radii = in.radii;
+       out.screen_pos = screen_pos;
+       out.clip_rect = in.clip_rect;
+       out.clip_radii = in.clip_radii;
        return out;
     }
     
@@ -132,6 +150,10 @@ fn vs_main(in: VertexInput) -> VertexOutput {
     out.color_end = in.color_end;
     out.params = vec4<f32>(f32(in.prim_type), in.stroke_width, in.softness, 0.0);
     out.radii = in.radii;
+    out.screen_pos = screen_pos;
+    out.clip_rect = in.clip_rect;
+    out.clip_radii = in.clip_radii;
+    out.corner = in.pos_b;
     
     return out;
 }
@@ -150,10 +172,71 @@ fn sd_rounded_box_varying(p: vec2<f32>, b: vec2<f32>, r: vec4<f32>) -> f32 {
     return min(max(q.x, q.y), 0.0) + length(max(q, vec2<f32>(0.0))) - rx;
 }
 
+// Coverage of the rounded clip mask at `p`; 1.0 when no clip is set.
+fn clip_coverage(p: vec2<f32>, rect: vec4<f32>, radii: vec4<f32>) -> f32 {
+    if (rect.z <= 0.0 || rect.w <= 0.0) {
+        return 1.0;
+    }
+    let d = sd_rounded_box_varying(p - rect.xy, rect.zw, radii);
+    return 1.0 - smoothstep(-0.5, 0.5, d);
+}
+
 fn sd_circle(p: vec2<f32>, r: f32) -> f32 {
     return length(p) - r;
 }
 
+// Arc of centerline radius `r` and half-thickness `h` with round caps.
+// Angles run clockwise from 12 o'clock (screen y points down).
+fn sd_arc(p: vec2<f32>, r: f32, start: f32, sweep: f32, h: f32) -> f32 {
+    let tau = 6.2831853;
+    if (sweep >= tau) {
+        return abs(length(p) - r) - h;
+    }
+    var a = atan2(p.x, -p.y);
+    if (a < 0.0) { a = a + tau; }
+    var rel = a - start;
+    if (rel < 0.0) { rel = rel + tau; }
+    if (rel <= sweep) {
+        return abs(length(p) - r) - h;
+    }
+    let e0 = r * vec2<f32>(sin(start), -cos(start));
+    let stop = start + sweep;
+    let e1 = r * vec2<f32>(sin(stop), -cos(stop));
+    return min(length(p - e0), length(p - e1)) - h;
+}
+
+// Five-pointed star with outer radius `r`, inner radius `r * rf`, one point
+// facing up (screen y points down).
+fn sd_star5(p_in: vec2<f32>, r: f32, rf: f32) -> f32 {
+    let k1 = vec2<f32>(0.809016994375, -0.587785252292);
+    let k2 = vec2<f32>(-k1.x, k1.y);
+    var p = vec2<f32>(abs(p_in.x), -p_in.y);
+    p = p - 2.0 * max(dot(k1, p), 0.0) * k1;
+    p = p - 2.0 * max(dot(k2, p), 0.0) * k2;
+    p.x = abs(p.x);
+    p.y = p.y - r;
+    let ba = rf * vec2<f32>(-k1.y, k1.x) - vec2<f32>(0.0, 1.0);
+    let h = clamp(dot(p, ba) / dot(ba, ba), 0.0, r);
+    return length(p - ba * h) * sign(p.y * ba.x - p.x * ba.y);
+}
+
+// Triangle through p0, p1, p2 (either winding).
+fn sd_triangle(p: vec2<f32>, p0: vec2<f32>, p1: vec2<f32>, p2: vec2<f32>) -> f32 {
+    let e0 = p1 - p0;
+    let e1 = p2 - p1;
+    let e2 = p0 - p2;
+    let v0 = p - p0;
+    let v1 = p - p1;
+    let v2 = p - p2;
+    let pq0 = v0 - e0 * clamp(dot(v0, e0) / dot(e0, e0), 0.0, 1.0);
+    let pq1 = v1 - e1 * clamp(dot(v1, e1) / dot(e1, e1), 0.0, 1.0);
+    let pq2 = v2 - e2 * clamp(dot(v2, e2) / dot(e2, e2), 0.0, 1.0);
+    let s = sign(e0.x * e2.y - e0.y * e2.x);
+    let d = min(min(vec2<f32>(dot(pq0, pq0), s * (v0.x * e0.y - v0.y * e0.x)),
+                    vec2<f32>(dot(pq1, pq1), s * (v1.x * e1.y - v1.y * e1.x))),
+                    vec2<f32>(dot(pq2, pq2), s * (v2.x * e2.y - v2.y * e2.x)));
+    return -sqrt(d.x) * sign(d.y);
+}
 
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
@@ -177,6 +260,18 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     } else if (prim_type == 2u) {
         // Line
         dist = sd_rounded_box_varying(in.uv, in.dim, vec4<f32>(in.dim.y)); 
+    } else if (prim_type == 3u) {
+        // Border ring: outer edge of the box
+        dist = sd_rounded_box_varying(in.uv, in.dim, in.radii);
+    } else if (prim_type == 4u) {
+        // Arc: radii = (radius, start, sweep, _)
+        dist = sd_arc(in.uv, in.radii.x, in.radii.y, in.radii.z, stroke_width * 0.5);
+    } else if (prim_type == 5u) {
+        // Star: radii = (radius, inner ratio, fill fraction, _)
+        dist = sd_star5(in.uv, in.radii.x, in.radii.y);
+    } else if (prim_type == 6u) {
+        // Triangle: corners = radii.xy, radii.zw, corner
+        dist = sd_triangle(in.uv, in.radii.xy, in.radii.zw, in.corner);
     }
     
     var alpha = 0.0;
@@ -184,7 +279,21 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     // AA width: usually 0.5 to 1.0 pixel.
     let aa = 0.5 + max(softness, 0.0);
     
-    if (stroke_width > 0.0) {
+    if (prim_type == 3u) {
+        // Inner edge is a true inset rounded box, so corner radii shrink
+        // with the border width instead of being offset from the outer SDF.
+        let inner_dim = max(in.dim - vec2<f32>(stroke_width), vec2<f32>(0.0));
+        let inner_radii = max(in.radii - vec4<f32>(stroke_width), vec4<f32>(0.0));
+        let d_inner = sd_rounded_box_varying(in.uv, inner_dim, inner_radii);
+        let cover_outer = 1.0 - smoothstep(-aa, aa, dist);
+        let outside_inner = smoothstep(-aa, aa, d_inner);
+        // Multiply coverages: never negative and no double counting where
+        // the AA bands of both edges meet on thin borders.
+        alpha = cover_outer * outside_inner;
+    } else if (prim_type == 4u) {
+        // Arc thickness is already part of its SDF
+        alpha = 1.0 - smoothstep(-aa, aa, dist);
+    } else if (stroke_width > 0.0) {
         // Stroke logic with softness? 
         // For now assume stroke is crisp or matches softness.
         let d_outer = dist;
@@ -206,7 +315,13 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let grad_t = clamp((in.uv.y / in.dim.y) * 0.5 + 0.5, 0.0, 1.0);
     var final_color = mix(in.color_start, in.color_end, grad_t);
     
-    final_color.a = final_color.a * alpha;
+    if (prim_type == 5u) {
+        // Only the left `fill` fraction of a star is drawn (half stars)
+        let cut = in.radii.x * (2.0 * in.radii.z - 1.0);
+        alpha = alpha * (1.0 - smoothstep(cut - 0.5, cut + 0.5, in.uv.x));
+    }
+
+    final_color.a = final_color.a * alpha * clip_coverage(in.screen_pos, in.clip_rect, in.clip_radii);
     
     if (final_color.a <= 0.0) {
         discard;
@@ -0,0 +1,208 @@
+//! Global keyboard shortcuts.
+//!
+//! A [`ShortcutMap`] binds key + modifier combinations such as `"Ctrl+S"`
+//! or `"Ctrl+Shift+P"` to action strings. `handle_keyboard_event` consults
+//! it before dispatching to the focused widget and reports the matched
+//! action back to the app.
+
+use anyhow::{anyhow, bail};
+use std::fmt;
+use winit::keyboard::{Key, ModifiersState, NamedKey};
+
+/// The non-modifier part of a shortcut.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShortcutKey {
+    /// A character key, stored lowercase so letters match regardless of
+    /// Shift or Caps Lock.
+    Char(char),
+    /// A named key such as `F5` or `Delete`.
+    Named(NamedKey),
+}
+
+/// A key plus the modifiers that must be held, exactly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortcut {
+    pub key: ShortcutKey,
+    pub modifiers: ModifiersState,
+}
+
+impl Shortcut {
+    /// Parses a combination like `"Ctrl+Shift+P"`, `"Alt+F4"` or `"Delete"`.
+    ///
+    /// Modifier names are `Ctrl`/`Control`, `Shift`, `Alt`/`Option` and
+    /// `Super`/`Cmd`/`Meta`, in any order and case. The key comes last.
+    pub fn parse(combo: &str) -> anyhow::Result<Shortcut> {
+        let parts: Vec<&str> = combo.split('+').map(str::trim).collect();
+        let (key_name, modifier_names) = match parts.split_last() {
+            Some((key, mods)) if !key.is_empty() => (*key, mods),
+            _ => bail!("Empty shortcut: {:?}", combo),
+        };
+
+        let mut modifiers = ModifiersState::empty();
+        for name in modifier_names {
+            modifiers |= match name.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => ModifiersState::CONTROL,
+                "shift" => ModifiersState::SHIFT,
+                "alt" | "option" => ModifiersState::ALT,
+                "super" | "cmd" | "meta" => ModifiersState::SUPER,
+                _ => bail!("Unknown modifier {:?} in shortcut {:?}", name, combo),
+            };
+        }
+
+        let key = parse_key(key_name)
+            .ok_or_else(|| anyhow!("Unknown key {:?} in shortcut {:?}", key_name, combo))?;
+        Ok(Shortcut { key, modifiers })
+    }
+
+    /// Returns true if `key` pressed with `modifiers` triggers this shortcut.
+    pub fn matches(&self, key: &Key, modifiers: ModifiersState) -> bool {
+        if modifiers != self.modifiers {
+            return false;
+        }
+        match (&self.key, key) {
+            (ShortcutKey::Char(c), Key::Character(s)) => {
+                let mut chars = s.chars().flat_map(char::to_lowercase);
+                chars.next() == Some(*c) && chars.next().is_none()
+            }
+            (ShortcutKey::Named(named), Key::Named(pressed)) => named == pressed,
+            _ => false,
+        }
+    }
+}
+
+fn parse_key(name: &str) -> Option<ShortcutKey> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(ShortcutKey::Char(c.to_ascii_lowercase()));
+    }
+    let named = match name.to_ascii_lowercase().as_str() {
+        "enter" | "return" => NamedKey::Enter,
+        "escape" | "esc" => NamedKey::Escape,
+        "tab" => NamedKey::Tab,
+        "space" => NamedKey::Space,
+        "backspace" => NamedKey::Backspace,
+        "delete" | "del" => NamedKey::Delete,
+        "insert" => NamedKey::Insert,
+        "home" => NamedKey::Home,
+        "end" => NamedKey::End,
+        "pageup" => NamedKey::PageUp,
+        "pagedown" => NamedKey::PageDown,
+        "up" | "arrowup" => NamedKey::ArrowUp,
+        "down" | "arrowdown" => NamedKey::ArrowDown,
+        "left" | "arrowleft" => NamedKey::ArrowLeft,
+        "right" | "arrowright" => NamedKey::ArrowRight,
+        "f1" => NamedKey::F1,
+        "f2" => NamedKey::F2,
+        "f3" => NamedKey::F3,
+        "f4" => NamedKey::F4,
+        "f5" => NamedKey::F5,
+        "f6" => NamedKey::F6,
+        "f7" => NamedKey::F7,
+        "f8" => NamedKey::F8,
+        "f9" => NamedKey::F9,
+        "f10" => NamedKey::F10,
+        "f11" => NamedKey::F11,
+        "f12" => NamedKey::F12,
+        _ => return None,
+    };
+    Some(ShortcutKey::Named(named))
+}
+
+impl fmt::Display for Shortcut {
+    /// Formats as `Ctrl+Shift+P`, suitable for menu item hints.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (flag, name) in [
+            (ModifiersState::CONTROL, "Ctrl"),
+            (ModifiersState::ALT, "Alt"),
+            (ModifiersState::SHIFT, "Shift"),
+            (ModifiersState::SUPER, "Super"),
+        ] {
+            if self.modifiers.contains(flag) {
+                write!(f, "{}+", name)?;
+            }
+        }
+        match &self.key {
+            ShortcutKey::Char(c) => write!(f, "{}", c.to_ascii_uppercase()),
+            ShortcutKey::Named(named) => write!(f, "{:?}", named),
+        }
+    }
+}
+
+/// Shortcut bindings, checked in insertion order.
+#[derive(Debug, Clone, Default)]
+pub struct ShortcutMap {
+    bindings: Vec<(Shortcut, String)>,
+}
+
+impl ShortcutMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds `combo` (see [`Shortcut::parse`]) to `action`.
+    pub fn bind(&mut self, combo: &str, action: impl Into<String>) -> anyhow::Result<()> {
+        self.bindings.push((Shortcut::parse(combo)?, action.into()));
+        Ok(())
+    }
+
+    /// Builder-style [`ShortcutMap::bind`].
+    pub fn with(mut self, combo: &str, action: impl Into<String>) -> anyhow::Result<Self> {
+        self.bind(combo, action)?;
+        Ok(self)
+    }
+
+    /// Returns the action bound to `key` with exactly `modifiers` held.
+    pub fn lookup(&self, key: &Key, modifiers: ModifiersState) -> Option<&str> {
+        self.bindings
+            .iter()
+            .find(|(shortcut, _)| shortcut.matches(key, modifiers))
+            .map(|(_, action)| action.as_str())
+    }
+
+    /// Returns the display label of the first shortcut bound to `action`,
+    /// e.g. to fill in a menu item's `shortcut` hint.
+    pub fn label_for(&self, action: &str) -> Option<String> {
+        self.bindings
+            .iter()
+            .find(|(_, bound)| bound == action)
+            .map(|(shortcut, _)| shortcut.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_letters_match_case_insensitively() {
+        let map = ShortcutMap::new()
+            .with("Ctrl+S", "save").unwrap()
+            .with("ctrl+shift+p", "palette").unwrap();
+
+        assert_eq!(map.lookup(&Key::Character("s".into()), ModifiersState::CONTROL), Some("save"));
+        assert_eq!(map.lookup(&Key::Character("S".into()), ModifiersState::CONTROL), Some("save"));
+        assert_eq!(
+            map.lookup(&Key::Character("P".into()), ModifiersState::CONTROL | ModifiersState::SHIFT),
+            Some("palette")
+        );
+        // Modifiers must match exactly.
+        assert_eq!(map.lookup(&Key::Character("p".into()), ModifiersState::CONTROL), None);
+        assert_eq!(map.lookup(&Key::Character("s".into()), ModifiersState::empty()), None);
+    }
+
+    #[test]
+    fn test_named_keys_and_labels() {
+        let map = ShortcutMap::new().with("Alt+F4", "quit").unwrap();
+        assert_eq!(map.lookup(&Key::Named(NamedKey::F4), ModifiersState::ALT), Some("quit"));
+        assert_eq!(map.label_for("quit").as_deref(), Some("Alt+F4"));
+        assert_eq!(Shortcut::parse("Ctrl+Shift+p").unwrap().to_string(), "Ctrl+Shift+P");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Shortcut::parse("").is_err());
+        assert!(Shortcut::parse("Ctrl+").is_err());
+        assert!(Shortcut::parse("Hyper+S").is_err());
+        assert!(Shortcut::parse("Ctrl+Bogus").is_err());
+    }
+}
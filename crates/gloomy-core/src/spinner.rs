@@ -0,0 +1,196 @@
+//! NumberInput spinner stepping and press-and-hold auto-repeat.
+//!
+//! A click on a spinner arrow (`{id}:up` / `{id}:down`) steps once with
+//! [`step_number_input`]. Holding the arrow down repeats the step: after
+//! `delay_ms` the value steps every `interval_ms`, each repeat a little
+//! faster down to `min_interval_ms`. Apps drive the repeat by calling
+//! [`tick_spinners`] every frame with the frame clock, and keep
+//! requesting redraws while `InteractionState::spin_repeat` is set.
+//! Releasing the mouse or moving off the arrow stops the repeat.
+
+use crate::action::Action;
+use crate::interaction::InteractionState;
+use crate::widget::Widget;
+use serde::{Deserialize, Serialize};
+
+/// Each repeat's interval is this fraction of the previous one.
+const ACCELERATION: f64 = 0.85;
+
+/// Auto-repeat timing for a NumberInput's spinner arrows.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpinRepeatConfig {
+    /// How long an arrow must be held before it repeats.
+    pub delay_ms: u64,
+    /// Time between the first repeats.
+    pub interval_ms: u64,
+    /// Shortest time between repeats once accelerated.
+    pub min_interval_ms: u64,
+}
+
+impl Default for SpinRepeatConfig {
+    fn default() -> Self {
+        Self { delay_ms: 400, interval_ms: 120, min_interval_ms: 25 }
+    }
+}
+
+impl SpinRepeatConfig {
+    /// Time before the repeat following `repeats` earlier ones.
+    pub fn interval(&self, repeats: u32) -> u64 {
+        let interval = self.interval_ms as f64 * ACCELERATION.powi(repeats as i32);
+        (interval.round() as u64).max(self.min_interval_ms)
+    }
+}
+
+/// A spinner arrow being held down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpinRepeat {
+    /// The held arrow's action (`{id}:up` / `{id}:down`).
+    pub action: String,
+    /// Frame time at which the next step is due.
+    pub next_at_ms: u64,
+    /// Steps repeated so far.
+    pub repeats: u32,
+}
+
+/// Steps the NumberInput named by an `{id}:up` / `{id}:down` action,
+/// clamped to its range. Returns true if the value changed.
+pub fn step_number_input(root: &mut Widget, action: &str) -> bool {
+    let (id, up) = match Action::parse(action) {
+        Action::Increment { id } => (id, true),
+        Action::Decrement { id } => (id, false),
+        _ => return false,
+    };
+    let Some(Widget::NumberInput { value, step, min, max, .. }) = crate::ui::find_widget_mut(root, &id) else {
+        return false;
+    };
+    let next = if up { *value + *step } else { *value - *step };
+    let next = next.clamp(min.unwrap_or(f64::MIN), max.unwrap_or(f64::MAX));
+    let changed = next != *value;
+    *value = next;
+    changed
+}
+
+/// Repeats the step of a held spinner arrow when due.
+///
+/// An arrow counts as held while the mouse is pressed and both
+/// `active_action` and `hovered_action` are its action. Starts the
+/// repeat clock on the first held frame and clears it as soon as the
+/// arrow is no longer held. Returns true if a value changed.
+pub fn tick_spinners(root: &mut Widget, interaction: &mut InteractionState, now_ms: u64) -> bool {
+    let held = interaction
+        .active_action
+        .as_ref()
+        .filter(|action| {
+            interaction.is_pressed
+                && interaction.hovered_action.as_ref() == Some(*action)
+                && matches!(Action::parse(action), Action::Increment { .. } | Action::Decrement { .. })
+        })
+        .cloned();
+    let Some(action) = held else {
+        interaction.spin_repeat = None;
+        return false;
+    };
+    let Some(config) = repeat_config(root, &action) else {
+        interaction.spin_repeat = None;
+        return false;
+    };
+
+    match &mut interaction.spin_repeat {
+        Some(repeat) if repeat.action == action => {
+            if now_ms < repeat.next_at_ms {
+                return false;
+            }
+            repeat.next_at_ms = now_ms + config.interval(repeat.repeats);
+            repeat.repeats += 1;
+            step_number_input(root, &action)
+        }
+        // Newly held: the click itself already stepped once.
+        _ => {
+            interaction.spin_repeat = Some(SpinRepeat { action, next_at_ms: now_ms + config.delay_ms, repeats: 0 });
+            false
+        }
+    }
+}
+
+fn repeat_config(root: &mut Widget, action: &str) -> Option<SpinRepeatConfig> {
+    let id = match Action::parse(action) {
+        Action::Increment { id } | Action::Decrement { id } => id,
+        _ => return None,
+    };
+    match crate::ui::find_widget_mut(root, &id)? {
+        Widget::NumberInput { repeat, .. } => Some(*repeat),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::parse_ui;
+
+    fn value(root: &Widget) -> f64 {
+        match root {
+            Widget::NumberInput { value, .. } => *value,
+            _ => unreachable!(),
+        }
+    }
+
+    fn hold(interaction: &mut InteractionState, action: &str) {
+        interaction.is_pressed = true;
+        interaction.active_action = Some(action.into());
+        interaction.hovered_action = Some(action.into());
+    }
+
+    #[test]
+    fn test_step_clamps_to_range() {
+        let mut root = parse_ui(r#"NumberInput(id: "qty", value: 9.0, step: 1.0, max: Some(10.0))"#).unwrap();
+        assert!(step_number_input(&mut root, "qty:up"));
+        assert!(!step_number_input(&mut root, "qty:up"));
+        assert_eq!(value(&root), 10.0);
+        assert!(step_number_input(&mut root, "qty:down"));
+        assert!(!step_number_input(&mut root, "other:up"));
+    }
+
+    #[test]
+    fn test_hold_repeats_after_delay_and_accelerates() {
+        let mut root = parse_ui(r#"
+            NumberInput(id: "qty", step: 1.0, repeat: (delay_ms: 300, interval_ms: 100, min_interval_ms: 50))
+        "#).unwrap();
+        let mut interaction = InteractionState::default();
+        hold(&mut interaction, "qty:up");
+
+        assert!(!tick_spinners(&mut root, &mut interaction, 0));
+        assert!(!tick_spinners(&mut root, &mut interaction, 299));
+        assert!(tick_spinners(&mut root, &mut interaction, 300));
+        // Next after 100ms, then 85ms, ... down to 50ms.
+        assert!(!tick_spinners(&mut root, &mut interaction, 399));
+        assert!(tick_spinners(&mut root, &mut interaction, 400));
+        assert!(tick_spinners(&mut root, &mut interaction, 485));
+        assert_eq!(value(&root), 3.0);
+        let config = SpinRepeatConfig { delay_ms: 300, interval_ms: 100, min_interval_ms: 50 };
+        assert_eq!(config.interval(10), 50);
+    }
+
+    #[test]
+    fn test_release_or_leave_stops_repeat() {
+        let mut root = parse_ui(r#"NumberInput(id: "qty", step: 1.0)"#).unwrap();
+        let mut interaction = InteractionState::default();
+        hold(&mut interaction, "qty:down");
+        tick_spinners(&mut root, &mut interaction, 0);
+        assert!(interaction.spin_repeat.is_some());
+
+        // Moving off the arrow stops it.
+        interaction.hovered_action = None;
+        assert!(!tick_spinners(&mut root, &mut interaction, 1000));
+        assert!(interaction.spin_repeat.is_none());
+
+        // Coming back restarts the delay rather than stepping at once.
+        hold(&mut interaction, "qty:down");
+        assert!(!tick_spinners(&mut root, &mut interaction, 1000));
+        interaction.set_pressed(false);
+        assert!(!tick_spinners(&mut root, &mut interaction, 2000));
+        assert!(interaction.spin_repeat.is_none());
+        assert_eq!(value(&root), 0.0);
+    }
+}
@@ -1,4 +1,6 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use glam::Vec2;
+use crate::theme::{themed_default, with_alpha, Theme, Themed};
 use crate::widget::Color;
 
 /// Global styling configuration.
@@ -25,7 +27,29 @@ pub struct ListViewStyle {
 }
 
 impl Default for ListViewStyle {
+    /// Derived from the current theme, see [`crate::theme::set_current_theme`].
     fn default() -> Self {
+        themed_default(Self::fallback)
+    }
+}
+
+impl Themed for ListViewStyle {
+    fn themed(theme: &Theme) -> Self {
+        let c = &theme.colors;
+        Self {
+            item_height: 40.0,
+            idle: BoxStyle { background: None, ..Default::default() },
+            hover: BoxStyle::fill(c.hover),
+            selected: BoxStyle::fill(c.primary),
+            text_color_idle: c.text,
+            text_color_selected: c.surface,
+        }
+    }
+}
+
+impl ListViewStyle {
+    /// Built-in dark style, used when no theme is current.
+    fn fallback() -> Self {
         Self {
             item_height: 40.0,
             idle: BoxStyle { background: None, ..Default::default() },
@@ -74,6 +98,50 @@ pub struct Gradient {
     pub end: Color,
 }
 
+/// How a background image fills its box.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BackgroundRepeat {
+    /// One copy stretched over the whole box.
+    #[default]
+    NoRepeat,
+    /// Tiles at the texture's size along the top edge.
+    RepeatX,
+    /// Tiles at the texture's size down the left edge.
+    RepeatY,
+    /// Tiles at the texture's size over the whole box.
+    Repeat,
+}
+
+impl BackgroundRepeat {
+    /// The quad covering a box at `pos` of `size` for a texture of `tile`
+    /// size, as `(top_left, size, uv_scale)`. The quad starts at the box's
+    /// top-left corner and the texture wraps `uv_scale` times across it, so
+    /// any number of tiles costs one instance; the last tiles overhang the
+    /// box and are clipped when drawn. `NoRepeat` is the box itself.
+    pub fn quad(self, pos: Vec2, size: Vec2, tile: Vec2) -> (Vec2, Vec2, Vec2) {
+        if self == BackgroundRepeat::NoRepeat || tile.x <= 0.0 || tile.y <= 0.0 {
+            return (pos, size, Vec2::ONE);
+        }
+        let count = |extent: f32, step: f32| (extent / step).max(1.0);
+        let (cols, rows) = match self {
+            BackgroundRepeat::RepeatX => (count(size.x, tile.x), 1.0),
+            BackgroundRepeat::RepeatY => (1.0, count(size.y, tile.y)),
+            _ => (count(size.x, tile.x), count(size.y, tile.y)),
+        };
+        let uv_scale = Vec2::new(cols, rows);
+        (pos, uv_scale * tile, uv_scale)
+    }
+}
+
+/// Image drawn over a box's background color or gradient.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BackgroundImage {
+    /// Name of a texture registered with `GloomyRenderer::register_texture`.
+    pub texture: String,
+    #[serde(default)]
+    pub repeat: BackgroundRepeat,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
 pub enum BorderStyle {
     #[default]
@@ -102,14 +170,20 @@ pub struct BoxStyle {
     /// Linear gradient background (Top to Bottom).
     #[serde(default)]
     pub gradient: Option<Gradient>,
+
+    /// Image drawn over the background, clipped to the box.
+    #[serde(default)]
+    pub background_image: Option<BackgroundImage>,
     
     /// Border stroke.
     #[serde(default)]
     pub border: Option<Border>,
     
-    /// Drop shadow.
-    #[serde(default)]
-    pub shadow: Option<Shadow>,
+    /// Drop shadows, drawn back-to-front (the first entry is the bottom-most).
+    ///
+    /// Also accepts the legacy single `shadow: Some(Shadow(..))` form.
+    #[serde(default, alias = "shadow", deserialize_with = "deserialize_shadows")]
+    pub shadows: Vec<Shadow>,
     
     /// Corner radii [TopLeft, TopRight, BottomRight, BottomLeft].
     #[serde(default)]
@@ -138,15 +212,69 @@ impl BoxStyle {
         self
     }
     
+    /// Adds a shadow on top of any existing ones.
     pub fn with_shadow(mut self, offset: (f32, f32), blur: f32, color: Color) -> Self {
-        self.shadow = Some(Shadow { offset, blur, color });
+        self.shadows.push(Shadow { offset, blur, color });
         self
     }
 }
 
+/// Accepts either a list of shadows or the older single optional shadow.
+fn deserialize_shadows<'de, D>(deserializer: D) -> Result<Vec<Shadow>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ShadowsRepr {
+        Many(Vec<Shadow>),
+        Single(Shadow),
+        Optional(Option<Shadow>),
+    }
+
+    Ok(match ShadowsRepr::deserialize(deserializer)? {
+        ShadowsRepr::Many(shadows) => shadows,
+        ShadowsRepr::Single(shadow) => vec![shadow],
+        ShadowsRepr::Optional(shadow) => shadow.into_iter().collect(),
+    })
+}
+
+/// Text properties a Container passes down to the Labels and Buttons
+/// inside it.
+///
+/// Labels inherit `font`, `font_size` and `color`; Buttons inherit `font`
+/// (their size and color come from `ButtonStyle`). A nested container's
+/// defaults replace the ones it inherits property by property. A widget
+/// overrides a property by setting it: a `font`, or a Label `size` or
+/// `color`, whatever its value.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct TextDefaults {
+    pub font: Option<String>,
+    pub font_size: Option<f32>,
+    pub color: Option<Color>,
+}
+
+impl TextDefaults {
+    /// These defaults with every property `inner` sets replaced.
+    pub fn inherit(&self, inner: &TextDefaults) -> TextDefaults {
+        TextDefaults {
+            font: inner.font.clone().or_else(|| self.font.clone()),
+            font_size: inner.font_size.or(self.font_size),
+            color: inner.color.or(self.color),
+        }
+    }
+
+    /// Whether no property is set.
+    pub fn is_empty(&self) -> bool {
+        self.font.is_none() && self.font_size.is_none() && self.color.is_none()
+    }
+}
+
 // --- Widget Specific Styles ---
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(from = "ButtonStylePatch")]
 pub struct ButtonStyle {
     #[serde(default)]
     pub idle: BoxStyle,
@@ -158,21 +286,118 @@ pub struct ButtonStyle {
     pub disabled: BoxStyle,
     #[serde(default)]
     pub text_color: Color,
+    /// Color of the ripple drawn from the press point; `None` (the
+    /// default) draws no ripple.
+    #[serde(default)]
+    pub ripple: Option<Color>,
+    /// Fields written out in the parsed RON, one bit each in declaration
+    /// order; `None` for styles built in code. Classes only yield the
+    /// fields an inline style wrote, see [`crate::style_context`].
+    #[serde(skip)]
+    pub(crate) written: Option<u8>,
+}
+
+impl PartialEq for ButtonStyle {
+    fn eq(&self, other: &Self) -> bool {
+        self.idle == other.idle
+            && self.hover == other.hover
+            && self.active == other.active
+            && self.disabled == other.disabled
+            && self.text_color == other.text_color
+            && self.ripple == other.ripple
+    }
+}
+
+/// A `ButtonStyle` as written, with left-out fields `None`.
+#[derive(Deserialize)]
+#[serde(rename = "ButtonStyle")]
+struct ButtonStylePatch {
+    #[serde(default, deserialize_with = "present")]
+    idle: Option<BoxStyle>,
+    #[serde(default, deserialize_with = "present")]
+    hover: Option<BoxStyle>,
+    #[serde(default, deserialize_with = "present")]
+    active: Option<BoxStyle>,
+    #[serde(default, deserialize_with = "present")]
+    disabled: Option<BoxStyle>,
+    #[serde(default, deserialize_with = "present")]
+    text_color: Option<Color>,
+    #[serde(default, deserialize_with = "present")]
+    ripple: Option<Option<Color>>,
+}
+
+impl From<ButtonStylePatch> for ButtonStyle {
+    fn from(patch: ButtonStylePatch) -> Self {
+        let written = mask(&[
+            patch.idle.is_some(),
+            patch.hover.is_some(),
+            patch.active.is_some(),
+            patch.disabled.is_some(),
+            patch.text_color.is_some(),
+            patch.ripple.is_some(),
+        ]);
+        Self {
+            idle: patch.idle.unwrap_or_default(),
+            hover: patch.hover.unwrap_or_default(),
+            active: patch.active.unwrap_or_default(),
+            disabled: patch.disabled.unwrap_or_default(),
+            text_color: patch.text_color.unwrap_or_default(),
+            ripple: patch.ripple.unwrap_or_default(),
+            written: Some(written),
+        }
+    }
+}
+
+/// Deserializes a field that is present; left-out fields stay `None`
+/// through `#[serde(default)]`.
+fn present<'de, D: Deserializer<'de>, T: Deserialize<'de>>(deserializer: D) -> Result<Option<T>, D::Error> {
+    T::deserialize(deserializer).map(Some)
+}
+
+/// Packs per-field flags into a bit mask, first field in bit 0.
+fn mask(fields: &[bool]) -> u8 {
+    fields.iter().enumerate().fold(0, |mask, (bit, &set)| mask | (set as u8) << bit)
 }
 
 impl Default for ButtonStyle {
+    /// Derived from the current theme, see [`crate::theme::set_current_theme`].
     fn default() -> Self {
+        themed_default(Self::fallback)
+    }
+}
+
+impl Themed for ButtonStyle {
+    fn themed(theme: &Theme) -> Self {
+        let c = &theme.colors;
+        Self {
+            idle: BoxStyle::fill(c.surface).with_radius(4.0).with_border(c.border, 1.0),
+            hover: BoxStyle::fill(c.hover).with_radius(4.0).with_border(c.border, 1.0),
+            active: BoxStyle::fill(c.active).with_radius(4.0),
+            disabled: BoxStyle::fill(with_alpha(c.surface, 0.5)).with_radius(4.0),
+            text_color: c.text,
+            ripple: None,
+            written: None,
+        }
+    }
+}
+
+impl ButtonStyle {
+    /// Built-in dark style, used when no theme is current.
+    fn fallback() -> Self {
         Self {
             idle: BoxStyle::fill((0.2, 0.2, 0.25, 1.0)).with_radius(4.0),
             hover: BoxStyle::fill((0.25, 0.25, 0.3, 1.0)).with_radius(4.0),
             active: BoxStyle::fill((0.15, 0.15, 0.2, 1.0)).with_radius(4.0),
             disabled: BoxStyle::fill((0.1, 0.1, 0.1, 0.5)).with_radius(4.0),
             text_color: (0.9, 0.9, 0.9, 1.0),
+            ripple: None,
+            written: None,
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(from = "TextInputStylePatch")]
 pub struct TextInputStyle {
     #[serde(default)]
     pub idle: BoxStyle,
@@ -186,10 +411,97 @@ pub struct TextInputStyle {
     pub cursor_color: Color,
     #[serde(default)]
     pub font: Option<String>,
+    /// Fields written out in the parsed RON, as for [`ButtonStyle`].
+    #[serde(skip)]
+    pub(crate) written: Option<u8>,
+}
+
+impl PartialEq for TextInputStyle {
+    fn eq(&self, other: &Self) -> bool {
+        self.idle == other.idle
+            && self.focused == other.focused
+            && self.placeholder_color == other.placeholder_color
+            && self.text_color == other.text_color
+            && self.cursor_color == other.cursor_color
+            && self.font == other.font
+    }
+}
+
+/// A `TextInputStyle` as written, with left-out fields `None`.
+#[derive(Deserialize)]
+#[serde(rename = "TextInputStyle")]
+struct TextInputStylePatch {
+    #[serde(default, deserialize_with = "present")]
+    idle: Option<BoxStyle>,
+    #[serde(default, deserialize_with = "present")]
+    focused: Option<BoxStyle>,
+    #[serde(default, deserialize_with = "present")]
+    placeholder_color: Option<Color>,
+    #[serde(default, deserialize_with = "present")]
+    text_color: Option<Color>,
+    #[serde(default, deserialize_with = "present")]
+    cursor_color: Option<Color>,
+    #[serde(default, deserialize_with = "present")]
+    font: Option<Option<String>>,
+}
+
+impl From<TextInputStylePatch> for TextInputStyle {
+    fn from(patch: TextInputStylePatch) -> Self {
+        let written = mask(&[
+            patch.idle.is_some(),
+            patch.focused.is_some(),
+            patch.placeholder_color.is_some(),
+            patch.text_color.is_some(),
+            patch.cursor_color.is_some(),
+            patch.font.is_some(),
+        ]);
+        Self {
+            idle: patch.idle.unwrap_or_default(),
+            focused: patch.focused.unwrap_or_default(),
+            placeholder_color: patch.placeholder_color.unwrap_or_default(),
+            text_color: patch.text_color.unwrap_or_default(),
+            cursor_color: patch.cursor_color.unwrap_or_default(),
+            font: patch.font.unwrap_or_default(),
+            written: Some(written),
+        }
+    }
 }
 
 impl Default for TextInputStyle {
+    /// Derived from the current theme, see [`crate::theme::set_current_theme`].
     fn default() -> Self {
+        themed_default(Self::fallback)
+    }
+}
+
+impl Themed for TextInputStyle {
+    fn themed(theme: &Theme) -> Self {
+        let c = &theme.colors;
+        Self {
+            idle: BoxStyle {
+                background: Some(c.surface),
+                border: Some(Border { width: 1.0, color: c.border, radius: [4.0; 4] }),
+                corner_radii: [4.0; 4],
+                ..Default::default()
+            },
+            focused: BoxStyle {
+                background: Some(c.surface),
+                border: Some(Border { width: 1.0, color: c.primary, radius: [4.0; 4] }),
+                corner_radii: [4.0; 4],
+                ..Default::default()
+            },
+            placeholder_color: c.text_disabled,
+            text_color: c.text,
+            cursor_color: c.primary,
+            font: None,
+            written: None,
+        }
+    }
+}
+
+impl TextInputStyle {
+    /// Built-in dark style, used when no theme is current.
+    fn fallback() -> Self {
         Self {
             idle: BoxStyle {
                 background: Some((0.1, 0.1, 0.12, 1.0)),
@@ -207,6 +519,63 @@ impl Default for TextInputStyle {
             text_color: (0.9, 0.9, 0.9, 1.0),
             cursor_color: (0.2, 0.5, 0.9, 1.0),
             font: None,
+            written: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_single_shadow() {
+        let style: BoxStyle = ron::from_str(
+            "(shadow: Some(Shadow(offset: (0.0, 2.0), blur: 4.0, color: (0.0, 0.0, 0.0, 0.5))))",
+        ).unwrap();
+        assert_eq!(style.shadows.len(), 1);
+        assert_eq!(style.shadows[0].offset, (0.0, 2.0));
+
+        let style: BoxStyle = ron::from_str("(shadow: None)").unwrap();
+        assert!(style.shadows.is_empty());
+    }
+
+    #[test]
+    fn test_stacked_shadows() {
+        let style: BoxStyle = ron::from_str(
+            "(shadows: [
+                Shadow(offset: (0.0, 1.0), blur: 2.0, color: (0.0, 0.0, 0.0, 0.4)),
+                Shadow(offset: (0.0, 8.0), blur: 24.0, color: (0.0, 0.0, 0.0, 0.15)),
+            ])",
+        ).unwrap();
+        assert_eq!(style.shadows.len(), 2);
+        assert_eq!(style.shadows[1].blur, 24.0);
+
+        let built = BoxStyle::default()
+            .with_shadow((0.0, 1.0), 2.0, (0.0, 0.0, 0.0, 0.4))
+            .with_shadow((0.0, 8.0), 24.0, (0.0, 0.0, 0.0, 0.15));
+        assert_eq!(built.shadows, style.shadows);
+    }
+
+    #[test]
+    fn test_background_repeat_quad_covers_the_box() {
+        let pos = Vec2::new(10.0, 20.0);
+        let size = Vec2::new(100.0, 50.0);
+        let tile = Vec2::new(25.0, 40.0);
+
+        let stretched = BackgroundRepeat::NoRepeat.quad(pos, size, tile);
+        assert_eq!(stretched, (pos, size, Vec2::ONE));
+
+        // 100 / 25 -> 4 columns, 50 / 40 -> 1.25 rows.
+        let (top_left, covered, uv) = BackgroundRepeat::Repeat.quad(pos, size, tile);
+        assert_eq!(top_left, pos);
+        assert_eq!(covered, size);
+        assert_eq!(uv, Vec2::new(4.0, 1.25));
+
+        // A single row or column keeps the texture's size on the other axis.
+        let row = BackgroundRepeat::RepeatX.quad(pos, size, tile);
+        assert_eq!(row, (pos, Vec2::new(100.0, 40.0), Vec2::new(4.0, 1.0)));
+        let column = BackgroundRepeat::RepeatY.quad(pos, size, tile);
+        assert_eq!(column, (pos, Vec2::new(25.0, 50.0), Vec2::new(1.0, 1.25)));
+    }
+}
@@ -3,11 +3,36 @@
 //! The StyleContext holds the active theme and global style,
 //! providing a central place to manage UI appearance that can
 //! be switched at runtime.
+//!
+//! It also holds style classes: named [`ClassStyle`]s that widgets opt
+//! into by listing the name in their `classes`. A widget's style is
+//! resolved in this order, later entries winning:
+//!
+//! 1. the default style, derived from the current theme;
+//! 2. its classes, in the order listed, so the last class that defines a
+//!    style for the widget's kind is used;
+//! 3. the fields its inline `style` writes out.
+//!
+//! A class replaces the whole default style; an inline style then overrides
+//! it field by field, so `style: (text_color: ..)` on a classed Button only
+//! changes the text color. Inline styles built in code rather than parsed
+//! override the fields that differ from the default style. Classes are applied when rendering with
+//! [`crate::ui::render_ui_styled`].
 
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 use crate::theme::Theme;
-use crate::style::GlobalStyle;
+use crate::style::{ButtonStyle, GlobalStyle, TextInputStyle};
 use crate::widget::Color;
 
+/// Styles a class gives each kind of widget it is applied to.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClassStyle {
+    pub button: Option<ButtonStyle>,
+    pub text_input: Option<TextInputStyle>,
+}
+
 /// Context holding the active theme and global style settings.
 ///
 /// This is the main entry point for theming in Gloomy UI.
@@ -19,6 +44,8 @@ pub struct StyleContext {
     pub theme: Theme,
     /// Active global style settings
     pub global_style: GlobalStyle,
+    /// Style classes by name
+    pub classes: HashMap<String, ClassStyle>,
 }
 
 impl StyleContext {
@@ -27,6 +54,34 @@ impl StyleContext {
         Self {
             theme,
             global_style,
+            classes: HashMap::new(),
+        }
+    }
+
+    /// Defines (or replaces) the style class `name`.
+    pub fn set_class(&mut self, name: impl Into<String>, style: ClassStyle) {
+        self.classes.insert(name.into(), style);
+    }
+
+    /// The style a Button with `classes` and inline `style` is drawn with.
+    pub fn button_style(&self, classes: &[String], style: &ButtonStyle) -> ButtonStyle {
+        self.resolve(classes, style, |class| class.button.as_ref())
+    }
+
+    /// The style a TextInput with `classes` and inline `style` is drawn with.
+    pub fn text_input_style(&self, classes: &[String], style: &TextInputStyle) -> TextInputStyle {
+        self.resolve(classes, style, |class| class.text_input.as_ref())
+    }
+
+    fn resolve<T: Overlay>(
+        &self,
+        classes: &[String],
+        inline: &T,
+        pick: impl Fn(&ClassStyle) -> Option<&T>,
+    ) -> T {
+        match classes.iter().rev().find_map(|name| self.classes.get(name).and_then(&pick)) {
+            Some(class) => class.overlay(inline, &T::default()),
+            None => inline.clone(),
         }
     }
     
@@ -66,6 +121,54 @@ impl StyleContext {
     }
 }
 
+/// Styles an inline style can override one field at a time.
+trait Overlay: Clone + Default {
+    /// `self` with every field `inline` wrote out, or for styles built in
+    /// code, every field of `inline` that differs from `unset`.
+    fn overlay(&self, inline: &Self, unset: &Self) -> Self;
+}
+
+/// `inline` if it was written (`Some(true)`), or when that is unknown,
+/// if it differs from `unset`; `base` otherwise.
+fn pick<T: Clone + PartialEq>(base: &T, inline: &T, unset: &T, written: Option<bool>) -> T {
+    if written.unwrap_or(inline != unset) { inline.clone() } else { base.clone() }
+}
+
+/// Whether field `bit` was written, per a style's `written` mask.
+fn bit(mask: Option<u8>, index: u8) -> Option<bool> {
+    mask.map(|mask| mask & (1 << index) != 0)
+}
+
+impl Overlay for ButtonStyle {
+    fn overlay(&self, inline: &Self, unset: &Self) -> Self {
+        let w = inline.written;
+        Self {
+            idle: pick(&self.idle, &inline.idle, &unset.idle, bit(w, 0)),
+            hover: pick(&self.hover, &inline.hover, &unset.hover, bit(w, 1)),
+            active: pick(&self.active, &inline.active, &unset.active, bit(w, 2)),
+            disabled: pick(&self.disabled, &inline.disabled, &unset.disabled, bit(w, 3)),
+            text_color: pick(&self.text_color, &inline.text_color, &unset.text_color, bit(w, 4)),
+            ripple: pick(&self.ripple, &inline.ripple, &unset.ripple, bit(w, 5)),
+            written: None,
+        }
+    }
+}
+
+impl Overlay for TextInputStyle {
+    fn overlay(&self, inline: &Self, unset: &Self) -> Self {
+        let w = inline.written;
+        Self {
+            idle: pick(&self.idle, &inline.idle, &unset.idle, bit(w, 0)),
+            focused: pick(&self.focused, &inline.focused, &unset.focused, bit(w, 1)),
+            placeholder_color: pick(&self.placeholder_color, &inline.placeholder_color, &unset.placeholder_color, bit(w, 2)),
+            text_color: pick(&self.text_color, &inline.text_color, &unset.text_color, bit(w, 3)),
+            cursor_color: pick(&self.cursor_color, &inline.cursor_color, &unset.cursor_color, bit(w, 4)),
+            font: pick(&self.font, &inline.font, &unset.font, bit(w, 5)),
+            written: None,
+        }
+    }
+}
+
 impl Default for StyleContext {
     /// Returns a default style context with dark theme and modern style.
     fn default() -> Self {
@@ -114,4 +217,58 @@ mod tests {
         let invalid = ctx.get_theme_color_or("invalid", fallback);
         assert_eq!(invalid, fallback);
     }
+
+    #[test]
+    fn test_shared_class_styles_two_buttons() {
+        use crate::style::BoxStyle;
+        use crate::widget::Widget;
+        let primary = ButtonStyle { idle: BoxStyle::fill((0.1, 0.3, 0.9, 1.0)), ..Default::default() };
+        let danger = ButtonStyle { idle: BoxStyle::fill((0.9, 0.1, 0.1, 1.0)), ..Default::default() };
+        let mut ctx = StyleContext::default();
+        ctx.set_class("primary", ClassStyle { button: Some(primary.clone()), ..Default::default() });
+        ctx.set_class("danger", ClassStyle { button: Some(danger.clone()), ..Default::default() });
+
+        let ui = crate::ui::parse_ui(r#"
+            Container(children: [
+                Button(text: "Save", action: "save", classes: ["primary"]),
+                Button(text: "Send", action: "send", classes: ["primary"]),
+                Button(text: "Delete", action: "delete", classes: ["primary", "danger"]),
+                Button(text: "Own", action: "own", classes: ["primary"],
+                    style: (text_color: (1.0, 0.0, 0.0, 1.0))),
+                Button(text: "Plain", action: "plain"),
+            ])
+        "#).unwrap();
+        let Widget::Container { children, .. } = &ui else { unreachable!() };
+        let resolved: Vec<ButtonStyle> = children
+            .iter()
+            .map(|child| match child {
+                Widget::Button { classes, style, .. } => ctx.button_style(classes, style),
+                _ => unreachable!(),
+            })
+            .collect();
+
+        assert_eq!(resolved[0], primary);
+        assert_eq!(resolved[1], primary);
+        // The last listed class wins; inline fields beat classes and keep
+        // the class's other fields.
+        assert_eq!(resolved[2], danger);
+        assert_eq!(resolved[3], ButtonStyle { text_color: (1.0, 0.0, 0.0, 1.0), ..primary.clone() });
+        assert_eq!(resolved[4], ButtonStyle::default());
+    }
+
+    #[test]
+    fn test_partial_inline_text_input_style_keeps_class_fields() {
+        use crate::style::BoxStyle;
+        use crate::widget::Widget;
+        let boxed = TextInputStyle { idle: BoxStyle::fill((0.2, 0.2, 0.2, 1.0)), ..Default::default() };
+        let mut ctx = StyleContext::default();
+        ctx.set_class("boxed", ClassStyle { text_input: Some(boxed.clone()), ..Default::default() });
+
+        let input = crate::ui::parse_ui(r#"
+            TextInput(id: "name", classes: ["boxed"], style: (cursor_color: (1.0, 0.0, 0.0, 1.0)))
+        "#).unwrap();
+        let Widget::TextInput { classes, style, .. } = &input else { unreachable!() };
+        let resolved = ctx.text_input_style(classes, style);
+        assert_eq!(resolved, TextInputStyle { cursor_color: (1.0, 0.0, 0.0, 1.0), ..boxed });
+    }
 }
@@ -0,0 +1,225 @@
+//! Suggestion sources for the Autocomplete widget.
+//!
+//! An Autocomplete with a `provider` id gets its `suggestions` from the
+//! matching [`SuggestionProvider`] instead of the app refilling the list on
+//! every keystroke. Call [`refresh_suggestions`] once per frame (or after
+//! input); it queries providers whose widget value changed, honouring the
+//! widget's `debounce_ms`.
+
+use crate::widget::Widget;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Produces suggestions for a query.
+pub trait SuggestionProvider: Send + Sync {
+    /// Returns the suggestions for `query`.
+    ///
+    /// Remote sources should start a fetch here and return what they have
+    /// (possibly nothing) without blocking.
+    fn suggest(&self, query: &str) -> Vec<String>;
+
+    /// Returns true while results for `query` are still being fetched.
+    ///
+    /// The widget shows a loading row and keeps polling `suggest` until
+    /// this returns false.
+    fn is_pending(&self, _query: &str) -> bool {
+        false
+    }
+}
+
+/// A static list, filtered by case-insensitive substring match.
+impl SuggestionProvider for Vec<String> {
+    fn suggest(&self, query: &str) -> Vec<String> {
+        let query = query.to_lowercase();
+        self.iter()
+            .filter(|s| s.to_lowercase().contains(&query))
+            .cloned()
+            .collect()
+    }
+}
+
+/// A static list, filtered and ranked with [`crate::fuzzy::rank`] so the
+/// order agrees with the dropdown's fuzzy highlight.
+#[derive(Debug, Clone, Default)]
+pub struct FuzzySuggestions(pub Vec<String>);
+
+impl SuggestionProvider for FuzzySuggestions {
+    fn suggest(&self, query: &str) -> Vec<String> {
+        crate::fuzzy::rank(query, &self.0)
+            .into_iter()
+            .map(|(i, _)| self.0[i].clone())
+            .collect()
+    }
+}
+
+/// Query bookkeeping kept on the widget between refreshes.
+#[derive(Debug, Clone, Default)]
+pub struct SuggestionState {
+    /// Value the suggestions were last requested for. `None` until the
+    /// first refresh, so an initial value is queried too.
+    pub(crate) query: Option<String>,
+    /// When the value last changed; the provider is queried once the
+    /// debounce has elapsed since then.
+    pub(crate) changed_at: Option<Instant>,
+    /// A query is waiting on its debounce or on a pending provider.
+    pub(crate) dirty: bool,
+    /// The provider reported the current query as still loading.
+    pub pending: bool,
+}
+
+/// Simple HashMap-based provider lookup, keyed by the widget's `provider`.
+#[derive(Default)]
+pub struct SuggestionProviders {
+    providers: HashMap<String, Box<dyn SuggestionProvider>>,
+}
+
+impl SuggestionProviders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a provider under `id`.
+    pub fn register<P: SuggestionProvider + 'static>(&mut self, id: impl Into<String>, provider: P) {
+        self.providers.insert(id.into(), Box::new(provider));
+    }
+
+    pub fn get(&self, id: &str) -> Option<&dyn SuggestionProvider> {
+        self.providers.get(id).map(|p| p.as_ref())
+    }
+}
+
+/// Updates the suggestions of every Autocomplete in the tree that has a
+/// registered provider.
+///
+/// Returns true if any widget's suggestions or loading state changed, in
+/// which case the app should redraw.
+pub fn refresh_suggestions(root: &mut Widget, providers: &SuggestionProviders, now: Instant) -> bool {
+    match root {
+        Widget::Container { children, .. } => children
+            .iter_mut()
+            .fold(false, |changed, child| refresh_suggestions(child, providers, now) | changed),
+        Widget::Tab { tabs, selected, .. } => tabs
+            .get_mut(*selected)
+            .map(|tab| refresh_suggestions(&mut tab.content, providers, now))
+            .unwrap_or(false),
+        Widget::Autocomplete { value, suggestions, provider: Some(provider), debounce_ms, suggest_state, .. } => {
+            let Some(source) = providers.get(provider) else {
+                return false;
+            };
+            if suggest_state.query.as_deref() != Some(value.as_str()) {
+                suggest_state.query = Some(value.clone());
+                suggest_state.changed_at = Some(now);
+                suggest_state.dirty = true;
+            }
+            if !suggest_state.dirty {
+                return false;
+            }
+            let debounce = Duration::from_millis(*debounce_ms);
+            if suggest_state.changed_at.is_some_and(|at| now.duration_since(at) < debounce) {
+                return false;
+            }
+
+            *suggestions = source.suggest(value);
+            suggest_state.pending = source.is_pending(value);
+            // Keep polling a pending provider until its results arrive.
+            suggest_state.dirty = suggest_state.pending;
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::{AutocompleteStyle, WidgetBounds};
+
+    fn autocomplete(value: &str, debounce_ms: u64) -> Widget {
+        Widget::Autocomplete {
+            id: "city".into(),
+            value: value.into(),
+            placeholder: String::new(),
+            suggestions: Vec::new(),
+            provider: Some("cities".into()),
+            debounce_ms,
+            suggest_state: SuggestionState::default(),
+            fuzzy: false,
+            max_visible: 5,
+            bounds: WidgetBounds::default(),
+            style: AutocompleteStyle::default(),
+            validation: None,
+            width: 0.0,
+            height: 0.0,
+            flex: 0.0,
+            grid_col: None,
+            grid_row: None,
+            col_span: 1,
+            row_span: 1,
+            grid_area: None,
+            align_self: Default::default(), justify_self: Default::default(),
+            order: 0,
+        }
+    }
+
+    fn suggestions(widget: &Widget) -> &[String] {
+        match widget {
+            Widget::Autocomplete { suggestions, .. } => suggestions,
+            _ => unreachable!(),
+        }
+    }
+
+    fn set_value(widget: &mut Widget, new_value: &str) {
+        if let Widget::Autocomplete { value, .. } = widget {
+            *value = new_value.into();
+        }
+    }
+
+    fn providers() -> SuggestionProviders {
+        let mut providers = SuggestionProviders::new();
+        providers.register("cities", vec!["Berlin".to_string(), "Bern".to_string(), "Paris".to_string()]);
+        providers
+    }
+
+    #[test]
+    fn test_static_list_filters() {
+        let list = vec!["Berlin".to_string(), "Paris".to_string()];
+        assert_eq!(list.suggest("ber"), vec!["Berlin".to_string()]);
+        assert_eq!(list.suggest("").len(), 2);
+
+        let fuzzy = FuzzySuggestions(vec!["Cumberland".to_string(), "Berlin".to_string()]);
+        assert_eq!(fuzzy.suggest("ber"), vec!["Berlin".to_string(), "Cumberland".to_string()]);
+    }
+
+    #[test]
+    fn test_refresh_queries_on_change() {
+        let providers = providers();
+        let mut widget = autocomplete("ber", 0);
+        let now = Instant::now();
+
+        assert!(refresh_suggestions(&mut widget, &providers, now));
+        assert_eq!(suggestions(&widget), ["Berlin", "Bern"]);
+        // Unchanged value: nothing to do.
+        assert!(!refresh_suggestions(&mut widget, &providers, now));
+
+        set_value(&mut widget, "par");
+        assert!(refresh_suggestions(&mut widget, &providers, now));
+        assert_eq!(suggestions(&widget), ["Paris"]);
+    }
+
+    #[test]
+    fn test_refresh_debounces() {
+        let providers = providers();
+        let mut widget = autocomplete("ber", 200);
+        let start = Instant::now();
+
+        assert!(!refresh_suggestions(&mut widget, &providers, start));
+        assert!(suggestions(&widget).is_empty());
+
+        // Typing again restarts the debounce.
+        set_value(&mut widget, "bern");
+        assert!(!refresh_suggestions(&mut widget, &providers, start + Duration::from_millis(150)));
+        assert!(!refresh_suggestions(&mut widget, &providers, start + Duration::from_millis(300)));
+        assert!(refresh_suggestions(&mut widget, &providers, start + Duration::from_millis(350)));
+        assert_eq!(suggestions(&widget), ["Bern"]);
+    }
+}
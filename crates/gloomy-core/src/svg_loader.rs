@@ -11,6 +11,39 @@ pub fn load_svg_texture(
     width: u32,
     height: u32,
 ) -> Result<Texture> {
+    let pixmap = rasterize(svg_data, width, height)?;
+    Texture::from_rgba(device, queue, pixmap.data(), width, height, Some("IconTexture"))
+}
+
+/// Like [`load_svg_texture`], but keeps only the SVG's coverage: every
+/// pixel is white with the shape's alpha, so an image tint colors the
+/// whole icon regardless of the fills in the file.
+pub fn load_svg_mask(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    svg_data: &[u8],
+    width: u32,
+    height: u32,
+) -> Result<Texture> {
+    let pixmap = rasterize(svg_data, width, height)?;
+    let mut mask = pixmap.data().to_vec();
+    for px in mask.chunks_exact_mut(4) {
+        px[0] = 255;
+        px[1] = 255;
+        px[2] = 255;
+    }
+    Texture::from_rgba(device, queue, &mask, width, height, Some("IconMask"))
+}
+
+/// Checks that `svg_data` parses, without rasterizing it.
+pub fn validate_svg(svg_data: &[u8]) -> Result<()> {
+    Tree::from_data(svg_data, &Options::default()).context("Failed to parse SVG")?;
+    Ok(())
+}
+
+/// Renders the SVG scaled to fit (contain) and centered in a
+/// `width` x `height` pixmap.
+fn rasterize(svg_data: &[u8], width: u32, height: u32) -> Result<Pixmap> {
     let opt = Options::default();
     let mut fontdb = usvg::fontdb::Database::new();
     fontdb.load_system_fonts();
@@ -36,7 +69,5 @@ pub fn load_svg_texture(
     let rtree = resvg::Tree::from_usvg(&tree);
     rtree.render(transform, &mut pixmap.as_mut());
     
-    Texture::from_rgba(device, queue, pixmap.data(), width, height, Some("IconTexture"))
+    Ok(pixmap)
 }
-
-
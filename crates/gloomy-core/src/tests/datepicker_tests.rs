@@ -0,0 +1,131 @@
+use crate::interaction::InteractionState;
+use crate::rect::Rect;
+use crate::ui::{handle_interactions, hit_test, parse_ui};
+use crate::widget::Widget;
+use crate::Vec2;
+use chrono::{Datelike, NaiveDate, Weekday};
+
+const RANGE_PICKER: &str = r#"
+DatePicker(
+    id: "stay",
+    range: true,
+    max_date: Some("2024-05-30"),
+    bounds: (x: 0.0, y: 0.0, width: 200.0, height: 30.0),
+)
+"#;
+
+fn click(root: &mut Widget, action: &str) -> bool {
+    let state = InteractionState {
+        clicked_id: Some(action.to_string()),
+        ..Default::default()
+    };
+    handle_interactions(root, &state, Vec2::ZERO)
+}
+
+fn range(root: &Widget) -> (Option<NaiveDate>, Option<NaiveDate>) {
+    match root {
+        Widget::DatePicker { start, end, .. } => (*start, *end),
+        _ => unreachable!(),
+    }
+}
+
+fn date(s: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
+}
+
+#[test]
+fn test_range_picks_start_then_end() {
+    let mut root = parse_ui(RANGE_PICKER).unwrap();
+
+    assert!(click(&mut root, "stay:day:2024-05-10"));
+    assert_eq!(range(&root), (date("2024-05-10"), None));
+
+    assert!(click(&mut root, "stay:day:2024-05-14"));
+    assert_eq!(range(&root), (date("2024-05-10"), date("2024-05-14")));
+
+    // A third click starts over.
+    assert!(click(&mut root, "stay:day:2024-05-20"));
+    assert_eq!(range(&root), (date("2024-05-20"), None));
+}
+
+#[test]
+fn test_range_swaps_earlier_end_and_respects_bounds() {
+    let mut root = parse_ui(RANGE_PICKER).unwrap();
+
+    click(&mut root, "stay:day:2024-05-14");
+    click(&mut root, "stay:day:2024-05-03");
+    assert_eq!(range(&root), (date("2024-05-03"), date("2024-05-14")));
+
+    // Days after max_date are ignored.
+    assert!(!click(&mut root, "stay:day:2024-06-02"));
+    assert_eq!(range(&root), (date("2024-05-03"), date("2024-05-14")));
+}
+
+fn first_column_days(week_start: &str) -> Vec<NaiveDate> {
+    let src = format!(
+        r#"DatePicker(
+            id: "dp",
+            value: Some("2024-05-15"),
+            week_start: "{}",
+            bounds: (x: 0.0, y: 0.0, width: 200.0, height: 30.0),
+        )"#,
+        week_start
+    );
+    let root = parse_ui(&src).unwrap();
+    let state = InteractionState {
+        focused_id: Some("dp".to_string()),
+        ..Default::default()
+    };
+
+    // The calendar opens at y = 32; its grid starts 5 + 30 + 28 below.
+    let cell_w = (250.0 - 10.0) / 7.0;
+    (0..6)
+        .map(|row| {
+            let point = Vec2::new(5.0 + cell_w * 0.5, 95.0 + 30.0 * (row as f32 + 0.5));
+            let hit = hit_test(&root, point, Some(&state)).unwrap();
+            let day = hit.action.strip_prefix("dp:day:").unwrap();
+            NaiveDate::parse_from_str(day, "%Y-%m-%d").unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_sunday_week_start_puts_sundays_in_first_column() {
+    let days = first_column_days("Sun");
+    // May 2024 starts on a Wednesday, so the grid opens on April 28th.
+    assert_eq!(days[0], NaiveDate::from_ymd_opt(2024, 4, 28).unwrap());
+    assert!(days.iter().all(|d| d.weekday() == Weekday::Sun));
+
+    let days = first_column_days("Mon");
+    assert_eq!(days[0], NaiveDate::from_ymd_opt(2024, 4, 29).unwrap());
+    assert!(days.iter().all(|d| d.weekday() == Weekday::Mon));
+}
+
+#[test]
+fn test_calendar_flips_above_anchor_near_bottom_edge() {
+    // The input ends 30px above the bottom of a 600px surface, far too
+    // close for the 248px calendar to open below it.
+    let root = parse_ui(
+        r#"DatePicker(
+            id: "dp",
+            value: Some("2024-05-15"),
+            bounds: (x: 0.0, y: 540.0, width: 200.0, height: 30.0),
+        )"#,
+    )
+    .unwrap();
+    let state = InteractionState {
+        focused_id: Some("dp".to_string()),
+        ..Default::default()
+    };
+    state.overlay_area.set(Some(Rect::from_size(800.0, 600.0)));
+
+    // Flipped, the calendar ends 2px above the input at y = 538 and
+    // starts at 290; its grid starts 5 + 30 + 28 below that.
+    let cell_w = (250.0 - 10.0) / 7.0;
+    let hit = hit_test(&root, Vec2::new(5.0 + cell_w * 0.5, 353.0 + 15.0), Some(&state)).unwrap();
+    assert_eq!(hit.action, "dp:day:2024-04-29");
+
+    // Nothing is left below the input.
+    let below = hit_test(&root, Vec2::new(100.0, 590.0), Some(&state));
+    assert!(below.is_none());
+}
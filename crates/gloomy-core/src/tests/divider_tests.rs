@@ -0,0 +1,27 @@
+use crate::ui::divider_lines;
+use crate::Vec2;
+
+#[test]
+fn test_divider_lines_stay_inside_bounds() {
+    let pos = Vec2::new(10.0, 20.0);
+    let size = Vec2::new(200.0, 16.0);
+
+    // Unlabeled: one line across the band, centered vertically.
+    let lines = divider_lines(pos, size, 2.0, None);
+    assert_eq!(lines, vec![(Vec2::new(110.0, 28.0), Vec2::new(100.0, 1.0))]);
+
+    // A 40px label leaves (200 - 40) / 2 - 8 = 72px on either side.
+    let lines = divider_lines(pos, size, 2.0, Some(40.0));
+    assert_eq!(lines, vec![
+        (Vec2::new(46.0, 28.0), Vec2::new(36.0, 1.0)),
+        (Vec2::new(174.0, 28.0), Vec2::new(36.0, 1.0)),
+    ]);
+    let (left, right) = (lines[0], lines[1]);
+    assert_eq!(left.0.x - left.1.x, pos.x);
+    assert_eq!(right.0.x + right.1.x, pos.x + size.x);
+    // The gap around the label stays clear.
+    assert_eq!(right.0.x - right.1.x - (left.0.x + left.1.x), 40.0 + 16.0);
+
+    // No room for lines beside a wide label.
+    assert!(divider_lines(pos, size, 2.0, Some(190.0)).is_empty());
+}
@@ -0,0 +1,85 @@
+use crate::interaction::InteractionState;
+use crate::ui::{activate_button_key, get_tab_order, parse_ui, release_focus_trap};
+use winit::keyboard::{Key, NamedKey};
+
+const DIALOG: &str = r#"
+    Container(
+        children: [
+            TextInput(id: "search"),
+            Container(
+                id: Some("dialog"),
+                focus_trap: true,
+                children: [
+                    TextInput(id: "name"),
+                    Container(
+                        id: Some("picker"),
+                        focus_trap: true,
+                        children: [TextInput(id: "color")],
+                    ),
+                    TextInput(id: "email"),
+                ],
+            ),
+        ],
+    )
+"#;
+
+#[test]
+fn test_tab_stays_inside_focus_trap() {
+    let root = parse_ui(DIALOG).unwrap();
+    let mut state = InteractionState::default();
+
+    // Focus outside any trap: the dialog takes it.
+    state.set_focus(Some("search".to_string()));
+    assert_eq!(get_tab_order(&root, &state), vec!["name", "color", "email"]);
+    state.focus_next(&get_tab_order(&root, &state));
+    assert_eq!(state.focused_id.as_deref(), Some("name"));
+
+    // Cycling wraps within the dialog.
+    state.set_focus(Some("email".to_string()));
+    state.focus_next(&get_tab_order(&root, &state));
+    assert_eq!(state.focused_id.as_deref(), Some("name"));
+
+    // The innermost trap around focus wins.
+    state.set_focus(Some("color".to_string()));
+    assert_eq!(get_tab_order(&root, &state), vec!["color"]);
+}
+
+#[test]
+fn test_escape_releases_innermost_trap() {
+    let root = parse_ui(DIALOG).unwrap();
+    let mut state = InteractionState::default();
+    state.set_focus(Some("color".to_string()));
+
+    assert!(release_focus_trap(&root, &mut state));
+    assert_eq!(get_tab_order(&root, &state), vec!["name", "color", "email"]);
+
+    assert!(release_focus_trap(&root, &mut state));
+    assert_eq!(get_tab_order(&root, &state), vec!["search", "name", "color", "email"]);
+    assert!(!release_focus_trap(&root, &mut state));
+
+    // Re-arming is up to the app.
+    state.released_traps.remove("dialog");
+    assert_eq!(get_tab_order(&root, &state), vec!["name", "color", "email"]);
+}
+
+#[test]
+fn test_enter_and_space_activate_a_focused_button() {
+    let button = parse_ui(r#"Button(text: "Save", action: "save")"#).unwrap();
+    let mut state = InteractionState::default();
+    for key in [NamedKey::Enter, NamedKey::Space] {
+        let outcome = activate_button_key(&button, &mut state, &Key::Named(key)).unwrap();
+        assert!(outcome.changed);
+        assert_eq!(outcome.activated.as_deref(), Some("save"));
+    }
+    assert!(activate_button_key(&button, &mut state, &Key::Named(NamedKey::Tab)).is_none());
+    assert!(activate_button_key(&button, &mut state, &Key::Character("a".into())).is_none());
+}
+
+#[test]
+fn test_disabled_button_consumes_the_key_without_activating() {
+    let button = parse_ui(r#"Button(text: "Save", action: "save", disabled: true)"#).unwrap();
+    let mut state = InteractionState::default();
+    let outcome = activate_button_key(&button, &mut state, &Key::Named(NamedKey::Space)).unwrap();
+    assert!(!outcome.changed);
+    assert_eq!(outcome.activated, None);
+}
@@ -0,0 +1,630 @@
+use crate::interaction::{CursorHint, InteractionState};
+use crate::ui::{hit_test, hit_test_all, parse_ui};
+use crate::Vec2;
+
+/// A scrolled container holding a DatePicker followed by a button that
+/// sits underneath where the calendar overlay opens.
+const SCROLLED_PICKER: &str = r#"
+Container(
+    id: Some("scroll"),
+    scrollable: true,
+    bounds: (x: 0.0, y: 0.0, width: 300.0, height: 150.0),
+    children: [
+        DatePicker(
+            id: "dp",
+            value: Some("2024-05-15"),
+            bounds: (x: 10.0, y: 110.0, width: 200.0, height: 30.0),
+        ),
+        Button(
+            text: "Below",
+            action: "below",
+            bounds: (x: 0.0, y: 150.0, width: 300.0, height: 100.0),
+        ),
+    ],
+)
+"#;
+
+fn scrolled_state(focused: bool) -> InteractionState {
+    let mut state = InteractionState::default();
+    state.scroll_offsets.insert("scroll".to_string(), Vec2::new(0.0, 100.0));
+    if focused {
+        state.focused_id = Some("dp".to_string());
+    }
+    state
+}
+
+#[test]
+fn test_datepicker_days_hit_inside_scrolled_container() {
+    let root = parse_ui(SCROLLED_PICKER).unwrap();
+    let state = scrolled_state(true);
+
+    // Input is drawn at y = 110 - 100 = 10; the calendar opens at y = 42 and
+    // its day grid starts at 42 + 5 + 30 + 28 = 105. May 2024 starts on a
+    // Wednesday, so May 1st is in column 2 of the first row.
+    let cell_w = (250.0 - 10.0) / 7.0;
+    let first = Vec2::new(10.0 + 5.0 + cell_w * 2.5, 105.0 + 15.0);
+    let hit = hit_test(&root, first, Some(&state)).unwrap();
+    assert_eq!(hit.action, "dp:day:2024-05-01");
+
+    // Row 2 lies below the container's clip rect but the overlay is drawn
+    // unclipped, so it must still be hittable.
+    let outside = Vec2::new(10.0 + 5.0 + cell_w * 0.5, 105.0 + 30.0 * 2.5);
+    let hit = hit_test(&root, outside, Some(&state)).unwrap();
+    assert_eq!(hit.action, "dp:day:2024-05-13");
+}
+
+#[test]
+fn test_datepicker_header_and_input_hits() {
+    let root = parse_ui(SCROLLED_PICKER).unwrap();
+    let state = scrolled_state(true);
+
+    let prev = hit_test(&root, Vec2::new(20.0, 60.0), Some(&state)).unwrap();
+    assert_eq!(prev.action, "dp:prev");
+
+    let input = hit_test(&root, Vec2::new(50.0, 20.0), Some(&state)).unwrap();
+    assert_eq!(input.action, "dp");
+}
+
+#[test]
+fn test_closed_calendar_does_not_shadow_siblings() {
+    let root = parse_ui(SCROLLED_PICKER).unwrap();
+    let state = scrolled_state(false);
+
+    let hit = hit_test(&root, Vec2::new(100.0, 120.0), Some(&state)).unwrap();
+    assert_eq!(hit.action, "below");
+}
+
+#[test]
+fn test_overflow_hidden_clips_without_scrolling() {
+    let root = parse_ui(r#"
+        Container(
+            id: Some("clip"),
+            overflow: Hidden,
+            bounds: (x: 0.0, y: 0.0, width: 100.0, height: 50.0),
+            children: [
+                Button(
+                    text: "Wide",
+                    action: "wide",
+                    bounds: (x: 0.0, y: 0.0, width: 200.0, height: 40.0),
+                ),
+            ],
+        )
+    "#).unwrap();
+
+    // A stale scroll offset must not shift a non-scrolling container.
+    let mut state = InteractionState::default();
+    state.scroll_offsets.insert("clip".to_string(), Vec2::new(0.0, 30.0));
+
+    assert_eq!(hit_test(&root, Vec2::new(50.0, 20.0), Some(&state)).unwrap().action, "wide");
+    assert!(hit_test(&root, Vec2::new(150.0, 20.0), Some(&state)).is_none());
+}
+
+#[test]
+fn test_clip_to_shape_requires_corner_radius() {
+    let src = |radius: f32| format!(r#"
+        Container(
+            clip_to_shape: true,
+            style: (corner_radii: ({r:.1}, {r:.1}, {r:.1}, {r:.1})),
+            bounds: (x: 0.0, y: 0.0, width: 100.0, height: 50.0),
+            children: [
+                Button(
+                    text: "Wide",
+                    action: "wide",
+                    bounds: (x: 0.0, y: 0.0, width: 200.0, height: 40.0),
+                ),
+            ],
+        )
+    "#, r = radius);
+
+    let rounded = parse_ui(&src(8.0)).unwrap();
+    assert!(hit_test(&rounded, Vec2::new(150.0, 20.0), None).is_none());
+
+    // Square corners: the flag alone does not clip.
+    let square = parse_ui(&src(0.0)).unwrap();
+    assert_eq!(hit_test(&square, Vec2::new(150.0, 20.0), None).unwrap().action, "wide");
+}
+
+#[test]
+fn test_z_index_orders_overlapping_siblings() {
+    let card = |action: &str, z: i32| format!(r#"
+        Container(
+            z_index: {z},
+            bounds: (x: 0.0, y: 0.0, width: 100.0, height: 50.0),
+            children: [
+                Button(
+                    text: "{action}",
+                    action: "{action}",
+                    bounds: (x: 0.0, y: 0.0, width: 100.0, height: 50.0),
+                ),
+            ],
+        )"#);
+    let root_src = |a: String, b: String, c: String| format!(r#"
+        Container(
+            bounds: (x: 0.0, y: 0.0, width: 100.0, height: 50.0),
+            children: [{a}, {b}, {c}],
+        )
+    "#);
+
+    // Raised card wins even though it is declared first.
+    let root = parse_ui(&root_src(card("raised", 1), card("first", 0), card("second", 0))).unwrap();
+    assert_eq!(hit_test(&root, Vec2::new(50.0, 25.0), None).unwrap().action, "raised");
+
+    // Equal z-index: the later declaration is on top.
+    let root = parse_ui(&root_src(card("first", 0), card("second", 0), card("lowered", -1))).unwrap();
+    assert_eq!(hit_test(&root, Vec2::new(50.0, 25.0), None).unwrap().action, "second");
+}
+
+#[test]
+fn test_z_ordered_only_sorts_out_of_order_siblings() {
+    use crate::widget::{z_ordered, Widget, ZOrdered};
+    let children = |zs: [i32; 3]| -> Vec<Widget> {
+        zs.iter().map(|z| parse_ui(&format!("Container(z_index: {z})")).unwrap()).collect()
+    };
+
+    let flat = children([0, 0, 0]);
+    assert!(matches!(z_ordered(&flat), ZOrdered::Declared(_)));
+
+    let raised = children([2, 0, 1]);
+    assert!(matches!(z_ordered(&raised), ZOrdered::Sorted(_)));
+    let zs: Vec<i32> = z_ordered(&raised).rev().map(|child| child.z_index()).collect();
+    assert_eq!(zs, [2, 1, 0]);
+}
+
+#[test]
+fn test_hit_test_all_lists_overlaps_top_to_bottom() {
+    let root = parse_ui(r#"
+        Container(
+            bounds: (x: 0.0, y: 0.0, width: 200.0, height: 100.0),
+            children: [
+                Container(
+                    z_index: 2,
+                    bounds: (x: 50.0, y: 0.0, width: 100.0, height: 100.0),
+                    children: [
+                        Button(text: "Raised", action: "raised", bounds: (x: 0.0, y: 0.0, width: 100.0, height: 100.0)),
+                    ],
+                ),
+                Button(text: "Back", action: "back", bounds: (x: 0.0, y: 0.0, width: 200.0, height: 100.0)),
+                Button(text: "Front", action: "front", order: -1, bounds: (x: 0.0, y: 0.0, width: 100.0, height: 100.0)),
+            ],
+        )
+    "#).unwrap();
+    let actions = |x: f32| -> Vec<String> {
+        hit_test_all(&root, Vec2::new(x, 50.0), None).into_iter().map(|h| h.action).collect()
+    };
+
+    // z_index first, then the later declaration; `order` only affects layout.
+    assert_eq!(actions(75.0), ["raised", "front", "back"]);
+    assert_eq!(actions(25.0), ["front", "back"]);
+    assert_eq!(actions(175.0), ["back"]);
+    assert!(actions(250.0).is_empty());
+
+    for x in [25.0, 75.0, 175.0] {
+        let top = hit_test(&root, Vec2::new(x, 50.0), None).unwrap().action;
+        assert_eq!(top, actions(x)[0]);
+    }
+}
+
+#[test]
+fn test_expanded_dropdown_options_hit_over_siblings() {
+    let src = r#"
+        Container(
+            bounds: (x: 0.0, y: 0.0, width: 300.0, height: 300.0),
+            children: [
+                Container(
+                    bounds: (x: 20.0, y: 10.0, width: 200.0, height: 40.0),
+                    children: [
+                        Dropdown(
+                            id: "size",
+                            options: ["Small", "Medium", "Large"],
+                            expanded: true,
+                            bounds: (x: 0.0, y: 0.0, width: 150.0, height: 30.0),
+                        ),
+                    ],
+                ),
+                Button(text: "Under", action: "under", bounds: (x: 0.0, y: 50.0, width: 300.0, height: 100.0)),
+            ],
+        )
+    "#;
+    let root = parse_ui(src).unwrap();
+    let mut state = InteractionState::default();
+    state.overlay_area.set(Some(crate::rect::Rect::from_size(300.0, 300.0)));
+    let action = |x: f32, y: f32| hit_test(&root, Vec2::new(x, y), Some(&state)).map(|h| h.action);
+
+    // The list opens below the field at (20, 40), one 30px row per option.
+    assert_eq!(action(50.0, 60.0).as_deref(), Some("select_size_0"));
+    assert_eq!(action(50.0, 105.0).as_deref(), Some("select_size_2"));
+    assert_eq!(action(50.0, 135.0).as_deref(), Some("under"));
+    assert_eq!(action(50.0, 20.0).as_deref(), Some("size"));
+
+    // Collapsed, the button underneath gets the click.
+    let collapsed = parse_ui(&src.replace("expanded: true", "expanded: false")).unwrap();
+    assert_eq!(hit_test(&collapsed, Vec2::new(50.0, 60.0), Some(&state)).unwrap().action, "under");
+}
+
+#[test]
+fn test_dropdown_opens_selects_and_closes() {
+    let mut root = parse_ui(r#"
+        Container(
+            bounds: (x: 0.0, y: 0.0, width: 300.0, height: 300.0),
+            children: [
+                Dropdown(id: "size", options: ["Small", "Medium", "Large"], bounds: (x: 0.0, y: 0.0, width: 150.0, height: 30.0)),
+                Button(text: "Elsewhere", action: "elsewhere", bounds: (x: 200.0, y: 0.0, width: 100.0, height: 30.0)),
+            ],
+        )
+    "#).unwrap();
+    let mut state = InteractionState::default();
+    state.overlay_area.set(Some(crate::rect::Rect::from_size(300.0, 300.0)));
+    // A press as apps report it: the hit is both clicked and active.
+    let mut click = |root: &mut crate::widget::Widget, x: f32, y: f32| {
+        state.is_pressed = true;
+        state.clicked_id = hit_test(root, Vec2::new(x, y), Some(&state)).map(|h| h.action);
+        state.active_action = state.clicked_id.clone();
+        crate::ui::handle_interactions(root, &state, Vec2::ZERO)
+    };
+    let dropdown = |root: &crate::widget::Widget| match root {
+        crate::widget::Widget::Container { children, .. } => match &children[0] {
+            crate::widget::Widget::Dropdown { selected_index, expanded, .. } => (*selected_index, *expanded),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+
+    assert!(click(&mut root, 50.0, 15.0));
+    assert_eq!(dropdown(&root), (None, true));
+    // Third option: rows are 30px from y = 30, so it spans 90..120.
+    assert!(click(&mut root, 50.0, 105.0));
+    assert_eq!(dropdown(&root), (Some(2), false));
+
+    // Opening and clicking elsewhere keeps the selection.
+    assert!(click(&mut root, 50.0, 15.0));
+    assert!(click(&mut root, 250.0, 15.0));
+    assert_eq!(dropdown(&root), (Some(2), false));
+    assert!(!click(&mut root, 250.0, 15.0));
+
+    // So does a press on bare background, which hits nothing.
+    assert!(click(&mut root, 50.0, 15.0));
+    assert!(click(&mut root, 150.0, 250.0));
+    assert_eq!(dropdown(&root), (Some(2), false));
+}
+
+#[test]
+fn test_dropdown_stays_open_while_its_press_is_held() {
+    let mut root = parse_ui(r#"
+        Container(children: [
+            Dropdown(id: "size", options: ["Small", "Large"], expanded: true, bounds: (x: 0.0, y: 0.0, width: 150.0, height: 30.0)),
+        ])
+    "#).unwrap();
+    // The frame after opening: the click is consumed but the button is
+    // still down on the field.
+    let mut state = InteractionState::default();
+    state.is_pressed = true;
+    state.active_action = Some("size".into());
+    assert!(!crate::ui::handle_interactions(&mut root, &state, Vec2::ZERO));
+}
+
+#[test]
+fn test_toggle_switch_flips_on_click() {
+    let mut root = parse_ui(r#"
+        Container(children: [
+            ToggleSwitch(id: "wifi", checked: false, bounds: (x: 0.0, y: 0.0, width: 50.0, height: 24.0)),
+        ])
+    "#).unwrap();
+    let checked = |root: &crate::widget::Widget| match root {
+        crate::widget::Widget::Container { children, .. } => matches!(children[0], crate::widget::Widget::ToggleSwitch { checked: true, .. }),
+        _ => unreachable!(),
+    };
+
+    let mut state = InteractionState::default();
+    state.clicked_id = hit_test(&root, Vec2::new(25.0, 12.0), None).map(|h| h.action);
+    assert!(crate::ui::handle_interactions(&mut root, &state, Vec2::ZERO));
+    assert!(checked(&root));
+    assert!(crate::ui::handle_interactions(&mut root, &state, Vec2::ZERO));
+    assert!(!checked(&root));
+
+    // With an animation the thumb slides from off to on.
+    use crate::ui::toggle_thumb_position;
+    assert_eq!(toggle_thumb_position(true, Some(1.0), 0.25, 1.125), 0.5);
+    assert_eq!(toggle_thumb_position(true, Some(1.0), 0.25, 2.0), 1.0);
+    assert_eq!(toggle_thumb_position(false, Some(1.0), 0.25, 1.0625), 0.75);
+    assert_eq!(toggle_thumb_position(true, Some(1.0), 0.0, 1.0), 1.0);
+}
+
+#[test]
+fn test_toggle_switch_reports_animation_until_settled() {
+    let src = |secs: f32| format!(r#"
+        Container(children: [
+            ToggleSwitch(id: "wifi", checked: false, style: (animation_secs: {secs:?}),
+                bounds: (x: 0.0, y: 0.0, width: 50.0, height: 24.0)),
+        ])
+    "#);
+    let mut state = InteractionState::default();
+    for (secs, animating) in [(60.0, true), (0.0, false)] {
+        let mut root = parse_ui(&src(secs)).unwrap();
+        assert!(!root.is_animating());
+        state.clicked_id = hit_test(&root, Vec2::new(25.0, 12.0), None).map(|h| h.action);
+        assert!(crate::ui::handle_interactions(&mut root, &state, Vec2::ZERO));
+        assert_eq!(root.is_animating(), animating);
+    }
+}
+
+#[test]
+fn test_stepper_allows_back_navigation_only() {
+    let src = |allow_skip: bool| format!(r#"
+        Stepper(
+            id: "wiz",
+            steps: ["Account", "Billing", "Confirm"],
+            current: 1,
+            allow_skip: {allow_skip},
+            bounds: (x: 0.0, y: 0.0, width: 300.0, height: 60.0),
+        )
+    "#);
+
+    let root = parse_ui(&src(false)).unwrap();
+    assert_eq!(hit_test(&root, Vec2::new(50.0, 20.0), None).unwrap().action, "wiz:step:0");
+    assert_eq!(hit_test(&root, Vec2::new(150.0, 20.0), None).unwrap().action, "wiz:step:1");
+    assert!(hit_test(&root, Vec2::new(250.0, 20.0), None).is_none());
+
+    let root = parse_ui(&src(true)).unwrap();
+    assert_eq!(hit_test(&root, Vec2::new(250.0, 20.0), None).unwrap().action, "wiz:step:2");
+}
+
+#[test]
+fn test_menu_bar_opens_and_hits_items() {
+    let mut root = parse_ui(r#"
+        Container(
+            bounds: (x: 0.0, y: 0.0, width: 400.0, height: 300.0),
+            children: [
+                MenuBar(
+                    id: "menu",
+                    menus: [
+                        (label: "File", items: [Item(label: "Open", action: "open"), Separator, Item(label: "Quit", action: "quit")]),
+                        (label: "Edit", items: [Item(label: "Undo", action: "undo")]),
+                    ],
+                    bounds: (x: 0.0, y: 0.0, width: 400.0, height: 28.0),
+                ),
+            ],
+        )
+    "#).unwrap();
+
+    // "File" is 4 * 14 * 0.6 + 24 = 57.6 wide.
+    let hit = hit_test(&root, Vec2::new(10.0, 10.0), None).unwrap();
+    assert_eq!(hit.action, "menu:menu:0");
+
+    let mut state = InteractionState::default();
+    state.clicked_id = Some("menu:menu:0".into());
+    assert!(crate::ui::handle_interactions(&mut root, &state, Vec2::ZERO));
+
+    // Panel starts below the bar: Open, separator (9px), Quit.
+    assert_eq!(hit_test(&root, Vec2::new(20.0, 40.0), Some(&state)).unwrap().action, "open");
+    assert_eq!(hit_test(&root, Vec2::new(20.0, 60.0), Some(&state)).unwrap().action, "menu");
+    assert_eq!(hit_test(&root, Vec2::new(20.0, 70.0), Some(&state)).unwrap().action, "quit");
+
+    // Choosing an item closes the menu.
+    state.clicked_id = Some("quit".into());
+    assert!(crate::ui::handle_interactions(&mut root, &state, Vec2::ZERO));
+    assert!(hit_test(&root, Vec2::new(20.0, 70.0), Some(&state)).is_none());
+}
+
+#[test]
+fn test_rating_maps_x_to_stars_and_halves() {
+    let src = |read_only: bool| format!(r#"
+        Rating(
+            id: "review",
+            value: 2.0,
+            allow_half: true,
+            read_only: {read_only},
+            bounds: (x: 10.0, y: 0.0, width: 140.0, height: 24.0),
+        )
+    "#);
+
+    // Stars are 24 wide with a 4 gap: star 2 spans x = 10 + 56 .. 10 + 80.
+    let mut root = parse_ui(&src(false)).unwrap();
+    assert_eq!(hit_test(&root, Vec2::new(70.0, 12.0), None).unwrap().action, "review:half_star:2");
+    assert_eq!(hit_test(&root, Vec2::new(85.0, 12.0), None).unwrap().action, "review:star:2");
+    assert!(hit_test(&root, Vec2::new(5.0, 12.0), None).is_none());
+
+    let mut state = InteractionState::default();
+    state.clicked_id = Some("review:half_star:3".into());
+    assert!(crate::ui::handle_interactions(&mut root, &state, Vec2::ZERO));
+    assert!(matches!(root, crate::widget::Widget::Rating { value, .. } if value == 3.5));
+
+    let mut root = parse_ui(&src(true)).unwrap();
+    assert!(hit_test(&root, Vec2::new(85.0, 12.0), None).is_none());
+    assert!(!crate::ui::handle_interactions(&mut root, &state, Vec2::ZERO));
+}
+
+#[test]
+fn test_chip_input_remove_buttons() {
+    let mut root = parse_ui(r#"
+        ChipInput(
+            id: "tags",
+            chips: ["rust", "gpu"],
+            style: (font_size: 10.0),
+            bounds: (x: 0.0, y: 0.0, width: 200.0, height: 28.0),
+        )
+    "#).unwrap();
+
+    // "rust" spans x = 4 .. 52; its remove button is the last 16px.
+    assert_eq!(hit_test(&root, Vec2::new(48.0, 14.0), None).unwrap().action, "tags:remove:0");
+    assert_eq!(hit_test(&root, Vec2::new(10.0, 14.0), None).unwrap().action, "tags");
+    assert_eq!(hit_test(&root, Vec2::new(150.0, 14.0), None).unwrap().action, "tags");
+    assert_eq!(hit_test(&root, Vec2::new(48.0, 14.0), None).unwrap().cursor, CursorHint::Pointer);
+    assert_eq!(hit_test(&root, Vec2::new(150.0, 14.0), None).unwrap().cursor, CursorHint::Text);
+
+    let mut state = InteractionState::default();
+    state.clicked_id = Some("tags:remove:0".into());
+    assert!(crate::ui::handle_interactions(&mut root, &state, Vec2::ZERO));
+    assert!(matches!(&root, crate::widget::Widget::ChipInput { chips, .. } if chips == &["gpu"]));
+}
+
+#[test]
+fn test_cursor_hints_follow_widget_kind() {
+    let root = parse_ui(r#"
+        Container(
+            bounds: (x: 0.0, y: 0.0, width: 200.0, height: 100.0),
+            children: [
+                Button(text: "Save", action: "save", bounds: (x: 0.0, y: 0.0, width: 200.0, height: 30.0)),
+                TextInput(id: "name", bounds: (x: 0.0, y: 40.0, width: 200.0, height: 30.0)),
+            ],
+        )
+    "#).unwrap();
+    assert_eq!(hit_test(&root, Vec2::new(10.0, 10.0), None).unwrap().cursor, CursorHint::Pointer);
+    assert_eq!(hit_test(&root, Vec2::new(10.0, 50.0), None).unwrap().cursor, CursorHint::Text);
+
+    // The calendar's month title only swallows the click.
+    let root = parse_ui(SCROLLED_PICKER).unwrap();
+    let state = scrolled_state(true);
+    let title = hit_test(&root, Vec2::new(130.0, 60.0), Some(&state)).unwrap();
+    assert_eq!((title.action.as_str(), title.cursor), ("dp", CursorHint::Default));
+    let prev = hit_test(&root, Vec2::new(20.0, 60.0), Some(&state)).unwrap();
+    assert_eq!(prev.cursor, CursorHint::Pointer);
+}
+
+#[test]
+fn test_list_view_hit_test_with_mixed_row_heights() {
+    // Rows start at 0, 20, 80 and 110; the last uses the 40px default.
+    let root = parse_ui(r#"
+        ListView(
+            id: "list",
+            items: ["a", "b", "c", "d"],
+            item_heights: [20.0, 60.0, 30.0],
+            bounds: (x: 0.0, y: 0.0, width: 200.0, height: 200.0),
+        )
+    "#).unwrap();
+    let action_at = |y: f32, state: Option<&InteractionState>| {
+        hit_test(&root, Vec2::new(10.0, y), state).unwrap().action.to_string()
+    };
+    assert_eq!(action_at(19.0, None), "list:0");
+    assert_eq!(action_at(70.0, None), "list:1");
+    assert_eq!(action_at(100.0, None), "list:2");
+    assert_eq!(action_at(140.0, None), "list:3");
+    // Below the last row only the list itself is hit.
+    assert_eq!(action_at(160.0, None), "list");
+
+    let mut state = InteractionState::default();
+    state.scroll_offsets.insert("list".to_string(), Vec2::new(0.0, 50.0));
+    assert_eq!(action_at(45.0, Some(&state)), "list:2");
+}
+
+#[test]
+fn test_list_view_item_widgets_take_clicks_in_their_row() {
+    let mut root = parse_ui(r#"
+        ListView(
+            id: "inbox",
+            items: [],
+            item_widgets: [
+                Container(
+                    layout: (direction: Row),
+                    children: [
+                        Label(text: "Welcome", size: 14.0),
+                        Button(text: "Archive", action: "archive:0", width: Some(60.0), height: Some(40.0)),
+                    ],
+                ),
+                Container(children: [Label(text: "Second", size: 14.0)]),
+            ],
+            bounds: (x: 0.0, y: 0.0, width: 200.0, height: 200.0),
+        )
+    "#).unwrap();
+    crate::layout_engine::compute_layout(&mut root, 0.0, 0.0, 200.0, 200.0);
+
+    let action_at = |x: f32, y: f32| hit_test(&root, Vec2::new(x, y), None).unwrap().action.to_string();
+    // The label is not interactive, so the row itself is hit.
+    assert_eq!(action_at(5.0, 20.0), "inbox:0");
+    assert_eq!(action_at(5.0, 60.0), "inbox:1");
+
+    let button_x = match &root {
+        crate::widget::Widget::ListView { item_widgets, .. } => match &item_widgets[0] {
+            crate::widget::Widget::Container { children, .. } => children[1].bounds().x,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(action_at(button_x + 5.0, 20.0), "archive:0");
+}
+
+#[test]
+fn test_datagrid_hit_test_with_fitted_row_heights() {
+    use crate::data_source::{CellValue, MapDataProvider, VecDataSource};
+    use crate::datagrid::fit_datagrid_rows;
+    use crate::measure::HeuristicMeasurer;
+
+    let mut root = parse_ui(r#"
+        DataGrid(
+            id: Some("notes"),
+            columns: [(header: "Note", field: "note", width: Fixed(200.0))],
+            data_source_id: Some("notes"),
+            header_height: 40.0,
+            row_height: 32.0,
+            auto_row_height: true,
+            bounds: (x: 0.0, y: 0.0, width: 200.0, height: 300.0),
+        )
+    "#).unwrap();
+    let mut provider = MapDataProvider::new();
+    provider.register("notes", VecDataSource::new(
+        vec!["note".into()],
+        vec![
+            vec![CellValue::Text("one\ntwo\nthree\nfour".into())],
+            vec![CellValue::Text("short".into())],
+        ],
+    ));
+    assert!(fit_datagrid_rows(&mut root, &provider, &HeuristicMeasurer));
+    assert!(!fit_datagrid_rows(&mut root, &provider, &HeuristicMeasurer));
+
+    // Row 0 is 4 × 13 + 16 = 68px tall, so y = 40 + 80 is in row 1.
+    let action_at = |y: f32| hit_test(&root, Vec2::new(10.0, y), None).unwrap().action.to_string();
+    assert_eq!(action_at(40.0 + 60.0), "notes:cell:0:0");
+    assert_eq!(action_at(40.0 + 80.0), "notes:cell:1:0");
+    // Past the last row.
+    assert_eq!(action_at(40.0 + 120.0), "notes");
+}
+
+#[test]
+fn test_order_reorders_row_and_hit_test() {
+    let button = |action: &str, order: i32| format!(r#"
+        Button(
+            text: "{action}",
+            action: "{action}",
+            order: {order},
+            bounds: (x: 0.0, y: 0.0, width: 50.0, height: 20.0),
+        )"#);
+    let mut root = parse_ui(&format!(r#"
+        Container(
+            layout: (direction: Row),
+            children: [{}, {}, {}],
+        )
+    "#, button("a", 1), button("b", -1), button("c", 1))).unwrap();
+    crate::layout_engine::compute_layout(&mut root, 0.0, 0.0, 300.0, 20.0);
+
+    // `b` moves to the front; `a` and `c` keep declaration order.
+    let hits: Vec<String> = [10.0, 60.0, 110.0].iter()
+        .map(|&x| hit_test(&root, Vec2::new(x, 10.0), None).unwrap().action)
+        .collect();
+    assert_eq!(hits, vec!["b", "a", "c"]);
+}
+
+#[test]
+fn test_hit_padding_expands_small_controls() {
+    let root = parse_ui(r#"
+        Container(
+            bounds: (x: 0.0, y: 0.0, width: 200.0, height: 100.0),
+            children: [
+                Checkbox(id: "a", bounds: (x: 10.0, y: 10.0, width: 16.0, height: 16.0)),
+                Checkbox(id: "b", bounds: (x: 36.0, y: 10.0, width: 16.0, height: 16.0), hit_padding: Some(8.0)),
+                Button(text: "Go", action: "go", bounds: (x: 100.0, y: 10.0, width: 40.0, height: 20.0)),
+            ],
+        )
+    "#).unwrap();
+    let hit = |state: &InteractionState, x: f32, y: f32| hit_test(&root, Vec2::new(x, y), Some(state)).map(|h| h.action);
+
+    // Pixel-precise by default.
+    let mut state = InteractionState::default();
+    assert_eq!(hit(&state, 96.0, 20.0), None);
+    // `b` carries its own padding.
+    assert_eq!(hit(&state, 33.0, 18.0).as_deref(), Some("b"));
+
+    // In the gap both padded checkboxes reach, the later (topmost) one wins.
+    state.hit_padding = 8.0;
+    assert_eq!(hit(&state, 96.0, 20.0).as_deref(), Some("go"));
+    assert_eq!(hit(&state, 30.0, 18.0).as_deref(), Some("b"));
+    assert_eq!(hit(&state, 10.0, 40.0), None);
+}
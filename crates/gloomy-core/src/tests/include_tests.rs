@@ -0,0 +1,75 @@
+use crate::ui::{load_ui, parse_ui_with_base};
+use crate::widget::Widget;
+use std::fs;
+use std::path::PathBuf;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("gloomy_include_{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_include_is_spliced_relative_to_parent() {
+    let dir = scratch_dir("splice");
+    fs::create_dir_all(dir.join("parts")).unwrap();
+    fs::write(
+        dir.join("parts/header.ron"),
+        r#"Label(text: "Header", size: 20.0)"#,
+    ).unwrap();
+    fs::write(
+        dir.join("main.ron"),
+        r#"Container(children: [Include(path: "parts/header.ron")])"#,
+    ).unwrap();
+
+    let root = load_ui(dir.join("main.ron")).unwrap();
+    if let Widget::Container { children, .. } = root {
+        match &children[0] {
+            Widget::Label { text, .. } => assert_eq!(text, "Header"),
+            other => panic!("Include was not resolved: {:?}", other),
+        }
+    } else {
+        panic!("Root is not a container");
+    }
+}
+
+#[test]
+fn test_nested_include_uses_included_file_directory() {
+    let dir = scratch_dir("nested");
+    fs::create_dir_all(dir.join("a/b")).unwrap();
+    fs::write(dir.join("a/b/leaf.ron"), r#"Label(text: "Leaf")"#).unwrap();
+    fs::write(
+        dir.join("a/mid.ron"),
+        r#"Container(children: [Include(path: "b/leaf.ron")])"#,
+    ).unwrap();
+
+    let root = parse_ui_with_base(
+        r#"Container(children: [Include(path: "a/mid.ron")])"#,
+        &dir,
+    ).unwrap();
+
+    if let Widget::Container { children, .. } = root {
+        if let Widget::Container { children: inner, .. } = &children[0] {
+            assert!(matches!(&inner[0], Widget::Label { text, .. } if text == "Leaf"));
+            return;
+        }
+    }
+    panic!("Nested include was not resolved");
+}
+
+#[test]
+fn test_recursive_include_is_rejected() {
+    let dir = scratch_dir("cycle");
+    fs::write(
+        dir.join("a.ron"),
+        r#"Container(children: [Include(path: "b.ron")])"#,
+    ).unwrap();
+    fs::write(
+        dir.join("b.ron"),
+        r#"Container(children: [Include(path: "a.ron")])"#,
+    ).unwrap();
+
+    let err = load_ui(dir.join("a.ron")).unwrap_err();
+    assert!(err.to_string().contains("Recursive include"), "{}", err);
+}
@@ -1,5 +1,5 @@
-use crate::widget::{Widget, WidgetBounds};
-use crate::layout::{Layout, Direction, Justify, Align};
+use crate::widget::{Overflow, Widget, WidgetBounds};
+use crate::layout::{Layout, Direction, Justify, Align, Padding};
 use crate::layout_engine::compute_layout;
 use crate::style::BoxStyle;
 use std::cell::RefCell;
@@ -18,25 +18,42 @@ fn test_container_fixed_size() {
     let child = Widget::Container {
         id: Some("child".into()),
         style: BoxStyle::default(),
+        text_defaults: Default::default(),
+        opacity: 1.0,
+        z_index: 0,
         width: Some(100.0),   // Direct field
         height: Some(200.0),  // Direct field
         layout: Layout::default(),
         children: vec![],
         bounds: Default::default(),
-        padding: 0.0,
+        padding: Padding::uniform(0.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
         scrollable: false,
+        overflow: Overflow::Visible,
+        clip_to_shape: false,
         layout_cache: None,
         render_cache: RefCell::new(None),
+        generation: 0,
         flex: 0.0,
         grid_col: None,
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
     };
 
     let root = Widget::Container {
         id: Some("root".into()),
         style: BoxStyle::default(),
+        text_defaults: Default::default(),
+        opacity: 1.0,
+        z_index: 0,
         width: None,
         height: None,
         layout: Layout {
@@ -45,15 +62,26 @@ fn test_container_fixed_size() {
         },
         children: vec![child],
         bounds: Default::default(),
-        padding: 0.0,
+        padding: Padding::uniform(0.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
         scrollable: false,
+        overflow: Overflow::Visible,
+        clip_to_shape: false,
         layout_cache: None,
         render_cache: RefCell::new(None),
+        generation: 0,
         flex: 0.0,
         grid_col: None,
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
     };
 
     let result = test_layout(root, 800.0, 600.0);
@@ -76,44 +104,75 @@ fn test_flex_row_distribution() {
     let child1 = Widget::Container {
         id: Some("c1".into()),
         style: BoxStyle::default(),
+        text_defaults: Default::default(),
+        opacity: 1.0,
+        z_index: 0,
         width: None,
         height: Some(50.0),
         flex: 1.0,  // Direct field
         layout: Layout::default(),
         children: vec![],
         bounds: Default::default(),
-        padding: 0.0,
+        padding: Padding::uniform(0.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
         scrollable: false,
+        overflow: Overflow::Visible,
+        clip_to_shape: false,
         layout_cache: None,
         render_cache: RefCell::new(None),
+        generation: 0,
         grid_col: None,
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
     };
     
     let child2 = Widget::Container {
         id: Some("c2".into()),
         style: BoxStyle::default(),
+        text_defaults: Default::default(),
+        opacity: 1.0,
+        z_index: 0,
         width: None,
         height: Some(50.0),
         flex: 1.0,  // Direct field
         layout: Layout::default(),
         children: vec![],
         bounds: Default::default(),
-        padding: 0.0,
+        padding: Padding::uniform(0.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
         scrollable: false,
+        overflow: Overflow::Visible,
+        clip_to_shape: false,
         layout_cache: None,
         render_cache: RefCell::new(None),
+        generation: 0,
         grid_col: None,
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
     };
 
     let root = Widget::Container {
         id: Some("root".into()),
         style: BoxStyle::default(),
+        text_defaults: Default::default(),
+        opacity: 1.0,
+        z_index: 0,
         width: None,
         height: None,
         flex: 0.0,
@@ -123,14 +182,25 @@ fn test_flex_row_distribution() {
         },
         children: vec![child1, child2],
         bounds: Default::default(),
-        padding: 0.0,
+        padding: Padding::uniform(0.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
         scrollable: false,
+        overflow: Overflow::Visible,
+        clip_to_shape: false,
         layout_cache: None,
         render_cache: RefCell::new(None),
+        generation: 0,
         grid_col: None,
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
     };
 
     // Layout in 200x100 box
@@ -155,9 +225,257 @@ fn test_flex_row_distribution() {
     }
 }
 
+#[test]
+fn test_skeleton_default_sizes_follow_shape() {
+    let root = crate::ui::parse_ui(r#"
+        Container(
+            layout: (direction: Column, align_items: Start),
+            children: [
+                Skeleton(shape: Line),
+                Skeleton(shape: Circle, height: Some(40.0)),
+                Skeleton(shape: Rect, width: Some(120.0)),
+            ],
+        )
+    "#).unwrap();
+    let result = test_layout(root, 400.0, 300.0);
+    let Widget::Container { children, .. } = result else { panic!("Root is not a container") };
+    let sizes: Vec<(f32, f32)> = children.iter().map(|c| (c.bounds().width, c.bounds().height)).collect();
+    assert_eq!(sizes, vec![(200.0, 12.0), (40.0, 40.0), (120.0, 48.0)]);
+}
+
+#[test]
+fn test_skeleton_shimmer_sweeps_past_both_edges() {
+    use crate::ui::shimmer_offset;
+    assert_eq!(shimmer_offset(0.0, 2.0, 100.0, 40.0), -40.0);
+    assert_eq!(shimmer_offset(1.0, 2.0, 100.0, 40.0), 30.0);
+    // Loops every period.
+    assert_eq!(shimmer_offset(3.0, 2.0, 100.0, 40.0), 30.0);
+}
+
+#[test]
+fn test_breakpoints_switch_layout_with_width() {
+    let ui = r#"
+        Container(
+            layout: (
+                direction: Column,
+                breakpoints: [
+                    (min_width: 900.0, layout: (direction: Row, spacing: 20.0)),
+                    (min_width: 600.0, layout: (direction: Row)),
+                ],
+            ),
+            children: [
+                Container(width: Some(100.0), height: Some(50.0)),
+                Container(width: Some(100.0), height: Some(50.0)),
+            ],
+        )
+    "#;
+    let positions = |root: &Widget| {
+        let Widget::Container { children, .. } = root else { panic!("Root is not a container") };
+        children.iter().map(|c| (get_bounds(c).x, get_bounds(c).y)).collect::<Vec<_>>()
+    };
+
+    let narrow = test_layout(crate::ui::parse_ui(ui).unwrap(), 599.0, 400.0);
+    assert_eq!(positions(&narrow), vec![(0.0, 0.0), (0.0, 50.0)]);
+    let medium = test_layout(crate::ui::parse_ui(ui).unwrap(), 600.0, 400.0);
+    assert_eq!(positions(&medium), vec![(0.0, 0.0), (100.0, 0.0)]);
+    // The largest threshold not above the width wins, whatever the order.
+    let mut wide = test_layout(crate::ui::parse_ui(ui).unwrap(), 1000.0, 400.0);
+    assert_eq!(positions(&wide), vec![(0.0, 0.0), (120.0, 0.0)]);
+
+    // Same parent constraints, but the container shrank below a
+    // threshold: the cached layout must not be reused.
+    if let Widget::Container { bounds, .. } = &mut wide {
+        bounds.width = 500.0;
+    }
+    compute_layout(&mut wide, 0.0, 0.0, 1000.0, 400.0);
+    assert_eq!(positions(&wide), vec![(0.0, 0.0), (0.0, 50.0)]);
+}
+
+#[test]
+fn test_safe_area_insets_root_and_adds_to_padding() {
+    use crate::layout::SafeArea;
+    use crate::layout_engine::compute_root_layout;
+
+    let mut root = crate::ui::parse_ui(r#"
+        Container(
+            padding: 10.0,
+            children: [Container(flex: 1.0)],
+        )
+    "#).unwrap();
+    let safe_area = SafeArea { top: 30.0, right: 0.0, bottom: 20.0, left: 5.0 };
+    compute_root_layout(&mut root, 400.0, 300.0, safe_area);
+
+    let root_bounds = get_bounds(&root);
+    assert_eq!((root_bounds.x, root_bounds.y, root_bounds.width, root_bounds.height), (5.0, 30.0, 395.0, 250.0));
+    let Widget::Container { children, .. } = &root else { panic!("Root is not a container") };
+    // Child positions are relative to the root, which is drawn at its
+    // inset position: the child's content starts at (15, 40) on screen.
+    let child = get_bounds(&children[0]);
+    assert_eq!((child.x, child.y), (10.0, 10.0));
+    assert_eq!(child.height, 230.0);
+
+    // No insets: the root fills the window.
+    compute_root_layout(&mut root, 400.0, 300.0, SafeArea::default());
+    let root_bounds = get_bounds(&root);
+    assert_eq!((root_bounds.x, root_bounds.y, root_bounds.width, root_bounds.height), (0.0, 0.0, 400.0, 300.0));
+}
+
+#[test]
+fn test_per_side_padding_offsets_and_shrinks_content() {
+    let root = crate::ui::parse_ui(r#"
+        Container(
+            padding: [5.0, 20.0, 15.0, 10.0],
+            layout: (direction: Row, align_items: Stretch),
+            children: [Container(flex: 1.0)],
+        )
+    "#).unwrap();
+    let result = test_layout(root, 200.0, 100.0);
+    let Widget::Container { children, .. } = &result else { panic!("Root is not a container") };
+    let child = get_bounds(&children[0]);
+    assert_eq!((child.x, child.y, child.width, child.height), (10.0, 5.0, 170.0, 80.0));
+
+    // A bare number is still the same padding on every side.
+    let root = crate::ui::parse_ui("Container(padding: 8.0)").unwrap();
+    let Widget::Container { padding, .. } = &root else { panic!("Root is not a container") };
+    assert_eq!(*padding, crate::layout::Padding::uniform(8.0));
+    assert_eq!(ron::to_string(padding).unwrap(), "8.0");
+}
+
+#[test]
+fn test_grid_applies_row_and_column_gaps() {
+    let root = crate::ui::parse_ui(r#"
+        Container(
+            layout: (direction: Grid(columns: 2), spacing: 99.0, column_gap: Some(10.0), row_gap: Some(20.0)),
+            children: [
+                Container(width: Some(50.0), height: Some(30.0)),
+                Container(width: Some(50.0), height: Some(30.0)),
+                Container(width: Some(50.0), height: Some(30.0)),
+                Container(width: Some(50.0), height: Some(30.0)),
+            ],
+        )
+    "#).unwrap();
+    let result = test_layout(root, 400.0, 300.0);
+    let Widget::Container { children, .. } = &result else { panic!("Root is not a container") };
+    let positions: Vec<(f32, f32)> = children.iter().map(|c| (get_bounds(c).x, get_bounds(c).y)).collect();
+    assert_eq!(positions, vec![(0.0, 0.0), (60.0, 0.0), (0.0, 50.0), (60.0, 50.0)]);
+}
+
+#[test]
+fn test_grid_named_areas_place_children() {
+    let root = crate::ui::parse_ui(r#"
+        Container(
+            layout: (
+                direction: Grid(columns: 2),
+                align_items: Stretch,
+                template_columns: [Px(100.0), Fr(1.0)],
+                areas: {
+                    "header": (col: 0, row: 0, col_span: 2),
+                    "sidebar": (col: 0, row: 1),
+                    "main": (col: 1, row: 1),
+                },
+            ),
+            children: [
+                Container(height: Some(40.0), grid_area: Some("main")),
+                Container(height: Some(30.0), grid_area: Some("header")),
+                Container(height: Some(50.0), grid_area: Some("sidebar")),
+            ],
+        )
+    "#).unwrap();
+    let result = test_layout(root, 400.0, 300.0);
+    let Widget::Container { children, .. } = &result else { panic!("Root is not a container") };
+    let rects: Vec<(f32, f32, f32)> = children.iter().map(|c| (get_bounds(c).x, get_bounds(c).y, get_bounds(c).width)).collect();
+    assert_eq!(rects, vec![(100.0, 30.0, 300.0), (0.0, 0.0, 400.0), (0.0, 30.0, 100.0)]);
+}
+
+#[test]
+fn test_unknown_grid_areas_are_an_error() {
+    let err = crate::ui::parse_ui(r#"
+        Container(
+            layout: (direction: Grid(columns: 2), areas: {"main": (col: 0, row: 0)}),
+            children: [
+                Label(text: "a", grid_area: Some("main")),
+                Label(text: "b", grid_area: Some("footer")),
+                Container(children: [Label(text: "c", grid_area: Some("aside"))]),
+            ],
+        )
+    "#).unwrap_err();
+    assert_eq!(err.to_string(), "Unknown grid areas: footer, aside");
+}
+
+#[test]
+fn test_align_self_centers_one_child_in_stretched_row() {
+    let root = crate::ui::parse_ui(r#"
+        Container(
+            layout: (direction: Row, align_items: Stretch),
+            children: [
+                Container(width: Some(50.0), height: Some(20.0)),
+                Container(width: Some(50.0), height: Some(20.0), align_self: Center),
+                Container(width: Some(50.0), height: Some(20.0), align_self: End),
+            ],
+        )
+    "#).unwrap();
+    let result = test_layout(root, 300.0, 100.0);
+    let Widget::Container { children, .. } = &result else { panic!("Root is not a container") };
+    let rects: Vec<(f32, f32)> = children.iter().map(|c| (get_bounds(c).y, get_bounds(c).height)).collect();
+    assert_eq!(rects, vec![(0.0, 100.0), (40.0, 20.0), (80.0, 20.0)]);
+}
+
+#[test]
+fn test_justify_self_positions_child_in_grid_cell() {
+    let root = crate::ui::parse_ui(r#"
+        Container(
+            layout: (direction: Grid(columns: 2), align_items: Stretch, template_columns: [Px(100.0), Px(100.0)]),
+            children: [
+                Container(width: Some(40.0), height: Some(20.0)),
+                Container(width: Some(40.0), height: Some(20.0), justify_self: Center),
+            ],
+        )
+    "#).unwrap();
+    let result = test_layout(root, 200.0, 100.0);
+    let Widget::Container { children, .. } = &result else { panic!("Root is not a container") };
+    let rects: Vec<(f32, f32)> = children.iter().map(|c| (get_bounds(c).x, get_bounds(c).width)).collect();
+    assert_eq!(rects, vec![(0.0, 100.0), (130.0, 40.0)]);
+}
+
 fn get_bounds(w: &Widget) -> WidgetBounds {
     match w {
         Widget::Container { bounds, .. } => *bounds,
         _ => panic!("Not a container"),
     }
 }
+
+#[test]
+fn test_flex_spacer_pushes_siblings_apart() {
+    let root = crate::ui::parse_ui(r#"
+        Container(
+            layout: (direction: Row),
+            children: [
+                Button(text: "Back", action: "back", bounds: (x: 0.0, y: 0.0, width: 50.0, height: 20.0)),
+                Spacer(flex: 1.0),
+                Button(text: "Next", action: "next", bounds: (x: 0.0, y: 0.0, width: 50.0, height: 20.0)),
+            ],
+        )
+    "#).unwrap();
+    let result = test_layout(root, 300.0, 20.0);
+    let Widget::Container { children, .. } = &result else { panic!("Root is not a container") };
+    assert_eq!(children[0].bounds().x, 0.0);
+    assert_eq!(children[2].bounds().x, 250.0);
+}
+
+#[test]
+fn test_scrollbar_gutter_narrows_scrolling_content() {
+    let ui = |mode: &str| crate::ui::parse_ui(&format!(r#"
+        Container(
+            scrollable: true,
+            scrollbar_mode: {mode},
+            layout: (direction: Column, align_items: Stretch),
+            children: [Container(height: Some(500.0))],
+        )
+    "#)).unwrap();
+    let width = |root: &Widget| {
+        let Widget::Container { children, .. } = root else { panic!("Root is not a container") };
+        get_bounds(&children[0]).width
+    };
+    assert_eq!(width(&test_layout(ui("Overlay"), 300.0, 200.0)), 300.0);
+    assert_eq!(width(&test_layout(ui("Gutter"), 300.0, 200.0)), 300.0 - crate::scrollbar::SCROLLBAR_WIDTH);
+}
@@ -1,4 +1,11 @@
 #[cfg(test)]
 mod layout_tests;
 mod tab_tests;
-
+mod include_tests;
+mod template_tests;
+mod hit_test_tests;
+mod datepicker_tests;
+mod focus_tests;
+mod render_cache_tests;
+mod scissor_tests;
+mod divider_tests;
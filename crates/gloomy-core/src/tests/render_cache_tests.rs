@@ -0,0 +1,67 @@
+use crate::layout::SafeArea;
+use crate::layout_engine::compute_root_layout;
+use crate::rect::Rect;
+use crate::ui::{parse_ui, static_cache_rect};
+use crate::widget::{RenderCache, Widget};
+use crate::Vec2;
+
+fn cache_of(widget: &Widget) -> Option<Rect> {
+    match widget {
+        Widget::Container { render_cache, .. } => render_cache.borrow().as_ref().map(|cache| cache.rect),
+        _ => None,
+    }
+}
+
+/// Rect the sidebar's snapshot is keyed on: the root draws its children
+/// at its own position, like `render_widget` does.
+fn sidebar_rect(root: &Widget) -> Rect {
+    let Widget::Container { bounds: root_bounds, children, .. } = root else { unreachable!() };
+    static_cache_rect(Vec2::new(root_bounds.x, root_bounds.y), &children[1].bounds())
+}
+
+const SRC: &str = r#"Container(
+    layout: (direction: Column, align_items: Stretch),
+    children: [
+        Container(id: Some("banner"), height: Some(40.0)),
+        Container(
+            id: Some("sidebar"),
+            static_cache: true,
+            flex: 1.0,
+            children: [Label(text: "Inbox", size: 14.0)],
+        ),
+    ],
+)"#;
+
+#[test]
+fn test_static_cache_replays_until_moved_resized_or_dirty() {
+    let mut root = parse_ui(SRC).unwrap();
+    compute_root_layout(&mut root, 300.0, 400.0, SafeArea::default());
+    let rect = sidebar_rect(&root);
+    assert_eq!(rect, Rect::new(0.0, 40.0, 300.0, 360.0));
+
+    // Stands in for the snapshot render_widget captures (which needs a GPU)
+    // when the sidebar is first drawn.
+    let Widget::Container { children, .. } = &root else { unreachable!() };
+    let Widget::Container { render_cache, .. } = &children[1] else { unreachable!() };
+    *render_cache.borrow_mut() = Some(Box::new(RenderCache { rect, ..Default::default() }));
+    let cache = render_cache.borrow().clone().unwrap();
+
+    // Same layout: replayed.
+    compute_root_layout(&mut root, 300.0, 400.0, SafeArea::default());
+    assert!(cache.replayable_at(sidebar_rect(&root)));
+
+    // The banner grows: the root stays put but the sidebar moves down.
+    let mut grown = parse_ui(&SRC.replace("height: Some(40.0)", "height: Some(60.0)")).unwrap();
+    compute_root_layout(&mut grown, 300.0, 400.0, SafeArea::default());
+    assert_eq!(sidebar_rect(&grown).y, 60.0);
+    assert!(!cache.replayable_at(sidebar_rect(&grown)));
+
+    // The window widens: same position, new size.
+    compute_root_layout(&mut root, 320.0, 400.0, SafeArea::default());
+    assert_eq!((sidebar_rect(&root).x, sidebar_rect(&root).y), (0.0, 40.0));
+    assert!(!cache.replayable_at(sidebar_rect(&root)));
+
+    root.mark_dirty();
+    let Widget::Container { children, .. } = &root else { unreachable!() };
+    assert_eq!(cache_of(&children[1]), None);
+}
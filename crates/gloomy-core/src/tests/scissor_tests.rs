@@ -0,0 +1,17 @@
+use crate::ui::intersect_scissor;
+
+#[test]
+fn test_top_level_scrollable_container_clips() {
+    // A scrollable container at (0, 40) sized 300x200 pushes its bounds
+    // while nothing clips yet; its overflowing children must be cut there.
+    let container = (0, 40, 300, 200);
+    assert_eq!(intersect_scissor(None, Some(container)), Some(container));
+
+    // A nested clip is narrowed to the outer one.
+    assert_eq!(intersect_scissor(Some(container), Some((100, 0, 400, 100))), Some((100, 40, 200, 60)));
+    // Disjoint clips hide everything instead of underflowing.
+    assert_eq!(intersect_scissor(Some(container), Some((400, 0, 50, 50))), Some((0, 0, 0, 0)));
+    // Pushing no rect keeps the current clip.
+    assert_eq!(intersect_scissor(Some(container), None), Some(container));
+    assert_eq!(intersect_scissor(None, None), None);
+}
@@ -58,3 +58,36 @@ fn test_tab_layout_and_interaction() {
          panic!("Not a tab widget");
     }
 }
+
+#[test]
+fn test_tab_header_scrolls_when_tabs_overflow() {
+    let tabs = (0..10)
+        .map(|i| TabItem { title: format!("Tab {}", i), content: Box::new(Widget::label("Content")) })
+        .collect();
+    let mut tab_widget = Widget::tab("many", tabs, Orientation::Horizontal, TabStyle::default());
+    if let Widget::Tab { bounds, .. } = &mut tab_widget {
+         bounds.width = 400.0;
+         bounds.height = 300.0;
+    }
+    compute_layout(&mut tab_widget, 0.0, 0.0, 400.0, 300.0);
+
+    // 10 tabs at the 80px minimum overflow 400px: chevrons take 24px each
+    // side, the strip starts at x = 24.
+    let mut interaction = InteractionState::new();
+    assert_eq!(hit_test(&tab_widget, Vec2::new(30.0, 16.0), None).unwrap().action, "many:tab:0");
+    assert_eq!(hit_test(&tab_widget, Vec2::new(390.0, 16.0), None).unwrap().action, "many:tabs_next");
+
+    interaction.clicked_id = Some("many:tabs_next".into());
+    assert!(handle_interactions(&mut tab_widget, &interaction, Vec2::ZERO));
+    if let Widget::Tab { header_scroll, .. } = &tab_widget {
+         assert_eq!(*header_scroll, 352.0, "pages by one viewport");
+    }
+    // Scrolled by 352px: x = 30 is now inside tab 4 (320..400 in strip space).
+    assert_eq!(hit_test(&tab_widget, Vec2::new(30.0, 16.0), None).unwrap().action, "many:tab:4");
+
+    // Paging past the end clamps to the last full page.
+    assert!(handle_interactions(&mut tab_widget, &interaction, Vec2::ZERO));
+    if let Widget::Tab { header_scroll, .. } = &tab_widget {
+         assert_eq!(*header_scroll, 800.0 - 352.0);
+    }
+}
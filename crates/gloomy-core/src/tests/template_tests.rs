@@ -0,0 +1,40 @@
+use crate::ui::{parse_ui_template, substitute_template};
+use crate::widget::Widget;
+use std::collections::HashMap;
+
+fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+#[test]
+fn test_template_substitutes_placeholders() {
+    let src = r#"Label(text: "${title}: ${value}", size: ${size})"#;
+    let widget = parse_ui_template(
+        src,
+        &params(&[("title", "Users"), ("value", "1024"), ("size", "18.0")]),
+    ).unwrap();
+
+    if let Widget::Label { text, size, .. } = widget {
+        assert_eq!(text, "Users: 1024");
+        assert_eq!(size, Some(18.0));
+    } else {
+        panic!("Expected a label");
+    }
+}
+
+#[test]
+fn test_template_dollar_escape() {
+    let out = substitute_template("cost: $$${price} and $5", &params(&[("price", "10")])).unwrap();
+    assert_eq!(out, "cost: $10 and $5");
+}
+
+#[test]
+fn test_template_reports_unfilled_placeholders() {
+    let err = substitute_template("${a} ${b} ${a}", &params(&[])).unwrap_err();
+    assert_eq!(err.to_string(), "Unfilled template placeholders: a, b");
+}
+
+#[test]
+fn test_template_unterminated_placeholder() {
+    assert!(substitute_template("text: ${title", &params(&[("title", "x")])).is_err());
+}
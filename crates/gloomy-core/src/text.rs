@@ -4,8 +4,35 @@ use wgpu_text::{
   glyph_brush::{HorizontalAlign, Section, Text, FontId},
   BrushBuilder,
 };
+use crate::lru::LruCache;
+use std::cell::RefCell;
 use std::collections::HashMap;
 
+/// Default number of strings kept by the measurement cache.
+pub const DEFAULT_MEASURE_CACHE_CAPACITY: usize = 1024;
+
+/// Printable ASCII: letters, digits and common punctuation. The usual set
+/// for [`TextRenderer::prewarm`].
+pub const PREWARM_ASCII: &str =
+  " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+
+/// Hash of `(text, size_x10, font)`, see [`TextRenderer::measure`].
+type MeasureKey = u64;
+
+/// A cached width with the text it was measured for, compared on lookup
+/// so a hash collision is a miss rather than another string's width.
+type MeasureEntry = (Box<str>, f32);
+
+/// Hashes a measurement's inputs so cache lookups don't copy the text.
+fn measure_key(text: &str, size_x10: u32, font_id: FontId) -> MeasureKey {
+  use std::hash::{Hash, Hasher};
+  let mut hasher = std::collections::hash_map::DefaultHasher::new();
+  text.hash(&mut hasher);
+  size_x10.hash(&mut hasher);
+  font_id.0.hash(&mut hasher);
+  hasher.finish()
+}
+
 /// A font family with variants (regular, bold, italic, bold-italic).
 #[derive(Clone)]
 pub struct FontFamily {
@@ -28,10 +55,51 @@ impl FontFamily {
   }
 }
 
+/// Vertical metrics of a font, in pixels.
+///
+/// [`TextRenderer::font_metrics`] returns them for a 1px font; use
+/// [`FontMetrics::scaled`] for a real size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontMetrics {
+  /// Distance from the top of the line box to the baseline.
+  pub ascent: f32,
+  /// Distance from the baseline to the bottom, negative below it.
+  pub descent: f32,
+  /// Extra space between lines.
+  pub line_gap: f32,
+}
+
+impl FontMetrics {
+  pub fn scaled(self, size: f32) -> Self {
+    Self {
+      ascent: self.ascent * size,
+      descent: self.descent * size,
+      line_gap: self.line_gap * size,
+    }
+  }
+
+  /// Height of the glyph box, from ascent to descent.
+  pub fn height(&self) -> f32 {
+    self.ascent - self.descent
+  }
+
+  /// Distance between consecutive baselines.
+  pub fn line_height(&self) -> f32 {
+    self.height() + self.line_gap
+  }
+
+  /// Draw position y that centers the glyph box in `top..top + height`.
+  pub fn centered_y(&self, top: f32, height: f32) -> f32 {
+    top + (height - self.height()) * 0.5
+  }
+}
+
 /// Registry for managing multiple font families.
 pub struct FontRegistry {
   families: HashMap<String, FontFamily>,
   default_family: String,
+  /// Fonts tried in order for glyphs the requested font lacks.
+  fallbacks: Vec<FontId>,
 }
 
 impl FontRegistry {
@@ -40,6 +108,7 @@ impl FontRegistry {
     Self {
       families: HashMap::new(),
       default_family,
+      fallbacks: Vec::new(),
     }
   }
   
@@ -55,6 +124,18 @@ impl FontRegistry {
       .or_else(|| self.families.get(&self.default_family))
   }
   
+  /// Appends a font to the fallback chain.
+  pub fn register_fallback(&mut self, font: FontId) {
+    if !self.fallbacks.contains(&font) {
+      self.fallbacks.push(font);
+    }
+  }
+
+  /// The fallback chain, in lookup order.
+  pub fn fallbacks(&self) -> &[FontId] {
+    &self.fallbacks
+  }
+
   /// Gets the appropriate font ID for a family and style.
   pub fn get_font_id(
     &self,
@@ -67,6 +148,47 @@ impl FontRegistry {
   }
 }
 
+/// How a string's box relates to its scissor rect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ScissorFit {
+  Inside,
+  Outside,
+  Partial,
+}
+
+/// Classifies `rect` against `scissor`, both `(x, y, w, h)` in physical
+/// pixels. A pixel of slack is allowed for glyph overhang.
+pub(crate) fn scissor_fit(rect: (f32, f32, f32, f32), scissor: (u32, u32, u32, u32)) -> ScissorFit {
+  const SLACK: f32 = 1.0;
+  let (x, y, w, h) = rect;
+  let (sx, sy) = (scissor.0 as f32, scissor.1 as f32);
+  let (sr, sb) = (sx + scissor.2 as f32, sy + scissor.3 as f32);
+  if x + w <= sx || x >= sr || y + h <= sy || y >= sb {
+    ScissorFit::Outside
+  } else if x - SLACK >= sx && x + w + SLACK <= sr && y - SLACK >= sy && y + h + SLACK <= sb {
+    ScissorFit::Inside
+  } else {
+    ScissorFit::Partial
+  }
+}
+
+/// The scissor text queued at `rect` under `clip` keeps: `Some(None)` when
+/// it fits inside and can join the unclipped pass, `None` when it lies
+/// wholly outside and is dropped.
+pub(crate) fn clip_for(rect: (f32, f32, f32, f32), clip: (u32, u32, u32, u32)) -> Option<Option<(u32, u32, u32, u32)>> {
+  match scissor_fit(rect, clip) {
+    ScissorFit::Inside => Some(None),
+    ScissorFit::Outside => None,
+    ScissorFit::Partial => Some(Some(clip)),
+  }
+}
+
+/// Render passes [`TextRenderer::render`] issues for text queued with
+/// `scissors`: one per distinct value.
+pub(crate) fn pass_count(scissors: impl IntoIterator<Item = Option<(u32, u32, u32, u32)>>) -> usize {
+  scissors.into_iter().collect::<std::collections::HashSet<_>>().len()
+}
+
 /// Text renderer wrapping wgpu_text for TTF rendering.
 pub struct TextRenderer {
   brush: wgpu_text::TextBrush<FontArc>,
@@ -75,10 +197,18 @@ pub struct TextRenderer {
   font_registry: FontRegistry,
   /// Cache for glyph dimensions: (char, size_x10) -> width
   glyph_cache: HashMap<(char, u32), f32>,
+  /// Widths of whole strings, so repeated labels skip the glyph loop.
+  measure_cache: RefCell<LruCache<MeasureKey, MeasureEntry>>,
   width: u32,
   height: u32,
+  /// Kept to rebuild the brush when fonts are added.
+  format: wgpu::TextureFormat,
   pending: Vec<(String, Vec2, f32, Vec4, Option<(u32, u32, u32, u32)>, HorizontalAlign, Option<String>)>,
   current_scissor: Option<(u32, u32, u32, u32)>,
+  /// Alpha multiplier applied to queued text.
+  opacity: f32,
+  /// Render passes issued by the last `render`.
+  draw_calls: usize,
   screen_size: Vec2,
   pub scale_factor: f32,
 }
@@ -122,9 +252,13 @@ impl TextRenderer {
         height, 
         pending: Vec::new(), 
         current_scissor: None, 
+        opacity: 1.0,
+        draw_calls: 0,
         screen_size: Vec2::new(width as f32, height as f32),
         scale_factor: 1.0, // Default 1.0, updated via resize
         glyph_cache: HashMap::new(),
+        measure_cache: RefCell::new(LruCache::new(DEFAULT_MEASURE_CACHE_CAPACITY)),
+        format,
     }
   }
   
@@ -201,9 +335,13 @@ impl TextRenderer {
         height, 
         pending: Vec::new(), 
         current_scissor: None, 
+        opacity: 1.0,
+        draw_calls: 0,
         screen_size: Vec2::new(width as f32, height as f32),
         scale_factor: 1.0,
         glyph_cache: HashMap::new(),
+        measure_cache: RefCell::new(LruCache::new(DEFAULT_MEASURE_CACHE_CAPACITY)),
+        format,
     }
   }
   
@@ -290,26 +428,135 @@ impl TextRenderer {
       height,
       pending: Vec::new(),
       current_scissor: None,
+      opacity: 1.0,
+      draw_calls: 0,
       screen_size: Vec2::new(width as f32, height as f32),
       scale_factor: 1.0,
       glyph_cache: HashMap::new(),
+      measure_cache: RefCell::new(LruCache::new(DEFAULT_MEASURE_CACHE_CAPACITY)),
+      format,
     }
   }
 
-  /// Adds a new font to the renderer.
-  /// NOTE: wgpu-text 0.8 doesn't support adding fonts after initialization.
-  /// This is a placeholder for future implementation.
-  pub fn add_font(&mut self, name: &str, font_bytes: &[u8]) {
-    // TODO: wgpu-text 0.8 doesn't have add_font method
-    // We would need to rebuild the brush with all fonts
-    // For now, just track the font name
+  /// Rasterizes `chars` into the glyph atlas at every size in `sizes`
+  /// (logical pixels) for every font in `fonts` (`None` is the default
+  /// font), so the first frame showing them doesn't stall on atlas uploads.
+  ///
+  /// The glyphs are queued through the brush like regular text but never
+  /// drawn. Atlas entries are per physical size, so call this after
+  /// `add_font` (which rebuilds the brush and its atlas) and again after a
+  /// scale factor change.
+  pub fn prewarm(
+    &mut self,
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    chars: &str,
+    sizes: &[f32],
+    fonts: &[Option<&str>],
+  ) {
+    let default_font = [None];
+    let fonts = if fonts.is_empty() { &default_font[..] } else { fonts };
+    let mut sections = Vec::new();
+    for font_name in fonts {
+      let font_id = self.resolve_font(*font_name);
+      let runs = self.font_runs(chars, font_id);
+      for size in sizes {
+        let text = runs
+          .iter()
+          .map(|(run, id)| {
+            Text::new(run)
+              .with_scale(size * self.scale_factor)
+              .with_color([0.0; 4])
+              .with_font_id(*id)
+          })
+          .collect();
+        sections.push(Section::default().with_text(text));
+      }
+    }
+    if let Err(err) = self.brush.queue(device, queue, sections) {
+      log::warn!("Failed to prewarm glyphs: {:?}", err);
+    }
+  }
+
+  /// Loads a font at runtime and makes it available as `name`.
+  ///
+  /// wgpu-text can't add fonts to a built brush, so the brush is rebuilt
+  /// with the extended list. Fonts are only ever appended, so existing
+  /// `FontId`s stay valid; queued text refers to fonts by name and is
+  /// resolved at render time, so it draws correctly after the rebuild.
+  /// Re-using a name points it at the new font.
+  pub fn add_font(&mut self, device: &wgpu::Device, name: &str, font_bytes: &[u8]) -> anyhow::Result<()> {
     let font = FontArc::try_from_vec(font_bytes.to_vec())
-      .expect("Failed to load font");
+      .map_err(|e| anyhow::anyhow!("Failed to load font '{}': {}", name, e))?;
     let next_id = FontId(self.font_instances.len());
     self.fonts.insert(name.to_string(), next_id);
     self.font_instances.push(font);
+
+    self.brush = BrushBuilder::using_fonts(self.font_instances.clone())
+      .build(device, self.width, self.height, self.format);
+    // Fallback glyphs may now resolve to the new font.
+    self.glyph_cache.clear();
+    self.measure_cache.get_mut().clear();
+    Ok(())
   }
   
+  /// Loads a font and appends it to the fallback chain, e.g. an emoji font.
+  ///
+  /// Characters the requested font has no glyph for are measured and drawn
+  /// with the first fallback that has one.
+  pub fn register_fallback(&mut self, device: &wgpu::Device, name: &str, font_bytes: &[u8]) -> anyhow::Result<()> {
+    self.add_font(device, name, font_bytes)?;
+    let id = self.fonts[name];
+    self.font_registry.register_fallback(id);
+    self.glyph_cache.clear();
+    self.measure_cache.get_mut().clear();
+    Ok(())
+  }
+
+  /// Resolves a font name to its id, defaulting to the first font.
+  fn resolve_font(&self, font_name: Option<&str>) -> FontId {
+    font_name
+      .and_then(|name| self.fonts.get(name))
+      .copied()
+      .unwrap_or(FontId(0))
+  }
+
+  /// The font that draws `c`: `primary` if it has the glyph, else the
+  /// first fallback that does, else `primary` (which draws tofu).
+  fn font_for_glyph(&self, primary: FontId, c: char) -> FontId {
+    let has_glyph = |id: FontId| self.font_instances[id.0].glyph_id(c).0 != 0;
+    if has_glyph(primary) {
+      return primary;
+    }
+    self.font_registry.fallbacks()
+      .iter()
+      .copied()
+      .find(|&id| has_glyph(id))
+      .unwrap_or(primary)
+  }
+
+  /// Splits `text` into runs that share a font after fallback.
+  fn font_runs<'t>(&self, text: &'t str, primary: FontId) -> Vec<(&'t str, FontId)> {
+    let mut runs: Vec<(&'t str, FontId)> = Vec::new();
+    let mut start = 0;
+    let mut current = None;
+    for (i, c) in text.char_indices() {
+      let font = self.font_for_glyph(primary, c);
+      match current {
+        Some(prev) if prev != font => {
+          runs.push((&text[start..i], prev));
+          start = i;
+        }
+        _ => {}
+      }
+      current = Some(font);
+    }
+    if let Some(font) = current {
+      runs.push((&text[start..], font));
+    }
+    runs
+  }
+
   /// Gets the appropriate font name based on family and style flags.
   pub fn get_font_for_style<'a>(
     &self,
@@ -345,6 +592,11 @@ impl TextRenderer {
 
   /// Handles viewport resize.
   pub fn resize(&mut self, queue: &wgpu::Queue, width: u32, height: u32, scale_factor: f32) {
+    if scale_factor != self.scale_factor {
+      // Widths measured for the old factor no longer match what is drawn.
+      self.glyph_cache.clear();
+      self.measure_cache.get_mut().clear();
+    }
     self.width = width;
     self.height = height;
     self.scale_factor = scale_factor;
@@ -360,6 +612,12 @@ impl TextRenderer {
       old
   }
 
+  /// Sets the alpha multiplier for subsequent draws.
+  /// Returns the previous value.
+  pub fn set_opacity(&mut self, opacity: f32) -> f32 {
+      std::mem::replace(&mut self.opacity, opacity)
+  }
+
   /// Queues text for rendering.
   pub fn draw(
     &mut self,
@@ -372,12 +630,57 @@ impl TextRenderer {
     align: HorizontalAlign,
     font_name: Option<&str>,
   ) {
+    self.queue_text(text, pos, size, color, align, font_name, None);
+  }
+
+  /// [`TextRenderer::draw`] for text whose width the caller has already
+  /// measured, so clipping it needn't measure it again.
+  #[allow(clippy::too_many_arguments)]
+  pub fn draw_measured(
+    &mut self,
+    _device: &wgpu::Device,
+    _queue: &wgpu::Queue,
+    text: &str,
+    pos: Vec2,
+    size: f32,
+    color: Vec4,
+    align: HorizontalAlign,
+    font_name: Option<&str>,
+    width: f32,
+  ) {
+    self.queue_text(text, pos, size, color, align, font_name, Some(width));
+  }
+
+  #[allow(clippy::too_many_arguments)]
+  fn queue_text(
+    &mut self,
+    text: &str,
+    pos: Vec2,
+    size: f32,
+    color: Vec4,
+    align: HorizontalAlign,
+    font_name: Option<&str>,
+    width: Option<f32>,
+  ) {
+    let mut color = color;
+    color.w *= self.opacity;
+    // Each distinct scissor costs a render pass. Text that lies wholly
+    // inside its scissor doesn't need it and joins the unclipped batch;
+    // text wholly outside is dropped here, before anything is copied.
+    let mut scissor = self.current_scissor;
+    if let Some(clip) = scissor.filter(|_| !text.contains('\n')) {
+        let width = width.unwrap_or_else(|| self.measure(text, size, font_name).x);
+        match clip_for(self.physical_rect(pos, size, width, align, font_name), clip) {
+            Some(kept) => scissor = kept,
+            None => return,
+        }
+    }
     self.pending.push((
         text.to_string(),
         pos,
         size,
         color,
-        self.current_scissor,
+        scissor,
         align,
         font_name.map(|s| s.to_string()),
     ));
@@ -391,6 +694,10 @@ impl TextRenderer {
     device: &wgpu::Device,
     queue: &wgpu::Queue,
   ) {
+      self.draw_calls = 0;
+      // `draw` already unclipped text that fits its scissor, so only text
+      // crossing a scissor edge keeps its own pass; e.g. a DataGrid's
+      // cells mostly share one.
       if self.pending.is_empty() { return; }
       
       // Sort by scissor rect - REVERSE order so None renders LAST (on top)
@@ -413,23 +720,26 @@ impl TextRenderer {
            let batch_items = &self.pending[current_idx..end_idx];
            let sections: Vec<Section> = batch_items.iter()
              .map(|(text, pos, size, color, _scissor, align, font_name)| {
-                 let font_id = font_name.as_deref()
-                     .and_then(|name| self.fonts.get(name))
-                     .copied()
-                     .unwrap_or(FontId(0));
+                 let font_id = self.resolve_font(font_name.as_deref());
                      
                  // Apply Scale Factor to Position and Size
                  let scaled_x = pos.x * scale;
                  let scaled_y = pos.y * scale;
                  let scaled_size = size * scale;
 
-                 Section::default()
-                     .add_text(
-                         Text::new(text.as_str())
+                 // One text run per font so missing glyphs use the fallbacks.
+                 let runs = self.font_runs(text, font_id)
+                     .into_iter()
+                     .map(|(run, id)| {
+                         Text::new(run)
                            .with_scale(scaled_size)
                            .with_color([color.x, color.y, color.z, color.w])
-                           .with_font_id(font_id),
-                     )
+                           .with_font_id(id)
+                     })
+                     .collect();
+
+                 Section::default()
+                     .with_text(runs)
                      .with_screen_position((scaled_x, scaled_y))
                      .with_layout(
                          wgpu_text::glyph_brush::Layout::default()
@@ -471,12 +781,41 @@ impl TextRenderer {
                
                self.brush.draw(&mut rpass);
            }
+           self.draw_calls += 1;
            
            current_idx = end_idx;
       }
       
       self.pending.clear();
       self.current_scissor = None;
+      self.opacity = 1.0;
+  }
+
+  /// Render passes issued by the last [`TextRenderer::render`]: one per
+  /// distinct scissor among the text that needed clipping, plus one for
+  /// the rest. For profiling draw calls.
+  pub fn draw_calls(&self) -> usize {
+      self.draw_calls
+  }
+
+  /// Box a single line `width` wide covers, in physical pixels.
+  /// Multi-line text is never taken out of its scissor.
+  fn physical_rect(
+      &self,
+      pos: Vec2,
+      size: f32,
+      width: f32,
+      align: HorizontalAlign,
+      font_name: Option<&str>,
+  ) -> (f32, f32, f32, f32) {
+      let height = self.font_metrics(font_name).scaled(size).line_height().max(size);
+      let left = match align {
+          HorizontalAlign::Left => pos.x,
+          HorizontalAlign::Center => pos.x - width * 0.5,
+          HorizontalAlign::Right => pos.x - width,
+      };
+      let s = self.scale_factor;
+      (left * s, pos.y * s, width * s, height * s)
   }
 
   /// Measures the bounds of the given text.
@@ -485,24 +824,44 @@ impl TextRenderer {
           return Vec2::new(0.0, size);
       }
       
-      let font_id = font_name
-          .and_then(|name| self.fonts.get(name))
-          .copied()
-          .unwrap_or(FontId(0));
+      let font_id = self.resolve_font(font_name);
+      // Same size bucketing as the glyph cache.
+      let key = measure_key(text, (size * 10.0) as u32, font_id);
+      if let Some((cached, width)) = self.measure_cache.borrow_mut().get(&key) {
+          if **cached == *text {
+              return Vec2::new(*width, size);
+          }
+      }
 
-      let font = &self.font_instances[font_id.0];
-      let scaled_font = font.as_scaled(size);
       let mut width = 0.0;
       
       for c in text.chars() {
+          let scaled_font = self.font_instances[self.font_for_glyph(font_id, c).0].as_scaled(size);
           let glyph_id = scaled_font.glyph_id(c);
           let advance = scaled_font.h_advance(glyph_id);
           width += advance;
       }
       
+      self.measure_cache.borrow_mut().insert(key, (text.into(), width));
       Vec2::new(width, size)
   }
+
+  /// Bounds the string measurement cache; 0 disables it.
+  pub fn set_measure_cache_capacity(&mut self, capacity: usize) {
+      self.measure_cache.get_mut().set_capacity(capacity);
+  }
   
+  /// Vertical metrics of a font at 1px, see [`FontMetrics::scaled`].
+  pub fn font_metrics(&self, font_name: Option<&str>) -> FontMetrics {
+      let font = &self.font_instances[self.resolve_font(font_name).0];
+      let units = font.units_per_em().unwrap_or(1.0);
+      FontMetrics {
+          ascent: font.ascent_unscaled() / units,
+          descent: font.descent_unscaled() / units,
+          line_gap: font.line_gap_unscaled() / units,
+      }
+  }
+
   /// Measures a single character with caching for performance.
   /// Uses cache key of (char, size*10) to avoid float key issues.
   pub fn measure_char_cached(
@@ -521,10 +880,7 @@ impl TextRenderer {
       }
       
       // Measure and cache
-      let font_id = font_name
-          .and_then(|name| self.fonts.get(name))
-          .copied()
-          .unwrap_or(FontId(0));
+      let font_id = self.font_for_glyph(self.resolve_font(font_name), c);
 
       let font = &self.font_instances[font_id.0];
       let scaled_font = font.as_scaled(size);
@@ -564,3 +920,50 @@ impl TextRenderer {
 pub struct TextSnapshot {
     pub pending: Vec<(String, Vec2, f32, Vec4, Option<(u32, u32, u32, u32)>, HorizontalAlign, Option<String>)>,
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_scissor_fit_classifies_cells() {
+    let cell = (100, 40, 120, 28);
+    // Text well inside its cell doesn't need the scissor...
+    assert_eq!(scissor_fit((108.0, 44.0, 60.0, 18.0), cell), ScissorFit::Inside);
+    // ...text running past the right edge does...
+    assert_eq!(scissor_fit((108.0, 44.0, 140.0, 18.0), cell), ScissorFit::Partial);
+    // ...and text scrolled out of view is not drawn at all.
+    assert_eq!(scissor_fit((108.0, 80.0, 60.0, 18.0), cell), ScissorFit::Outside);
+  }
+
+  #[test]
+  fn test_measure_key_separates_size_and_font() {
+    let key = measure_key("Hello", 160, FontId(0));
+    assert_eq!(key, measure_key(&String::from("Hello"), 160, FontId(0)));
+    assert_ne!(key, measure_key("Hello", 170, FontId(0)));
+    assert_ne!(key, measure_key("Hello", 160, FontId(1)));
+    assert_ne!(key, measure_key("Hello!", 160, FontId(0)));
+  }
+
+  #[test]
+  fn test_grid_cells_share_one_text_pass() {
+    // 500 rows x 8 columns of 100x24 cells, each drawn under its own cell
+    // scissor; one cell in 50 overflows its column.
+    let mut per_cell = Vec::new();
+    let mut queued = Vec::new();
+    for row in 0..500u32 {
+      for col in 0..8u32 {
+        let cell = (col * 100, row * 24, 100, 24);
+        let width = if (row + col) % 50 == 0 { 140.0 } else { 60.0 };
+        let rect = (cell.0 as f32 + 6.0, cell.1 as f32 + 4.0, width, 16.0);
+        per_cell.push(Some(cell));
+        queued.extend(clip_for(rect, cell));
+      }
+    }
+    // Clipping every cell: 4000 passes. Only the 80 overflowing cells keep
+    // theirs, the rest share the unclipped pass.
+    assert_eq!(pass_count(per_cell), 4000);
+    assert_eq!(queued.len(), 4000);
+    assert_eq!(pass_count(queued), 81);
+  }
+}
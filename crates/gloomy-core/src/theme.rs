@@ -5,10 +5,67 @@
 //! - Semantic color naming
 //! - Runtime theme switching
 //! - RON configuration support
+//! - Theme-aware style defaults
+//!
+//! Widget styles implement [`Themed`]. Their `Default` impls read the
+//! thread's current theme, set with [`set_current_theme`] or scoped with
+//! [`with_theme`], so widgets built or loaded from RON without an explicit
+//! style follow the active palette. With no current theme the built-in
+//! dark styles are used.
 
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use crate::widget::Color;
 
+thread_local! {
+    static CURRENT_THEME: RefCell<Option<Theme>> = const { RefCell::new(None) };
+}
+
+/// Sets the theme that style defaults on this thread are derived from.
+/// `None` restores the built-in defaults.
+pub fn set_current_theme(theme: Option<Theme>) {
+    CURRENT_THEME.with(|current| *current.borrow_mut() = theme);
+}
+
+/// The theme style defaults on this thread are derived from, if any.
+pub fn current_theme() -> Option<Theme> {
+    CURRENT_THEME.with(|current| current.borrow().clone())
+}
+
+/// Runs `f` with `theme` as the current theme, then restores the previous
+/// one.
+pub fn with_theme<R>(theme: &Theme, f: impl FnOnce() -> R) -> R {
+    let previous = CURRENT_THEME.with(|current| current.replace(Some(theme.clone())));
+    let result = f();
+    CURRENT_THEME.with(|current| *current.borrow_mut() = previous);
+    result
+}
+
+/// A style that can be derived from a theme's palette.
+pub trait Themed: Sized {
+    /// The style in `theme`'s colors.
+    fn themed(theme: &Theme) -> Self;
+}
+
+/// The style for the current theme, or `fallback()` when none is set.
+/// Style `Default` impls are built on this.
+pub fn themed_default<T: Themed>(fallback: fn() -> T) -> T {
+    CURRENT_THEME
+        .with(|current| current.borrow().as_ref().map(T::themed))
+        .unwrap_or_else(fallback)
+}
+
+/// `color` with its alpha replaced.
+pub fn with_alpha(color: Color, alpha: f32) -> Color {
+    (color.0, color.1, color.2, alpha)
+}
+
+/// Linear blend from `a` (t = 0) to `b` (t = 1).
+pub fn mix(a: Color, b: Color, t: f32) -> Color {
+    let lerp = |x: f32, y: f32| x + (y - x) * t;
+    (lerp(a.0, b.0), lerp(a.1, b.1), lerp(a.2, b.2), lerp(a.3, b.3))
+}
+
 /// A complete UI theme with named color palette.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Theme {
@@ -254,4 +311,29 @@ mod tests {
         assert_eq!(light.name, "Light");
         assert_eq!(hc.name, "High Contrast");
     }
+
+    #[test]
+    fn test_style_defaults_follow_current_theme() {
+        use crate::style::ButtonStyle;
+        let fallback = ButtonStyle::default();
+
+        set_current_theme(Some(Theme::light()));
+        assert_eq!(ButtonStyle::default().text_color, Theme::light().colors.text);
+        set_current_theme(None);
+        assert_eq!(ButtonStyle::default(), fallback);
+
+        let themed = with_theme(&Theme::high_contrast(), ButtonStyle::default);
+        assert_eq!(themed, ButtonStyle::themed(&Theme::high_contrast()));
+        assert!(current_theme().is_none());
+    }
+
+    #[test]
+    fn test_widgets_parsed_under_theme_use_its_palette() {
+        let light = Theme::light();
+        let widget = with_theme(&light, || {
+            crate::ui::parse_ui(r#"TextInput(id: "name")"#).unwrap()
+        });
+        let crate::widget::Widget::TextInput { style, .. } = widget else { unreachable!() };
+        assert_eq!(style.text_color, light.colors.text);
+    }
 }
@@ -0,0 +1,195 @@
+//! Geometry and formatting for the TimePicker widget.
+//!
+//! The overlay shows a 4×6 grid of hours above a grid of minute slots
+//! (`step_minutes` apart). Every cell resolves to a full time: an hour
+//! cell keeps the current minute, a minute cell keeps the current hour.
+//! Render and hit testing both go through [`TimePanel`].
+
+use crate::popover::Popover;
+use crate::rect::Rect;
+use crate::widget::WidgetBounds;
+use chrono::{NaiveTime, Timelike};
+use glam::Vec2;
+
+/// A cell in the overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelCell {
+    Hour(u32),
+    Minute(u32),
+}
+
+/// Layout of the hour/minute overlay, in absolute coordinates.
+#[derive(Debug, Clone, Copy)]
+pub struct TimePanel {
+    pub origin: Vec2,
+    pub width: f32,
+    minute_count: usize,
+}
+
+impl TimePanel {
+    pub const PADDING: f32 = 5.0;
+    pub const CELL_HEIGHT: f32 = 28.0;
+    pub const SECTION_GAP: f32 = 8.0;
+    pub const COLUMNS: usize = 6;
+    const HOUR_ROWS: usize = 4;
+
+    /// `pos` is the absolute top-left corner of the TimePicker input. The
+    /// panel opens below it, or above when it does not fit in `area`
+    /// (see [`crate::popover`]).
+    pub fn new(pos: Vec2, bounds: &WidgetBounds, step_minutes: u32, area: Option<&Rect>) -> Self {
+        let mut panel = Self {
+            origin: Vec2::ZERO,
+            width: bounds.width.max(220.0),
+            minute_count: minute_slots(step_minutes).len(),
+        };
+        let anchor = Rect::new(pos.x, pos.y, bounds.width, bounds.height);
+        panel.origin = Popover::new(anchor, Vec2::new(panel.width, panel.height()))
+            .gap(2.0)
+            .origin(area);
+        panel
+    }
+
+    fn minute_rows(&self) -> usize {
+        self.minute_count.div_ceil(Self::COLUMNS)
+    }
+
+    pub fn height(&self) -> f32 {
+        Self::PADDING * 2.0
+            + (Self::HOUR_ROWS + self.minute_rows()) as f32 * Self::CELL_HEIGHT
+            + Self::SECTION_GAP
+    }
+
+    pub fn contains(&self, p: Vec2) -> bool {
+        p.x >= self.origin.x && p.x <= self.origin.x + self.width
+            && p.y >= self.origin.y && p.y <= self.origin.y + self.height()
+    }
+
+    pub fn cell_width(&self) -> f32 {
+        (self.width - Self::PADDING * 2.0) / Self::COLUMNS as f32
+    }
+
+    fn minutes_top(&self) -> f32 {
+        self.origin.y + Self::PADDING + Self::HOUR_ROWS as f32 * Self::CELL_HEIGHT + Self::SECTION_GAP
+    }
+
+    /// Center of the `index`-th cell of a grid starting at `top`.
+    fn grid_center(&self, top: f32, index: usize) -> Vec2 {
+        let (row, col) = (index / Self::COLUMNS, index % Self::COLUMNS);
+        Vec2::new(
+            self.origin.x + Self::PADDING + self.cell_width() * (col as f32 + 0.5),
+            top + Self::CELL_HEIGHT * (row as f32 + 0.5),
+        )
+    }
+
+    pub fn hour_center(&self, hour: u32) -> Vec2 {
+        self.grid_center(self.origin.y + Self::PADDING, hour as usize)
+    }
+
+    /// Center of the `index`-th minute slot.
+    pub fn minute_center(&self, index: usize) -> Vec2 {
+        self.grid_center(self.minutes_top(), index)
+    }
+
+    /// Returns the cell under `p`, if any.
+    pub fn cell_at(&self, p: Vec2, step_minutes: u32) -> Option<PanelCell> {
+        let col = ((p.x - self.origin.x - Self::PADDING) / self.cell_width()).floor();
+        if col < 0.0 || col >= Self::COLUMNS as f32 {
+            return None;
+        }
+        let col = col as usize;
+
+        let hours_top = self.origin.y + Self::PADDING;
+        let row = ((p.y - hours_top) / Self::CELL_HEIGHT).floor();
+        if row >= 0.0 && row < Self::HOUR_ROWS as f32 {
+            return Some(PanelCell::Hour((row as usize * Self::COLUMNS + col) as u32));
+        }
+
+        let row = ((p.y - self.minutes_top()) / Self::CELL_HEIGHT).floor();
+        if row < 0.0 {
+            return None;
+        }
+        minute_slots(step_minutes)
+            .get(row as usize * Self::COLUMNS + col)
+            .map(|m| PanelCell::Minute(*m))
+    }
+}
+
+/// Minutes offered in the overlay. `step` is clamped to 1..=60.
+pub fn minute_slots(step: u32) -> Vec<u32> {
+    (0..60).step_by(step.clamp(1, 60) as usize).collect()
+}
+
+/// The time selected by clicking `cell` while `current` is set.
+///
+/// The kept minute is snapped down to the step so the result is always a
+/// listed slot.
+pub fn pick(current: Option<NaiveTime>, cell: PanelCell, step_minutes: u32) -> NaiveTime {
+    let step = step_minutes.clamp(1, 60);
+    let (hour, minute) = current.map(|t| (t.hour(), t.minute() / step * step)).unwrap_or((0, 0));
+    let (hour, minute) = match cell {
+        PanelCell::Hour(h) => (h, minute),
+        PanelCell::Minute(m) => (hour, m),
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap_or(NaiveTime::MIN)
+}
+
+/// True if `time` lies within the optional bounds (inclusive).
+pub fn in_bounds(time: NaiveTime, min: Option<NaiveTime>, max: Option<NaiveTime>) -> bool {
+    min.is_none_or(|min| time >= min) && max.is_none_or(|max| time <= max)
+}
+
+/// Formats a time for the input field: `14:05` or `2:05 PM`.
+pub fn format_time(time: NaiveTime, use_24h: bool) -> String {
+    if use_24h {
+        time.format("%H:%M").to_string()
+    } else {
+        time.format("%-I:%M %p").to_string()
+    }
+}
+
+/// Label of an hour cell: `14` or `2p`.
+pub fn hour_label(hour: u32, use_24h: bool) -> String {
+    if use_24h {
+        format!("{:02}", hour)
+    } else {
+        let h12 = if hour.is_multiple_of(12) { 12 } else { hour % 12 };
+        format!("{}{}", h12, if hour < 12 { "a" } else { "p" })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn t(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn test_pick_keeps_other_component() {
+        assert_eq!(pick(Some(t(9, 37)), PanelCell::Hour(14), 15), t(14, 30));
+        assert_eq!(pick(Some(t(9, 37)), PanelCell::Minute(45), 15), t(9, 45));
+        assert_eq!(pick(None, PanelCell::Minute(15), 15), t(0, 15));
+    }
+
+    #[test]
+    fn test_formatting() {
+        assert_eq!(format_time(t(14, 5), true), "14:05");
+        assert_eq!(format_time(t(14, 5), false), "2:05 PM");
+        assert_eq!(format_time(t(0, 30), false), "12:30 AM");
+        assert_eq!(hour_label(0, false), "12a");
+        assert_eq!(hour_label(13, false), "1p");
+        assert_eq!(hour_label(7, true), "07");
+    }
+
+    #[test]
+    fn test_cell_at_round_trips_centers() {
+        let bounds = WidgetBounds { x: 0.0, y: 0.0, width: 100.0, height: 30.0 };
+        let panel = TimePanel::new(Vec2::ZERO, &bounds, 5, None);
+        assert_eq!(panel.cell_at(panel.hour_center(17), 5), Some(PanelCell::Hour(17)));
+        assert_eq!(panel.cell_at(panel.minute_center(7), 5), Some(PanelCell::Minute(35)));
+        // The gap between the two grids is not a cell.
+        let gap = Vec2::new(20.0, panel.hour_center(23).y + TimePanel::CELL_HEIGHT * 0.5 + 2.0);
+        assert_eq!(panel.cell_at(gap, 5), None);
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::theme::{themed_default, with_alpha, Theme, Themed};
 
 /// Node in the tree hierarchy.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,7 +72,30 @@ pub struct TreeStyle {
 }
 
 impl Default for TreeStyle {
+    /// Derived from the current theme, see [`crate::theme::set_current_theme`].
     fn default() -> Self {
+        themed_default(Self::fallback)
+    }
+}
+
+impl Themed for TreeStyle {
+    fn themed(theme: &Theme) -> Self {
+        let c = &theme.colors;
+        Self {
+            font_size: 14.0,
+            text_color: c.text,
+            icon_color: c.text_secondary,
+            indent_size: 20.0,
+            row_height: 24.0,
+            selected_background: with_alpha(c.primary, 0.8),
+            hover_background: with_alpha(c.text, 0.1),
+        }
+    }
+}
+
+impl TreeStyle {
+    /// Built-in dark style, used when no theme is current.
+    fn fallback() -> Self {
         Self {
             font_size: 14.0,
             text_color: (0.9, 0.9, 0.9, 1.0),
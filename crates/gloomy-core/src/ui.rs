@@ -1,39 +1,195 @@
 //! UI loading and rendering from RON definitions.
 
-use crate::interaction::HitTestResult;
+use crate::interaction::{CursorHint, HitTestResult};
 use crate::interaction::InteractionState;
-use crate::primitives::PrimitiveRenderer;
+use crate::primitives::{PrimitiveRenderer, RoundedClip};
+use crate::popover::Popover;
+use crate::rect::Rect;
 use crate::text::TextRenderer;
-use crate::widget::{Widget, TextAlign, WidgetBounds};
-use crate::layout::Layout;
+use crate::widget::{Widget, TextAlign, WidgetBounds, Overflow};
+use crate::layout::{Layout, Padding};
 use wgpu_text::glyph_brush::HorizontalAlign;
 use glam::{Vec2, Vec4};
 use crate::style::{BoxStyle, ButtonStyle, TextInputStyle, Border};
 use std::fs;
-use std::path::Path;
-use winit::keyboard::{Key, NamedKey};
+use std::path::{Path, PathBuf};
+use anyhow::Context;
+use winit::keyboard::{Key, ModifiersState, NamedKey};
 use winit::event::ElementState;
-use chrono::{NaiveDate, Datelike};
+use chrono::{NaiveDate, Datelike, Timelike};
 
 /// Loads a UI definition from a RON file.
 ///
+/// Any `Include { path: "..." }` placeholders are replaced by the widget
+/// loaded from `path`, resolved relative to the including file's directory.
+///
 /// # Arguments
 /// * `path` - Path to the RON file
 ///
 /// # Returns
-/// The root widget, or an error if loading/parsing fails.
+/// The root widget, or an error if loading/parsing fails, the includes
+/// form a cycle or a `grid_area` names an undefined area.
 pub fn load_ui(path: impl AsRef<Path>) -> anyhow::Result<Widget> {
-  let content = fs::read_to_string(path)?;
-  let widget: Widget = ron::from_str(&content)?;
+  let mut stack = Vec::new();
+  let widget = load_ui_file(path.as_ref(), &mut stack)?;
+  crate::layout_engine::validate_grid_areas(&widget)?;
   Ok(widget)
 }
 
 /// Loads a UI definition from a RON string.
+///
+/// Includes are resolved relative to the current working directory.
 pub fn parse_ui(ron_str: &str) -> anyhow::Result<Widget> {
-  let widget: Widget = ron::from_str(ron_str)?;
+  parse_ui_with_base(ron_str, ".")
+}
+
+/// Loads a UI definition from a RON string, resolving includes relative
+/// to `base_dir`.
+pub fn parse_ui_with_base(
+  ron_str: &str,
+  base_dir: impl AsRef<Path>,
+) -> anyhow::Result<Widget> {
+  let mut widget: Widget = ron::from_str(ron_str)?;
+  let mut stack = Vec::new();
+  resolve_includes(&mut widget, base_dir.as_ref(), &mut stack)?;
+  crate::layout_engine::validate_grid_areas(&widget)?;
+  Ok(widget)
+}
+
+/// Instantiates a parameterized RON template and parses it.
+///
+/// Every `${name}` in `src` is replaced with `params["name"]` before
+/// deserializing, so one template can be stamped out with different
+/// values:
+///
+/// ```ignore
+/// // stat_card.ron: Label(text: "${title}: ${value}", size: 18.0)
+/// let params = HashMap::from([
+///     ("title".to_string(), "Users".to_string()),
+///     ("value".to_string(), "1024".to_string()),
+/// ]);
+/// let card = parse_ui_template(&src, &params)?;
+/// ```
+///
+/// Values are inserted verbatim, so string parameters should sit inside
+/// quotes in the template. Write `$$` for a literal `$`; a lone `$` not
+/// followed by `{` is also kept as-is. Placeholders without a value are
+/// an error listing every missing name. Includes resolve relative to the
+/// current working directory, as with [`parse_ui`].
+pub fn parse_ui_template(
+  src: &str,
+  params: &HashMap<String, String>,
+) -> anyhow::Result<Widget> {
+  let expanded = substitute_template(src, params)?;
+  parse_ui(&expanded)
+}
+
+/// Performs the `${name}` substitution used by [`parse_ui_template`].
+pub fn substitute_template(
+  src: &str,
+  params: &HashMap<String, String>,
+) -> anyhow::Result<String> {
+  let mut out = String::with_capacity(src.len());
+  let mut missing: Vec<String> = Vec::new();
+  let mut rest = src;
+
+  while let Some(idx) = rest.find('$') {
+    out.push_str(&rest[..idx]);
+    let after = &rest[idx + 1..];
+
+    if let Some(stripped) = after.strip_prefix('$') {
+      out.push('$');
+      rest = stripped;
+    } else if let Some(body) = after.strip_prefix('{') {
+      let end = body.find('}').ok_or_else(|| {
+        anyhow::anyhow!(
+          "Unterminated template placeholder at byte {}",
+          src.len() - rest.len() + idx
+        )
+      })?;
+      let name = body[..end].trim();
+      match params.get(name) {
+        Some(value) => out.push_str(value),
+        None => {
+          if !missing.iter().any(|m| m == name) {
+            missing.push(name.to_string());
+          }
+        }
+      }
+      rest = &body[end + 1..];
+    } else {
+      out.push('$');
+      rest = after;
+    }
+  }
+  out.push_str(rest);
+
+  if !missing.is_empty() {
+    anyhow::bail!("Unfilled template placeholders: {}", missing.join(", "));
+  }
+  Ok(out)
+}
+
+/// Reads and parses a single file, then resolves its includes.
+///
+/// `stack` holds the canonical paths of the files currently being loaded
+/// and is used to detect recursive includes.
+fn load_ui_file(path: &Path, stack: &mut Vec<PathBuf>) -> anyhow::Result<Widget> {
+  let canonical = fs::canonicalize(path)
+    .with_context(|| format!("Failed to open UI file {}", path.display()))?;
+
+  if stack.contains(&canonical) {
+    let chain: Vec<String> = stack
+      .iter()
+      .chain(std::iter::once(&canonical))
+      .map(|p| p.display().to_string())
+      .collect();
+    anyhow::bail!("Recursive include detected: {}", chain.join(" -> "));
+  }
+
+  let content = fs::read_to_string(&canonical)?;
+  let mut widget: Widget = ron::from_str(&content)
+    .with_context(|| format!("Failed to parse UI file {}", canonical.display()))?;
+
+  let base_dir = canonical.parent().map(Path::to_path_buf).unwrap_or_default();
+  stack.push(canonical);
+  resolve_includes(&mut widget, &base_dir, stack)?;
+  stack.pop();
+
   Ok(widget)
 }
 
+/// Replaces every `Widget::Include` in the tree with the loaded widget.
+fn resolve_includes(
+  widget: &mut Widget,
+  base_dir: &Path,
+  stack: &mut Vec<PathBuf>,
+) -> anyhow::Result<()> {
+  match widget {
+    Widget::Include { path } => {
+      let included = load_ui_file(&base_dir.join(path.as_str()), stack)?;
+      *widget = included;
+    }
+    Widget::Container { children, .. } => {
+      for child in children.iter_mut() {
+        resolve_includes(child, base_dir, stack)?;
+      }
+    }
+    Widget::Tab { tabs, .. } => {
+      for tab in tabs.iter_mut() {
+        resolve_includes(&mut tab.content, base_dir, stack)?;
+      }
+    }
+    Widget::ListView { item_widgets, .. } => {
+      for row in item_widgets.iter_mut() {
+        resolve_includes(row, base_dir, stack)?;
+      }
+    }
+    _ => {}
+  }
+  Ok(())
+}
+
 use crate::image_renderer::ImageRenderer;
 use crate::texture::Texture;
 use std::collections::HashMap;
@@ -44,6 +200,8 @@ pub struct RenderContext<'a> {
   pub text: &'a mut TextRenderer,
   pub images: &'a mut ImageRenderer,
   pub textures: &'a mut HashMap<String, Texture>,
+  /// Named icons for `Icon` widgets; unset, icons come from `textures` only.
+  pub icons: Option<&'a mut crate::icons::IconRegistry>,
   pub chart_primitives: &'a mut mpl_wgpu::primitives::PrimitiveRenderer,
   pub chart_text: &'a mut mpl_wgpu::text::TextRenderer,
   pub device: &'a wgpu::Device,
@@ -53,6 +211,15 @@ pub struct RenderContext<'a> {
   pub offset: Vec2,
   pub scissor_stack: Vec<Option<(u32, u32, u32, u32)>>,
   pub current_scissor: Option<(u32, u32, u32, u32)>,
+  /// Accumulated opacity of the enclosing containers.
+  pub opacity: f32,
+  pub opacity_stack: Vec<f32>,
+  /// Animation clock in seconds, sampled once when the context is created
+  /// so every widget in a frame sees the same value.
+  pub time: f32,
+  /// Innermost rounded clip mask, if any.
+  pub rounded_clip: Option<RoundedClip>,
+  pub rounded_clip_stack: Vec<Option<RoundedClip>>,
   pub surface_width: u32,
   pub surface_height: u32,
   pub scale_factor: f32, // Added scale factor
@@ -60,11 +227,90 @@ pub struct RenderContext<'a> {
   pub data_provider: Option<&'a dyn crate::data_source::DataProvider>,
   pub widget_tracker: Option<&'a mut crate::widget_state::WidgetStateTracker>,
   pub deferred_draws: Option<&'a mut Vec<Box<dyn FnOnce(&mut crate::renderer::GloomyRenderer, &wgpu::Device, &wgpu::Queue)>>>,
+  /// Style classes applied to widgets with `classes`; unset, widgets use
+  /// their inline styles.
+  pub styles: Option<&'a crate::style_context::StyleContext>,
+  /// Text properties inherited from the enclosing containers.
+  pub text_defaults: crate::style::TextDefaults,
 }
 
-impl<'a> RenderContext<'a> {
-  /// Creates a new render context.
-  pub fn new(
+/// Seconds since the first frame was rendered.
+pub(crate) fn frame_clock() -> f32 {
+  static START: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+  START.get_or_init(std::time::Instant::now).elapsed().as_secs_f32()
+}
+
+/// Absolute rect a `static_cache` container's snapshot is keyed on, for a
+/// container with `bounds` drawn under a parent at `offset`.
+pub(crate) fn static_cache_rect(offset: Vec2, bounds: &WidgetBounds) -> Rect {
+  Rect::new(offset.x + bounds.x, offset.y + bounds.y, bounds.width, bounds.height)
+}
+
+/// Space between a Divider's label and the line on either side.
+const DIVIDER_LABEL_GAP: f32 = 8.0;
+
+/// Lines of a horizontal Divider at `pos` (absolute) of `size`, as
+/// (center, half size) for `draw_rect`: one across the whole width, or
+/// one either side of a centered label `label_width` wide. None are left
+/// when the label doesn't fit.
+pub(crate) fn divider_lines(pos: Vec2, size: Vec2, thickness: f32, label_width: Option<f32>) -> Vec<(Vec2, Vec2)> {
+  let center_y = pos.y + size.y * 0.5;
+  let Some(label_width) = label_width else {
+    return vec![(Vec2::new(pos.x + size.x * 0.5, center_y), Vec2::new(size.x * 0.5, thickness * 0.5))];
+  };
+  let segment = (size.x - label_width) * 0.5 - DIVIDER_LABEL_GAP;
+  if segment <= 0.0 {
+    return Vec::new();
+  }
+  let half = Vec2::new(segment * 0.5, thickness * 0.5);
+  vec![
+    (Vec2::new(pos.x + segment * 0.5, center_y), half),
+    (Vec2::new(pos.x + size.x - segment * 0.5, center_y), half),
+  ]
+}
+
+/// Thumb position of a ToggleSwitch from 0 (off) to 1 (on) at `now`,
+/// sliding for `secs` after it was toggled at `toggled_at`.
+pub(crate) fn toggle_thumb_position(checked: bool, toggled_at: Option<f32>, secs: f32, now: f32) -> f32 {
+  let t = match toggled_at {
+    Some(at) if secs > 0.0 => ((now - at) / secs).clamp(0.0, 1.0),
+    _ => 1.0,
+  };
+  if checked { t } else { 1.0 - t }
+}
+
+/// Named construction of a [`RenderContext`].
+///
+/// Start from [`RenderContext::builder`], which takes the renderers,
+/// icons, surface size and scale factor from a `GloomyRenderer`, or from
+/// [`RenderContextBuilder::from_parts`] for separately owned renderers.
+/// Everything else is unset unless given: no interaction state, data
+/// provider, widget tracker, deferred draws or style classes.
+pub struct RenderContextBuilder<'a> {
+  primitives: &'a mut PrimitiveRenderer,
+  text: &'a mut TextRenderer,
+  images: &'a mut ImageRenderer,
+  textures: &'a mut HashMap<String, Texture>,
+  icons: Option<&'a mut crate::icons::IconRegistry>,
+  chart_primitives: &'a mut mpl_wgpu::primitives::PrimitiveRenderer,
+  chart_text: &'a mut mpl_wgpu::text::TextRenderer,
+  device: &'a wgpu::Device,
+  queue: &'a wgpu::Queue,
+  interaction: Option<&'a InteractionState>,
+  surface_width: u32,
+  surface_height: u32,
+  scale_factor: f32,
+  data_provider: Option<&'a dyn crate::data_source::DataProvider>,
+  widget_tracker: Option<&'a mut crate::widget_state::WidgetStateTracker>,
+  deferred_draws: Option<&'a mut Vec<Box<dyn FnOnce(&mut crate::renderer::GloomyRenderer, &wgpu::Device, &wgpu::Queue)>>>,
+  styles: Option<&'a crate::style_context::StyleContext>,
+}
+
+impl<'a> RenderContextBuilder<'a> {
+  /// A builder drawing into the given renderers, with a 0x0 surface at
+  /// scale 1 and no icons.
+  #[allow(clippy::too_many_arguments)]
+  pub fn from_parts(
     primitives: &'a mut PrimitiveRenderer,
     text: &'a mut TextRenderer,
     images: &'a mut ImageRenderer,
@@ -73,54 +319,161 @@ impl<'a> RenderContext<'a> {
     chart_text: &'a mut mpl_wgpu::text::TextRenderer,
     device: &'a wgpu::Device,
     queue: &'a wgpu::Queue,
-    interaction: Option<&'a InteractionState>,
-    surface_width: u32,
-    surface_height: u32,
-    scale_factor: f32, // Added arg
-    data_provider: Option<&'a dyn crate::data_source::DataProvider>,
-    widget_tracker: Option<&'a mut crate::widget_state::WidgetStateTracker>,
-    deferred_draws: Option<&'a mut Vec<Box<dyn FnOnce(&mut crate::renderer::GloomyRenderer, &wgpu::Device, &wgpu::Queue)>>>,
   ) -> Self {
     Self {
       primitives,
       text,
       images,
       textures,
+      icons: None,
       chart_primitives,
       chart_text,
       device,
       queue,
-      interaction,
+      interaction: None,
+      surface_width: 0,
+      surface_height: 0,
+      scale_factor: 1.0,
+      data_provider: None,
+      widget_tracker: None,
+      deferred_draws: None,
+      styles: None,
+    }
+  }
+
+  pub fn interaction(mut self, interaction: Option<&'a InteractionState>) -> Self {
+    self.interaction = interaction;
+    self
+  }
+
+  /// Logical size of the surface, used to place popovers.
+  pub fn surface_size(mut self, width: u32, height: u32) -> Self {
+    self.surface_width = width;
+    self.surface_height = height;
+    self
+  }
+
+  pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+    self.scale_factor = scale_factor;
+    self
+  }
+
+  pub fn icons(mut self, icons: Option<&'a mut crate::icons::IconRegistry>) -> Self {
+    self.icons = icons;
+    self
+  }
+
+  pub fn data_provider(mut self, data_provider: Option<&'a dyn crate::data_source::DataProvider>) -> Self {
+    self.data_provider = data_provider;
+    self
+  }
+
+  pub fn widget_tracker(mut self, widget_tracker: Option<&'a mut crate::widget_state::WidgetStateTracker>) -> Self {
+    self.widget_tracker = widget_tracker;
+    self
+  }
+
+  /// Where overlays (dropdown lists, calendars) queue their draws, to be
+  /// run after the rest of the frame.
+  pub fn deferred_draws(
+    mut self,
+    deferred_draws: Option<&'a mut Vec<Box<dyn FnOnce(&mut crate::renderer::GloomyRenderer, &wgpu::Device, &wgpu::Queue)>>>,
+  ) -> Self {
+    self.deferred_draws = deferred_draws;
+    self
+  }
+
+  pub fn styles(mut self, styles: Option<&'a crate::style_context::StyleContext>) -> Self {
+    self.styles = styles;
+    self
+  }
+
+  pub fn build(self) -> RenderContext<'a> {
+    RenderContext {
+      primitives: self.primitives,
+      text: self.text,
+      images: self.images,
+      textures: self.textures,
+      icons: self.icons,
+      chart_primitives: self.chart_primitives,
+      chart_text: self.chart_text,
+      device: self.device,
+      queue: self.queue,
+      interaction: self.interaction,
       offset: Vec2::ZERO,
       scissor_stack: Vec::new(),
       current_scissor: None,
-      surface_width,
-      surface_height,
-      scale_factor, // Init field
+      opacity: 1.0,
+      opacity_stack: Vec::new(),
+      time: frame_clock(),
+      rounded_clip: None,
+      rounded_clip_stack: Vec::new(),
+      surface_width: self.surface_width,
+      surface_height: self.surface_height,
+      scale_factor: self.scale_factor,
       overlay_queue: Vec::new(),
-      data_provider,
-      widget_tracker,
-      deferred_draws,
+      data_provider: self.data_provider,
+      widget_tracker: self.widget_tracker,
+      deferred_draws: self.deferred_draws,
+      styles: self.styles,
+      text_defaults: Default::default(),
     }
   }
+}
+
+impl<'a> RenderContext<'a> {
+  /// Starts a context drawing with `renderer`, at its size and scale
+  /// factor and with its icons.
+  pub fn builder(
+    renderer: &'a mut crate::renderer::GloomyRenderer,
+    device: &'a wgpu::Device,
+    queue: &'a wgpu::Queue,
+  ) -> RenderContextBuilder<'a> {
+    let size = renderer.size();
+    let scale_factor = renderer.scale_factor;
+    let (primitives, text, images, textures, icons, chart_primitives, chart_text) = renderer.split_mut();
+    RenderContextBuilder::from_parts(primitives, text, images, textures, chart_primitives, chart_text, device, queue)
+      .surface_size(size.x as u32, size.y as u32)
+      .scale_factor(scale_factor)
+      .icons(Some(icons))
+  }
+
+  /// Creates a new render context.
+  ///
+  /// Prefer [`RenderContext::builder`], which names each setting.
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    primitives: &'a mut PrimitiveRenderer,
+    text: &'a mut TextRenderer,
+    images: &'a mut ImageRenderer,
+    textures: &'a mut HashMap<String, Texture>,
+    chart_primitives: &'a mut mpl_wgpu::primitives::PrimitiveRenderer,
+    chart_text: &'a mut mpl_wgpu::text::TextRenderer,
+    device: &'a wgpu::Device,
+    queue: &'a wgpu::Queue,
+    interaction: Option<&'a InteractionState>,
+    surface_width: u32,
+    surface_height: u32,
+    scale_factor: f32,
+    data_provider: Option<&'a dyn crate::data_source::DataProvider>,
+    widget_tracker: Option<&'a mut crate::widget_state::WidgetStateTracker>,
+    deferred_draws: Option<&'a mut Vec<Box<dyn FnOnce(&mut crate::renderer::GloomyRenderer, &wgpu::Device, &wgpu::Queue)>>>,
+  ) -> Self {
+    RenderContextBuilder::from_parts(primitives, text, images, textures, chart_primitives, chart_text, device, queue)
+      .interaction(interaction)
+      .surface_size(surface_width, surface_height)
+      .scale_factor(scale_factor)
+      .data_provider(data_provider)
+      .widget_tracker(widget_tracker)
+      .deferred_draws(deferred_draws)
+      .build()
+  }
 
+    /// Clips to `rect` intersected with the current scissor until
+    /// `pop_scissor`. `None` keeps the current scissor.
     pub fn push_scissor(&mut self, rect: Option<(u32, u32, u32, u32)>) {
           self.scissor_stack.push(self.current_scissor);
-          
-          
-          if let Some(r) = rect {
-              if let Some(current) = self.current_scissor {
-                  let x = r.0.max(current.0);
-                  let y = r.1.max(current.1);
-                  let w = (r.0 + r.2).min(current.0 + current.2) - x;
-                  let h = (r.1 + r.3).min(current.1 + current.3) - y;
-                  if w > 0 && h > 0 {
-                      self.current_scissor = Some((x, y, w, h));
-                  } else {
-                      self.current_scissor = Some((0, 0, 0, 0));
-                  }
-              }
-          }
+          self.current_scissor = intersect_scissor(self.current_scissor, rect);
           
           self.primitives.set_scissor(self.current_scissor);
           self.text.set_scissor(self.current_scissor);
@@ -134,14 +487,63 @@ impl<'a> RenderContext<'a> {
         self.images.set_scissor(self.current_scissor);
     }
 
+    /// Multiplies `opacity` into the running opacity for a subtree.
+    /// Must be balanced with `pop_opacity`.
+    pub fn push_opacity(&mut self, opacity: f32) {
+        self.opacity_stack.push(self.opacity);
+        self.opacity *= opacity.clamp(0.0, 1.0);
+        self.apply_opacity();
+    }
+
+    pub fn pop_opacity(&mut self) {
+        self.opacity = self.opacity_stack.pop().unwrap_or(1.0);
+        self.apply_opacity();
+    }
+
+    fn apply_opacity(&mut self) {
+        self.primitives.set_opacity(self.opacity);
+        self.text.set_opacity(self.opacity);
+        self.images.set_opacity(self.opacity);
+    }
+
+    /// Area popovers are kept within: the whole surface.
+    pub fn overlay_area(&self) -> Rect {
+        Rect::from_size(self.surface_width as f32, self.surface_height as f32)
+    }
+
+    /// Masks primitives and images to a rounded rect until `pop_rounded_clip`.
+    ///
+    /// This is a per-fragment SDF test, not a stencil: every primitive and
+    /// image fragment drawn while the clip is active evaluates one extra
+    /// rounded-box SDF, and instances carry 32 more bytes whether or not a
+    /// clip is set. Masks do not nest; the innermost one replaces the outer
+    /// one, which still clips through its rectangular scissor. Text is
+    /// drawn by a separate glyph pipeline and is only scissored.
+    pub fn push_rounded_clip(&mut self, clip: RoundedClip) {
+        self.rounded_clip_stack.push(self.rounded_clip);
+        self.rounded_clip = Some(clip);
+        self.primitives.set_rounded_clip(self.rounded_clip);
+        self.images.set_rounded_clip(self.rounded_clip);
+    }
+
+    pub fn pop_rounded_clip(&mut self) {
+        self.rounded_clip = self.rounded_clip_stack.pop().flatten();
+        self.primitives.set_rounded_clip(self.rounded_clip);
+        self.images.set_rounded_clip(self.rounded_clip);
+    }
+
     // --- RENDER CACHING API ---
 
-    pub fn begin_capture(&self) -> CaptureState {
+    /// Starts recording what is drawn for a container at `rect`
+    /// (absolute), see [`RenderCache::replayable_at`].
+    ///
+    /// [`RenderCache::replayable_at`]: crate::widget::RenderCache::replayable_at
+    pub fn begin_capture(&self, rect: Rect) -> CaptureState {
         CaptureState {
             prim_counts: self.primitives.get_counts(),
             text_count: self.text.get_count(),
             img_counts: self.images.get_counts(),
-            base_offset: self.offset,
+            rect,
         }
     }
 
@@ -161,21 +563,21 @@ impl<'a> RenderContext<'a> {
             primitives: Some(primitives),
             text: Some(text),
             images: Some(images),
-            base_offset: start.base_offset,
+            rect: start.rect,
         }
     }
 
+    /// Replays a snapshot in place; only call it where
+    /// `cache.replayable_at` holds.
     pub fn replay_cache(&mut self, cache: &crate::widget::RenderCache) {
-        let delta = self.offset - cache.base_offset;
-        
         if let Some(p) = &cache.primitives {
-            self.primitives.replay(p, delta);
+            self.primitives.replay(p, Vec2::ZERO);
         }
         if let Some(t) = &cache.text {
-            self.text.replay(t, delta);
+            self.text.replay(t, Vec2::ZERO);
         }
         if let Some(i) = &cache.images {
-            self.images.replay(i, delta);
+            self.images.replay(i, Vec2::ZERO);
         }
     }
 }
@@ -184,12 +586,13 @@ pub struct CaptureState {
     pub prim_counts: (usize, usize),
     pub text_count: usize,
     pub img_counts: (usize, usize),
-    pub base_offset: Vec2,
+    pub rect: Rect,
 }
 
 /// Renders text with optional rich text markup support.
 /// Automatically detects and parses HTML-like markup.
 /// Uses cached parsing to avoid re-parsing unchanged text.
+/// Returns the width of the drawn text.
 fn render_text_field(
     ctx: &mut RenderContext,
     text: &str,
@@ -199,7 +602,8 @@ fn render_text_field(
     default_font: Option<&str>,
     align: TextAlign,
     max_width: Option<f32>,
-) {
+    tab_width: Option<f32>,
+) -> f32 {
     use crate::rich_text::{RichText, TextStyle};
     use std::collections::HashMap;
     use std::cell::RefCell;
@@ -249,10 +653,42 @@ fn render_text_field(
                 RichText::parse(text, base_style)
         };
         
-        render_rich_text(ctx, &rich_text, pos, default_size, align, max_width);
+        render_rich_text(ctx, &rich_text, pos, default_size, align, max_width, tab_width)
+    } else if let Some(tab) = tab_width.filter(|_| text.contains('\t')) {
+        // Draw each tab-separated segment at its stop.
+        let mut segments = Vec::new();
+        let mut x = 0.0;
+        for (i, segment) in text.split('\t').enumerate() {
+            if i > 0 {
+                x = crate::rich_text::next_tab_stop(x, tab);
+            }
+            let width = ctx.text.measure(segment, default_size, default_font).x;
+            segments.push((segment, x, width));
+            x += width;
+        }
+        let start = match align {
+            TextAlign::Left => 0.0,
+            TextAlign::Center => -x / 2.0,
+            TextAlign::Right => -x,
+        };
+        for (segment, seg_x, width) in segments {
+            ctx.text.draw_measured(
+                ctx.device,
+                ctx.queue,
+                segment,
+                Vec2::new(pos.x + start + seg_x, pos.y),
+                default_size,
+                Vec4::from(default_color),
+                HorizontalAlign::Left,
+                default_font,
+                width,
+            );
+        }
+        x
     } else {
         // Render as plain text (fast path)
-        ctx.text.draw(
+        let width = ctx.text.measure(text, default_size, default_font).x;
+        ctx.text.draw_measured(
             ctx.device,
             ctx.queue,
             text,
@@ -260,13 +696,16 @@ fn render_text_field(
             default_size,
             Vec4::from(default_color),
             map_text_align(align),
-            default_font
+            default_font,
+            width,
         );
+        width
     }
 }
 
 /// Renders parsed rich text with per-span styling.
 /// Uses per-character rendering with glyph caching for optimal performance.
+/// Returns the width used for alignment.
 fn render_rich_text(
     ctx: &mut RenderContext,
     rich_text: &crate::rich_text::RichText,
@@ -274,16 +713,40 @@ fn render_rich_text(
     default_size: f32,
     align: TextAlign,
     _max_width: Option<f32>,
-) {
+    tab_width: Option<f32>,
+) -> f32 {
     // Calculate total width for alignment
-    let (total_width, _) = rich_text.measure(default_size);
+    let total_width = match tab_width {
+        // Stops depend on the real advances, so measure glyph by glyph.
+        Some(tab) if rich_text.spans.iter().any(|s| s.text.contains('\t')) => {
+            let mut x = 0.0;
+            for span in &rich_text.spans {
+                let size = span.style.font_size.unwrap_or(default_size);
+                let font_name = ctx.text.get_font_for_style(
+                    span.style.font_family.as_deref(),
+                    span.style.bold,
+                    span.style.italic
+                );
+                for ch in span.text.chars() {
+                    x = if ch == '\t' {
+                        crate::rich_text::next_tab_stop(x, tab)
+                    } else {
+                        x + ctx.text.measure_char_cached(ch, size, font_name)
+                    };
+                }
+            }
+            x
+        }
+        _ => rich_text.measure(default_size).0,
+    };
     
     // Calculate starting X offset based on alignment
-    let mut x_offset = match align {
+    let start = match align {
         TextAlign::Left => 0.0,
         TextAlign::Center => -total_width / 2.0,
         TextAlign::Right => -total_width,
     };
+    let mut x_offset = start;
     
     // Render each span with per-character positioning (uses glyph cache)
     for span in &rich_text.spans {
@@ -299,6 +762,10 @@ fn render_rich_text(
         
         // Render each character with cached measurements
         for ch in span.text.chars() {
+            if let (Some(tab), '\t') = (tab_width, ch) {
+                x_offset = start + crate::rich_text::next_tab_stop(x_offset - start, tab);
+                continue;
+            }
             let ch_str = ch.to_string();
             let char_pos = Vec2::new(base_pos.x + x_offset, base_pos.y);
             
@@ -331,6 +798,38 @@ fn render_rich_text(
             x_offset += char_width;
         }
     }
+    total_width
+}
+
+const LABEL_SELECTION_COLOR: Vec4 = Vec4::new(0.25, 0.45, 0.85, 0.5);
+
+/// Absolute x of every character boundary of a plain-text label drawn
+/// with its anchor at `anchor_x`, honouring tab stops.
+fn label_char_stops(
+    ctx: &mut RenderContext,
+    text: &str,
+    anchor_x: f32,
+    size: f32,
+    font: Option<&str>,
+    align: TextAlign,
+    tab: f32,
+) -> Vec<f32> {
+    let mut stops = vec![0.0];
+    let mut x = 0.0;
+    for ch in text.chars() {
+        x = if ch == '\t' {
+            crate::rich_text::next_tab_stop(x, tab)
+        } else {
+            x + ctx.text.measure_char_cached(ch, size, font)
+        };
+        stops.push(x);
+    }
+    let start = match align {
+        TextAlign::Left => anchor_x,
+        TextAlign::Center => anchor_x - x * 0.5,
+        TextAlign::Right => anchor_x - x,
+    };
+    stops.iter().map(|stop| start + stop).collect()
 }
 
 /// Maps TextAlign to glyph_brush HorizontalAlign.
@@ -463,7 +962,7 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
             pb.render(ctx.chart_primitives, ctx.chart_text, Some(transform));
         }
     }
-    Widget::ToggleSwitch { id, checked, style, bounds, .. } => {
+    Widget::ToggleSwitch { id, checked, style, bounds, toggled_at, .. } => {
         let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
         let center = pos + Vec2::new(bounds.width * 0.5, bounds.height * 0.5);
         let track_h = if style.track_height > 0.0 { style.track_height } else { 20.0 };
@@ -473,18 +972,26 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
         ctx.primitives.draw_rect(center, Vec2::new(bounds.width * 0.5, track_h * 0.5), Vec4::new(track_col.0, track_col.1, track_col.2, track_col.3), [track_h * 0.5; 4], 0.0);
         let pad = 2.0;
         let travel = bounds.width - (thumb_r * 2.0) - (pad * 2.0);
-        let offset_x = if *checked { travel } else { 0.0 };
-        let thumb_x = pad + thumb_r + offset_x - (bounds.width * 0.5); // Relative to center?
+        let offset_x = travel * toggle_thumb_position(*checked, *toggled_at, style.animation_secs, ctx.time);
         // Primitive renderer draw_circle takes center pos.
         let thumb_pos = pos + Vec2::new(pad + thumb_r + offset_x, bounds.height * 0.5); 
         ctx.primitives.draw_circle(thumb_pos, thumb_r, Vec4::new(thumb_col.0, thumb_col.1, thumb_col.2, thumb_col.3), 0.0);
     }
-    Widget::ProgressBar { value, min, max, style, bounds, .. } => {
+    Widget::ProgressBar { value, min, max, indeterminate, style, bounds, .. } => {
         let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
         let center = pos + Vec2::new(bounds.width * 0.5, bounds.height * 0.5);
         let bg = style.background_color.unwrap_or((0.15, 0.15, 0.18, 1.0));
         let cr = style.corner_radius;
         ctx.primitives.draw_rect(center, Vec2::new(bounds.width * 0.5, bounds.height * 0.5), Vec4::new(bg.0, bg.1, bg.2, bg.3), [cr; 4], 0.0);
+        if *indeterminate {
+            if let Some((start, end)) = indeterminate_segment(ctx.time, bounds.width) {
+                let seg_w = end - start;
+                let seg_center = pos + Vec2::new(start + seg_w * 0.5, bounds.height * 0.5);
+                let fill_col = style.fill_color.unwrap_or((0.3, 0.5, 0.9, 1.0));
+                ctx.primitives.draw_rect(seg_center, Vec2::new(seg_w * 0.5, bounds.height * 0.5), Vec4::new(fill_col.0, fill_col.1, fill_col.2, fill_col.3), [cr; 4], 0.0);
+            }
+            return;
+        }
         let range = max - min;
         let pct = if range > 0.0 { ((*value - min) / range).clamp(0.0, 1.0) } else { 0.0 };
         if pct > 0.0 {
@@ -494,6 +1001,335 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
             ctx.primitives.draw_rect(fill_center, Vec2::new(fill_w * 0.5, bounds.height * 0.5), Vec4::new(fill_col.0, fill_col.1, fill_col.2, fill_col.3), [cr; 4], 0.0);
         }
     }
+    Widget::CircularProgress { value, min, max, indeterminate, show_label, style, bounds, .. } => {
+        let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
+        let center = pos + Vec2::new(bounds.width * 0.5, bounds.height * 0.5);
+        let thickness = if style.thickness > 0.0 { style.thickness } else { 4.0 };
+        let radius = (bounds.width.min(bounds.height) - thickness) * 0.5;
+        if radius <= 0.0 {
+            return;
+        }
+        let tau = std::f32::consts::TAU;
+
+        let track = style.track_color.unwrap_or((0.15, 0.15, 0.18, 1.0));
+        ctx.primitives.draw_arc(center, radius, 0.0, tau, thickness, Vec4::from(track));
+
+        let fill = Vec4::from(style.fill_color.unwrap_or((0.3, 0.5, 0.9, 1.0)));
+        if *indeterminate {
+            // Quarter-ring spinner, one revolution per second
+            ctx.primitives.draw_arc(center, radius, ctx.time.fract() * tau, tau * 0.25, thickness, fill);
+            return;
+        }
+
+        let range = max - min;
+        let pct = if range > 0.0 { ((*value - min) / range).clamp(0.0, 1.0) } else { 0.0 };
+        ctx.primitives.draw_arc(center, radius, 0.0, pct * tau, thickness, fill);
+
+        if *show_label {
+            let size = if style.font_size > 0.0 { style.font_size } else { (radius * 0.6).max(8.0) };
+            let label = format!("{:.0}%", pct * 100.0);
+            let dims = ctx.text.measure(&label, size, None);
+            let text_col = Vec4::from(style.text_color.unwrap_or((0.9, 0.9, 0.9, 1.0)));
+            ctx.text.draw(ctx.device, ctx.queue, &label, center - dims * 0.5, size, text_col, HorizontalAlign::Left, None);
+        }
+    }
+    Widget::VirtualFlow { id, bounds, realized, .. } => {
+        let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
+        let scroll_offset = ctx.interaction
+            .and_then(|i| i.scroll_offsets.get(id.as_str()))
+            .map(|v| v.y)
+            .unwrap_or(0.0);
+
+        let s = ctx.scale_factor;
+        ctx.push_scissor(Some((
+            (pos.x * s).floor() as u32,
+            (pos.y * s).floor() as u32,
+            (bounds.width * s).ceil() as u32,
+            (bounds.height * s).ceil() as u32,
+        )));
+        // Items were laid out relative to the flow's content.
+        let old_offset = ctx.offset;
+        ctx.offset = pos - Vec2::new(0.0, scroll_offset);
+        for (_, item) in realized {
+            render_widget(item, ctx);
+        }
+        ctx.offset = old_offset;
+        ctx.pop_scissor();
+    }
+
+    Widget::Skeleton { shape, style, paused, bounds, .. } => {
+        let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
+        let (center, half, radius) = match shape {
+            crate::widget::SkeletonShape::Line => {
+                let half = Vec2::new(bounds.width, bounds.height) * 0.5;
+                (pos + half, half, half.y)
+            }
+            crate::widget::SkeletonShape::Rect => {
+                let half = Vec2::new(bounds.width, bounds.height) * 0.5;
+                (pos + half, half, style.corner_radius)
+            }
+            crate::widget::SkeletonShape::Circle => {
+                let r = bounds.width.min(bounds.height) * 0.5;
+                (pos + Vec2::splat(r), Vec2::splat(r), r)
+            }
+        };
+        if half.x <= 0.0 || half.y <= 0.0 {
+            return;
+        }
+        let radii = [radius; 4];
+        let base = Vec4::from(style.color.unwrap_or((0.2, 0.2, 0.23, 1.0)));
+        ctx.primitives.draw_rect(center, half, base, radii, 0.0);
+        if *paused {
+            return;
+        }
+
+        // Soft band built from slices whose alpha ramps up to the middle,
+        // clipped to the placeholder's outline.
+        const SLICES: usize = 12;
+        let period = if style.shimmer_period > 0.0 { style.shimmer_period } else { 1.5 };
+        let band = (half.x * 2.0 * 0.4).max(24.0);
+        let band_x = shimmer_offset(ctx.time, period, half.x * 2.0, band);
+        let shimmer = Vec4::from(style.shimmer_color.unwrap_or((1.0, 1.0, 1.0, 0.08)));
+        let slice_w = band / SLICES as f32;
+        ctx.push_rounded_clip(RoundedClip { center, half_size: half, radii });
+        for i in 0..SLICES {
+            let t = (i as f32 + 0.5) / SLICES as f32;
+            let mut color = shimmer;
+            color.w *= 1.0 - (t * 2.0 - 1.0).abs();
+            let slice_center = Vec2::new(center.x - half.x + band_x + slice_w * (i as f32 + 0.5), center.y);
+            ctx.primitives.draw_rect(slice_center, Vec2::new(slice_w * 0.5, half.y), color, [0.0; 4], 0.0);
+        }
+        ctx.pop_rounded_clip();
+    }
+    Widget::ChipInput { id, chips, value, placeholder, style, bounds, .. } => {
+        let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
+        let half = Vec2::new(bounds.width, bounds.height) * 0.5;
+        let is_focused = ctx.interaction.map(|s| s.focused_id.as_deref() == Some(id)).unwrap_or(false);
+
+        let bg = style.background.unwrap_or((0.15, 0.15, 0.18, 1.0));
+        ctx.primitives.draw_rect(pos + half, half, Vec4::from(bg), [style.corner_radius; 4], 0.0);
+        let border = if is_focused { style.border_focused.or(style.border) } else { style.border };
+        if let Some(b) = border {
+            ctx.primitives.draw_border(pos + half, half, [style.corner_radius; 4], b.width, Vec4::from(b.color));
+        }
+
+        let size = style.text_size();
+        let layout = crate::chips::layout_chips(chips, size, bounds.width);
+        let chip_bg = Vec4::from(style.chip_background.unwrap_or((0.25, 0.35, 0.5, 1.0)));
+        let chip_text = Vec4::from(style.chip_text_color.unwrap_or((0.95, 0.95, 0.95, 1.0)));
+        let hovered = ctx.interaction.and_then(|s| s.hovered_action.clone());
+
+        for (i, (label, rect)) in chips.iter().zip(&layout.chips).enumerate() {
+            let chip_half = Vec2::new(rect.width, rect.height) * 0.5;
+            let chip_center = pos + Vec2::new(rect.x, rect.y) + chip_half;
+            ctx.primitives.draw_rect(chip_center, chip_half, chip_bg, [rect.height * 0.5; 4], 0.0);
+            ctx.text.draw(ctx.device, ctx.queue, label, pos + Vec2::new(rect.x + 8.0, rect.y + 5.0), size, chip_text, HorizontalAlign::Left, None);
+
+            // "×" drawn as two short lines so it does not depend on the font
+            let x_center = pos + Vec2::new(rect.x + rect.width - crate::chips::REMOVE_WIDTH * 0.5 - 2.0, rect.y + rect.height * 0.5);
+            let arm = 3.5;
+            let remove_hovered = hovered.as_deref() == Some(format!("{}:remove:{}", id, i).as_str());
+            let mut x_col = chip_text;
+            x_col.w *= if remove_hovered { 1.0 } else { 0.6 };
+            ctx.primitives.draw_line(x_center - Vec2::splat(arm), x_center + Vec2::splat(arm), 1.5, x_col);
+            ctx.primitives.draw_line(x_center + Vec2::new(-arm, arm), x_center + Vec2::new(arm, -arm), 1.5, x_col);
+        }
+
+        // Inline field
+        let input_pos = pos + Vec2::new(layout.input_x + 2.0, layout.input_y + 5.0);
+        if value.is_empty() {
+            if chips.is_empty() && !placeholder.is_empty() {
+                let ph = Vec4::from(style.placeholder_color.unwrap_or((0.5, 0.5, 0.6, 1.0)));
+                ctx.text.draw(ctx.device, ctx.queue, placeholder, input_pos, size, ph, HorizontalAlign::Left, None);
+            }
+        } else {
+            let text_col = Vec4::from(style.text_color.unwrap_or((0.9, 0.9, 0.9, 1.0)));
+            ctx.text.draw(ctx.device, ctx.queue, value, input_pos, size, text_col, HorizontalAlign::Left, None);
+        }
+        if is_focused && (ctx.time * 2.0) as u32 % 2 == 0 {
+            let cursor_x = input_pos.x + if value.is_empty() { 0.0 } else { ctx.text.measure(value, size, None).x };
+            let text_col = Vec4::from(style.text_color.unwrap_or((0.9, 0.9, 0.9, 1.0)));
+            ctx.primitives.draw_line(Vec2::new(cursor_x, input_pos.y), Vec2::new(cursor_x, input_pos.y + size), 1.5, text_col);
+        }
+    }
+    Widget::Rating { id, value, max, read_only, style, bounds, .. } => {
+        let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
+        let (size, gap) = (style.size(), style.gap());
+        let radius = size * 0.5;
+
+        // Hovering previews the value the click would set
+        let preview = if *read_only {
+            None
+        } else {
+            ctx.interaction
+                .and_then(|s| s.hovered_action.as_deref())
+                .and_then(|action| rating_action_value(action, id))
+        };
+        let shown = preview.unwrap_or(*value);
+        let fill_col = match (preview, style.hover_color) {
+            (Some(_), Some(hover)) => hover,
+            _ => style.filled_color.unwrap_or((0.95, 0.75, 0.2, 1.0)),
+        };
+        let empty_col = Vec4::from(style.empty_color.unwrap_or((0.3, 0.3, 0.35, 1.0)));
+
+        for i in 0..*max {
+            let center = pos + Vec2::new(i as f32 * (size + gap) + radius, bounds.height * 0.5);
+            ctx.primitives.draw_star(center, radius, 1.0, style.outline_width, empty_col);
+            let fill = (shown - i as f32).clamp(0.0, 1.0);
+            ctx.primitives.draw_star(center, radius, fill, 0.0, Vec4::from(fill_col));
+        }
+    }
+    Widget::MenuBar { menus, open, highlighted, style, bounds, .. } => {
+        let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
+        let size = style.text_size();
+        let text_col = Vec4::from(style.text_color.unwrap_or((0.9, 0.9, 0.9, 1.0)));
+        let highlight = Vec4::from(style.highlight_color.unwrap_or((0.25, 0.35, 0.5, 1.0)));
+        let bg = Vec4::from(style.background.unwrap_or((0.15, 0.15, 0.18, 1.0)));
+        let half = Vec2::new(bounds.width, bounds.height) * 0.5;
+        ctx.primitives.draw_rect(pos + half, half, bg, [0.0; 4], 0.0);
+
+        let spans = crate::menu::title_spans(menus, size);
+        let text_y = pos.y + (bounds.height - size) * 0.5;
+        for (i, (menu, (x, w))) in menus.iter().zip(&spans).enumerate() {
+            if *open == Some(i) {
+                let title_half = Vec2::new(w * 0.5, bounds.height * 0.5);
+                ctx.primitives.draw_rect(Vec2::new(pos.x + x, pos.y) + title_half, title_half, highlight, [0.0; 4], 0.0);
+            }
+            ctx.text.draw(ctx.device, ctx.queue, &menu.label, Vec2::new(pos.x + x + w * 0.5, text_y), size, text_col, HorizontalAlign::Center, None);
+        }
+
+        // Dropped-down panel goes through the overlay pass.
+        if let Some((menu, (x, w))) = open.and_then(|i| menus.get(i).zip(spans.get(i).copied())) {
+            let items = menu.items.clone();
+            let width = style.panel_width_or_default();
+            let title = Rect::new(pos.x + x, pos.y, w, bounds.height);
+            let origin = crate::menu::panel_origin(title, width, &items, Some(&ctx.overlay_area()));
+            let panel_bg = Vec4::from(style.panel_background.unwrap_or((0.2, 0.2, 0.25, 1.0)));
+            let disabled = Vec4::from(style.disabled_color.unwrap_or((0.5, 0.5, 0.55, 1.0)));
+            let highlighted = *highlighted;
+            let hovered = ctx.interaction.and_then(|s| s.hovered_action.clone());
+
+            if let Some(deferred) = ctx.deferred_draws.as_mut() {
+                deferred.push(Box::new(move |renderer, device, queue| {
+                    let (primitives, text) = renderer.split_overlay_mut();
+                    let panel_half = Vec2::new(width, crate::menu::panel_height(&items)) * 0.5;
+                    primitives.draw_styled_rect(origin + panel_half + Vec2::new(0.0, 4.0), panel_half, Vec4::new(0.0, 0.0, 0.0, 0.5), Vec4::new(0.0, 0.0, 0.0, 0.5), [4.0; 4], 0.0, 8.0);
+                    primitives.draw_rect(origin + panel_half, panel_half, panel_bg, [4.0; 4], 0.0);
+
+                    let mut y = origin.y;
+                    for (i, item) in items.iter().enumerate() {
+                        let h = item.height();
+                        match item {
+                            crate::menu::MenuItem::Separator => {
+                                primitives.fill_rect(Vec2::new(origin.x + width * 0.5, y + h * 0.5), Vec2::new(width * 0.5 - 8.0, 0.5), disabled);
+                            }
+                            crate::menu::MenuItem::Item { label, action, shortcut, disabled: is_disabled } => {
+                                let active = highlighted == Some(i) || hovered.as_deref() == Some(action.as_str());
+                                if active && !is_disabled {
+                                    let row_half = Vec2::new(width * 0.5, h * 0.5);
+                                    primitives.draw_rect(Vec2::new(origin.x, y) + row_half, row_half, highlight, [0.0; 4], 0.0);
+                                }
+                                let color = if *is_disabled { disabled } else { text_col };
+                                let ty = y + (h - size) * 0.5;
+                                text.draw(device, queue, label, Vec2::new(origin.x + 12.0, ty), size, color, HorizontalAlign::Left, None);
+                                if let Some(shortcut) = shortcut {
+                                    text.draw(device, queue, shortcut, Vec2::new(origin.x + width - 12.0, ty), size, disabled, HorizontalAlign::Right, None);
+                                }
+                            }
+                        }
+                        y += h;
+                    }
+                }));
+            }
+        }
+    }
+    Widget::Breadcrumb { segments, separator, style, bounds, crumb_slots, .. } => {
+        let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
+        let size = if style.font_size > 0.0 { style.font_size } else { 14.0 };
+        let text_col = Vec4::from(style.text_color.unwrap_or((0.6, 0.7, 0.9, 1.0)));
+        let current_col = Vec4::from(style.current_color.unwrap_or((0.9, 0.9, 0.9, 1.0)));
+        let sep_col = Vec4::from(style.separator_color.unwrap_or((0.5, 0.5, 0.55, 1.0)));
+
+        let widths: Vec<f32> = segments.iter().map(|seg| ctx.text.measure(seg, size, None).x).collect();
+        let sep_label = format!(" {} ", separator);
+        let sep_w = ctx.text.measure(&sep_label, size, None).x;
+        let ellipsis_w = ctx.text.measure("\u{2026}", size, None).x;
+        let slots = crate::breadcrumb::layout_crumbs(&widths, sep_w, ellipsis_w, bounds.width);
+
+        // Clip an oversized last segment to the widget, within any
+        // clipping parent.
+        let s = ctx.scale_factor;
+        ctx.push_scissor(Some((
+            (pos.x * s).max(0.0).floor() as u32,
+            (pos.y * s).max(0.0).floor() as u32,
+            (bounds.width * s).max(0.0).ceil() as u32,
+            (bounds.height * s).max(0.0).ceil() as u32,
+        )));
+        let text_y = pos.y + (bounds.height - size) * 0.5;
+        for slot in &slots {
+            let (label, color) = match slot.part {
+                crate::breadcrumb::CrumbPart::Segment(i) => {
+                    let color = if i + 1 == segments.len() { current_col } else { text_col };
+                    (segments[i].as_str(), color)
+                }
+                crate::breadcrumb::CrumbPart::Separator => (sep_label.as_str(), sep_col),
+                crate::breadcrumb::CrumbPart::Ellipsis => ("\u{2026}", sep_col),
+            };
+            ctx.text.draw(ctx.device, ctx.queue, label, Vec2::new(pos.x + slot.x, text_y), size, color, HorizontalAlign::Left, None);
+        }
+        ctx.pop_scissor();
+
+        *crumb_slots.borrow_mut() = slots;
+    }
+    Widget::Stepper { steps, current, orientation, style, bounds, .. } => {
+        let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
+        let radius = style.radius();
+        let geo = StepperGeometry::new(pos, bounds, steps.len(), *orientation, radius);
+
+        let completed = Vec4::from(style.completed_color.unwrap_or((0.2, 0.7, 0.4, 1.0)));
+        let active = Vec4::from(style.current_color.unwrap_or((0.3, 0.5, 0.9, 1.0)));
+        let upcoming = Vec4::from(style.upcoming_color.unwrap_or((0.4, 0.4, 0.45, 1.0)));
+        let line = Vec4::from(style.line_color.unwrap_or((0.3, 0.3, 0.35, 1.0)));
+        let text_col = Vec4::from(style.text_color.unwrap_or((0.9, 0.9, 0.9, 1.0)));
+        let text_size = style.text_size();
+
+        // Connectors stop at the circle edges.
+        for i in 1..steps.len() {
+            let a = geo.center(i - 1);
+            let b = geo.center(i);
+            let dir = (b - a).normalize_or_zero();
+            let color = if i <= *current { completed } else { line };
+            ctx.primitives.draw_line(a + dir * (radius + 4.0), b - dir * (radius + 4.0), 2.0, color);
+        }
+
+        for (i, label) in steps.iter().enumerate() {
+            let c = geo.center(i);
+            let number = (i + 1).to_string();
+            let number_col = if i < *current {
+                ctx.primitives.draw_circle(c, radius, completed, 0.0);
+                text_col
+            } else if i == *current {
+                ctx.primitives.draw_circle(c, radius, active, 0.0);
+                text_col
+            } else {
+                ctx.primitives.draw_circle(c, radius, upcoming, 2.0);
+                upcoming
+            };
+            ctx.text.draw(ctx.device, ctx.queue, &number, c - Vec2::new(0.0, text_size * 0.5), text_size, number_col, HorizontalAlign::Center, None);
+
+            let label_col = if i > *current { upcoming } else { text_col };
+            match orientation {
+                crate::widget::Orientation::Horizontal => {
+                    let label_pos = c + Vec2::new(0.0, radius + 6.0);
+                    ctx.text.draw(ctx.device, ctx.queue, label, label_pos, text_size, label_col, HorizontalAlign::Center, None);
+                }
+                crate::widget::Orientation::Vertical => {
+                    let label_pos = c + Vec2::new(radius + 10.0, -text_size * 0.5);
+                    ctx.text.draw(ctx.device, ctx.queue, label, label_pos, text_size, label_col, HorizontalAlign::Left, None);
+                }
+            }
+        }
+    }
     Widget::RadioButton { selected, style, bounds, .. } => {
          let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
          let center = pos + Vec2::new(bounds.width * 0.5, bounds.height * 0.5);
@@ -531,7 +1367,7 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
                 let action = format!("select_{}_{}", id, i);
                 let is_sel = Some(i) == *selected_index;
                 let bg_col = if is_sel { (0.3, 0.3, 0.4, 1.0) } else { (0.25, 0.25, 0.3, 1.0) };
-                let item_h = 30.0;
+                let item_h = DROPDOWN_ITEM_HEIGHT;
                 let btn = Widget::Button {
                     text: opt.clone(), action, bounds: WidgetBounds { x: 0.0, y: 0.0, width: w, height: item_h },
                     style: ButtonStyle {
@@ -541,40 +1377,70 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
                         text_color: (1.0, 1.0, 1.0, 1.0),
                         ..Default::default()
                     },
+                    classes: Vec::new(),
                     width: None, height: None, disabled: false, layout: Layout::default(),
                     flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
+                    hit_padding: None,
                     font: None,
+                    measured: None,
                 };
                 list_children.push(btn);
             }
-            let list_height = (list_children.len() as f32) * 30.0;
+            let list_height = (list_children.len() as f32) * DROPDOWN_ITEM_HEIGHT;
             let dropdown_list = Widget::Container {
                 id: Some(format!("{}_list", id)), scrollable: false,
+                overflow: Overflow::Visible,
+                clip_to_shape: false,
                 bounds: WidgetBounds { x: 0.0, y: 0.0, width: w, height: list_height },
                 width: Some(w), height: Some(list_height),
                 style: BoxStyle {
                     background: Some((0.2, 0.2, 0.25, 1.0)),
                     border: Some(crate::style::Border { width: 1.0, color: (0.1, 0.1, 0.1, 1.0), ..Default::default() }),
-                    shadow: Some(crate::style::Shadow { offset: (0.0, 4.0), blur: 8.0, color: (0.0, 0.0, 0.0, 0.5) }),
+                    shadows: vec![crate::style::Shadow { offset: (0.0, 4.0), blur: 8.0, color: (0.0, 0.0, 0.0, 0.5) }],
                     ..Default::default()
                 },
-                padding: 0.0, layout: crate::layout::Layout { direction: crate::layout::Direction::Column, ..Default::default() },
+                text_defaults: Default::default(),
+                opacity: 1.0,
+                z_index: 0,
+                padding: Padding::uniform(0.0), focus_trap: false, live_region: None, static_cache: false, scrollbar: Default::default(), scrollbar_mode: Default::default(), layout: crate::layout::Layout { direction: crate::layout::Direction::Column, ..Default::default() },
                 flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 children: list_children,
                 layout_cache: None,
                 render_cache: std::cell::RefCell::new(None),
+                generation: 0,
             };
-            let overlay_pos = pos + Vec2::new(0.0, h);
-            ctx.overlay_queue.push((dropdown_list, overlay_pos));
+            dropdown_popover(pos, Vec2::new(w, h), options.len()).enqueue_widget(ctx, dropdown_list);
         }
     }
-    Widget::Container { id, children, bounds, padding: _, style, scrollable, layout_cache, render_cache, .. } => {
-      // --- RENDER CACHE DISABLED ---
-      // The render cache is causing issues with initial sizing and scroll updates.
-      // TODO: Implement proper cache invalidation based on scroll state and layout changes.
-      // For now, always re-render containers to ensure correctness.
+    Widget::Container { id, children, bounds, padding: _, style, text_defaults, scrollable, overflow, clip_to_shape, opacity, static_cache, render_cache, .. } => {
+      // Fully transparent subtrees draw nothing.
+      let opacity = opacity.clamp(0.0, 1.0);
+      if opacity <= 0.0 {
+          return;
+      }
+
+      // Only subtrees declared static are cached: nothing else tracks
+      // whether a subtree changed. A moved or resized container is drawn
+      // afresh.
+      let cache_rect = static_cache_rect(ctx.offset, bounds);
+      if *static_cache {
+          if let Some(cache) = render_cache.borrow().as_ref().filter(|cache| cache.replayable_at(cache_rect)) {
+              ctx.replay_cache(cache);
+              return;
+          }
+      }
+      let capture_state = static_cache.then(|| ctx.begin_capture(cache_rect));
 
-      let capture_state = ctx.begin_capture();
+      let pushed_opacity = opacity < 1.0;
+      if pushed_opacity {
+          ctx.push_opacity(opacity);
+      }
 
       let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
       let size = Vec2::new(bounds.width, bounds.height);
@@ -583,8 +1449,10 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
 
       let mut child_offset = pos;
       let mut pushed_scissor = false;
+      let overflow = overflow.resolve(*scrollable);
+      let shape_clip = *clip_to_shape && style.corner_radii.iter().any(|r| *r > 0.0);
 
-      if *scrollable {
+      if overflow.scrolls() {
           let scroll = if let Some(wid) = id {
               ctx.interaction.and_then(|i| i.scroll_offsets.get(wid)).copied().unwrap_or(Vec2::ZERO)
           } else {
@@ -592,7 +1460,9 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
           };
           
           child_offset = pos - scroll;
+      }
 
+      if overflow.clips() || shape_clip {
           let s = ctx.scale_factor;
           let x = (pos.x * s).max(0.0).floor() as u32;
           let y = (pos.y * s).max(0.0).floor() as u32;
@@ -602,24 +1472,51 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
           ctx.push_scissor(Some((x, y, w, h)));
           pushed_scissor = true;
       }
+      if shape_clip {
+          // Same shape draw_box fills, so children meet the border cleanly.
+          ctx.push_rounded_clip(RoundedClip {
+              center: pos + size * 0.5,
+              half_size: size * 0.5,
+              radii: style.corner_radii,
+          });
+      }
 
       let old_offset = ctx.offset;
       ctx.offset = child_offset;
-      for child in children {
+      let inherited = (!text_defaults.is_empty()).then(|| {
+          let merged = ctx.text_defaults.inherit(text_defaults);
+          std::mem::replace(&mut ctx.text_defaults, merged)
+      });
+      for child in crate::widget::z_ordered(children) {
           render_widget(child, ctx);
       }
+      if let Some(inherited) = inherited {
+          ctx.text_defaults = inherited;
+      }
       ctx.offset = old_offset;
 
+      if shape_clip {
+          ctx.pop_rounded_clip();
+      }
       if pushed_scissor {
           ctx.pop_scissor();
       }
+      crate::scrollbar::draw_scrollbar(widget, ctx);
+      if pushed_opacity {
+          ctx.pop_opacity();
+      }
 
-      // --- RENDER CACHE UPDATE ---
-      let new_cache = ctx.end_capture(&capture_state);
-      *render_cache.borrow_mut() = Some(Box::new(new_cache));
+      if let Some(capture_state) = capture_state {
+          *render_cache.borrow_mut() = Some(Box::new(ctx.end_capture(&capture_state)));
+      }
     }
 
-    Widget::Label { text, x, y, size, color, text_align, width, height, font, .. } => {
+    Widget::Label {
+      text, id, x, y, size, color, text_align, width, height, font, tab_width,
+      underline, strikethrough, selectable, char_stops, ..
+    } => {
+      let (size, color, font) = crate::widget::label_text(*size, *color, font, &ctx.text_defaults);
+      let (size, color, font) = (&size, &color, &font);
       // Set scissor to clip text within label bounds
       let s = ctx.scale_factor;
       let scissor_x = ((ctx.offset.x + x) * s).max(0.0).floor() as u32;
@@ -640,8 +1537,25 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
           text_pos.x += width;
       }
 
+      // Selection works on plain text only.
+      if *selectable && id.is_some() && !crate::rich_text::RichText::has_markup(text) {
+        let stops = label_char_stops(ctx, text, text_pos.x, *size, font.as_deref(), *text_align, tab_width.to_px(*size));
+        let selection = ctx.interaction
+          .and_then(|i| i.label_selection.as_ref())
+          .filter(|sel| Some(&sel.id) == id.as_ref() && !sel.is_empty());
+        if let Some(sel) = selection {
+          let range = sel.range();
+          let x0 = stops[range.start.min(stops.len() - 1)];
+          let x1 = stops[range.end.min(stops.len() - 1)];
+          let line_h = ctx.text.font_metrics(font.as_deref()).scaled(*size).height();
+          let half = Vec2::new((x1 - x0) * 0.5, line_h * 0.5);
+          ctx.primitives.draw_rect(Vec2::new(x0, text_pos.y) + half, half, LABEL_SELECTION_COLOR, [0.0; 4], 0.0);
+        }
+        *char_stops.borrow_mut() = stops;
+      }
+
       // Use rich text rendering (automatically handles markup)
-      render_text_field(
+      let text_width = render_text_field(
         ctx,
         text,
         text_pos,
@@ -650,7 +1564,36 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
         font.as_deref(),
         *text_align,
         Some(*width),
+        Some(tab_width.to_px(*size)),
       );
+
+      if *underline || *strikethrough {
+        let line_width = if *width > 0.0 { text_width.min(*width) } else { text_width };
+        let start_x = match text_align {
+          TextAlign::Left => text_pos.x,
+          TextAlign::Center => text_pos.x - line_width * 0.5,
+          TextAlign::Right => text_pos.x - line_width,
+        };
+        let metrics = ctx.text.font_metrics(font.as_deref()).scaled(*size);
+        let baseline = text_pos.y + metrics.ascent;
+        let thickness = (*size / 14.0).max(1.0);
+        let mut decorate = |line_y: f32| {
+          ctx.primitives.draw_line(
+            Vec2::new(start_x, line_y),
+            Vec2::new(start_x + line_width, line_y),
+            thickness,
+            Vec4::from(*color),
+          );
+        };
+        if *underline {
+          // Halfway into the descender.
+          decorate(baseline - metrics.descent * 0.5);
+        }
+        if *strikethrough {
+          // Roughly the middle of the x-height.
+          decorate(baseline - metrics.ascent * 0.3);
+        }
+      }
       
       // Restore scissor
       if let Some(prev) = old_scissor {
@@ -660,13 +1603,24 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
 
     Widget::ListView {
         items,
+        item_widgets,
+        item_heights,
         selected_index,
+        multi_select,
+        selected_indices,
+        show_checkboxes,
         style,
+        empty_message,
+        empty_icon,
+        scrollbar,
+        scrollbar_mode,
         bounds,
         id,
         ..
     } => {
          let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
+         // Rows stop short of a scrollbar gutter.
+         let row_width = bounds.width - crate::scrollbar::gutter_width(*scrollbar, *scrollbar_mode);
 
          // 1. Get Scroll Offset
          let scroll_offset = ctx.interaction.as_ref()
@@ -683,33 +1637,49 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
               (bounds.height * s).ceil() as u32
          );
          ctx.push_scissor(Some(list_scissor));
+
+         let count = crate::list_view::row_count(items, item_widgets);
+         if count == 0 {
+             let color = Vec4::from(style.text_color_idle) * Vec4::new(1.0, 1.0, 1.0, 0.6);
+             draw_empty_state(
+                 ctx,
+                 pos,
+                 Vec2::new(bounds.width, bounds.height),
+                 empty_message.as_deref(),
+                 empty_icon.as_deref(),
+                 color,
+             );
+         }
          
          // 3. Calculate visible range
-         let item_h = style.item_height;
-         let start_index = (scroll_offset / item_h).floor() as usize;
-         let visible_count = (bounds.height / item_h).ceil() as usize;
+         let rows = crate::list_view::RowOffsets::new(count, style.item_height, item_heights);
+         let visible = rows.visible_range(scroll_offset, bounds.height);
          // Add buffer
          let buffer = 2;
-         let start_index = start_index.saturating_sub(buffer);
-         let end_index = (start_index + visible_count + buffer * 2).min(items.len());
+         let start_index = visible.start.saturating_sub(buffer);
+         let end_index = (visible.end + buffer).min(count);
 
          let mouse_pos = ctx.interaction.map(|s| s.mouse_pos).unwrap_or(Vec2::ZERO);
          let local_mouse_y = mouse_pos.y - pos.y + scroll_offset;
          let hover_index = if mouse_pos.x >= pos.x && mouse_pos.x <= pos.x + bounds.width 
              && mouse_pos.y >= pos.y && mouse_pos.y <= pos.y + bounds.height 
          {
-             Some((local_mouse_y / item_h) as usize)
+             rows.index_at(local_mouse_y)
          } else {
              None
          };
          
          for i in start_index..end_index {
-             let item = &items[i];
-             let item_y = pos.y + i as f32 * item_h - scroll_offset;
-             let item_rect_pos = Vec2::new(pos.x + bounds.width * 0.5, item_y + item_h * 0.5);
-             let item_size = Vec2::new(bounds.width, item_h);
+             let item_h = rows.height(i);
+             let item_y = pos.y + rows.top(i) - scroll_offset;
+             let item_rect_pos = Vec2::new(pos.x + row_width * 0.5, item_y + item_h * 0.5);
+             let item_size = Vec2::new(row_width, item_h);
              
-             let is_selected = selected_index.map(|si| si == i).unwrap_or(false);
+             let is_selected = if *multi_select {
+                 selected_indices.contains(&i)
+             } else {
+                 selected_index.map(|si| si == i).unwrap_or(false)
+             };
              let is_hovered = hover_index.map(|hi| hi == i).unwrap_or(false);
              
              let (bg_style, text_color) = if is_selected {
@@ -731,46 +1701,36 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
                   );
              }
              
-             // Draw Text
-             let text_pos = Vec2::new(pos.x + 12.0, item_y + item_h * 0.5 - 8.0); 
-             ctx.text.draw(
-                 ctx.device, ctx.queue, item, text_pos, 16.0, 
-                 Vec4::from(text_color), HorizontalAlign::Left, None
-             );
+             let inset = crate::list_view::row_inset(*multi_select, *show_checkboxes);
+             if inset > 0.0 {
+                 let center = Vec2::new(pos.x + inset * 0.5, item_y + item_h * 0.5);
+                 let half = Vec2::splat(crate::list_view::CHECKBOX_SIZE * 0.5);
+                 ctx.primitives.draw_border(center, half, [3.0; 4], 1.5, Vec4::from(text_color));
+                 if is_selected {
+                     ctx.primitives.draw_rect(center, half - Vec2::splat(4.0), Vec4::from(text_color), [1.0; 4], 0.0);
+                 }
+             }
+
+             // Draw Content
+             if let Some(row) = item_widgets.get(i) {
+                 // Rows were laid out relative to the list's content.
+                 let old_offset = ctx.offset;
+                 ctx.offset = pos - Vec2::new(0.0, scroll_offset);
+                 render_widget(row, ctx);
+                 ctx.offset = old_offset;
+             } else {
+                 let text_pos = Vec2::new(pos.x + inset + 12.0, item_y + item_h * 0.5 - 8.0); 
+                 ctx.text.draw(
+                     ctx.device, ctx.queue, &items[i], text_pos, 16.0, 
+                     Vec4::from(text_color), HorizontalAlign::Left, None
+                 );
+             }
          }
          
          ctx.pop_scissor();
 
          // 4. Draw Scrollbar
-         let content_height = items.len() as f32 * item_h;
-         if content_height > bounds.height {
-              let track_w = 10.0;
-              let track_h = bounds.height;
-              let track_x = pos.x + bounds.width - track_w;
-              let track_y = pos.y;
-              
-              // Track
-              ctx.primitives.draw_rect(
-                  Vec2::new(track_x + track_w * 0.5, track_y + track_h * 0.5),
-                  Vec2::new(track_w * 0.5, track_h * 0.5),
-                  Vec4::new(0.0, 0.0, 0.0, 0.2), 
-                  [4.0; 4], 0.0
-              );
-              
-              // Thumb
-              let thumb_h = (bounds.height / content_height * track_h).max(20.0);
-              let max_scroll = content_height - bounds.height;
-              let scroll_ratio = if max_scroll > 0.0 { (scroll_offset / max_scroll).clamp(0.0, 1.0) } else { 0.0 };
-              let thumb_offset = scroll_ratio * (track_h - thumb_h);
-              let thumb_y = track_y + thumb_offset;
-              
-              ctx.primitives.draw_rect(
-                  Vec2::new(track_x + track_w * 0.5, thumb_y + thumb_h * 0.5),
-                  Vec2::new(track_w * 0.5 - 2.0, thumb_h * 0.5), 
-                  Vec4::new(0.5, 0.5, 0.5, 0.8),
-                  [3.0; 4], 0.0
-              );
-         }
+         crate::scrollbar::draw_scrollbar(widget, ctx);
     }
 
     Widget::Button {
@@ -778,10 +1738,20 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
       action,
       bounds,
       style,
+      classes,
       disabled,
       font,
       ..
     } => {
+      let resolved;
+      let style = match ctx.styles {
+          Some(styles) if !classes.is_empty() => {
+              resolved = styles.button_style(classes, style);
+              &resolved
+          }
+          _ => style,
+      };
+      let font = &font.clone().or_else(|| ctx.text_defaults.font.clone());
       let is_disabled = *disabled;
       let is_hovered = ctx.interaction.map(|i| i.is_hovered(action)).unwrap_or(false);
       let is_active = ctx.interaction.map(|i| i.is_active(action)).unwrap_or(false);
@@ -800,9 +1770,28 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
       let size = Vec2::new(bounds.width, bounds.height);
       
       draw_box(ctx, pos, size, box_style);
+
+      // Ripple from the press point, masked to the button's shape.
+      let ripple = ctx.interaction.and_then(|i| i.ripples.get(action)).copied();
+      if let (Some(ripple_color), Some(ripple), false) = (style.ripple, ripple, is_disabled) {
+          let t = (ctx.time - ripple.start) / crate::interaction::RIPPLE_SECONDS;
+          if (0.0..1.0).contains(&t) {
+              let half = size * 0.5;
+              let center = pos + half;
+              // Grow until the circle covers the farthest corner.
+              let reach = (ripple.origin - center).abs() + half;
+              let radius = reach.length() * (1.0 - (1.0 - t) * (1.0 - t));
+              let mut color = Vec4::from(ripple_color);
+              color.w *= 1.0 - t;
+              ctx.push_rounded_clip(RoundedClip { center, half_size: half, radii: box_style.corner_radii });
+              ctx.primitives.draw_circle(ripple.origin, radius, color, 0.0);
+              ctx.pop_rounded_clip();
+          }
+      }
       
-      let text_size = 16.0;
-      let text_pos = pos + Vec2::new(bounds.width * 0.5, (bounds.height - text_size) * 0.5);
+      let text_size = crate::measure::BUTTON_TEXT_SIZE;
+      let metrics = ctx.text.font_metrics(font.as_deref()).scaled(text_size);
+      let text_pos = Vec2::new(pos.x + bounds.width * 0.5, metrics.centered_y(pos.y, bounds.height));
       // Determine text color based on state if supported, or just base color
       let text_col = style.text_color;
 
@@ -815,6 +1804,7 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
         font.as_deref(),
         TextAlign::Center,
         Some(bounds.width),
+        None,
       );
     }
 
@@ -824,10 +1814,20 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
       id,
       font_size,
       text_align,
+      mask,
       style,
+      classes,
       bounds,
       ..
     } => {
+        let resolved;
+        let style = match ctx.styles {
+            Some(styles) if !classes.is_empty() => {
+                resolved = styles.text_input_style(classes, style);
+                &resolved
+            }
+            _ => style,
+        };
         let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
         let size = Vec2::new(bounds.width, bounds.height); 
 
@@ -853,6 +1853,8 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
              ctx.primitives.draw_rect(center, half_size, Vec4::new(1.0, 0.2, 0.2, 1.0), style.idle.corner_radii, 1.5);
         }
 
+        let formatted = mask.as_deref().map(|m| crate::mask::format(m, value));
+        let value = formatted.as_ref().unwrap_or(value);
         let text = if value.is_empty() { placeholder } else { value };
         let col_tuple = if value.is_empty() { style.placeholder_color } else { style.text_color };
         let col = Vec4::new(col_tuple.0, col_tuple.1, col_tuple.2, col_tuple.3);
@@ -863,8 +1865,8 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
         // (Assuming Left align for now as per original code logic usually hardcoded x=8.0)
         // Original code: text_pos = pos + Vec2::new(8.0, ...)
         
-        let text_dims = ctx.text.measure(text, size_val, style.font.as_deref());
-        let text_pos = pos + Vec2::new(8.0, (bounds.height - text_dims.y) * 0.5);
+        let metrics = ctx.text.font_metrics(style.font.as_deref()).scaled(size_val);
+        let text_pos = Vec2::new(pos.x + 8.0, metrics.centered_y(pos.y, bounds.height));
 
         ctx.text.draw(ctx.device, ctx.queue, text, text_pos, size_val, col, HorizontalAlign::Left, style.font.as_deref());
         
@@ -891,6 +1893,8 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
     }
 
     Widget::Spacer { .. } => {}
+    // Unresolved includes (e.g. from `ron::from_str` directly) draw nothing.
+    Widget::Include { .. } => {}
     Widget::NumberInput {
         id,
         value,
@@ -898,6 +1902,7 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
         max: _,
         step: _,
         precision,
+        number_format,
         show_spinner,
         bounds,
         style,
@@ -943,14 +1948,14 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
         let text_area_width = bounds.width - spinner_width - 8.0; // 8px padding
 
         // Value Formatting
-        let text = format!("{:.1$}", value, precision);
+        let text = number_format.format(*value, *precision);
         let col = Vec4::new(style.text_color.0, style.text_color.1, style.text_color.2, style.text_color.3);
         let size = 14.0; // Default font size for now
         
         // Text Rendering
         let text_dims = ctx.text.measure(&text, size, style.font.as_deref());
-        let text_y = (bounds.height - text_dims.y) * 0.5;
-        let text_pos = pos + Vec2::new(8.0, text_y);
+        let metrics = ctx.text.font_metrics(style.font.as_deref()).scaled(size);
+        let text_pos = Vec2::new(pos.x + 8.0, metrics.centered_y(pos.y, bounds.height));
 
         // Clip text if it exceeds area? For now just draw.
         // Helper to clip would be nice, but let's assume it fits or simple scrolling later.
@@ -1036,6 +2041,8 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
         value,
         placeholder,
         suggestions,
+        suggest_state,
+        fuzzy,
         max_visible,
         bounds,
         style,
@@ -1121,26 +2128,28 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
         }
         
         // Dropdown
-        if is_focused && !suggestions.is_empty() {
-             let item_height = 24.0;
+        // A pending provider gets an extra "Loading..." row under whatever
+        // results it already returned.
+        let loading = suggest_state.pending;
+        if is_focused && (!suggestions.is_empty() || loading) {
+             let item_height = AUTOCOMPLETE_ITEM_HEIGHT;
              let count = suggestions.len().min(*max_visible);
-             let dd_height = count as f32 * item_height;
+             let rows = count + loading as usize;
              let dd_width = bounds.width;
-             
-             // Position dropdown below input
-             let dd_pos = pos + Vec2::new(0.0, bounds.height + 2.0); 
-             let dd_center = dd_pos + Vec2::new(dd_width * 0.5, dd_height * 0.5);
-             let dd_half = Vec2::new(dd_width * 0.5, dd_height * 0.5);
+             let popover = suggestion_popover(pos, bounds, rows);
 
              // Clone data for closure
              let style = style.clone();
              let suggestions = suggestions.clone();
              let id = id.clone();
+             let query = if *fuzzy { value.clone() } else { String::new() };
              let hovered_action = ctx.interaction.as_ref().and_then(|s| s.hovered_action.clone());
 
-             let draw_dropdown = move |renderer: &mut crate::renderer::GloomyRenderer, _device: &wgpu::Device, _queue: &wgpu::Queue| {
+             popover.enqueue(ctx, move |renderer, _device, _queue, dd_pos| {
                   // Use overlay renderers for Z-order correctness
                   let (primitives, text) = renderer.split_overlay_mut();
+                  let dd_center = dd_pos + popover.size * 0.5;
+                  let dd_half = popover.size * 0.5;
                   
                   // DD Background
                   let dd_bg = style.dropdown_background.unwrap_or((0.12, 0.12, 0.15, 1.0));
@@ -1181,55 +2190,110 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
                           );
                       }
                       
-                      // Item Text
+                      // Item Text, with fuzzy-matched characters highlighted
                       let tc = style.dropdown_text_color;
                       let item_text_pos = Vec2::new(dd_pos.x + 8.0, item_y + 4.0); 
+                      let matched = crate::fuzzy::fuzzy_match(&query, item)
+                          .filter(|m| !m.indices.is_empty());
+                      if let Some(m) = matched {
+                          let base = crate::rich_text::TextStyle {
+                              color: tc,
+                              font_family: style.font.clone(),
+                              ..Default::default()
+                          };
+                          let highlight = crate::rich_text::TextStyle {
+                              color: style.match_color,
+                              bold: true,
+                              ..base.clone()
+                          };
+                          let mut x = item_text_pos.x;
+                          for span in crate::fuzzy::highlight_spans(item, &m.indices, &base, &highlight) {
+                              let font = text.get_font_for_style(
+                                  span.style.font_family.as_deref(),
+                                  span.style.bold,
+                                  span.style.italic,
+                              );
+                              text.draw(
+                                  _device,
+                                  _queue,
+                                  &span.text,
+                                  Vec2::new(x, item_text_pos.y),
+                                  14.0,
+                                  Vec4::from(span.style.color),
+                                  HorizontalAlign::Left,
+                                  font
+                              );
+                              x += text.measure(&span.text, 14.0, font).x;
+                          }
+                      } else {
+                          text.draw(
+                              _device,
+                              _queue,
+                              item,
+                              item_text_pos,
+                              14.0,
+                              Vec4::new(tc.0, tc.1, tc.2, tc.3),
+                              HorizontalAlign::Left,
+                              style.font.as_deref()
+                          );
+                      }
+                  }
+
+                  if loading {
+                      let tc = style.dropdown_text_color;
+                      let item_y = dd_pos.y + count as f32 * item_height;
                       text.draw(
                           _device,
                           _queue,
-                          item,
-                          item_text_pos,
+                          "Loading...",
+                          Vec2::new(dd_pos.x + 8.0, item_y + 4.0),
                           14.0,
-                          Vec4::new(tc.0, tc.1, tc.2, tc.3),
+                          Vec4::new(tc.0, tc.1, tc.2, tc.3 * 0.6),
                           HorizontalAlign::Left,
                           style.font.as_deref()
                       );
                   }
-             };
-
-             if let Some(queue) = &mut ctx.deferred_draws {
-                 queue.push(Box::new(draw_dropdown));
-             } else {
-                 // Fallback if no deferred queue available (e.g. tests)
-                 // Just skip or log? For now skip to be safe.
-             }
+             });
         }
     }
     
     Widget::Spacer { .. } => {}
-    Widget::Divider { bounds, orientation, thickness, color, margin, .. } => {
+    Widget::Divider { bounds, orientation, thickness, color, margin, label, label_size, .. } => {
       use crate::widget::Orientation;
       let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
+      let line_col = Vec4::new(color.0, color.1, color.2, color.3);
       
       // Draw divider based on orientation
       match orientation {
         Orientation::Horizontal => {
-          let line_pos = Vec2::new(pos.x, pos.y + margin);
-          let line_size = Vec2::new(bounds.width, *thickness);
-          ctx.primitives.draw_rect(
-            line_pos,
-            line_size,
-            Vec4::new(color.0, color.1, color.2, color.3),
-            [0.0; 4],
-            0.0
-          );
+          // The line runs through the middle of the band between the margins.
+          let center_y = pos.y + bounds.height * 0.5;
+          let font = ctx.text_defaults.font.clone();
+          let dims = label.as_ref().map(|label| ctx.text.measure(label, *label_size, font.as_deref()));
+          for (center, half) in divider_lines(pos, Vec2::new(bounds.width, bounds.height), *thickness, dims.map(|d| d.x)) {
+            ctx.primitives.draw_rect(center, half, line_col, [0.0; 4], 0.0);
+          }
+          if let (Some(label), Some(dims)) = (label, dims) {
+            let text_x = pos.x + (bounds.width - dims.x) * 0.5;
+            let text_col = ctx.text_defaults.color.unwrap_or((0.6, 0.6, 0.6, 1.0));
+            ctx.text.draw(
+              ctx.device,
+              ctx.queue,
+              label,
+              Vec2::new(text_x, center_y - dims.y * 0.5),
+              *label_size,
+              Vec4::new(text_col.0, text_col.1, text_col.2, text_col.3),
+              HorizontalAlign::Left,
+              font.as_deref()
+            );
+          }
         }
         Orientation::Vertical => {
-          let line_pos = Vec2::new(pos.x + margin, pos.y);
-          let line_size = Vec2::new(*thickness, bounds.height);
+          let line_center = Vec2::new(pos.x + margin + thickness * 0.5, pos.y + bounds.height * 0.5);
+          let line_half = Vec2::new(thickness * 0.5, bounds.height * 0.5);
           ctx.primitives.draw_rect(
-            line_pos,
-            line_size,
+            line_center,
+            line_half,
             Vec4::new(color.0, color.1, color.2, color.3),
             [0.0; 4],
             0.0
@@ -1297,17 +2361,28 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
       data_source_id,
       header_height,
       row_height,
+      auto_row_height,
+      fitted_rows,
       striped,
       show_vertical_lines,
       show_horizontal_lines,
       style,
       id,
+      selection_mode,
       selected_rows,
       sort_column,
       sort_direction,
+      sort_keys,
+      empty_message,
+      empty_icon,
+      scrollbar,
+      scrollbar_mode,
+      content_height,
       ..
     } => {
       let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
+      // Columns and cells stop short of a scrollbar gutter.
+      let body_width = bounds.width - crate::scrollbar::gutter_width(*scrollbar, *scrollbar_mode);
       
       // Calculate Scroll Offset
       let scroll_offset = id.as_ref()
@@ -1323,39 +2398,7 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
            .and_then(|id| ctx.data_provider.and_then(|dp| dp.get_source(id)));
 
       // 2. Calculate column widths
-      let available_width = bounds.width;
-      let mut col_widths = Vec::with_capacity(columns.len());
-      let mut total_fixed = 0.0;
-      let mut total_flex = 0.0;
-      
-      for col in columns {
-          match col.width {
-              crate::datagrid::ColumnWidth::Fixed(w) => {
-                  total_fixed += w;
-              }
-              crate::datagrid::ColumnWidth::Flex(f) => {
-                  total_flex += f;
-              }
-              _ => total_fixed += col.min_width,
-          }
-      }
-      
-      let remaining = (available_width - total_fixed).max(0.0);
-      
-      for col in columns {
-          let w = match col.width {
-              crate::datagrid::ColumnWidth::Fixed(w) => w,
-              crate::datagrid::ColumnWidth::Flex(f) => {
-                  if total_flex > 0.0 {
-                      (remaining * f / total_flex).max(col.min_width)
-                  } else {
-                      col.min_width
-                  }
-              }
-              crate::datagrid::ColumnWidth::Auto => col.min_width, 
-          };
-          col_widths.push(w);
-      }
+      let col_widths = crate::datagrid::column_widths(columns, body_width);
       
       // Background
       ctx.primitives.draw_rect(
@@ -1366,18 +2409,21 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
         0.0,
       );
 
+      // Row tops, from the fitted heights when rows auto-size
+      let fitted = fitted_rows.as_ref().filter(|_| *auto_row_height);
+      let rows = source.map(|ds| crate::datagrid::grid_rows(ds.row_count(), *row_height, fitted));
+      content_height.set(rows.as_ref().map_or(0.0, |rows| rows.total()));
+
       // Render Rows
-      if let Some(ds) = source {
+      if let (Some(ds), Some(rows)) = (source, &rows) {
           let row_count = ds.row_count();
           let visible_height = bounds.height - header_height;
           
-          let visible_rows_count = (visible_height / row_height).ceil() as usize;
-          
           let buffer_size = 5;
-          let calculated_start = (scroll_offset / row_height).floor() as usize;
-          let start_row = calculated_start.saturating_sub(buffer_size);
+          let visible = rows.visible_range(scroll_offset, visible_height);
+          let start_row = visible.start.saturating_sub(buffer_size);
           
-          let end_row = (calculated_start + visible_rows_count + buffer_size).min(row_count);
+          let end_row = (visible.end + buffer_size).min(row_count);
           
           let content_y = pos.y + header_height;
           
@@ -1386,7 +2432,7 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
           let my_scissor_rect = (
               (pos.x * s) as u32,
               (content_y * s) as u32,
-              (bounds.width * s) as u32,
+              (body_width * s) as u32,
               ((visible_height + 0.5) * s) as u32 // +0.5 to prevent sub-pixel cutoff
           );
           
@@ -1417,9 +2463,14 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
           // let old_img_scissor = ctx.images.set_scissor(None); 
           // ...
           
+          let cell_selection = ctx.interaction
+              .and_then(|i| i.cell_selection.clone())
+              .filter(|sel| id.as_deref() == Some(sel.grid.as_str()));
+          
           let mut r = start_row;
           while r < end_row {
-               let row_y = pos.y + header_height + ((r as f32) * row_height) - scroll_offset;
+               let row_y = content_y + rows.top(r) - scroll_offset;
+               let row_height = rows.height(r);
                let center_y = row_y + row_height * 0.5;
                
                // Clip check can be loose now since strictly scissoring
@@ -1433,7 +2484,7 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
                }
                
                // Selection & Striping
-               if selected_rows.contains(&r) {
+               if *selection_mode != crate::datagrid::SelectionMode::Cells && selected_rows.contains(&r) {
                    ctx.primitives.draw_rect(
                        Vec2::new(pos.x + bounds.width * 0.5, center_y),
                        Vec2::new(bounds.width * 0.5, row_height * 0.5),
@@ -1481,6 +2532,17 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
                        break; // All subsequent columns are also to the right
                    }
                    
+                   // Selected cell range
+                   if cell_selection.as_ref().is_some_and(|sel| sel.contains(r, c)) {
+                       ctx.primitives.draw_rect(
+                           Vec2::new(x + w * 0.5, center_y),
+                           Vec2::new(w * 0.5, row_height * 0.5),
+                           Vec4::from(style.selected_background),
+                           [0.0; 4],
+                           0.0
+                       );
+                   }
+                   
                    // Check if this cell is being edited
                    let is_editing = id.as_ref().map(|grid_id| {
                        ctx.interaction.as_ref()
@@ -1526,6 +2588,12 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
                        );
                    } else {
                        let text = ds.cell_text(r, c);
+                       let lines = if *auto_row_height {
+                           let wrap_width = (w - style.cell_padding * 2.0).max(1.0);
+                           crate::datagrid::wrap_cell_text(&text, wrap_width, crate::datagrid::CELL_FONT_SIZE, &*ctx.text)
+                       } else {
+                           Vec::new()
+                       };
                        
                        let (text_align_enum, text_x) = match col.align {
                            crate::widget::TextAlign::Left => (crate::widget::TextAlign::Left, x + style.cell_padding),
@@ -1533,17 +2601,36 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
                            crate::widget::TextAlign::Right => (crate::widget::TextAlign::Right, x + w - style.cell_padding),
                        };
                        
-                       // Use rich text rendering for cells
-                       render_text_field(
-                           ctx,
-                           &text,
-                           Vec2::new(text_x, center_y),
-                           13.0,
-                           style.row_text_color,
-                           None,
-                           text_align_enum,
-                           Some(w),
-                       );
+                       if lines.len() > 1 {
+                           // Wrapped lines stack down from the top padding
+                           let line_h = ctx.text.measure("Ag", crate::datagrid::CELL_FONT_SIZE, None).y;
+                           for (i, line) in lines.iter().enumerate() {
+                               let line_y = row_y + style.cell_padding + line_h * (i as f32 + 0.5);
+                               ctx.text.draw(
+                                   ctx.device,
+                                   ctx.queue,
+                                   line,
+                                   Vec2::new(text_x, line_y),
+                                   crate::datagrid::CELL_FONT_SIZE,
+                                   Vec4::from(style.row_text_color),
+                                   map_text_align(text_align_enum),
+                                   None
+                               );
+                           }
+                       } else {
+                           // Use rich text rendering for cells
+                           render_text_field(
+                               ctx,
+                               &text,
+                               Vec2::new(text_x, center_y),
+                               crate::datagrid::CELL_FONT_SIZE,
+                               style.row_text_color,
+                               None,
+                               text_align_enum,
+                               Some(w),
+                               None,
+                           );
+                       }
                    }
                    
                    // Check for dirty state (modified)
@@ -1579,49 +2666,25 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
            ctx.text.set_scissor(old_text_scissor);
        }
 
-       // Scrollbar
-       if let Some(ds) = source {
-           let row_count = ds.row_count();
-           let total_height = row_count as f32 * row_height;
-           let visible_height = (bounds.height - header_height).max(0.0);
-           
-           if total_height > visible_height {
-                let track_w = 12.0;
-                let track_h = visible_height;
-                let track_x = pos.x + bounds.width - track_w - 2.0;
-                let track_y = pos.y + header_height;
-                
-                // Track
-                let track_center = Vec2::new(track_x + track_w * 0.5, track_y + track_h * 0.5);
-                ctx.primitives.draw_rect(
-                    track_center,
-                    Vec2::new(track_w * 0.5, track_h * 0.5),
-                    Vec4::new(0.0, 0.0, 0.0, 0.2), 
-                    [4.0; 4],
-                    0.0
-                );
-                
-                // Thumb
-                let thumb_h = (visible_height / total_height * track_h).max(20.0);
-                // Clamp ratio 0..1 to be safe
-                let max_scroll = total_height - visible_height;
-                let scroll_ratio = (scroll_offset / max_scroll).clamp(0.0, 1.0);
-                
-                let available_travel = track_h - thumb_h;
-                let thumb_offset = scroll_ratio * available_travel;
-                let thumb_y = track_y + thumb_offset;
-                
-                let thumb_center = Vec2::new(track_x + track_w * 0.5, thumb_y + thumb_h * 0.5);
-                ctx.primitives.draw_rect(
-                    thumb_center,
-                    Vec2::new(track_w * 0.5 - 2.0, thumb_h * 0.5), 
-                    Vec4::new(0.5, 0.5, 0.5, 0.8),
-                    [3.0; 4],
-                    0.0
-                );
-           }
+       // Empty state, centered in the body below the header
+       if source.map_or(true, |ds| ds.row_count() == 0) {
+           let body_pos = Vec2::new(pos.x, pos.y + header_height);
+           let body_size = Vec2::new(bounds.width, (bounds.height - header_height).max(0.0));
+           let s = ctx.scale_factor;
+           ctx.push_scissor(Some((
+               (body_pos.x * s).floor() as u32,
+               (body_pos.y * s).floor() as u32,
+               (body_size.x * s).ceil() as u32,
+               (body_size.y * s).ceil() as u32,
+           )));
+           let color = Vec4::from(style.row_text_color) * Vec4::new(1.0, 1.0, 1.0, 0.6);
+           draw_empty_state(ctx, body_pos, body_size, empty_message.as_deref(), empty_icon.as_deref(), color);
+           ctx.pop_scissor();
        }
 
+       // Scrollbar
+       crate::scrollbar::draw_scrollbar(widget, ctx);
+
       // Header
        ctx.primitives.draw_rect(
          pos + Vec2::new(bounds.width * 0.5, header_height * 0.5),
@@ -1634,6 +2697,29 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
        let mut x = pos.x;
        for (i, col) in columns.iter().enumerate() {
            let w = col_widths[i];
+
+           // Hover / press feedback on sortable headers
+           if col.sortable {
+               let header_action = format!("{}:header:{}", id.as_deref().unwrap_or(""), i);
+               let overlay = ctx.interaction.and_then(|interaction| {
+                   if interaction.hovered_action.as_deref() != Some(header_action.as_str()) {
+                       None
+                   } else if interaction.is_pressed && interaction.active_action.as_deref() == Some(header_action.as_str()) {
+                       Some(style.header_pressed_background)
+                   } else {
+                       Some(style.header_hover_background)
+                   }
+               });
+               if let Some(color) = overlay {
+                   ctx.primitives.draw_rect(
+                       Vec2::new(x + w * 0.5, pos.y + header_height * 0.5),
+                       Vec2::new(w * 0.5, header_height * 0.5),
+                       Vec4::from(color),
+                       [0.0; 4],
+                       0.0,
+                   );
+               }
+           }
            
            ctx.text.draw(
              ctx.device,
@@ -1674,32 +2760,44 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
                  );
             }
 
-            // Sort Indicator
-            if let Some(sc) = sort_column {
-                if *sc == i {
-                     if let Some(dir) = sort_direction {
-                         let arrow = match dir {
-                             crate::data_source::SortDirection::Ascending => "▲",
-                             crate::data_source::SortDirection::Descending => "▼",
-                         };
-                         
-                         // Position at right edge of column
-                         // But we don't have exact text width of header label here easily.
-                         // Just put it at right - padding.
-                         let arrow_x = x + w - style.cell_padding;
-                         
-                         ctx.text.draw(
-                              ctx.device,
-                              ctx.queue,
-                              arrow,
-                              Vec2::new(arrow_x, pos.y + header_height * 0.5),
-                              10.0,
-                              Vec4::from(style.header_text_color),
-                              HorizontalAlign::Right,
-                              None
-                         );
-                     }
+            // Sort Indicator, numbered by priority when sorting by several columns
+            let key = sort_keys.iter().position(|(c, _)| *c == i).map(|p| (p, sort_keys[p].1)).or_else(|| {
+                match (sort_keys.is_empty(), sort_column, sort_direction) {
+                    (true, Some(sc), Some(dir)) if *sc == i => Some((0, *dir)),
+                    _ => None,
                 }
+            });
+            if let Some((priority, dir)) = key {
+                 // Triangle right after the measured label
+                 let label_width = ctx.text.measure(&col.header, 14.0, None).x;
+                 let cx = crate::datagrid::sort_indicator_x(x, w, label_width, style);
+                 let cy = pos.y + header_height * 0.5;
+                 let half_w = style.sort_indicator_size * 0.5;
+                 let half_h = style.sort_indicator_size * 0.3;
+                 let (tip, base) = match dir {
+                     crate::data_source::SortDirection::Ascending => (cy - half_h, cy + half_h),
+                     crate::data_source::SortDirection::Descending => (cy + half_h, cy - half_h),
+                 };
+                 ctx.primitives.draw_triangle(
+                      Vec2::new(cx, tip),
+                      Vec2::new(cx - half_w, base),
+                      Vec2::new(cx + half_w, base),
+                      Vec4::from(style.header_text_color),
+                      0.0,
+                 );
+
+                 if sort_keys.len() > 1 {
+                     ctx.text.draw(
+                          ctx.device,
+                          ctx.queue,
+                          &(priority + 1).to_string(),
+                          Vec2::new(cx + half_w + 2.0, cy),
+                          9.0,
+                          Vec4::from(style.header_text_color),
+                          HorizontalAlign::Left,
+                          None
+                     );
+                 }
             }
            
            if *show_vertical_lines && i > 0 {
@@ -1718,7 +2816,7 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
     }
 
     Widget::DatePicker { 
-        id, value, placeholder, format, style, bounds, min_date, max_date, .. 
+        id, value, range, start, end, placeholder, format, week_start, day_names, style, bounds, min_date, max_date, .. 
     } => {
         let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
         let center = pos + Vec2::new(bounds.width * 0.5, bounds.height * 0.5);
@@ -1733,16 +2831,25 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
         if bg_color.3 > 0.0 {
             ctx.primitives.draw_rect(center, half_size, Vec4::from(bg_color), [style.corner_radius; 4], 0.0);
         }
-        // Note: Border drawing skipped - draw_border not implemented
+        if let Some(b) = border {
+            ctx.primitives.draw_border(center, half_size, [style.corner_radius; 4], b.width, Vec4::from(b.color));
+        }
 
         // Draw Text
-        let text_str = if let Some(date) = value {
+        let text_str = if *range {
+             match (start, end) {
+                 (Some(s), Some(e)) => format!("{} - {}", s.format(format), e.format(format)),
+                 (Some(s), None) => format!("{} - ...", s.format(format)),
+                 _ => placeholder.clone(),
+             }
+        } else if let Some(date) = value {
              date.format(format).to_string()
         } else {
              placeholder.clone()
         };
         
-        let text_color = if value.is_some() { style.text_color } else { style.placeholder_color };
+        let has_value = if *range { start.is_some() } else { value.is_some() };
+        let text_color = if has_value { style.text_color } else { style.placeholder_color };
         
         // Add padding
         let text_pos = Vec2::new(pos.x + 8.0, center.y);
@@ -1764,126 +2871,248 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
              let style = style.clone();
              let id = id.clone();
              let value = *value;
+             let range = *range;
+             let (start, end) = (*start, *end);
+             let week_start = *week_start;
+             let headers = weekday_headers(week_start, day_names.as_deref());
              let min_date = *min_date;
              let max_date = *max_date;
-             let offset = ctx.offset;
+             let popover = CalendarGeometry::popover(ctx.offset + Vec2::new(bounds.x, bounds.y), &bounds);
              
              let view_state = ctx.interaction.and_then(|s| s.calendar_view_state.get(&id).copied());
              let hovered_action = ctx.interaction.and_then(|s| s.hovered_action.clone());
              
-             if let Some(deferred) = ctx.deferred_draws.as_mut() {
-                 deferred.push(Box::new(move |renderer, device, queue| {
-                     // Get Overlay Renderer
-                     let (primitives, text) = renderer.split_overlay_mut();
-                     
-                     let dd_width = bounds.width.max(250.0);
-                     let dd_x = offset.x + bounds.x;
-                     let dd_y = offset.y + bounds.y + bounds.height + 2.0;
-
-                     let header_height = 30.0;
-                     let day_names_height = 28.0;
-                     let row_height = 30.0;
-                     let padding = 5.0;
-                     let dd_height = header_height + day_names_height + 6.0 * row_height + padding * 2.0;
-                     
-                     let dd_center = Vec2::new(dd_x + dd_width * 0.5, dd_y + dd_height * 0.5);
-                     let dd_half = Vec2::new(dd_width * 0.5, dd_height * 0.5);
-                     
-                     // Background
-                     if let Some(bg) = style.calendar_background {
-                         primitives.draw_rect(dd_center, dd_half, Vec4::from(bg), [style.corner_radius; 4], 0.0);
-                     }
-                     // Note: Border drawing skipped - draw_border not implemented
-                     
-                     // Determine View Date
-                     let (view_month, view_year) = view_state
-                         .or_else(|| value.map(|d| (d.month(), d.year())))
-                         .unwrap_or_else(|| {
-                             let now = chrono::Local::now().naive_local().date();
-                             (now.month(), now.year())
-                         });
-
-                     // Draw Header
-                     let header_y = dd_y + padding + header_height * 0.5;
-                     
-                     // Prev Button (<)
-                     let prev_hover = hovered_action.as_deref() == Some(&format!("{}:prev", id));
-                     let prev_color = if prev_hover { style.day_hover_color } else { style.month_header_color };
-                     text.draw(device, queue, "<", Vec2::new(dd_x + 20.0, header_y), 20.0, Vec4::from(prev_color), HorizontalAlign::Center, None);
-
-                     // Next Button (>)
-                     let next_hover = hovered_action.as_deref() == Some(&format!("{}:next", id));
-                     let next_color = if next_hover { style.day_hover_color } else { style.month_header_color };
-                     text.draw(device, queue, ">", Vec2::new(dd_x + dd_width - 20.0, header_y), 20.0, Vec4::from(next_color), HorizontalAlign::Center, None);
-
-                     // Month Year Text
-                     let header_str = format!("{} {}", chrono::Month::try_from(view_month as u8).map(|m| m.name()).unwrap_or(""), view_year);
-                     text.draw(device, queue, &header_str, Vec2::new(dd_x + dd_width * 0.5, header_y), 18.0, Vec4::from(style.month_header_color), HorizontalAlign::Center, None);
-                     
-                     // Day Names
-                     let days = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
-                     let day_names_y = dd_y + padding + header_height + day_names_height * 0.5;
-                     let cell_w = (dd_width - padding * 2.0) / 7.0;
-                     
-                     for (i, day) in days.iter().enumerate() {
-                         let cx = dd_x + padding + cell_w * i as f32 + cell_w * 0.5;
-                         text.draw(device, queue, day, Vec2::new(cx, day_names_y), 14.0, Vec4::from(style.month_header_color), HorizontalAlign::Center, None);
-                     }
-                     
-                     // Grid
-                     let grid_start_y = dd_y + padding + header_height + day_names_height;
+             popover.enqueue(ctx, move |renderer, device, queue, origin| {
+                 // Get Overlay Renderer
+                 let (primitives, text) = renderer.split_overlay_mut();
+                 
+                 let cal = CalendarGeometry::at(&popover, origin);
+                 let dd_width = cal.width;
+                 let dd_x = cal.origin.x;
+                 let dd_y = cal.origin.y;
+
+                 let header_height = CalendarGeometry::HEADER_HEIGHT;
+                 let day_names_height = CalendarGeometry::DAY_NAMES_HEIGHT;
+                 let row_height = CalendarGeometry::ROW_HEIGHT;
+                 let padding = CalendarGeometry::PADDING;
+                 let dd_height = cal.height;
+                 
+                 let dd_center = Vec2::new(dd_x + dd_width * 0.5, dd_y + dd_height * 0.5);
+                 let dd_half = Vec2::new(dd_width * 0.5, dd_height * 0.5);
+                 
+                 // Background
+                 if let Some(bg) = style.calendar_background {
+                     primitives.draw_rect(dd_center, dd_half, Vec4::from(bg), [style.corner_radius; 4], 0.0);
+                 }
+                 if let Some(b) = style.calendar_border {
+                     primitives.draw_border(dd_center, dd_half, [style.corner_radius; 4], b.width, Vec4::from(b.color));
+                 }
+                 
+                 // Determine View Date
+                 let (view_month, view_year) = view_state
+                     .or_else(|| value.or(start).map(|d| (d.month(), d.year())))
+                     .unwrap_or_else(|| {
+                         let now = chrono::Local::now().naive_local().date();
+                         (now.month(), now.year())
+                     });
+
+                 // Draw Header
+                 let header_y = dd_y + padding + header_height * 0.5;
+                 
+                 // Prev Button (<)
+                 let prev_hover = hovered_action.as_deref() == Some(&format!("{}:prev", id));
+                 let prev_color = if prev_hover { style.day_hover_color } else { style.month_header_color };
+                 text.draw(device, queue, "<", Vec2::new(dd_x + 20.0, header_y), 20.0, Vec4::from(prev_color), HorizontalAlign::Center, None);
+
+                 // Next Button (>)
+                 let next_hover = hovered_action.as_deref() == Some(&format!("{}:next", id));
+                 let next_color = if next_hover { style.day_hover_color } else { style.month_header_color };
+                 text.draw(device, queue, ">", Vec2::new(dd_x + dd_width - 20.0, header_y), 20.0, Vec4::from(next_color), HorizontalAlign::Center, None);
+
+                 // Month Year Text
+                 let header_str = format!("{} {}", chrono::Month::try_from(view_month as u8).map(|m| m.name()).unwrap_or(""), view_year);
+                 text.draw(device, queue, &header_str, Vec2::new(dd_x + dd_width * 0.5, header_y), 18.0, Vec4::from(style.month_header_color), HorizontalAlign::Center, None);
+                 
+                 // Day Names
+                 let day_names_y = dd_y + padding + header_height + day_names_height * 0.5;
+                 let cell_w = cal.cell_width();
+                 
+                 for (i, day) in headers.iter().enumerate() {
+                     let cx = dd_x + padding + cell_w * i as f32 + cell_w * 0.5;
+                     text.draw(device, queue, day, Vec2::new(cx, day_names_y), 14.0, Vec4::from(style.month_header_color), HorizontalAlign::Center, None);
+                 }
+                 
+                 // Grid
+                 let grid_start_y = cal.grid_top();
+                 
+                 // While only the start is picked, preview the band up
+                 // to the hovered day.
+                 let hover_prefix = format!("{}:day:", id);
+                 let hovered_date = hovered_action.as_deref()
+                     .and_then(|a| a.strip_prefix(&hover_prefix))
+                     .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+                 let band = if range { range_band(start, end.or(hovered_date)) } else { None };
+
+                 if let Some(first_day) = NaiveDate::from_ymd_opt(view_year, view_month, 1) {
+                     let offset = leading_days(first_day, week_start);
                      
-                     if let Some(first_day) = NaiveDate::from_ymd_opt(view_year, view_month, 1) {
-                         let start_weekday = first_day.weekday().num_days_from_monday(); // 0=Mon
-                         let offset = start_weekday as i64;
-                         
-                         for row in 0..6 {
-                             for col in 0..7 {
-                                 let day_idx = (row * 7 + col) as i64;
-                                 let date_offset = day_idx - offset;
+                     for row in 0..6 {
+                         for col in 0..7 {
+                             let day_idx = (row * 7 + col) as i64;
+                             let date_offset = day_idx - offset;
+                             
+                             if let Some(date) = first_day.checked_add_signed(chrono::Duration::days(date_offset)) {
+                                 let is_current_month = date.month() == view_month;
+                                 let is_selected = if range {
+                                     start == Some(date) || end == Some(date)
+                                 } else {
+                                     value == Some(date)
+                                 };
+                                 let is_today = date == chrono::Local::now().naive_local().date();
+                                 let action_id = format!("{}:day:{}", id, date.format("%Y-%m-%d"));
+                                 let is_hovered = hovered_action.as_ref() == Some(&action_id);
                                  
-                                 if let Some(date) = first_day.checked_add_signed(chrono::Duration::days(date_offset)) {
-                                     let is_current_month = date.month() == view_month;
-                                     let is_selected = value == Some(date);
-                                     let is_today = date == chrono::Local::now().naive_local().date();
-                                     let action_id = format!("{}:day:{}", id, date.format("%Y-%m-%d"));
-                                     let is_hovered = hovered_action.as_ref() == Some(&action_id);
-                                     
-                                     let cx = dd_x + padding + cell_w * col as f32 + cell_w * 0.5;
-                                     let cy = grid_start_y + row_height * row as f32 + row_height * 0.5;
-                                     
-                                     // Draw Cell Background
-                                     if is_selected {
-                                         primitives.draw_rect(Vec2::new(cx, cy), Vec2::new(cell_w * 0.45, row_height * 0.45), Vec4::from(style.selected_day_color), [4.0; 4], 0.0);
-                                     } else if is_hovered {
-                                         primitives.draw_rect(Vec2::new(cx, cy), Vec2::new(cell_w * 0.45, row_height * 0.45), Vec4::from(style.day_hover_color), [4.0; 4], 0.0);
+                                 let cx = dd_x + padding + cell_w * col as f32 + cell_w * 0.5;
+                                 let cy = grid_start_y + row_height * row as f32 + row_height * 0.5;
+                                 
+                                 // Range band, drawn edge to edge so adjacent days join up
+                                 if let Some((lo, hi)) = band {
+                                     if lo <= date && date <= hi && lo != hi {
+                                         let left = if date == lo { cx } else { cx - cell_w * 0.5 };
+                                         let right = if date == hi { cx } else { cx + cell_w * 0.5 };
+                                         primitives.draw_rect(
+                                             Vec2::new((left + right) * 0.5, cy),
+                                             Vec2::new((right - left) * 0.5, row_height * 0.45),
+                                             Vec4::from(style.range_color),
+                                             [0.0; 4],
+                                             0.0,
+                                         );
                                      }
-                                     
-                                     // Draw Text
-                                     let mut color = if is_selected {
-                                         Vec4::ONE // White on selection
-                                     } else if is_today && is_current_month {
-                                         Vec4::from(style.today_color)
-                                     } else if !is_current_month {
-                                         Vec4::new(0.5, 0.5, 0.5, 1.0) // Gray
-                                     } else {
-                                         Vec4::from(style.day_text_color)
-                                     };
-                                     
-                                     // Check min/max bounds
-                                     if let Some(min) = min_date { if date < min { color.w = 0.3; } }
-                                     if let Some(max) = max_date { if date > max { color.w = 0.3; } }
-
-                                     // Offset y by half font size to vertically center
-                                     let font_size = 16.0;
-                                     let text_y = cy - font_size * 0.5;
-                                     text.draw(device, queue, &date.day().to_string(), Vec2::new(cx, text_y), font_size, color, HorizontalAlign::Center, None);
                                  }
+
+                                 // Draw Cell Background
+                                 if is_selected {
+                                     primitives.draw_rect(Vec2::new(cx, cy), Vec2::new(cell_w * 0.45, row_height * 0.45), Vec4::from(style.selected_day_color), [4.0; 4], 0.0);
+                                 } else if is_hovered {
+                                     primitives.draw_rect(Vec2::new(cx, cy), Vec2::new(cell_w * 0.45, row_height * 0.45), Vec4::from(style.day_hover_color), [4.0; 4], 0.0);
+                                 }
+                                 
+                                 // Draw Text
+                                 let mut color = if is_selected {
+                                     Vec4::ONE // White on selection
+                                 } else if is_today && is_current_month {
+                                     Vec4::from(style.today_color)
+                                 } else if !is_current_month {
+                                     Vec4::new(0.5, 0.5, 0.5, 1.0) // Gray
+                                 } else {
+                                     Vec4::from(style.day_text_color)
+                                 };
+                                 
+                                 // Check min/max bounds
+                                 if let Some(min) = min_date { if date < min { color.w = 0.3; } }
+                                 if let Some(max) = max_date { if date > max { color.w = 0.3; } }
+
+                                 // Offset y by half font size to vertically center
+                                 let font_size = 16.0;
+                                 let text_y = cy - font_size * 0.5;
+                                 text.draw(device, queue, &date.day().to_string(), Vec2::new(cx, text_y), font_size, color, HorizontalAlign::Center, None);
                              }
                          }
                      }
-                 }));
-             }
+                 }
+             });
+        }
+    }
+
+    Widget::TimePicker {
+        id, value, placeholder, step_minutes, use_24h, min_time, max_time, style, bounds, ..
+    } => {
+        use crate::time_picker::{self, PanelCell, TimePanel};
+
+        let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
+        let center = pos + Vec2::new(bounds.width * 0.5, bounds.height * 0.5);
+        let half_size = Vec2::new(bounds.width * 0.5, bounds.height * 0.5);
+
+        let is_focused = ctx.interaction.as_ref().map(|s| s.focused_id.as_deref() == Some(id)).unwrap_or(false);
+
+        // Draw Input Background
+        let bg_color = if is_focused { style.background_focused } else { style.background }.unwrap_or_default();
+        let border = if is_focused { style.border_focused } else { style.border };
+
+        if bg_color.3 > 0.0 {
+            ctx.primitives.draw_rect(center, half_size, Vec4::from(bg_color), [style.corner_radius; 4], 0.0);
+        }
+        if let Some(b) = border {
+            ctx.primitives.draw_border(center, half_size, [style.corner_radius; 4], b.width, Vec4::from(b.color));
+        }
+
+        // Draw Text
+        let text_str = match value {
+            Some(t) => time_picker::format_time(*t, *use_24h),
+            None => placeholder.clone(),
+        };
+        let text_color = if value.is_some() { style.text_color } else { style.placeholder_color };
+        ctx.text.draw(
+            ctx.device,
+            ctx.queue,
+            &text_str,
+            Vec2::new(pos.x + 8.0, center.y),
+            16.0,
+            Vec4::from(text_color),
+            HorizontalAlign::Left,
+            style.font.as_deref()
+        );
+
+        // Draw Overlay (Deferred)
+        if is_focused {
+            let panel = TimePanel::new(pos, bounds, *step_minutes, Some(&ctx.overlay_area()));
+            let style = style.clone();
+            let id = id.clone();
+            let (value, step, use_24h) = (*value, *step_minutes, *use_24h);
+            let (min_time, max_time) = (*min_time, *max_time);
+            let hovered_action = ctx.interaction.and_then(|s| s.hovered_action.clone());
+
+            if let Some(deferred) = ctx.deferred_draws.as_mut() {
+                deferred.push(Box::new(move |renderer, device, queue| {
+                    let (primitives, text) = renderer.split_overlay_mut();
+
+                    let height = panel.height();
+                    let panel_center = panel.origin + Vec2::new(panel.width * 0.5, height * 0.5);
+                    let panel_half = Vec2::new(panel.width * 0.5, height * 0.5);
+                    if let Some(bg) = style.panel_background {
+                        primitives.draw_rect(panel_center, panel_half, Vec4::from(bg), [style.corner_radius; 4], 0.0);
+                    }
+                    if let Some(b) = style.panel_border {
+                        primitives.draw_border(panel_center, panel_half, [style.corner_radius; 4], b.width, Vec4::from(b.color));
+                    }
+
+                    let cell_half = Vec2::new(panel.cell_width() * 0.45, TimePanel::CELL_HEIGHT * 0.42);
+                    let mut draw_cell = |cell: PanelCell, center: Vec2, label: String, selected: bool| {
+                        let time = time_picker::pick(value, cell, step);
+                        let action_id = format!("{}:time:{}", id, time.format("%H:%M"));
+                        if selected {
+                            primitives.draw_rect(center, cell_half, Vec4::from(style.selected_color), [4.0; 4], 0.0);
+                        } else if hovered_action.as_deref() == Some(action_id.as_str()) {
+                            primitives.draw_rect(center, cell_half, Vec4::from(style.hover_color), [4.0; 4], 0.0);
+                        }
+                        let mut color = if selected { Vec4::ONE } else { Vec4::from(style.cell_text_color) };
+                        if !time_picker::in_bounds(time, min_time, max_time) {
+                            color.w = 0.3;
+                        }
+                        let font_size = 14.0;
+                        text.draw(device, queue, &label, Vec2::new(center.x, center.y - font_size * 0.5), font_size, color, HorizontalAlign::Center, None);
+                    };
+
+                    for hour in 0..24 {
+                        let selected = value.map(|t| t.hour()) == Some(hour);
+                        draw_cell(PanelCell::Hour(hour), panel.hour_center(hour), time_picker::hour_label(hour, use_24h), selected);
+                    }
+                    for (i, minute) in time_picker::minute_slots(step).into_iter().enumerate() {
+                        let selected = value.map(|t| t.minute()) == Some(minute);
+                        draw_cell(PanelCell::Minute(minute), panel.minute_center(i), format!(":{:02}", minute), selected);
+                    }
+                }));
+            }
         }
     }
 
@@ -1917,17 +3146,61 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
     }
 
     Widget::Icon { icon_name, color, bounds, .. } => {
+        use crate::icons::IconSource;
         let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
         let center = pos + Vec2::new(bounds.width * 0.5, bounds.height * 0.5);
         let size = Vec2::new(bounds.width, bounds.height);
-        
-        if let Some(tex) = ctx.textures.get(icon_name) {
-             let tint = if let Some(c) = color {
-                 Vec4::new(c.0, c.1, c.2, c.3)
-             } else {
-                 Vec4::ONE
-             };
-            ctx.images.draw(ctx.device, tex, center, size, tint);
+        let tint = if let Some(c) = color {
+            Vec4::new(c.0, c.1, c.2, c.3)
+        } else {
+            Vec4::ONE
+        };
+
+        // Registry first, then plain textures, then the registry fallback.
+        // Glyphs carry their font and codepoint; `None` marks an SVG.
+        let registered = ctx.icons.as_deref().and_then(|icons| {
+            let (name, source) = match icons.get(icon_name) {
+                Some(source) => (icon_name.as_str(), source),
+                None if ctx.textures.contains_key(icon_name) => return None,
+                None => icons.fallback()?,
+            };
+            let glyph = match source {
+                IconSource::Svg(_) => None,
+                IconSource::Glyph { font, codepoint } => Some((font.clone(), *codepoint)),
+            };
+            Some((name.to_string(), glyph))
+        });
+
+        match registered {
+            Some((name, None)) => {
+                // Rasterize at the drawn size in device pixels so the icon stays sharp.
+                let px = (bounds.width.min(bounds.height) * ctx.scale_factor).round() as u32;
+                let icons = ctx.icons.as_deref_mut().expect("icon resolved from the registry");
+                if let Some(tex) = icons.svg_mask(ctx.device, ctx.queue, &name, px) {
+                    let side = bounds.width.min(bounds.height);
+                    ctx.images.draw(ctx.device, tex, center, Vec2::splat(side), tint);
+                }
+            }
+            Some((_, Some((font, codepoint)))) => {
+                let glyph = codepoint.to_string();
+                let glyph_size = bounds.width.min(bounds.height);
+                let dims = ctx.text.measure(&glyph, glyph_size, font.as_deref());
+                ctx.text.draw(
+                    ctx.device,
+                    ctx.queue,
+                    &glyph,
+                    center - dims * 0.5,
+                    glyph_size,
+                    tint,
+                    HorizontalAlign::Left,
+                    font.as_deref()
+                );
+            }
+            None => {
+                if let Some(tex) = ctx.textures.get(icon_name) {
+                    ctx.images.draw(ctx.device, tex, center, size, tint);
+                }
+            }
         }
     }
 
@@ -2017,6 +3290,7 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
                 None,
                 TextAlign::Left,
                 Some(bounds.width - label_x),
+                None,
              );
              
              y += style.row_height;
@@ -2065,7 +3339,7 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
     }
 
     Widget::Tab {
-        id, tabs, selected, orientation, style, bounds, ..
+        id, tabs, selected, header_scroll, orientation, style, bounds, ..
     } => {
         let pos = ctx.offset + Vec2::new(bounds.x, bounds.y);
         let header_rect = match orientation {
@@ -2089,16 +3363,32 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
         // Draw Tabs
         let tab_count = tabs.len();
         if tab_count > 0 {
+             let header = TabHeaderGeometry::new(header_rect.width, tab_count, style.min_tab_width, *header_scroll);
              let (tab_w, tab_h) = match orientation {
-                 crate::widget::Orientation::Horizontal => (header_rect.width / tab_count as f32, header_rect.height),
+                 crate::widget::Orientation::Horizontal => (header.tab_width, header_rect.height),
                  crate::widget::Orientation::Vertical => (header_rect.width, 32.0), // Fixed height per tab in vertical
              };
 
+             // Clip the scrolled strip to the area between the chevrons.
+             let scrolling = *orientation == crate::widget::Orientation::Horizontal && header.overflow;
+             if scrolling {
+                 let s = ctx.scale_factor;
+                 ctx.push_scissor(Some((
+                     ((header_rect.x + header.strip_x) * s).max(0.0).floor() as u32,
+                     (header_rect.y * s).max(0.0).floor() as u32,
+                     (header.viewport * s).ceil() as u32,
+                     (header_rect.height * s).ceil() as u32,
+                 )));
+             }
+
              for (i, tab) in tabs.iter().enumerate() {
                  let (tx, ty) = match orientation {
-                     crate::widget::Orientation::Horizontal => (header_rect.x + i as f32 * tab_w, header_rect.y),
+                     crate::widget::Orientation::Horizontal => (header_rect.x + header.tab_x(i), header_rect.y),
                      crate::widget::Orientation::Vertical => (header_rect.x, header_rect.y + i as f32 * tab_h),
                  };
+                 if scrolling && (tx + tab_w < header_rect.x + header.strip_x || tx > header_rect.x + header.strip_x + header.viewport) {
+                     continue;
+                 }
                  
                  let color = if i == *selected { style.selected_color } else { style.unselected_color };
                  
@@ -2118,6 +3408,19 @@ pub fn render_widget(widget: &Widget, ctx: &mut RenderContext) {
                       14.0, Vec4::ONE, HorizontalAlign::Center, None
                  );
              }
+
+             if scrolling {
+                 ctx.pop_scissor();
+                 let w = TabHeaderGeometry::CHEVRON_WIDTH;
+                 let cy = header_rect.y + header_rect.height * 0.5;
+                 for (cx, glyph, enabled) in [
+                     (header_rect.x + w * 0.5, "<", header.scroll > 0.0),
+                     (header_rect.x + header_rect.width - w * 0.5, ">", header.scroll < header.max_scroll),
+                 ] {
+                     let color = if enabled { Vec4::ONE } else { Vec4::new(1.0, 1.0, 1.0, 0.3) };
+                     ctx.text.draw(ctx.device, ctx.queue, glyph, Vec2::new(cx, cy - 7.0), 14.0, color, HorizontalAlign::Center, None);
+                 }
+             }
         }
 
         // Render Selected Content
@@ -2158,85 +3461,664 @@ pub fn render_ui_with_state(
   widget_tracker: Option<&mut crate::widget_state::WidgetStateTracker>,
   deferred_draws: &mut Vec<Box<dyn FnOnce(&mut crate::renderer::GloomyRenderer, &wgpu::Device, &wgpu::Queue)>>,
 ) {
-  let size = renderer.size();
-  let surface_width = size.x as u32;
-  let surface_height = size.y as u32;
-  let scale_factor = renderer.scale_factor;
-  
-  let (primitives, text, images, textures, chart_primitives, chart_text) = renderer.split_mut();
-  
-  let mut ctx = RenderContext::new(
-      primitives, 
-      text, 
-      images, 
-      textures, 
-      chart_primitives,
-      chart_text,
-      device, 
-      queue, 
-      interaction, 
-      surface_width, 
-      surface_height, 
-      scale_factor, // Use local variable
-      data_provider, 
-      widget_tracker,
-      Some(deferred_draws)
-  );
-  render_widget(widget, &mut ctx);
+  render_root(widget, renderer, device, queue, interaction, data_provider, widget_tracker, deferred_draws, None);
 }
 
-/// Performs a hit test on the widget tree.
+/// Like [`render_ui`], resolving widget `classes` against `styles`.
 ///
-/// Returns the first interactive widget found under the given point.
-pub fn hit_test<'a>(
-  widget: &'a Widget,
-  point: Vec2,
+/// See [`crate::style_context`] for the precedence between theme defaults,
+/// classes and inline styles.
+pub fn render_ui_styled(
+  widget: &Widget,
+  renderer: &mut crate::renderer::GloomyRenderer,
+  device: &wgpu::Device,
+  queue: &wgpu::Queue,
   interaction: Option<&InteractionState>,
-) -> Option<HitTestResult<'a>> {
-  match widget {
-    Widget::Container { id, scrollable, bounds, children, .. } => {
-      // Check if point is inside container bounds first (clipping check)
-      if *scrollable {
-          if point.x < bounds.x || point.x > bounds.x + bounds.width ||
-             point.y < bounds.y || point.y > bounds.y + bounds.height {
-              return None;
-          }
-      }
-
-      // Transform point to local space
-      let mut local_point = point - Vec2::new(bounds.x, bounds.y);
-
-      // Apply scroll offset
-      if *scrollable {
-          if let Some(state) = interaction {
-              if let Some(wid) = id {
-                  if let Some(scroll) = state.scroll_offsets.get(wid) {
-                      local_point += *scroll;
-                  }
-              }
-          }
-      }
+  data_provider: Option<&dyn crate::data_source::DataProvider>,
+  styles: &crate::style_context::StyleContext,
+) {
+    let mut deferred_draws = Vec::new();
+    render_root(widget, renderer, device, queue, interaction, data_provider, None, &mut deferred_draws, Some(styles));
 
-      // Check children in reverse order (top to bottom)
-      for child in children.iter().rev() {
-        if let Some(result) = hit_test(child, local_point, interaction) {
-          return Some(result);
-        }
-      }
-      None
+    for draw_op in deferred_draws {
+        draw_op(renderer, device, queue);
     }
-    Widget::Button { bounds, action, .. } => {
-        if point.x >= bounds.x && point.x <= bounds.x + bounds.width
-           && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
-             Some(HitTestResult { widget, action: action.clone() })
-           } else {
-             None
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_root(
+  widget: &Widget,
+  renderer: &mut crate::renderer::GloomyRenderer,
+  device: &wgpu::Device,
+  queue: &wgpu::Queue,
+  interaction: Option<&InteractionState>,
+  data_provider: Option<&dyn crate::data_source::DataProvider>,
+  widget_tracker: Option<&mut crate::widget_state::WidgetStateTracker>,
+  deferred_draws: &mut Vec<Box<dyn FnOnce(&mut crate::renderer::GloomyRenderer, &wgpu::Device, &wgpu::Queue)>>,
+  styles: Option<&crate::style_context::StyleContext>,
+) {
+  let mut ctx = RenderContext::builder(renderer, device, queue)
+      .interaction(interaction)
+      .data_provider(data_provider)
+      .widget_tracker(widget_tracker)
+      .deferred_draws(Some(deferred_draws))
+      .styles(styles)
+      .build();
+  if let Some(state) = interaction {
+      state.overlay_area.set(Some(ctx.overlay_area()));
+  }
+  render_widget(widget, &mut ctx);
+}
+
+/// Span `(start, end)` of the indeterminate ProgressBar segment at `time`,
+/// relative to the track's left edge.
+///
+/// The segment is a third of the track and loops left to right every
+/// 1.5 seconds, entering and leaving through the track ends.
+fn indeterminate_segment(time: f32, track_width: f32) -> Option<(f32, f32)> {
+  const PERIOD: f32 = 1.5;
+  let seg_w = track_width / 3.0;
+  let t = time.rem_euclid(PERIOD) / PERIOD;
+  let x = -seg_w + t * (track_width + seg_w);
+  let start = x.max(0.0);
+  let end = (x + seg_w).min(track_width);
+  (end > start).then_some((start, end))
+}
+
+/// Left edge of a Skeleton's shimmer band at `time`, relative to the
+/// placeholder's left edge.
+///
+/// The band enters fully outside the left edge and leaves fully past the
+/// right one, once per `period` seconds.
+pub(crate) fn shimmer_offset(time: f32, period: f32, width: f32, band: f32) -> f32 {
+  let t = time.rem_euclid(period) / period;
+  -band + t * (width + band)
+}
+
+/// Placement of a Stepper's step circles.
+///
+/// Each step owns an equal slot along the main axis; the whole slot is
+/// clickable. Shared by rendering and hit testing.
+struct StepperGeometry {
+  origin: Vec2,
+  slot: Vec2,
+  orientation: crate::widget::Orientation,
+  radius: f32,
+}
+
+impl StepperGeometry {
+  fn new(pos: Vec2, bounds: &WidgetBounds, count: usize, orientation: crate::widget::Orientation, radius: f32) -> Self {
+    let n = count.max(1) as f32;
+    let slot = match orientation {
+      crate::widget::Orientation::Horizontal => Vec2::new(bounds.width / n, bounds.height),
+      crate::widget::Orientation::Vertical => Vec2::new(bounds.width, bounds.height / n),
+    };
+    Self { origin: pos, slot, orientation, radius }
+  }
+
+  fn center(&self, index: usize) -> Vec2 {
+    let along = index as f32 + 0.5;
+    match self.orientation {
+      crate::widget::Orientation::Horizontal => self.origin + Vec2::new(self.slot.x * along, self.radius + 2.0),
+      crate::widget::Orientation::Vertical => self.origin + Vec2::new(self.radius + 2.0, self.slot.y * along),
+    }
+  }
+
+  fn step_at(&self, point: Vec2, count: usize) -> Option<usize> {
+    let local = point - self.origin;
+    let (along, slot) = match self.orientation {
+      crate::widget::Orientation::Horizontal => (local.x, self.slot.x),
+      crate::widget::Orientation::Vertical => (local.y, self.slot.y),
+    };
+    if slot <= 0.0 || along < 0.0 {
+      return None;
+    }
+    let index = (along / slot) as usize;
+    (index < count).then_some(index)
+  }
+}
+
+/// Returns true if a Stepper may navigate from `current` to `target`.
+fn stepper_can_select(current: usize, target: usize, allow_skip: bool) -> bool {
+  target <= current || allow_skip
+}
+
+/// Returns the star under `local_x` (relative to the Rating's left edge)
+/// and whether it is the left half of it.
+///
+/// The gap after a star belongs to that star. Halves are only reported
+/// with `allow_half`.
+fn rating_star_at(local_x: f32, style: &crate::widget::RatingStyle, max: usize, allow_half: bool) -> Option<(usize, bool)> {
+  if local_x < 0.0 || max == 0 {
+    return None;
+  }
+  let slot = style.size() + style.gap();
+  let index = (local_x / slot) as usize;
+  if index >= max {
+    return None;
+  }
+  let within = local_x - index as f32 * slot;
+  Some((index, allow_half && within < style.size() * 0.5))
+}
+
+/// Value a Rating action sets: `i + 1` for `{id}:star:{i}`, `i + 0.5` for
+/// `{id}:half_star:{i}`.
+fn rating_action_value(action: &str, id: &str) -> Option<f32> {
+  let rest = action.strip_prefix(id)?.strip_prefix(':')?;
+  if let Some(i) = rest.strip_prefix("star:") {
+    return i.parse::<usize>().ok().map(|i| i as f32 + 1.0);
+  }
+  rest.strip_prefix("half_star:")?.parse::<usize>().ok().map(|i| i as f32 + 0.5)
+}
+
+/// Horizontal tab header layout, including overflow scrolling.
+///
+/// Tabs share the bar width evenly until they would get narrower than
+/// `TabStyle::min_tab_width`; past that they keep the minimum width, the
+/// header scrolls, and chevrons take `CHEVRON_WIDTH` at each end.
+/// Positions are relative to the bar's left edge.
+struct TabHeaderGeometry {
+  tab_width: f32,
+  /// Left edge of the tab strip, after the prev chevron.
+  strip_x: f32,
+  viewport: f32,
+  scroll: f32,
+  max_scroll: f32,
+  overflow: bool,
+}
+
+impl TabHeaderGeometry {
+  const CHEVRON_WIDTH: f32 = 24.0;
+
+  fn new(bar_width: f32, count: usize, min_tab_width: f32, scroll: f32) -> Self {
+    let n = count.max(1) as f32;
+    let even = bar_width / n;
+    if even >= min_tab_width || count <= 1 {
+      return Self { tab_width: even, strip_x: 0.0, viewport: bar_width, scroll: 0.0, max_scroll: 0.0, overflow: false };
+    }
+    let viewport = (bar_width - Self::CHEVRON_WIDTH * 2.0).max(0.0);
+    let max_scroll = (min_tab_width * n - viewport).max(0.0);
+    Self {
+      tab_width: min_tab_width,
+      strip_x: Self::CHEVRON_WIDTH,
+      viewport,
+      scroll: scroll.clamp(0.0, max_scroll),
+      max_scroll,
+      overflow: true,
+    }
+  }
+
+  /// Left edge of tab `index`; may lie outside the viewport.
+  fn tab_x(&self, index: usize) -> f32 {
+    self.strip_x + index as f32 * self.tab_width - self.scroll
+  }
+
+  fn tab_at(&self, x: f32, count: usize) -> Option<usize> {
+    if x < self.strip_x || x > self.strip_x + self.viewport || self.tab_width <= 0.0 {
+      return None;
+    }
+    let index = ((x - self.strip_x + self.scroll) / self.tab_width) as usize;
+    (index < count).then_some(index)
+  }
+
+  /// Scroll after paging one viewport back or forward.
+  fn paged(&self, forward: bool) -> f32 {
+    let step = if forward { self.viewport } else { -self.viewport };
+    (self.scroll + step).clamp(0.0, self.max_scroll)
+  }
+}
+
+/// Geometry of the DatePicker calendar overlay.
+///
+/// Shared by rendering and hit testing so both agree on where the header,
+/// day names and day cells sit.
+struct CalendarGeometry {
+  origin: Vec2,
+  width: f32,
+  height: f32,
+}
+
+impl CalendarGeometry {
+  const HEADER_HEIGHT: f32 = 30.0;
+  const DAY_NAMES_HEIGHT: f32 = 28.0;
+  const ROW_HEIGHT: f32 = 30.0;
+  const PADDING: f32 = 5.0;
+
+  /// The calendar popover; `pos` is the absolute top-left corner of the
+  /// DatePicker input.
+  fn popover(pos: Vec2, bounds: &WidgetBounds) -> Popover {
+    let height = Self::HEADER_HEIGHT
+      + Self::DAY_NAMES_HEIGHT
+      + 6.0 * Self::ROW_HEIGHT
+      + Self::PADDING * 2.0;
+    let anchor = Rect::new(pos.x, pos.y, bounds.width, bounds.height);
+    Popover::new(anchor, Vec2::new(bounds.width.max(250.0), height)).gap(2.0)
+  }
+
+  /// The calendar of `popover` placed at `origin`.
+  fn at(popover: &Popover, origin: Vec2) -> Self {
+    Self { origin, width: popover.size.x, height: popover.size.y }
+  }
+
+  /// The calendar placed within `area`, see [`crate::popover`].
+  fn new(pos: Vec2, bounds: &WidgetBounds, area: Option<&Rect>) -> Self {
+    let popover = Self::popover(pos, bounds);
+    Self::at(&popover, popover.origin(area))
+  }
+
+  fn contains(&self, p: Vec2) -> bool {
+    p.x >= self.origin.x && p.x <= self.origin.x + self.width
+      && p.y >= self.origin.y && p.y <= self.origin.y + self.height
+  }
+
+  fn cell_width(&self) -> f32 {
+    (self.width - Self::PADDING * 2.0) / 7.0
+  }
+
+  /// Y coordinate where the first row of day cells starts.
+  fn grid_top(&self) -> f32 {
+    self.origin.y + Self::PADDING + Self::HEADER_HEIGHT + Self::DAY_NAMES_HEIGHT
+  }
+}
+
+/// Number of grid cells before the 1st of the month, i.e. how many days
+/// the month's first day lies after `week_start`. Render and hit testing
+/// both lay out the grid with this.
+fn leading_days(first_day: NaiveDate, week_start: chrono::Weekday) -> i64 {
+  let first = first_day.weekday().num_days_from_monday() as i64;
+  let start = week_start.num_days_from_monday() as i64;
+  (first - start).rem_euclid(7)
+}
+
+/// Column headers for the calendar grid, starting at `week_start`.
+///
+/// `names` are Monday-first; anything but exactly seven falls back to the
+/// English abbreviations.
+fn weekday_headers(week_start: chrono::Weekday, names: Option<&[String]>) -> Vec<String> {
+  const DEFAULT: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+  let names: Vec<String> = match names {
+    Some(names) if names.len() == 7 => names.to_vec(),
+    _ => DEFAULT.iter().map(|n| n.to_string()).collect(),
+  };
+  let skip = week_start.num_days_from_monday() as usize;
+  names.iter().cycle().skip(skip).take(7).cloned().collect()
+}
+
+/// Returns the ordered `(first, last)` days of a range, if both ends are
+/// known.
+fn range_band(start: Option<NaiveDate>, end: Option<NaiveDate>) -> Option<(NaiveDate, NaiveDate)> {
+  let (a, b) = (start?, end?);
+  Some((a.min(b), a.max(b)))
+}
+
+/// Applies a day click to a DatePicker range.
+///
+/// The first click (or any click after a complete range) starts a new
+/// range; the second sets the end, swapping the two if it is earlier.
+pub(crate) fn pick_range_day(start: &mut Option<NaiveDate>, end: &mut Option<NaiveDate>, date: NaiveDate) {
+  match *start {
+    Some(first) if end.is_none() => {
+      *start = Some(first.min(date));
+      *end = Some(first.max(date));
+    }
+    _ => {
+      *start = Some(date);
+      *end = None;
+    }
+  }
+}
+
+/// Height of a single Autocomplete suggestion row.
+const AUTOCOMPLETE_ITEM_HEIGHT: f32 = 24.0;
+
+/// Height of a single option row in an expanded Dropdown.
+const DROPDOWN_ITEM_HEIGHT: f32 = 30.0;
+
+/// Option list of a Dropdown whose `size` field is at `pos` (absolute),
+/// with `count` options.
+fn dropdown_popover(pos: Vec2, size: Vec2, count: usize) -> Popover {
+  let anchor = Rect::new(pos.x, pos.y, size.x, size.y);
+  Popover::new(anchor, Vec2::new(size.x, count as f32 * DROPDOWN_ITEM_HEIGHT))
+}
+
+/// Suggestion list of an Autocomplete whose input is at `pos` (absolute),
+/// `rows` rows tall.
+fn suggestion_popover(pos: Vec2, bounds: &WidgetBounds, rows: usize) -> Popover {
+  let anchor = Rect::new(pos.x, pos.y, bounds.width, bounds.height);
+  Popover::new(anchor, Vec2::new(bounds.width, rows as f32 * AUTOCOMPLETE_ITEM_HEIGHT)).gap(2.0)
+}
+
+/// Performs a hit test on the widget tree.
+///
+/// Returns the topmost interactive widget under the given point, i.e. the
+/// one drawn last:
+/// - Open overlays (DatePicker calendars, Autocomplete suggestion lists,
+///   Dropdown option lists) are drawn on top of everything and outside
+///   any container clip, so they are tested first.
+/// - Scrollbars win over the content they scroll.
+/// - Among overlapping siblings the highest `z_index` wins, and among
+///   equal `z_index` the last declared; layout `order` plays no part.
+/// - A child wins over its parent, wherever the parent sits among its
+///   own siblings.
+///
+/// [`hit_test_all`] lists every hit in the same order.
+pub fn hit_test<'a>(
+  widget: &'a Widget,
+  point: Vec2,
+  interaction: Option<&InteractionState>,
+) -> Option<HitTestResult<'a>> {
+  if let Some(state) = interaction {
+    if let Some(hit) = hit_test_overlays(widget, point, Vec2::ZERO, state) {
+      return Some(hit);
+    }
+  }
+  hit_test_widget(widget, point, interaction)
+}
+
+/// Scissor in effect after pushing `rect` onto `current`: `rect` itself
+/// when nothing clips yet, their intersection otherwise (an empty rect
+/// when they don't overlap), and `current` unchanged for `None`.
+pub(crate) fn intersect_scissor(
+  current: Option<(u32, u32, u32, u32)>,
+  rect: Option<(u32, u32, u32, u32)>,
+) -> Option<(u32, u32, u32, u32)> {
+  let Some(r) = rect else {
+    return current;
+  };
+  let Some(current) = current else {
+    return Some(r);
+  };
+  let x = r.0.max(current.0);
+  let y = r.1.max(current.1);
+  let w = (r.0 + r.2).min(current.0 + current.2).saturating_sub(x);
+  let h = (r.1 + r.3).min(current.1 + current.3).saturating_sub(y);
+  if w > 0 && h > 0 {
+    Some((x, y, w, h))
+  } else {
+    Some((0, 0, 0, 0))
+  }
+}
+
+/// Returns every interactive widget under `point`, topmost first.
+///
+/// The first entry is what [`hit_test`] returns. Useful for debugging
+/// overlaps and for passing an event through to what lies underneath.
+/// Widgets that hit test their own content (a Tab's panel, ListView rows)
+/// contribute only their topmost hit.
+pub fn hit_test_all<'a>(
+  widget: &'a Widget,
+  point: Vec2,
+  interaction: Option<&InteractionState>,
+) -> Vec<HitTestResult<'a>> {
+  let mut hits = Vec::new();
+  if let Some(state) = interaction {
+    hits.extend(hit_test_overlays(widget, point, Vec2::ZERO, state));
+  }
+  collect_hits(widget, point, interaction, &mut hits);
+  hits
+}
+
+fn collect_hits<'a>(
+  widget: &'a Widget,
+  point: Vec2,
+  interaction: Option<&InteractionState>,
+  hits: &mut Vec<HitTestResult<'a>>,
+) {
+  let Widget::Container { children, .. } = widget else {
+    hits.extend(hit_test_widget(widget, point, interaction));
+    return;
+  };
+  if let Some(action) = crate::scrollbar::hit_action(widget, point, interaction) {
+    hits.push(HitTestResult { widget, action, cursor: CursorHint::Default });
+  }
+  if let Some(local_point) = container_child_point(widget, point, interaction) {
+    for child in crate::widget::z_ordered(children).rev() {
+      collect_hits(child, local_point, interaction, hits);
+    }
+  }
+}
+
+/// Hit tests overlays in absolute coordinates.
+///
+/// `offset` accumulates container positions and scroll offsets the same
+/// way `RenderContext::offset` does while rendering, so the hit regions
+/// line up with what is drawn regardless of nesting.
+fn hit_test_overlays<'a>(
+  widget: &'a Widget,
+  point: Vec2,
+  offset: Vec2,
+  state: &InteractionState,
+) -> Option<HitTestResult<'a>> {
+  match widget {
+    Widget::Container { id, scrollable, overflow, bounds, children, .. } => {
+      let mut child_offset = offset + Vec2::new(bounds.x, bounds.y);
+      if overflow.resolve(*scrollable).scrolls() {
+        if let Some(scroll) = id.as_ref().and_then(|wid| state.scroll_offsets.get(wid)) {
+          child_offset -= *scroll;
+        }
+      }
+      crate::widget::z_ordered(children)
+        .rev()
+        .find_map(|child| hit_test_overlays(child, point, child_offset, state))
+    }
+    Widget::Tab { tabs, selected, .. } => tabs
+      .get(*selected)
+      .and_then(|tab| hit_test_overlays(&tab.content, point, offset, state)),
+    Widget::DatePicker { id, value, start, week_start, bounds, .. }
+      if state.focused_id.as_deref() == Some(id.as_str()) =>
+    {
+      let pos = offset + Vec2::new(bounds.x, bounds.y);
+      let cal = CalendarGeometry::new(pos, bounds, state.overlay_area.get().as_ref());
+      if !cal.contains(point) {
+        return None;
+      }
+
+      let local_x = point.x - cal.origin.x;
+      let local_y = point.y - cal.origin.y;
+
+      // Header
+      if local_y <= CalendarGeometry::PADDING + CalendarGeometry::HEADER_HEIGHT {
+        if local_x < 40.0 {
+          return Some(HitTestResult { widget, action: format!("{}:prev", id), cursor: CursorHint::Pointer });
+        }
+        if local_x > cal.width - 40.0 {
+          return Some(HitTestResult { widget, action: format!("{}:next", id), cursor: CursorHint::Pointer });
+        }
+        return Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Default });
+      }
+
+      // Grid
+      if point.y >= cal.grid_top() {
+        let row = ((point.y - cal.grid_top()) / CalendarGeometry::ROW_HEIGHT) as i32;
+        let col = ((local_x - CalendarGeometry::PADDING) / cal.cell_width()).floor() as i32;
+
+        if (0..6).contains(&row) && (0..7).contains(&col) {
+          let (view_month, view_year) = state.calendar_view_state.get(id)
+            .copied()
+            .or_else(|| value.or(*start).map(|d| (d.month(), d.year())))
+            .unwrap_or_else(|| {
+              let now = chrono::Local::now().naive_local().date();
+              (now.month(), now.year())
+            });
+
+          if let Some(first_day) = NaiveDate::from_ymd_opt(view_year, view_month, 1) {
+            let day_idx = (row * 7 + col) as i64;
+            let date_offset = day_idx - leading_days(first_day, *week_start);
+
+            if let Some(date) = first_day.checked_add_signed(chrono::Duration::days(date_offset)) {
+              return Some(HitTestResult {
+                widget,
+                action: format!("{}:day:{}", id, date.format("%Y-%m-%d")),
+                cursor: CursorHint::Pointer,
+              });
+            }
+          }
+        }
+      }
+      Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Default })
+    }
+    Widget::TimePicker { id, value, step_minutes, bounds, .. }
+      if state.focused_id.as_deref() == Some(id.as_str()) =>
+    {
+      let area = state.overlay_area.get();
+      let panel = crate::time_picker::TimePanel::new(offset + Vec2::new(bounds.x, bounds.y), bounds, *step_minutes, area.as_ref());
+      if !panel.contains(point) {
+        return None;
+      }
+      let (action, cursor) = match panel.cell_at(point, *step_minutes) {
+        Some(cell) => {
+          let time = crate::time_picker::pick(*value, cell, *step_minutes);
+          (format!("{}:time:{}", id, time.format("%H:%M")), CursorHint::Pointer)
+        }
+        // Padding and the gap between the grids swallow the click.
+        None => (id.clone(), CursorHint::Default),
+      };
+      Some(HitTestResult { widget, action, cursor })
+    }
+    Widget::MenuBar { id, menus, open: Some(open), style, bounds, .. } => {
+      let menu = menus.get(*open)?;
+      let (x, w) = crate::menu::title_spans(menus, style.text_size())[*open];
+      let title = Rect::new(offset.x + bounds.x + x, offset.y + bounds.y, w, bounds.height);
+      let width = style.panel_width_or_default();
+      let origin = crate::menu::panel_origin(title, width, &menu.items, state.overlay_area.get().as_ref());
+      if point.x < origin.x || point.x > origin.x + width || point.y < origin.y {
+        return None;
+      }
+      let index = crate::menu::item_at(&menu.items, point.y - origin.y)?;
+      // Separators and disabled items still swallow the click.
+      let item_action = menu.items[index].action();
+      let cursor = if item_action.is_some() { CursorHint::Pointer } else { CursorHint::Default };
+      Some(HitTestResult { widget, action: item_action.unwrap_or(id).to_string(), cursor })
+    }
+    Widget::Dropdown { id, options, expanded: true, bounds, width, height, .. } if !options.is_empty() => {
+      let size = Vec2::new(width.unwrap_or(bounds.width), height.unwrap_or(bounds.height));
+      let area = state.overlay_area.get();
+      let list = dropdown_popover(offset + Vec2::new(bounds.x, bounds.y), size, options.len()).rect(area.as_ref());
+      if !list.contains(point) {
+        return None;
+      }
+      // `contains` includes the bottom edge, which belongs to the last row.
+      let idx = (((point.y - list.y) / DROPDOWN_ITEM_HEIGHT) as usize).min(options.len() - 1);
+      Some(HitTestResult { widget, action: format!("select_{}_{}", id, idx), cursor: CursorHint::Pointer })
+    }
+    Widget::Autocomplete { id, suggestions, suggest_state, max_visible, bounds, .. }
+      if state.focused_id.as_deref() == Some(id.as_str())
+        && (!suggestions.is_empty() || suggest_state.pending) =>
+    {
+      let count = suggestions.len().min(*max_visible);
+      let rows = count + suggest_state.pending as usize;
+      let area = state.overlay_area.get();
+      let list = suggestion_popover(offset + Vec2::new(bounds.x, bounds.y), bounds, rows).rect(area.as_ref());
+
+      if list.contains(point) {
+        let idx = ((point.y - list.y) / AUTOCOMPLETE_ITEM_HEIGHT) as usize;
+        if idx < count {
+          return Some(HitTestResult { widget, action: format!("{}:opt:{}", id, idx), cursor: CursorHint::Pointer });
+        }
+        // The loading row swallows the click.
+        return Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Default });
+      }
+      None
+    }
+    _ => None,
+  }
+}
+
+/// Moves `point` onto the bounds of a small control when it lies within
+/// the control's hit padding, so the bounds checks in [`hit_test_widget`]
+/// hit it. Siblings
+/// are still tested topmost first, so where padded regions overlap the
+/// topmost control wins.
+fn padded_hit_point(widget: &Widget, point: Vec2, interaction: Option<&InteractionState>) -> Vec2 {
+  let padding = match widget {
+    Widget::Button { hit_padding, .. }
+    | Widget::Checkbox { hit_padding, .. }
+    | Widget::RadioButton { hit_padding, .. }
+    | Widget::ToggleSwitch { hit_padding, .. }
+    | Widget::Slider { hit_padding, .. } => hit_padding.unwrap_or_else(|| interaction.map_or(0.0, |s| s.hit_padding)),
+    _ => return point,
+  };
+  if padding <= 0.0 {
+    return point;
+  }
+  let b = widget.bounds();
+  let nearest = point.clamp(Vec2::new(b.x, b.y), Vec2::new(b.x + b.width, b.y + b.height));
+  if (point - nearest).abs().max_element() <= padding {
+    nearest
+  } else {
+    point
+  }
+}
+
+/// Maps `point` (in the coordinates of a Container's `bounds`) into the
+/// coordinates of its children, applying its scroll offset. `None` when
+/// the container clips and the point is outside it.
+fn container_child_point(widget: &Widget, point: Vec2, interaction: Option<&InteractionState>) -> Option<Vec2> {
+  let Widget::Container { id, scrollable, overflow, clip_to_shape, style, bounds, .. } = widget else {
+    return None;
+  };
+  let overflow = overflow.resolve(*scrollable);
+  let shape_clip = *clip_to_shape && style.corner_radii.iter().any(|r| *r > 0.0);
+
+  // Check if point is inside container bounds first (clipping check).
+  // A shape clip is hit-tested by its bounding rect.
+  if overflow.clips() || shape_clip {
+      if point.x < bounds.x || point.x > bounds.x + bounds.width ||
+         point.y < bounds.y || point.y > bounds.y + bounds.height {
+          return None;
+      }
+  }
+
+  // Transform point to local space
+  let mut local_point = point - Vec2::new(bounds.x, bounds.y);
+
+  // Apply scroll offset
+  if overflow.scrolls() {
+      if let Some(scroll) = id.as_ref().and_then(|wid| interaction?.scroll_offsets.get(wid)) {
+          local_point += *scroll;
+      }
+  }
+  Some(local_point)
+}
+
+/// Hit tests the regular (non-overlay) widget tree in local coordinates.
+fn hit_test_widget<'a>(
+  widget: &'a Widget,
+  point: Vec2,
+  interaction: Option<&InteractionState>,
+) -> Option<HitTestResult<'a>> {
+  let point = padded_hit_point(widget, point, interaction);
+  // Scrollbars are drawn over the content they scroll.
+  if let Some(action) = crate::scrollbar::hit_action(widget, point, interaction) {
+    return Some(HitTestResult { widget, action, cursor: CursorHint::Default });
+  }
+  match widget {
+    Widget::Container { children, .. } => {
+      let local_point = container_child_point(widget, point, interaction)?;
+      // Topmost first: highest z_index, then last declared.
+      crate::widget::z_ordered(children)
+        .rev()
+        .find_map(|child| hit_test_widget(child, local_point, interaction))
+    }
+    Widget::Label { id: Some(id), selectable: true, x, y, width, height, .. } => {
+        if point.x >= *x && point.x <= x + width && point.y >= *y && point.y <= y + height {
+            Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Text })
+        } else {
+            None
+        }
+    }
+    Widget::Button { bounds, action, .. } => {
+        if point.x >= bounds.x && point.x <= bounds.x + bounds.width
+           && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
+             Some(HitTestResult { widget, action: action.clone(), cursor: CursorHint::Pointer })
+           } else {
+             None
            }
     }
     Widget::TextInput { bounds, id, .. } => {
         if point.x >= bounds.x && point.x <= bounds.x + bounds.width
            && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
-             Some(HitTestResult { widget, action: id.clone() })
+             Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Text })
            } else {
              None
            }
@@ -2251,52 +4133,54 @@ pub fn hit_test<'a>(
                  if point.x >= spinner_x {
                      let mid_y = bounds.y + bounds.height * 0.5;
                      if point.y < mid_y {
-                         Some(HitTestResult { widget, action: format!("{}:up", id) })
+                         Some(HitTestResult { widget, action: format!("{}:up", id), cursor: CursorHint::Pointer })
                      } else {
-                         Some(HitTestResult { widget, action: format!("{}:down", id) })
+                         Some(HitTestResult { widget, action: format!("{}:down", id), cursor: CursorHint::Pointer })
                      }
                  } else {
-                     Some(HitTestResult { widget, action: id.clone() })
+                     Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Text })
                  }
              } else {
-                 Some(HitTestResult { widget, action: id.clone() })
+                 Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Text })
              }
            } else {
              None
            }
     }
-    Widget::Autocomplete {
-        id, suggestions, max_visible, bounds, ..
-    } => {
-        let hit_input = point.x >= bounds.x && point.x <= bounds.x + bounds.width
-           && point.y >= bounds.y && point.y <= bounds.y + bounds.height;
-           
-        if let Some(state) = interaction {
-             if state.focused_id.as_deref() == Some(id) && !suggestions.is_empty() {
-                 let item_height = 24.0;
-                 let count = suggestions.len().min(*max_visible);
-                 let dd_height = count as f32 * item_height;
-                 let dd_width = bounds.width;
-                 let dd_y = bounds.y + bounds.height + 2.0; 
-                 
-                 if point.x >= bounds.x && point.x <= bounds.x + dd_width 
-                    && point.y >= dd_y && point.y <= dd_y + dd_height {
-                        let local_y = point.y - dd_y;
-                        let idx = (local_y / item_height) as usize;
-                        if idx < count {
-                             return Some(HitTestResult { widget, action: format!("{}:opt:{}", id, idx) });
-                        }
-                 }
-             }
-        }
-        
-        if hit_input {
-             Some(HitTestResult { widget, action: id.clone() })
+    Widget::Autocomplete { id, bounds, .. } => {
+        // The suggestion list is handled by `hit_test_overlays`.
+        if point.x >= bounds.x && point.x <= bounds.x + bounds.width
+           && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
+             Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Text })
         } else {
              None
         }
     }
-    Widget::ListView { id, items, style, bounds, .. } => {
+    Widget::VirtualFlow { id, item_count, item_size, spacing, bounds, realized, .. } => {
+        if point.x < bounds.x || point.x > bounds.x + bounds.width
+           || point.y < bounds.y || point.y > bounds.y + bounds.height {
+            return None;
+        }
+        let scroll_y = interaction
+            .and_then(|state| state.scroll_offsets.get(id.as_str()))
+            .map(|v| v.y)
+            .unwrap_or(0.0);
+        let local = point - Vec2::new(bounds.x, bounds.y - scroll_y);
+        let geometry = crate::virtual_flow::FlowGeometry::new(bounds.width, Vec2::from(*item_size), *spacing);
+        let Some(index) = geometry.index_at(*item_count, local) else {
+            return Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Default });
+        };
+        // Interactive children of the item take the click.
+        if let Some((_, item)) = realized.iter().find(|(i, _)| *i == index) {
+            if let Some(hit) = hit_test_widget(item, local, interaction) {
+                return Some(hit);
+            }
+        }
+        Some(HitTestResult { widget, action: format!("{}:{}", id, index), cursor: CursorHint::Pointer })
+    }
+    Widget::ListView {
+        id, items, item_widgets, item_heights, style, bounds, multi_select, show_checkboxes, ..
+    } => {
         if point.x >= bounds.x && point.x <= bounds.x + bounds.width
            && point.y >= bounds.y && point.y <= bounds.y + bounds.height 
         {
@@ -2305,86 +4189,49 @@ pub fn hit_test<'a>(
              } else { 0.0 };
              
              let local_y = point.y - bounds.y + scroll_y;
-             let index = (local_y / style.item_height) as usize;
+             let count = crate::list_view::row_count(items, item_widgets);
+             let rows = crate::list_view::RowOffsets::new(count, style.item_height, item_heights);
+             // The scrollbar is drawn over the rows.
+             if let Some(action) = crate::scrollbar::list_hit_action(widget, &rows, point, interaction) {
+                 return Some(HitTestResult { widget, action, cursor: CursorHint::Default });
+             }
              
-             if index < items.len() {
+             if let Some(index) = rows.index_at(local_y) {
+                 let inset = crate::list_view::row_inset(*multi_select, *show_checkboxes);
+                 if point.x - bounds.x < inset {
+                     let action = format!("{}:check:{}", id, index);
+                     return Some(HitTestResult { widget, action, cursor: CursorHint::Pointer });
+                 }
+                 // Interactive children of a widget row take the click.
+                 if let Some(row) = item_widgets.get(index) {
+                     let local_point = Vec2::new(point.x - bounds.x, local_y);
+                     if let Some(hit) = hit_test_widget(row, local_point, interaction) {
+                         return Some(hit);
+                     }
+                 }
                  let action = format!("{}:{}", id, index);
-                 Some(HitTestResult { widget, action })
+                 Some(HitTestResult { widget, action, cursor: CursorHint::Pointer })
              } else {
-                 Some(HitTestResult { widget, action: id.clone() })
+                 Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Default })
              }
         } else {
             None
         }
     }
-    Widget::DatePicker {
-        id, value, bounds, ..
-    } => {
-        let hit_input = point.x >= bounds.x && point.x <= bounds.x + bounds.width
-            && point.y >= bounds.y && point.y <= bounds.y + bounds.height;
-
-        if let Some(state) = interaction {
-            if state.focused_id.as_deref() == Some(id) {
-                let dd_width = bounds.width.max(250.0);
-                let dd_x = bounds.x;
-                // Layout constants matching render logic
-                let header_height = 30.0;
-                let day_names_height = 28.0;
-                let row_height = 30.0;
-                let padding = 5.0;
-                let dd_height = header_height + day_names_height + 6.0 * row_height + padding * 2.0;
-
-                let dd_y = bounds.y + bounds.height + 2.0;
-
-                if point.x >= dd_x && point.x <= dd_x + dd_width 
-                   && point.y >= dd_y && point.y <= dd_y + dd_height {
-                    
-                    let local_x = point.x - dd_x;
-                    let local_y = point.y - dd_y;
-
-                    // Header
-                    if local_y <= header_height {
-                         if local_x < 40.0 { return Some(HitTestResult { widget, action: format!("{}:prev", id) }); }
-                         if local_x > dd_width - 40.0 { return Some(HitTestResult { widget, action: format!("{}:next", id) }); }
-                         return Some(HitTestResult { widget, action: id.clone() }); 
-                    }
-
-                    // Grid
-                    if local_y > header_height + day_names_height {
-                        let grid_y = local_y - (header_height + day_names_height);
-                        let row = (grid_y / row_height) as i32;
-                        let cell_w = (dd_width - padding * 2.0) / 7.0;
-                        let col = ((local_x - padding) / cell_w) as i32;
-                        
-                        if row >= 0 && row < 6 && col >= 0 && col < 7 {
-                            // Determine View Date
-                            let (view_month, view_year) = state.calendar_view_state.get(id)
-                                .copied()
-                                .or_else(|| value.map(|d| (d.month(), d.year())))
-                                .unwrap_or_else(|| {
-                                    let now = chrono::Local::now().naive_local().date();
-                                    (now.month(), now.year())
-                                });
-                            
-                            if let Some(first_day) = NaiveDate::from_ymd_opt(view_year, view_month, 1) {
-                                let start_weekday = first_day.weekday().num_days_from_monday(); // 0=Mon
-                                let offset = start_weekday as i64;
-                                let day_idx = (row * 7 + col) as i64;
-                                let date_offset = day_idx - offset;
-                                
-                                if let Some(date) = first_day.checked_add_signed(chrono::Duration::days(date_offset)) {
-                                     return Some(HitTestResult { widget, action: format!("{}:day:{}", id, date.format("%Y-%m-%d")) });
-                                }
-                            }
-                        }
-                    }
-                    return Some(HitTestResult { widget, action: id.clone() });
-                }
-            }
+    Widget::TimePicker { id, bounds, .. } => {
+        // The hour/minute overlay is handled by `hit_test_overlays`.
+        if point.x >= bounds.x && point.x <= bounds.x + bounds.width
+           && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
+             Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Pointer })
+        } else {
+             None
         }
-        
-        if hit_input {
-             Some(HitTestResult { widget, action: id.clone() })
+    }
+    Widget::DatePicker { id, bounds, .. } => {
+        // The calendar overlay is handled by `hit_test_overlays`.
+        if point.x >= bounds.x && point.x <= bounds.x + bounds.width
+           && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
+             Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Pointer })
         } else {
              None
         }
@@ -2392,7 +4239,7 @@ pub fn hit_test<'a>(
     Widget::Checkbox { bounds, id, .. } => {
         if point.x >= bounds.x && point.x <= bounds.x + bounds.width
            && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
-             Some(HitTestResult { widget, action: id.clone() })
+             Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Pointer })
         } else {
              None
         }
@@ -2400,7 +4247,7 @@ pub fn hit_test<'a>(
     Widget::Slider { bounds, id, .. } => {
          if point.x >= bounds.x && point.x <= bounds.x + bounds.width
            && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
-             Some(HitTestResult { widget, action: id.clone() })
+             Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Pointer })
         } else {
              None
         }
@@ -2408,7 +4255,7 @@ pub fn hit_test<'a>(
     Widget::ToggleSwitch { bounds, id, .. } => {
         if point.x >= bounds.x && point.x <= bounds.x + bounds.width
            && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
-             Some(HitTestResult { widget, action: id.clone() })
+             Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Pointer })
         } else {
              None
         }
@@ -2416,7 +4263,7 @@ pub fn hit_test<'a>(
     Widget::RadioButton { bounds, value, .. } => {
         if point.x >= bounds.x && point.x <= bounds.x + bounds.width
            && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
-             Some(HitTestResult { widget, action: value.clone() })
+             Some(HitTestResult { widget, action: value.clone(), cursor: CursorHint::Pointer })
         } else {
              None
         }
@@ -2442,10 +4289,10 @@ pub fn hit_test<'a>(
                    
                    if point.x >= indent_x && point.x < indent_x + style.indent_size {
                        // Toggle area
-                       return Some(HitTestResult { widget, action: format!("{}:toggle:{}", wid, node.id) });
+                       return Some(HitTestResult { widget, action: format!("{}:toggle:{}", wid, node.id), cursor: CursorHint::Pointer });
                    } else {
                        // Select area (row)
-                       return Some(HitTestResult { widget, action: format!("{}:select:{}", wid, node.id) });
+                       return Some(HitTestResult { widget, action: format!("{}:select:{}", wid, node.id), cursor: CursorHint::Pointer });
                    }
                }
                None
@@ -2453,7 +4300,7 @@ pub fn hit_test<'a>(
               None
           }
     }
-    Widget::DataGrid { bounds, id, header_height, row_height, columns, .. } => {
+    Widget::DataGrid { bounds, id, header_height, row_height, auto_row_height, fitted_rows, columns, scrollbar, scrollbar_mode, .. } => {
          if point.x >= bounds.x && point.x <= bounds.x + bounds.width
             && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
               if let Some(wid) = id {
@@ -2463,7 +4310,7 @@ pub fn hit_test<'a>(
                   // Header check
                   if local_y < *header_height {
                        // Determine column
-                       let content_width = (bounds.width).max(0.0);
+                       let content_width = (bounds.width - crate::scrollbar::gutter_width(*scrollbar, *scrollbar_mode)).max(0.0);
                        let mut col_widths = Vec::new();
                        let mut total_fixed = 0.0;
                        let mut total_flex = 0.0;
@@ -2499,17 +4346,17 @@ pub fn hit_test<'a>(
                            // Check for resize (Right edge) - 8px tolerance
                            if (local_x - right_edge).abs() <= 8.0 && i < columns.len() {
                                if columns[i].resizable {
-                                   return Some(HitTestResult { widget, action: format!("{}:header_resize:{}", wid, i) });
+                                   return Some(HitTestResult { widget, action: format!("{}:header_resize:{}", wid, i), cursor: CursorHint::EwResize });
                                }
                            }
                            
                            if local_x >= cx && local_x < cx + w {
-                               return Some(HitTestResult { widget, action: format!("{}:header:{}", wid, i) });
+                               return Some(HitTestResult { widget, action: format!("{}:header:{}", wid, i), cursor: CursorHint::Pointer });
                            }
                            cx += w;
                        }
                        
-                       return Some(HitTestResult { widget, action: wid.clone() });
+                       return Some(HitTestResult { widget, action: wid.clone(), cursor: CursorHint::Default });
                   }
                   
                   let scroll_y = if let Some(state) = interaction {
@@ -2518,10 +4365,16 @@ pub fn hit_test<'a>(
                   
                   let content_y = local_y - header_height + scroll_y;
                   if content_y >= 0.0 {
-                      let row = (content_y / row_height).floor() as isize;
+                      // Fitted rows know their count; past the last one hits the grid itself
+                      let row = match fitted_rows.as_ref().filter(|_| *auto_row_height) {
+                          Some(fitted) => fitted.rows()
+                              .index_at(content_y)
+                              .map_or(-1, |r| r as isize),
+                          None => (content_y / row_height).floor() as isize,
+                      };
                       if row >= 0 {
                            // Calculate column based on local_x
-                           let content_width = (bounds.width).max(0.0);
+                           let content_width = (bounds.width - crate::scrollbar::gutter_width(*scrollbar, *scrollbar_mode)).max(0.0);
                            let mut total_fixed = 0.0;
                            let mut total_flex = 0.0;
                            
@@ -2555,11 +4408,11 @@ pub fn hit_test<'a>(
                                cx += w;
                            }
                            
-                           return Some(HitTestResult { widget, action: format!("{}:cell:{}:{}", wid, row, col_idx) });
+                           return Some(HitTestResult { widget, action: format!("{}:cell:{}:{}", wid, row, col_idx), cursor: CursorHint::Default });
                       }
                   }
                   
-                  Some(HitTestResult { widget, action: wid.clone() })
+                  Some(HitTestResult { widget, action: wid.clone(), cursor: CursorHint::Default })
               } else {
                   None
               }
@@ -2570,7 +4423,7 @@ pub fn hit_test<'a>(
     Widget::Dropdown { bounds, id, .. } => {
         if point.x >= bounds.x && point.x <= bounds.x + bounds.width
            && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
-             Some(HitTestResult { widget, action: id.clone() })
+             Some(HitTestResult { widget, action: id.clone(), cursor: CursorHint::Pointer })
         } else {
              None
         }
@@ -2578,12 +4431,67 @@ pub fn hit_test<'a>(
     Widget::KpiCard { bounds, id, .. } => {
         if point.x >= bounds.x && point.x <= bounds.x + bounds.width
            && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
-             Some(HitTestResult { widget, action: id.clone().unwrap_or_default() })
+             let cursor = if id.is_some() { CursorHint::Pointer } else { CursorHint::Default };
+             Some(HitTestResult { widget, action: id.clone().unwrap_or_default(), cursor })
         } else {
              None
         }
     }
-    Widget::Tab { id, bounds, tabs, selected, orientation, .. } => {
+    Widget::MenuBar { id, menus, style, bounds, .. } => {
+        if point.x >= bounds.x && point.x <= bounds.x + bounds.width
+           && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
+            let local_x = point.x - bounds.x;
+            let spans = crate::menu::title_spans(menus, style.text_size());
+            if let Some(i) = spans.iter().position(|(x, w)| local_x >= *x && local_x < x + w) {
+                return Some(HitTestResult { widget, action: format!("{}:menu:{}", id, i), cursor: CursorHint::Pointer });
+            }
+        }
+        None
+    }
+    Widget::Breadcrumb { id, bounds, crumb_slots, .. } => {
+        if point.x >= bounds.x && point.x <= bounds.x + bounds.width
+           && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
+            // Slots come from the last render; nothing is hit before that.
+            if let Some(i) = crate::breadcrumb::segment_at(&crumb_slots.borrow(), point.x - bounds.x) {
+                return Some(HitTestResult { widget, action: format!("{}:crumb:{}", id, i), cursor: CursorHint::Pointer });
+            }
+        }
+        None
+    }
+    Widget::ChipInput { id, chips, style, bounds, .. } => {
+        if point.x < bounds.x || point.x > bounds.x + bounds.width
+           || point.y < bounds.y || point.y > bounds.y + bounds.height {
+            return None;
+        }
+        let (lx, ly) = (point.x - bounds.x, point.y - bounds.y);
+        let layout = crate::chips::layout_chips(chips, style.text_size(), bounds.width);
+        let (action, cursor) = layout.chips.iter()
+            .position(|rect| rect.contains(lx, ly) && rect.on_remove(lx))
+            .map(|i| (format!("{}:remove:{}", id, i), CursorHint::Pointer))
+            .unwrap_or_else(|| (id.clone(), CursorHint::Text));
+        Some(HitTestResult { widget, action, cursor })
+    }
+    Widget::Rating { id, max, allow_half, read_only: false, style, bounds, .. } => {
+        if point.y < bounds.y || point.y > bounds.y + bounds.height {
+            return None;
+        }
+        let (index, half) = rating_star_at(point.x - bounds.x, style, *max, *allow_half)?;
+        let kind = if half { "half_star" } else { "star" };
+        Some(HitTestResult { widget, action: format!("{}:{}:{}", id, kind, index), cursor: CursorHint::Pointer })
+    }
+    Widget::Stepper { id, steps, current, orientation, allow_skip, style, bounds, .. } => {
+        if point.x >= bounds.x && point.x <= bounds.x + bounds.width
+           && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
+            let geo = StepperGeometry::new(Vec2::new(bounds.x, bounds.y), bounds, steps.len(), *orientation, style.radius());
+            if let Some(i) = geo.step_at(point, steps.len()) {
+                if stepper_can_select(*current, i, *allow_skip) {
+                    return Some(HitTestResult { widget, action: format!("{}:step:{}", id, i), cursor: CursorHint::Pointer });
+                }
+            }
+        }
+        None
+    }
+    Widget::Tab { id, bounds, tabs, selected, header_scroll, orientation, style, .. } => {
         if point.x >= bounds.x && point.x <= bounds.x + bounds.width
            && point.y >= bounds.y && point.y <= bounds.y + bounds.height {
             
@@ -2600,11 +4508,16 @@ pub fn hit_test<'a>(
                       crate::widget::Orientation::Horizontal => {
                           if point.y >= bounds.y && point.y <= bounds.y + header_h {
                               let local_x = point.x - bounds.x;
-                              let tab_w = header_w / tab_count as f32;
-                              let idx = (local_x / tab_w) as usize;
-                              if idx < tab_count {
-                                  if let Some(wid) = id {
-                                       return Some(HitTestResult { widget, action: format!("{}:tab:{}", wid, idx) });
+                              let header = TabHeaderGeometry::new(header_w, tab_count, style.min_tab_width, *header_scroll);
+                              if let Some(wid) = id {
+                                  if header.overflow && local_x < header.strip_x {
+                                      return Some(HitTestResult { widget, action: format!("{}:tabs_prev", wid), cursor: CursorHint::Pointer });
+                                  }
+                                  if header.overflow && local_x > header.strip_x + header.viewport {
+                                      return Some(HitTestResult { widget, action: format!("{}:tabs_next", wid), cursor: CursorHint::Pointer });
+                                  }
+                                  if let Some(idx) = header.tab_at(local_x, tab_count) {
+                                      return Some(HitTestResult { widget, action: format!("{}:tab:{}", wid, idx), cursor: CursorHint::Pointer });
                                   }
                               }
                           }
@@ -2616,7 +4529,7 @@ pub fn hit_test<'a>(
                               let idx = (local_y / tab_h) as usize;
                               if idx < tab_count {
                                    if let Some(wid) = id {
-                                        return Some(HitTestResult { widget, action: format!("{}:tab:{}", wid, idx) });
+                                        return Some(HitTestResult { widget, action: format!("{}:tab:{}", wid, idx), cursor: CursorHint::Pointer });
                                    }
                               }
                           }
@@ -2626,7 +4539,7 @@ pub fn hit_test<'a>(
 
              // Check Content
              if let Some(tab) = tabs.get(*selected) {
-                 if let Some(res) = hit_test(&tab.content, point, interaction) {
+                 if let Some(res) = hit_test_widget(&tab.content, point, interaction) {
                      return Some(res);
                  }
              }
@@ -2651,6 +4564,8 @@ pub fn find_widget_mut<'a>(root: &'a mut Widget, id: &str) -> Option<&'a mut Wid
         | Widget::NumberInput { id: w_id, .. }
         | Widget::Autocomplete { id: w_id, .. }
         | Widget::DatePicker { id: w_id, .. }
+        | Widget::TimePicker { id: w_id, .. }
+        | Widget::ChipInput { id: w_id, .. }
         | Widget::ToggleSwitch { id: w_id, .. } => {
             if w_id == id {
                 return Some(root);
@@ -2670,6 +4585,15 @@ pub fn find_widget_mut<'a>(root: &'a mut Widget, id: &str) -> Option<&'a mut Wid
                  }
             }
         },
+        Widget::DataGrid { id: Some(w_id), .. } if w_id == id => return Some(root),
+        Widget::ListView { id: w_id, .. } if w_id == id => return Some(root),
+        Widget::ListView { item_widgets, .. } => {
+            for row in item_widgets.iter_mut() {
+                if let Some(w) = find_widget_mut(row, id) {
+                    return Some(w);
+                }
+            }
+        },
         _ => {}
     }
     None
@@ -2682,6 +4606,77 @@ pub fn get_focusable_ids(widget: &Widget) -> Vec<String> {
     ids
 }
 
+/// Collects the focusable IDs Tab cycles through: those inside the active
+/// focus trap, or the whole tree when there is none.
+///
+/// A Container with `focus_trap: true` is active unless its `id` is in
+/// `InteractionState::released_traps`. Traps nest: the innermost active
+/// trap around the focused widget wins. When focus is in no trap, the last
+/// outermost active trap in the tree takes it, so Tab moves into a dialog
+/// declared after the content it covers.
+///
+/// Escape releases the active trap (see [`release_focus_trap`]); Tab then
+/// cycles through the enclosing trap or the whole tree. A trap without an
+/// `id` cannot be released.
+pub fn get_tab_order(root: &Widget, interaction: &InteractionState) -> Vec<String> {
+    get_focusable_ids(active_focus_trap(root, interaction).unwrap_or(root))
+}
+
+/// The focus trap Tab cycling is scoped to, see [`get_tab_order`].
+pub fn active_focus_trap<'a>(root: &'a Widget, interaction: &InteractionState) -> Option<&'a Widget> {
+    let mut traps = Vec::new();
+    collect_active_traps(root, interaction, 0, &mut traps);
+
+    // Traps around the focused widget are nested, so the deepest is innermost.
+    let focused = interaction.focused_id.as_deref();
+    let around_focus = traps
+        .iter()
+        .filter(|(_, trap)| focused.is_some_and(|id| get_focusable_ids(trap).iter().any(|f| f == id)))
+        .max_by_key(|(depth, _)| *depth);
+    around_focus
+        .or_else(|| traps.iter().rev().find(|(depth, _)| *depth == 0))
+        .map(|(_, trap)| *trap)
+}
+
+/// Releases the active focus trap so Tab can leave it. Returns true if a
+/// trap was released.
+pub fn release_focus_trap(root: &Widget, interaction: &mut InteractionState) -> bool {
+    match active_focus_trap(root, interaction) {
+        Some(Widget::Container { id: Some(id), .. }) => interaction.released_traps.insert(id.clone()),
+        _ => false,
+    }
+}
+
+/// Active traps in depth-first order with the number of active traps
+/// around each. Walks the same subtrees as [`get_focusable_ids`].
+fn collect_active_traps<'a>(widget: &'a Widget, interaction: &InteractionState, depth: usize, traps: &mut Vec<(usize, &'a Widget)>) {
+    let mut depth = depth;
+    if let Widget::Container { focus_trap: true, id, .. } = widget {
+        if !id.as_ref().is_some_and(|id| interaction.released_traps.contains(id)) {
+            traps.push((depth, widget));
+            depth += 1;
+        }
+    }
+    match widget {
+        Widget::Container { children, .. } => {
+            for child in children {
+                collect_active_traps(child, interaction, depth, traps);
+            }
+        }
+        Widget::Tab { tabs, selected, .. } => {
+            if let Some(tab) = tabs.get(*selected) {
+                collect_active_traps(&tab.content, interaction, depth, traps);
+            }
+        }
+        Widget::ListView { item_widgets, .. } => {
+            for row in item_widgets {
+                collect_active_traps(row, interaction, depth, traps);
+            }
+        }
+        _ => {}
+    }
+}
+
 fn collect_focusable_ids_recursive(widget: &Widget, ids: &mut Vec<String>) {
     // If this widget is focusable, add its ID
     if let Some(id) = widget.get_focusable_id() {
@@ -2701,6 +4696,13 @@ fn collect_focusable_ids_recursive(widget: &Widget, ids: &mut Vec<String>) {
             collect_focusable_ids_recursive(&tab.content, ids);
         }
     }
+
+    // ListView rows, in row order
+    if let Widget::ListView { item_widgets, .. } = widget {
+        for row in item_widgets {
+            collect_focusable_ids_recursive(row, ids);
+        }
+    }
 }
   
 /// Handles widget interactions (toggles, sliders) based on input state.
@@ -2727,9 +4729,24 @@ pub fn handle_interactions(
     }
 
     match widget {
-        Widget::Tab { id: wid, selected, tabs, .. } => {
+        Widget::Tab { id: wid, selected, header_scroll, tabs, style, bounds, .. } => {
              if let Some(ref clicked) = ctx.clicked_id {
                  if let Some(wid) = wid {
+                     let forward = if *clicked == format!("{}:tabs_next", wid) {
+                         Some(true)
+                     } else if *clicked == format!("{}:tabs_prev", wid) {
+                         Some(false)
+                     } else {
+                         None
+                     };
+                     if let Some(forward) = forward {
+                         let header = TabHeaderGeometry::new(bounds.width, tabs.len(), style.min_tab_width, *header_scroll);
+                         let paged = header.paged(forward);
+                         if paged != *header_scroll {
+                             *header_scroll = paged;
+                             changed = true;
+                         }
+                     }
                      let prefix = format!("{}:tab:", wid);
                      if clicked.starts_with(&prefix) {
                          if let Ok(idx) = clicked[prefix.len()..].parse::<usize>() {
@@ -2750,10 +4767,121 @@ pub fn handle_interactions(
              }
         }
 
-        Widget::Container { children, bounds, padding, id, scrollable, .. } => {
+        Widget::MenuBar { id, menus, open, highlighted, .. } => {
+             if let Some(ref clicked) = ctx.clicked_id {
+                 let prefix = format!("{}:menu:", id);
+                 if let Some(idx) = clicked.strip_prefix(&prefix).and_then(|i| i.parse::<usize>().ok()) {
+                     if idx < menus.len() {
+                         *open = if *open == Some(idx) { None } else { Some(idx) };
+                         *highlighted = None;
+                         changed = true;
+                     }
+                 } else if open.is_some() && clicked.as_str() != id.as_str() {
+                     // Choosing an item or clicking elsewhere closes the menu.
+                     *open = None;
+                     *highlighted = None;
+                     changed = true;
+                 }
+             }
+        }
+
+        Widget::Dropdown { id, options, selected_index, expanded, .. } => {
+             if let Some(ref clicked) = ctx.clicked_id {
+                 let prefix = format!("select_{}_", id);
+                 if let Some(idx) = clicked.strip_prefix(&prefix).and_then(|i| i.parse::<usize>().ok()) {
+                     if idx < options.len() {
+                         *selected_index = Some(idx);
+                     }
+                     *expanded = false;
+                     changed = true;
+                 } else if clicked.as_str() == id.as_str() {
+                     *expanded = !*expanded;
+                     changed = true;
+                 } else if *expanded {
+                     // Clicking elsewhere closes the list.
+                     *expanded = false;
+                     changed = true;
+                 }
+             } else if *expanded && ctx.is_pressed {
+                 // A press on bare background hits nothing but still closes
+                 // the list; a press held on the field or an option doesn't.
+                 let prefix = format!("select_{}_", id);
+                 let own = ctx.active_action.as_deref()
+                     .is_some_and(|action| action == id.as_str() || action.starts_with(&prefix));
+                 if !own {
+                     *expanded = false;
+                     changed = true;
+                 }
+             }
+        }
+
+        Widget::DatePicker { id, range: true, start, end, min_date, max_date, .. } => {
+             if let Some(ref clicked) = ctx.clicked_id {
+                 let prefix = format!("{}:day:", id);
+                 let date = clicked.strip_prefix(&prefix)
+                     .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+                 if let Some(date) = date {
+                     let in_bounds = min_date.map_or(true, |min| date >= min)
+                         && max_date.map_or(true, |max| date <= max);
+                     if in_bounds {
+                         pick_range_day(start, end, date);
+                         changed = true;
+                     }
+                 }
+             }
+        }
+
+        Widget::TimePicker { id, value, min_time, max_time, .. } => {
+             if let Some(ref clicked) = ctx.clicked_id {
+                 let prefix = format!("{}:time:", id);
+                 let time = clicked.strip_prefix(&prefix)
+                     .and_then(|t| chrono::NaiveTime::parse_from_str(t, "%H:%M").ok());
+                 if let Some(time) = time {
+                     if crate::time_picker::in_bounds(time, *min_time, *max_time) && *value != Some(time) {
+                         *value = Some(time);
+                         changed = true;
+                     }
+                 }
+             }
+        }
+
+        Widget::ChipInput { id, chips, .. } => {
+             if let Some(ref clicked) = ctx.clicked_id {
+                 let prefix = format!("{}:remove:", id);
+                 if let Some(idx) = clicked.strip_prefix(&prefix).and_then(|i| i.parse::<usize>().ok()) {
+                     if idx < chips.len() {
+                         chips.remove(idx);
+                         changed = true;
+                     }
+                 }
+             }
+        }
+
+        Widget::Rating { id, value, read_only: false, .. } => {
+             if let Some(new_value) = ctx.clicked_id.as_deref().and_then(|c| rating_action_value(c, id)) {
+                 if *value != new_value {
+                     *value = new_value;
+                     changed = true;
+                 }
+             }
+        }
+
+        Widget::Stepper { id, steps, current, allow_skip, .. } => {
+             if let Some(ref clicked) = ctx.clicked_id {
+                 let prefix = format!("{}:step:", id);
+                 if let Some(idx) = clicked.strip_prefix(&prefix).and_then(|i| i.parse::<usize>().ok()) {
+                     if idx < steps.len() && idx != *current && stepper_can_select(*current, idx, *allow_skip) {
+                         *current = idx;
+                         changed = true;
+                     }
+                 }
+             }
+        }
+
+        Widget::Container { children, bounds, padding, id, scrollable, overflow, .. } => {
             let my_pos = offset + Vec2::new(bounds.x, bounds.y);
             
-             let scroll_off = if *scrollable {
+             let scroll_off = if overflow.resolve(*scrollable).scrolls() {
                 id.as_ref().and_then(|i| ctx.scroll_offsets.get(i)).copied().unwrap_or(Vec2::ZERO)
             } else {
                 Vec2::ZERO
@@ -2769,6 +4897,16 @@ pub fn handle_interactions(
                 }
             }
         }
+
+        Widget::ListView { id, bounds, item_widgets, .. } => {
+            let scroll_off = ctx.scroll_offsets.get(id.as_str()).copied().unwrap_or(Vec2::ZERO);
+            let row_base = offset + Vec2::new(bounds.x, bounds.y - scroll_off.y);
+            for row in item_widgets {
+                if handle_interactions(row, ctx, row_base) {
+                    changed = true;
+                }
+            }
+        }
         
         Widget::Checkbox { id, checked, .. } => {
              // Check if clicked
@@ -2777,6 +4915,14 @@ pub fn handle_interactions(
                  changed = true;
              }
         }
+
+        Widget::ToggleSwitch { id, checked, toggled_at, .. } => {
+             if ctx.clicked_id.as_deref() == Some(id) {
+                 *checked = !*checked;
+                 *toggled_at = Some(frame_clock());
+                 changed = true;
+             }
+        }
         
         Widget::Slider { id, bounds, value, min, max, .. } => {
             let my_pos = offset + Vec2::new(bounds.x, bounds.y);
@@ -2821,44 +4967,236 @@ fn flatten_tree<'a>(
     }
 }
 
+/// Result of [`handle_keyboard_event`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyboardOutcome {
+  /// The widget tree or interaction state changed; redraw.
+  pub changed: bool,
+  /// Action of the matched shortcut. The key is not passed on to the
+  /// focused widget when this is set.
+  pub shortcut: Option<String>,
+  /// Action of the focused button activated with Enter or Space.
+  pub activated: Option<String>,
+}
+
 /// Handles keyboard events for the UI system.
 ///
-/// Returns true if the event was handled and the UI needs a redraw.
+/// An open menu gets the key first, then global `shortcuts` (matched with
+/// the current `modifiers`), then a DataGrid cell edit in progress, then
+/// Tab focus cycling (scoped by focus traps, see [`get_tab_order`]), then
+/// Escape releasing the active focus trap, then the focused widget. Cell
+/// edits commit into `data` on Enter; without it Enter is left to the app
+/// (see [`crate::datagrid::handle_cell_edit_key`]).
+///
+/// Enter or Space on a focused, enabled Button activates it: its action is
+/// returned in [`KeyboardOutcome::activated`] and the key goes no further.
 pub fn handle_keyboard_event(
   root: &mut Widget,
   interaction: &mut InteractionState,
   event: &winit::event::KeyEvent,
-) -> bool {
+  modifiers: ModifiersState,
+  shortcuts: Option<&crate::shortcut::ShortcutMap>,
+  data: Option<&mut dyn crate::data_source::DataProvider>,
+) -> KeyboardOutcome {
+    let handled = KeyboardOutcome { changed: true, shortcut: None, activated: None };
     if event.state != ElementState::Pressed {
-        return false;
+        return KeyboardOutcome::default();
     }
 
-    let mut changed = false;
+    // 0. An open menu takes the keyboard before anything else
+    if let Some(menu_bar) = find_open_menu_bar(root) {
+        if handle_menu_bar_key(menu_bar, interaction, &event.logical_key) {
+            return handled;
+        }
+    }
+
+    // 1. Global shortcuts win over focus dispatch
+    if let Some(action) = shortcuts.and_then(|map| map.lookup(&event.logical_key, modifiers)) {
+        return KeyboardOutcome { changed: false, shortcut: Some(action.to_string()), activated: None };
+    }
 
-    // 1. Handle focus cycling (Tab)
+    // 2. A grid cell being edited takes typing, Backspace, Enter and Escape
+    if crate::datagrid::handle_cell_edit_key(root, interaction, &event.logical_key, data) {
+        return handled;
+    }
+
+    // 3. Handle focus cycling (Tab / Shift+Tab)
     if let Key::Named(NamedKey::Tab) = &event.logical_key {
-        let focusable_ids = get_focusable_ids(root);
-        // Shift check for winit
-        // We'd need modifiers state for true Shift-Tab, but for now just Tab.
-        // If we want Shift-Tab we need more info.
-        interaction.focus_next(&focusable_ids);
-        return true; 
+        let focusable_ids = get_tab_order(root, interaction);
+        if modifiers.shift_key() {
+            interaction.focus_prev(&focusable_ids);
+        } else {
+            interaction.focus_next(&focusable_ids);
+        }
+        return handled;
     }
 
-    // 2. Dispatch to focused widget
+    // 4. Escape lets focus out of the active trap
+    if event.logical_key == Key::Named(NamedKey::Escape) && release_focus_trap(root, interaction) {
+        return handled;
+    }
+
+    // 5. Dispatch to focused widget
+    let mut changed = false;
     if let Some(focused_id) = interaction.focused_id.clone() {
         if let Some(widget) = find_widget_mut(root, &focused_id) {
+            if let Some(outcome) = activate_button_key(widget, interaction, &event.logical_key) {
+                return outcome;
+            }
+            if matches!(widget, Widget::ListView { .. }) && event.logical_key == Key::Named(NamedKey::Space) {
+                if crate::list_view::toggle_anchor_row(widget) {
+                    interaction.triggered_action = Some(format!("{}:selection", focused_id));
+                }
+                return handled;
+            }
             changed = handle_text_input_to_widget(widget, event);
         }
     }
 
-    changed
+    KeyboardOutcome { changed, shortcut: None, activated: None }
+}
+
+/// Enter or Space on a focused Button. Returns `None` for other keys or
+/// widgets; a disabled button still consumes the key so Space is never
+/// typed elsewhere, but activates nothing.
+pub(crate) fn activate_button_key(widget: &Widget, interaction: &mut InteractionState, key: &Key) -> Option<KeyboardOutcome> {
+    let Widget::Button { action, disabled, .. } = widget else {
+        return None;
+    };
+    if !matches!(key, Key::Named(NamedKey::Enter | NamedKey::Space)) {
+        return None;
+    }
+    let activated = (!*disabled).then(|| action.clone());
+    if let Some(action) = &activated {
+        interaction.emit(crate::interaction::UiEvent::Action, action);
+    }
+    Some(KeyboardOutcome { changed: activated.is_some(), shortcut: None, activated })
+}
+
+/// Pastes `text` (e.g. from a [`crate::ClipboardProvider`]) into the
+/// focused text input. Masked inputs take only the characters that fit
+/// their slots, so formatted text pastes cleanly; number inputs replace
+/// their value if `text` parses in their `number_format`. Returns true if
+/// the value changed.
+pub fn paste_text(root: &mut Widget, interaction: &InteractionState, text: &str) -> bool {
+    let Some(focused_id) = interaction.focused_id.as_deref() else {
+        return false;
+    };
+    match find_widget_mut(root, focused_id) {
+        Some(Widget::TextInput { value, mask: Some(mask), .. }) => crate::mask::insert(mask, value, text),
+        Some(Widget::NumberInput { value, min, max, number_format, .. }) => {
+            let Some(parsed) = number_format.parse(text) else {
+                return false;
+            };
+            let parsed = parsed.clamp(min.unwrap_or(f64::MIN), max.unwrap_or(f64::MAX));
+            let changed = parsed != *value;
+            *value = parsed;
+            changed
+        }
+        Some(Widget::TextInput { value, .. }) | Some(Widget::Autocomplete { value, .. }) => {
+            let text: String = text.chars().filter(|c| !c.is_control()).collect();
+            value.push_str(&text);
+            !text.is_empty()
+        }
+        _ => false,
+    }
+}
+
+fn find_open_menu_bar(widget: &mut Widget) -> Option<&mut Widget> {
+    if matches!(widget, Widget::MenuBar { open: Some(_), .. }) {
+        return Some(widget);
+    }
+    match widget {
+        Widget::Container { children, .. } => children.iter_mut().find_map(find_open_menu_bar),
+        Widget::Tab { tabs, selected, .. } => tabs
+            .get_mut(*selected)
+            .and_then(|tab| find_open_menu_bar(&mut tab.content)),
+        _ => None,
+    }
+}
+
+/// Arrow keys move within and between menus, Enter chooses the highlighted
+/// item (reported through `triggered_action`), Escape closes the menu.
+/// Returns true if the key was consumed.
+fn handle_menu_bar_key(widget: &mut Widget, interaction: &mut InteractionState, key: &Key) -> bool {
+    let Widget::MenuBar { menus, open, highlighted, .. } = widget else {
+        return false;
+    };
+    let Some(current) = *open else {
+        return false;
+    };
+    let items = menus.get(current).map(|m| m.items.as_slice()).unwrap_or(&[]);
+    match key {
+        Key::Named(NamedKey::ArrowDown) => {
+            *highlighted = crate::menu::step_highlight(items, *highlighted, true);
+        }
+        Key::Named(NamedKey::ArrowUp) => {
+            *highlighted = crate::menu::step_highlight(items, *highlighted, false);
+        }
+        Key::Named(NamedKey::ArrowRight) | Key::Named(NamedKey::ArrowLeft) => {
+            let n = menus.len();
+            let forward = matches!(key, Key::Named(NamedKey::ArrowRight));
+            *open = Some(if forward { (current + 1) % n } else { (current + n - 1) % n });
+            *highlighted = None;
+        }
+        Key::Named(NamedKey::Enter) => {
+            if let Some(action) = highlighted.and_then(|i| items.get(i)).and_then(|item| item.action()) {
+                interaction.triggered_action = Some(action.to_string());
+                *open = None;
+                *highlighted = None;
+            }
+        }
+        Key::Named(NamedKey::Escape) => {
+            *open = None;
+            *highlighted = None;
+        }
+        _ => return false,
+    }
+    true
 }
 
 fn handle_text_input_to_widget(widget: &mut Widget, event: &winit::event::KeyEvent) -> bool {
     let mut changed = false;
+
+    if let Widget::ChipInput { chips, value, .. } = widget {
+        match &event.logical_key {
+            Key::Named(NamedKey::Enter) => {
+                let chip = value.trim();
+                if !chip.is_empty() {
+                    chips.push(chip.to_string());
+                }
+                changed = !value.is_empty();
+                value.clear();
+            }
+            // Backspace on an empty field removes the last chip
+            Key::Named(NamedKey::Backspace) => {
+                changed = value.pop().is_some() || chips.pop().is_some();
+            }
+            Key::Character(c) if !c.chars().any(|ch| ch.is_control()) => {
+                value.push_str(c);
+                changed = true;
+            }
+            Key::Named(NamedKey::Space) => {
+                value.push(' ');
+                changed = true;
+            }
+            _ => {}
+        }
+        return changed;
+    }
     
-    if let Widget::TextInput { value, .. } = widget {
+    if let Widget::TextInput { value, mask: Some(mask), .. } = widget {
+        return match &event.logical_key {
+            // The raw value has no literals, so this also removes the ones
+            // shown after the deleted character.
+            Key::Named(NamedKey::Backspace) => value.pop().is_some(),
+            Key::Character(c) => crate::mask::insert(mask, value, c),
+            Key::Named(NamedKey::Space) => crate::mask::insert(mask, value, " "),
+            _ => false,
+        };
+    }
+
+    if let Widget::TextInput { value, .. } | Widget::Autocomplete { value, .. } = widget {
         match &event.logical_key {
             Key::Named(NamedKey::Backspace) => {
                 if value.pop().is_some() {
@@ -2882,6 +5220,48 @@ fn handle_text_input_to_widget(widget: &mut Widget, event: &winit::event::KeyEve
     changed
 }
 
+/// Draws an empty-state placeholder (optional icon above an optional
+/// message) centered in the area at `pos` with `size`. Callers set the
+/// scissor; nothing is drawn when both parts are `None`.
+fn draw_empty_state(
+    ctx: &mut RenderContext,
+    pos: Vec2,
+    size: Vec2,
+    message: Option<&str>,
+    icon: Option<&str>,
+    color: Vec4,
+) {
+    const ICON_SIZE: f32 = 32.0;
+    const GAP: f32 = 8.0;
+    const TEXT_SIZE: f32 = 14.0;
+
+    let icon = icon.and_then(|name| ctx.textures.get(name));
+    let text_h = message.map(|m| ctx.text.measure(m, TEXT_SIZE, None).y).unwrap_or(0.0);
+    let icon_h = if icon.is_some() { ICON_SIZE } else { 0.0 };
+    let gap = if icon.is_some() && message.is_some() { GAP } else { 0.0 };
+
+    let center_x = pos.x + size.x * 0.5;
+    let mut y = pos.y + (size.y - (icon_h + gap + text_h)) * 0.5;
+
+    if let Some(tex) = icon {
+        ctx.images.draw(
+            ctx.device,
+            tex,
+            Vec2::new(center_x, y + ICON_SIZE * 0.5),
+            Vec2::splat(ICON_SIZE),
+            color,
+        );
+        y += ICON_SIZE + gap;
+    }
+
+    if let Some(message) = message {
+        ctx.text.draw(
+            ctx.device, ctx.queue, message, Vec2::new(center_x, y), TEXT_SIZE,
+            color, HorizontalAlign::Center, None
+        );
+    }
+}
+
 /// Helper to render a styled box (shadow, background, border).
 fn draw_box(
     ctx: &mut RenderContext,
@@ -2892,8 +5272,8 @@ fn draw_box(
     let center = Vec2::new(pos.x + size.x * 0.5, pos.y + size.y * 0.5);
     let half_size = size * 0.5;
 
-    // 1. Shadow
-    if let Some(shadow) = style.shadow {
+    // 1. Shadows, back-to-front
+    for shadow in &style.shadows {
         if shadow.color.3 > 0.0 {
             // Shadow is drawn as a blurred rect behind the box.
             ctx.primitives.draw_styled_rect(
@@ -2908,40 +5288,88 @@ fn draw_box(
         }
     }
 
+    // The fill stops at the inner edge of the border so a semi-transparent
+    // border is not blended over the background.
+    let border = style.border.filter(|b| b.width > 0.0 && b.color.3 > 0.0);
+    let inset = border.map(|b| b.width.min(half_size.x).min(half_size.y)).unwrap_or(0.0);
+    let fill_half = half_size - Vec2::splat(inset);
+    let fill_radii = style.corner_radii.map(|r| (r - inset).max(0.0));
+
     // 2. Background (Gradient or Solid)
     if let Some(grad) = style.gradient {
         ctx.primitives.draw_styled_rect(
             center,
-            half_size,
+            fill_half,
             Vec4::from(grad.start),
             Vec4::from(grad.end),
-            style.corner_radii,
+            fill_radii,
             0.0,
             0.0 
         );
     } else if let Some(bg) = style.background {
         if bg.3 > 0.0 {
-            // Use draw_styled_rect for consistent radius handling, or basic draw_rect
             ctx.primitives.draw_rect(
                 center,
-                half_size,
+                fill_half,
                 Vec4::from(bg),
-                style.corner_radii,
+                fill_radii,
                 0.0
             );
         }
     }
 
-    // 3. Border (Stroke)
-    if let Some(border) = style.border {
-        if border.width > 0.0 && border.color.3 > 0.0 {
-             ctx.primitives.draw_rect(
-                center,
-                half_size,
-                Vec4::from(border.color),
-                style.corner_radii,
-                border.width
-             );
-        }
+    // 3. Background image, clipped to the fill
+    if let Some(image) = &style.background_image {
+        draw_background_image(ctx, center - fill_half, fill_half * 2.0, fill_radii, image);
+    }
+
+    // 4. Border (Ring)
+    if let Some(border) = border {
+        ctx.primitives.draw_border(
+            center,
+            half_size,
+            style.corner_radii,
+            border.width,
+            Vec4::from(border.color),
+        );
+    }
+}
+
+/// Draws a box's background image over `pos`/`size`, stretched or tiled
+/// per its `repeat` and clipped to the box's rounded shape.
+fn draw_background_image(
+    ctx: &mut RenderContext,
+    pos: Vec2,
+    size: Vec2,
+    radii: [f32; 4],
+    image: &crate::style::BackgroundImage,
+) {
+    if !ctx.textures.contains_key(&image.texture) || size.x <= 0.0 || size.y <= 0.0 {
+        return;
+    }
+
+    let s = ctx.scale_factor;
+    ctx.push_scissor(Some((
+        (pos.x * s).max(0.0).floor() as u32,
+        (pos.y * s).max(0.0).floor() as u32,
+        (size.x * s).ceil() as u32,
+        (size.y * s).ceil() as u32,
+    )));
+    let rounded = radii.iter().any(|r| *r > 0.0);
+    if rounded {
+        ctx.push_rounded_clip(RoundedClip { center: pos + size * 0.5, half_size: size * 0.5, radii });
+    }
+
+    if let Some(tex) = ctx.textures.get(&image.texture) {
+        // One texel per device pixel: a tile is the texture's size divided
+        // by the scale factor in the logical units boxes are laid out in.
+        let tile = Vec2::new(tex.width as f32, tex.height as f32) / s;
+        let (top_left, quad, uv_scale) = image.repeat.quad(pos, size, tile);
+        ctx.images.draw_tiled(ctx.device, tex, top_left, quad, uv_scale, Vec4::ONE);
+    }
+
+    if rounded {
+        ctx.pop_rounded_clip();
     }
+    ctx.pop_scissor();
 }
@@ -1,5 +1,10 @@
 use serde::{Serialize, Deserialize};
 use regex::Regex;
+use std::collections::HashMap;
+use crate::accessibility::LiveRegion;
+use crate::interaction::InteractionState;
+use crate::widget::Widget;
+use crate::widget_index::find_widget;
 
 /// Rule defining validation logic.
 // We can't easily serialize closures/Box<dyn Fn>, so the custom logic might need to be application-side or trait objects if we want full serialization.
@@ -89,7 +94,7 @@ impl ValidationRule {
             }
             ValidationRule::Email => {
                 // Simple email regex
-                 if let Ok(re) = Regex::new(r"^[\w-\.]+@([\w-]+\.)+[\w-]{2,4}$") {
+                 if let Ok(re) = Regex::new(r"^[\w.-]+@([\w-]+\.)+[\w-]{2,}$") {
                     if re.is_match(value) {
                          Ok(())
                     } else {
@@ -102,3 +107,277 @@ impl ValidationRule {
         }
     }
 }
+
+/// When input widgets are validated.
+///
+/// Set on [`InteractionState::validation_trigger`] and acted on by
+/// [`validate_triggered`], which apps call once per frame after handling
+/// input. Submitting a form always validates everything with
+/// [`apply_validation`], whatever the trigger.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ValidationTrigger {
+    /// Validate the focused widget on every edit.
+    OnChange,
+    /// Validate a widget when it loses focus. Once it shows errors, edits
+    /// re-validate it so the errors clear as soon as they are fixed.
+    #[default]
+    OnBlur,
+    /// Validate only on submit.
+    OnSubmit,
+}
+
+/// Id of a widget that `Widget::validate` checks, if `widget` is one.
+fn validatable_id(widget: &Widget) -> Option<&String> {
+    match widget {
+        Widget::TextInput { id, .. }
+        | Widget::NumberInput { id, .. }
+        | Widget::Autocomplete { id, .. }
+        | Widget::DatePicker { id, .. }
+        | Widget::TimePicker { id, .. } => Some(id),
+        _ => None,
+    }
+}
+
+/// Validates every validatable widget in the tree.
+///
+/// Returns the errors of each failing widget by id; widgets that pass are
+/// left out, so an empty map means the form is valid. Cross-field rules
+/// (passwords that must match, a date range) are app logic: add their
+/// errors to the map before showing it.
+pub fn validate_all(root: &Widget) -> HashMap<String, Vec<String>> {
+    let mut errors = HashMap::new();
+    collect_errors(root, &mut errors);
+    errors
+}
+
+fn collect_errors(widget: &Widget, errors: &mut HashMap<String, Vec<String>>) {
+    if let Some(id) = validatable_id(widget) {
+        let found = widget.validate();
+        if !found.is_empty() {
+            errors.entry(id.clone()).or_default().extend(found);
+        }
+    }
+    widget.for_each_child(&mut |child| collect_errors(child, errors));
+}
+
+/// True if no widget in the tree has validation errors, e.g. to enable a
+/// submit button.
+pub fn is_valid(root: &Widget) -> bool {
+    validate_all(root).is_empty()
+}
+
+/// Validates the tree and stores the result in
+/// `interaction.validation_errors`, so failing widgets draw their error
+/// border.
+///
+/// Errors of validatable widgets that now pass are cleared; entries for
+/// other ids (set by the app) are kept. Widgets whose errors changed
+/// announce their first error, in tree order. Returns true if the form is
+/// valid.
+pub fn apply_validation(root: &Widget, interaction: &mut InteractionState) -> bool {
+    let errors = validate_all(root);
+    let valid = errors.is_empty();
+    let previous = interaction.validation_errors.clone();
+    clear_errors(root, &mut interaction.validation_errors);
+    interaction.validation_errors.extend(errors);
+
+    let mut ids = Vec::new();
+    collect_validatable_ids(root, &mut ids);
+    for id in ids {
+        if let Some(current) = interaction.validation_errors.get(&id) {
+            if previous.get(&id) != Some(current) {
+                let message = current[0].clone();
+                interaction.announce(message, LiveRegion::Assertive);
+            }
+        }
+    }
+    valid
+}
+
+fn collect_validatable_ids(widget: &Widget, ids: &mut Vec<String>) {
+    if let Some(id) = validatable_id(widget) {
+        ids.push(id.to_string());
+    }
+    widget.for_each_child(&mut |child| collect_validatable_ids(child, ids));
+}
+
+fn clear_errors(widget: &Widget, errors: &mut HashMap<String, Vec<String>>) {
+    if let Some(id) = validatable_id(widget) {
+        errors.remove(id);
+    }
+    widget.for_each_child(&mut |child| clear_errors(child, errors));
+}
+
+/// Validates the widgets due under `interaction.validation_trigger`.
+///
+/// Call once per frame after input handling, with `edited` true if the
+/// focused widget's value changed (e.g. `KeyboardOutcome::changed`).
+/// Consumes `interaction.blurred_id`. A widget whose errors change to a
+/// new, non-empty list announces the first one. Returns true if any
+/// widget's errors changed.
+pub fn validate_triggered(root: &Widget, interaction: &mut InteractionState, edited: bool) -> bool {
+    let blurred = interaction.blurred_id.take();
+    let trigger = interaction.validation_trigger;
+    let mut due = Vec::new();
+    if trigger != ValidationTrigger::OnSubmit {
+        due.extend(blurred);
+    }
+    if let (true, Some(focused)) = (edited, interaction.focused_id.clone()) {
+        let showing_errors = interaction.validation_errors.contains_key(&focused);
+        match trigger {
+            ValidationTrigger::OnChange => due.push(focused),
+            ValidationTrigger::OnBlur | ValidationTrigger::OnSubmit if showing_errors => due.push(focused),
+            _ => {}
+        }
+    }
+
+    let mut changed = false;
+    for id in due {
+        let Some(widget) = find_widget(root, &id).filter(|w| validatable_id(w).is_some()) else {
+            continue;
+        };
+        let errors = widget.validate();
+        let previous = if errors.is_empty() {
+            interaction.validation_errors.remove(&id)
+        } else {
+            interaction.validation_errors.insert(id, errors.clone())
+        };
+        let differs = previous.unwrap_or_default() != errors;
+        if differs && !errors.is_empty() {
+            interaction.announce(errors[0].clone(), LiveRegion::Assertive);
+        }
+        changed |= differs;
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::parse_ui;
+
+    const FORM: &str = r#"
+        Container(
+            children: [
+                TextInput(id: "name", validation: Some([Required])),
+                TextInput(id: "email", value: "ada@example.com", validation: Some([Email])),
+                Container(children: [
+                    NumberInput(id: "age", value: 12.0, validation: Some([Min(18.0), Max(130.0)])),
+                ]),
+            ],
+        )
+    "#;
+
+    #[test]
+    fn test_email_rule_accepts_addresses() {
+        assert!(ValidationRule::Email.validate("ada@example.com").is_ok());
+        assert!(ValidationRule::Email.validate("a.b-c@mail.example.museum").is_ok());
+        assert!(ValidationRule::Email.validate("ada@example").is_err());
+        assert!(ValidationRule::Email.validate("not an email").is_err());
+    }
+
+    #[test]
+    fn test_validate_all_collects_errors_by_id() {
+        let root = parse_ui(FORM).unwrap();
+        let errors = validate_all(&root);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors["name"], vec!["This field is required".to_string()]);
+        assert_eq!(errors["age"], vec!["Must be at least 18".to_string()]);
+        assert!(!is_valid(&root));
+    }
+
+    #[test]
+    fn test_apply_validation_replaces_stale_errors() {
+        let mut root = parse_ui(FORM).unwrap();
+        let mut interaction = InteractionState::default();
+        interaction.validation_errors.insert("email".into(), vec!["stale".into()]);
+        interaction.validation_errors.insert("server".into(), vec!["Try again".into()]);
+
+        assert!(!apply_validation(&root, &mut interaction));
+        assert!(interaction.validation_errors.contains_key("name"));
+        assert!(!interaction.validation_errors.contains_key("email"));
+        // App-set errors for other ids survive.
+        assert!(interaction.validation_errors.contains_key("server"));
+
+        if let Widget::Container { children, .. } = &mut root {
+            children.remove(2);
+            if let Widget::TextInput { value, .. } = &mut children[0] {
+                *value = "Ada".into();
+            }
+        }
+        assert!(apply_validation(&root, &mut interaction));
+        assert!(!interaction.validation_errors.contains_key("name"));
+    }
+
+    #[test]
+    fn test_apply_validation_announces_new_errors_once() {
+        let root = parse_ui(FORM).unwrap();
+        let mut interaction = InteractionState::default();
+
+        apply_validation(&root, &mut interaction);
+        let messages: Vec<String> = interaction.take_announcements().into_iter().map(|a| a.message).collect();
+        assert_eq!(messages, vec!["This field is required", "Must be at least 18"]);
+
+        // Unchanged errors stay quiet.
+        apply_validation(&root, &mut interaction);
+        assert!(interaction.take_announcements().is_empty());
+    }
+
+    fn set_name(root: &mut Widget, text: &str) {
+        if let Some(Widget::TextInput { value, .. }) = crate::ui::find_widget_mut(root, "name") {
+            *value = text.into();
+        }
+    }
+
+    /// Two text fields whose rules don't depend on anything but length.
+    const BLUR_FORM: &str = r#"
+        Container(
+            children: [
+                TextInput(id: "name", validation: Some([Required])),
+                TextInput(id: "nickname", value: "Ada", validation: Some([MinLength(2)])),
+            ],
+        )
+    "#;
+
+    #[test]
+    fn test_on_blur_waits_for_focus_to_leave() {
+        let mut root = parse_ui(BLUR_FORM).unwrap();
+        let mut interaction = InteractionState::default();
+        interaction.set_focus(Some("name".into()));
+        set_name(&mut root, "A");
+        set_name(&mut root, "");
+        // Still typing: no error yet.
+        assert!(!validate_triggered(&root, &mut interaction, true));
+        assert!(interaction.validation_errors.is_empty());
+
+        interaction.set_focus(Some("nickname".into()));
+        assert!(validate_triggered(&root, &mut interaction, false));
+        assert!(interaction.validation_errors.contains_key("name"));
+
+        // Back in the field, the error clears as soon as it is fixed.
+        interaction.set_focus(Some("name".into()));
+        validate_triggered(&root, &mut interaction, false);
+        set_name(&mut root, "Ada");
+        assert!(validate_triggered(&root, &mut interaction, true));
+        assert!(interaction.validation_errors.is_empty());
+    }
+
+    #[test]
+    fn test_on_change_and_on_submit() {
+        let mut root = parse_ui(FORM).unwrap();
+        let mut interaction = InteractionState::default();
+        interaction.validation_trigger = ValidationTrigger::OnChange;
+        interaction.set_focus(Some("name".into()));
+        assert!(validate_triggered(&root, &mut interaction, true));
+        assert!(interaction.validation_errors.contains_key("name"));
+
+        let mut interaction = InteractionState::default();
+        interaction.validation_trigger = ValidationTrigger::OnSubmit;
+        interaction.set_focus(Some("name".into()));
+        interaction.set_focus(Some("age".into()));
+        set_name(&mut root, "");
+        assert!(!validate_triggered(&root, &mut interaction, true));
+        assert!(interaction.validation_errors.is_empty());
+        assert!(!apply_validation(&root, &mut interaction));
+    }
+}
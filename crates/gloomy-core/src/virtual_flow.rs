@@ -0,0 +1,237 @@
+//! Item builders and geometry for `VirtualFlow`.
+//!
+//! A `VirtualFlow` shows `item_count` equally sized items in rows that
+//! wrap to the widget's width. Items are not stored in the tree: an
+//! [`ItemBuilder`] registered under the widget's `builder_id` creates
+//! them by index. Call [`refresh_virtual_flows`] after layout and after
+//! scrolling; it builds and lays out only the items intersecting the
+//! viewport (plus one row either side) and drops the rest, so render and
+//! hit-test never touch off-screen items.
+//!
+//! The number of columns follows the width, so a resize reflows the
+//! items on the next layout without rebuilding them.
+
+use crate::interaction::InteractionState;
+use crate::widget::{Widget, WidgetBounds};
+use glam::Vec2;
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Creates the content of a `VirtualFlow` item.
+pub trait ItemBuilder {
+    /// Returns the widget for item `index`. It is laid out to fill the
+    /// flow's `item_size`.
+    fn build(&self, index: usize) -> Widget;
+}
+
+/// Any closure from index to widget is a builder.
+impl<F: Fn(usize) -> Widget> ItemBuilder for F {
+    fn build(&self, index: usize) -> Widget {
+        self(index)
+    }
+}
+
+/// Simple HashMap-based builder lookup, keyed by the widget's
+/// `builder_id`.
+#[derive(Default)]
+pub struct ItemBuilders {
+    builders: HashMap<String, Box<dyn ItemBuilder>>,
+}
+
+impl ItemBuilders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a builder under `id`.
+    pub fn register<B: ItemBuilder + 'static>(&mut self, id: impl Into<String>, builder: B) {
+        self.builders.insert(id.into(), Box::new(builder));
+    }
+
+    pub fn get(&self, id: &str) -> Option<&dyn ItemBuilder> {
+        self.builders.get(id).map(|b| b.as_ref())
+    }
+}
+
+/// Item placement for a flow of a given width.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlowGeometry {
+    pub columns: usize,
+    pub item_size: Vec2,
+    pub spacing: f32,
+}
+
+impl FlowGeometry {
+    /// Fits as many columns as `width` allows, at least one.
+    pub fn new(width: f32, item_size: Vec2, spacing: f32) -> Self {
+        let pitch = item_size.x + spacing;
+        let columns = if pitch > 0.0 { ((width + spacing) / pitch).floor() as usize } else { 1 };
+        Self { columns: columns.max(1), item_size, spacing }
+    }
+
+    fn row_pitch(&self) -> f32 {
+        self.item_size.y + self.spacing
+    }
+
+    /// Number of rows needed for `count` items.
+    pub fn rows(&self, count: usize) -> usize {
+        count.div_ceil(self.columns)
+    }
+
+    /// Height of all rows together.
+    pub fn content_height(&self, count: usize) -> f32 {
+        let rows = self.rows(count);
+        if rows == 0 {
+            0.0
+        } else {
+            rows as f32 * self.row_pitch() - self.spacing
+        }
+    }
+
+    /// Top-left of item `index`, relative to the top of the content.
+    pub fn item_pos(&self, index: usize) -> Vec2 {
+        let (row, col) = (index / self.columns, index % self.columns);
+        Vec2::new(
+            col as f32 * (self.item_size.x + self.spacing),
+            row as f32 * self.row_pitch(),
+        )
+    }
+
+    /// Items in the rows intersecting a viewport `height` tall scrolled
+    /// to `scroll`.
+    pub fn visible_range(&self, count: usize, scroll: f32, height: f32) -> Range<usize> {
+        let pitch = self.row_pitch();
+        if count == 0 || pitch <= 0.0 {
+            return 0..0;
+        }
+        let first_row = (scroll.max(0.0) / pitch).floor() as usize;
+        let last_row = ((scroll + height) / pitch).ceil() as usize;
+        let start = (first_row * self.columns).min(count);
+        let end = (last_row * self.columns).min(count);
+        start..end
+    }
+
+    /// Item under a content-relative point, or `None` in the spacing or
+    /// past the last item.
+    pub fn index_at(&self, count: usize, point: Vec2) -> Option<usize> {
+        if point.x < 0.0 || point.y < 0.0 {
+            return None;
+        }
+        let col = (point.x / (self.item_size.x + self.spacing)) as usize;
+        let row = (point.y / self.row_pitch()) as usize;
+        let index = row * self.columns + col;
+        let local = point - self.item_pos(index);
+        let inside = col < self.columns && local.x <= self.item_size.x && local.y <= self.item_size.y;
+        (inside && index < count).then_some(index)
+    }
+}
+
+/// Positions and lays out realized items for the flow's current width.
+pub(crate) fn layout_items(bounds: &WidgetBounds, item_size: (f32, f32), spacing: f32, items: &mut [(usize, Widget)]) {
+    let geometry = FlowGeometry::new(bounds.width, Vec2::from(item_size), spacing);
+    for (index, item) in items {
+        let pos = geometry.item_pos(*index);
+        crate::layout_engine::set_pos(item, pos.x, pos.y);
+        crate::layout_engine::set_size(item, item_size.0, item_size.1);
+        crate::layout_engine::compute_layout(item, pos.x, pos.y, item_size.0, item_size.1);
+    }
+}
+
+/// Builds the items of every `VirtualFlow` in the tree that come into
+/// view and drops those that left it.
+///
+/// Returns true if any flow's realized items changed, in which case the
+/// app should redraw.
+pub fn refresh_virtual_flows(root: &mut Widget, builders: &ItemBuilders, interaction: &InteractionState) -> bool {
+    match root {
+        Widget::Container { children, .. } => children
+            .iter_mut()
+            .fold(false, |changed, child| refresh_virtual_flows(child, builders, interaction) | changed),
+        Widget::Tab { tabs, selected, .. } => tabs
+            .get_mut(*selected)
+            .map(|tab| refresh_virtual_flows(&mut tab.content, builders, interaction))
+            .unwrap_or(false),
+        Widget::VirtualFlow { id, item_count, item_size, spacing, builder_id, bounds, realized, .. } => {
+            let Some(builder) = builders.get(builder_id) else {
+                return false;
+            };
+            let scroll = interaction.scroll_offsets.get(id.as_str()).map(|v| v.y).unwrap_or(0.0);
+            let geometry = FlowGeometry::new(bounds.width, Vec2::from(*item_size), *spacing);
+            // One extra row either side so small scrolls don't pop items in.
+            let pitch = item_size.1 + *spacing;
+            let range = geometry.visible_range(*item_count, scroll - pitch, bounds.height + pitch * 2.0);
+
+            let before = realized.len();
+            realized.retain(|(index, _)| range.contains(index));
+            let kept = realized.len();
+            let missing: Vec<usize> = range.filter(|i| !realized.iter().any(|(r, _)| r == i)).collect();
+            if missing.is_empty() && kept == before {
+                return false;
+            }
+            realized.extend(missing.into_iter().map(|i| (i, builder.build(i))));
+            realized.sort_by_key(|(index, _)| *index);
+            layout_items(bounds, *item_size, *spacing, realized);
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns_follow_width() {
+        let size = Vec2::new(100.0, 80.0);
+        assert_eq!(FlowGeometry::new(320.0, size, 10.0).columns, 3);
+        assert_eq!(FlowGeometry::new(319.0, size, 10.0).columns, 2);
+        // Narrower than one item still shows one column.
+        assert_eq!(FlowGeometry::new(40.0, size, 10.0).columns, 1);
+    }
+
+    #[test]
+    fn test_visible_range_and_hit() {
+        let g = FlowGeometry::new(330.0, Vec2::new(100.0, 80.0), 10.0);
+        assert_eq!(g.content_height(10), 4.0 * 90.0 - 10.0);
+        // Rows 1 and 2 (y 90..270) intersect 100..250.
+        assert_eq!(g.visible_range(10, 100.0, 150.0), 3..9);
+        assert_eq!(g.visible_range(10, 300.0, 500.0), 9..10);
+        assert_eq!(g.item_pos(4), Vec2::new(110.0, 90.0));
+        assert_eq!(g.index_at(10, Vec2::new(150.0, 120.0)), Some(4));
+        // In the gap between columns.
+        assert_eq!(g.index_at(10, Vec2::new(105.0, 120.0)), None);
+        // Past the last item.
+        assert_eq!(g.index_at(10, Vec2::new(150.0, 300.0)), None);
+    }
+
+    #[test]
+    fn test_refresh_builds_only_visible_items() {
+        let mut builders = ItemBuilders::new();
+        builders.register("cards", |i: usize| Widget::label(format!("Card {}", i)));
+        let mut root = crate::ui::parse_ui(r#"
+            VirtualFlow(
+                id: "gallery",
+                item_count: 10000,
+                item_size: (100.0, 80.0),
+                spacing: 10.0,
+                builder_id: "cards",
+                bounds: (x: 0.0, y: 0.0, width: 330.0, height: 170.0),
+            )
+        "#).unwrap();
+        let mut state = InteractionState::default();
+        let realized = |root: &Widget| match root {
+            Widget::VirtualFlow { realized, .. } => realized.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+            _ => unreachable!(),
+        };
+
+        assert!(refresh_virtual_flows(&mut root, &builders, &state));
+        // Rows 0-1 in view plus one spare row below.
+        assert_eq!(realized(&root), (0..9).collect::<Vec<_>>());
+        assert!(!refresh_virtual_flows(&mut root, &builders, &state));
+
+        state.scroll_offsets.insert("gallery".into(), Vec2::new(0.0, 90.0 * 100.0));
+        assert!(refresh_virtual_flows(&mut root, &builders, &state));
+        assert_eq!(realized(&root), (297..309).collect::<Vec<_>>());
+    }
+}
@@ -2,12 +2,20 @@
 //!
 //! Widgets can be deserialized from RON files for declarative UI layouts.
 
-use crate::layout::Layout;
+use crate::accessibility::LiveRegion;
+use crate::layout::{AlignSelf, Layout, Padding};
 use std::cell::RefCell;
 use serde::{Deserialize, Serialize};
 use crate::validation::ValidationRule;
-use chrono::NaiveDate;
-use crate::style::{BoxStyle, ButtonStyle, TextInputStyle, ListViewStyle, Shadow, Gradient, Border, BorderStyle};
+use crate::number_format::NumberFormat;
+use crate::spinner::SpinRepeatConfig;
+use chrono::{NaiveDate, NaiveTime, Weekday};
+use crate::breadcrumb::CrumbSlot;
+use crate::menu::Menu;
+use crate::measure::MeasuredText;
+use crate::rich_text::TabWidth;
+use crate::style::{BoxStyle, ButtonStyle, TextInputStyle, ListViewStyle, Shadow, Gradient, Border, BorderStyle, TextDefaults};
+use crate::theme::{themed_default, with_alpha, Theme, Themed};
 
 /// RGBA color as tuple for serde.
 pub type Color = (f32, f32, f32, f32);
@@ -48,6 +56,39 @@ impl Default for Orientation {
   }
 }
 
+/// How a container treats children that extend past its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Default)]
+pub enum Overflow {
+  /// Children may draw outside the container.
+  #[default]
+  Visible,
+  /// Children are clipped to the container bounds.
+  ///
+  /// Clipping uses a rectangular scissor, so content still shows in the
+  /// corners of a container with rounded corners unless `clip_to_shape`
+  /// is also set.
+  Hidden,
+  /// Children are clipped and can be scrolled. Same as `scrollable: true`.
+  Scroll,
+}
+
+impl Overflow {
+  /// Combines the overflow mode with the legacy `scrollable` flag.
+  pub fn resolve(self, scrollable: bool) -> Self {
+    if scrollable { Overflow::Scroll } else { self }
+  }
+
+  /// Returns true if children are clipped to the container bounds.
+  pub fn clips(self) -> bool {
+    self != Overflow::Visible
+  }
+
+  /// Returns true if the container applies a scroll offset.
+  pub fn scrolls(self) -> bool {
+    self == Overflow::Scroll
+  }
+}
+
 /// Architecture: Layout Caching
 /// Stores inputs and results of the last layout calculation.
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
@@ -62,6 +103,9 @@ pub struct LayoutCache {
     pub parent_y: f32,
     /// Resulting bounds
     pub result_bounds: WidgetBounds,
+    /// Breakpoint the layout was computed with (invalidation trigger)
+    #[serde(default)]
+    pub breakpoint: Option<usize>,
     /// Whether the cache is valid
     pub valid: bool,
 }
@@ -73,7 +117,19 @@ pub struct RenderCache {
     pub primitives: Option<crate::primitives::PrimitiveSnapshot>,
     pub text: Option<crate::text::TextSnapshot>,
     pub images: Option<crate::image_renderer::ImageSnapshot>,
-    pub base_offset: glam::Vec2,
+    /// Absolute rect the container occupied when captured.
+    pub rect: crate::rect::Rect,
+}
+
+impl RenderCache {
+    /// Whether the snapshot can be replayed for the container drawn at
+    /// `rect` (absolute position and size). Captured batches keep absolute
+    /// positions and scissor rects, so only the exact rect it was captured
+    /// at qualifies: a container moved or resized by layout is redrawn
+    /// even when its parent stays put.
+    pub fn replayable_at(&self, rect: crate::rect::Rect) -> bool {
+        self.rect == rect
+    }
 }
 
 // Implement Debug manually if needed, or omit Debug for Snapshots if they are large/complex.
@@ -85,7 +141,7 @@ pub struct RenderCache {
 impl std::fmt::Debug for RenderCache {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RenderCache")
-         .field("base_offset", &self.base_offset)
+         .field("rect", &self.rect)
          .finish_non_exhaustive()
     }
 }
@@ -99,6 +155,14 @@ pub enum Widget {
     id: Option<String>,
     #[serde(default)]
     scrollable: bool,
+    /// Clipping/scrolling of children. `scrollable: true` implies `Scroll`.
+    #[serde(default)]
+    overflow: Overflow,
+    /// Clips children to the rounded shape of `style.corner_radii` instead
+    /// of the bounding rect. Only takes effect with a non-zero radius; see
+    /// `RenderContext::push_rounded_clip` for the cost.
+    #[serde(default)]
+    clip_to_shape: bool,
     #[serde(default)]
     bounds: WidgetBounds,
     #[serde(default)]
@@ -107,9 +171,40 @@ pub enum Widget {
     height: Option<f32>,
     #[serde(default)]
     style: BoxStyle,
+    /// Text properties inherited by the labels and buttons inside.
+    #[serde(default)]
+    text_defaults: TextDefaults,
+    /// Opacity multiplied into everything drawn for this subtree (0..1).
+    /// Purely visual: a faded container is still hit-tested.
+    #[serde(default = "default_opacity")]
+    opacity: f32,
+    /// Paint order among siblings: higher draws on top and is hit first.
+    /// Ties keep declaration order.
+    #[serde(default)]
+    z_index: i32,
+    /// Keeps Tab focus cycling inside this subtree while active; see
+    /// [`crate::ui::get_tab_order`].
+    #[serde(default)]
+    focus_trap: bool,
+    /// Announces changes to the text inside; see
+    /// [`crate::accessibility::update_live_regions`]. Needs an `id`.
+    #[serde(default)]
+    live_region: Option<LiveRegion>,
+    /// Declares that nothing in the subtree changes between frames, so it
+    /// is drawn once and replayed from the render cache afterwards. See
+    /// [`Widget::mark_dirty`] for invalidation.
+    #[serde(default)]
+    static_cache: bool,
+    /// When the vertical scrollbar of a scrolling container is drawn.
+    #[serde(default)]
+    scrollbar: crate::scrollbar::ScrollbarPolicy,
+    /// Scrollbar over the content or in a gutter beside it.
+    #[serde(default)]
+    scrollbar_mode: crate::scrollbar::ScrollbarMode,
 
+    /// A number for all sides or `[top, right, bottom, left]`.
     #[serde(default)]
-    padding: f32,
+    padding: Padding,
     #[serde(default)]
     layout: Layout,
     #[serde(default)]
@@ -123,6 +218,14 @@ pub enum Widget {
     #[serde(default = "default_span_one")]
     row_span: usize,
     #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+    #[serde(default)]
     children: Vec<Widget>,
 
     /// Internal cache for high-performance layout skipping.
@@ -131,6 +234,10 @@ pub enum Widget {
     /// Internal cache for high-performance rendering.
     #[serde(skip)]
     render_cache: RefCell<Option<Box<RenderCache>>>,
+    /// Bumped by [`Widget::mark_dirty`]; a [`crate::WidgetIndex`] built
+    /// from this container is stale once it changes.
+    #[serde(skip)]
+    generation: u64,
   },
 
   /// Tab widget for switching between pages.
@@ -141,6 +248,9 @@ pub enum Widget {
     tabs: Vec<TabItem>,
     #[serde(default)]
     selected: usize,
+    /// Horizontal scroll of an overflowing tab header, in pixels.
+    #[serde(default)]
+    header_scroll: f32,
     #[serde(default)]
     orientation: Orientation,
     #[serde(default)]
@@ -164,6 +274,14 @@ pub enum Widget {
     #[serde(default = "default_span_one")]
     row_span: usize,
     #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+    #[serde(default)]
     layout_cache: Option<Box<LayoutCache>>,
     #[serde(skip)]
     render_cache: RefCell<Option<Box<RenderCache>>>,
@@ -172,6 +290,12 @@ pub enum Widget {
   /// Text label widget.
   Label {
     text: String,
+    /// Identifies the label, e.g. for selection.
+    #[serde(default)]
+    id: Option<String>,
+    /// Value-store key `text` is read from, see [`crate::binding`].
+    #[serde(default)]
+    bind: Option<String>,
     #[serde(default)]
     x: f32,
     #[serde(default)]
@@ -180,10 +304,12 @@ pub enum Widget {
     width: f32,
     #[serde(default)]
     height: f32,
-    #[serde(default = "default_font_size")]
-    size: f32,
-    #[serde(default = "default_color")]
-    color: Color,
+    /// Font size; `None` inherits it, see [`TextDefaults`].
+    #[serde(default, deserialize_with = "deserialize_maybe")]
+    size: Option<f32>,
+    /// Text color; `None` inherits it.
+    #[serde(default, deserialize_with = "deserialize_maybe")]
+    color: Option<Color>,
     #[serde(default)]
     text_align: TextAlign,
     #[serde(default)]
@@ -197,7 +323,31 @@ pub enum Widget {
     #[serde(default = "default_span_one")]
     row_span: usize,
     #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+    #[serde(default)]
     font: Option<String>,
+    /// Spacing of the stops `\t` advances to.
+    #[serde(default)]
+    tab_width: TabWidth,
+    #[serde(default)]
+    underline: bool,
+    #[serde(default)]
+    strikethrough: bool,
+    /// Lets a drag select text for copying, see [`crate::selection`].
+    #[serde(default)]
+    selectable: bool,
+    /// Absolute x of each character boundary from the last render.
+    #[serde(skip)]
+    char_stops: RefCell<Vec<f32>>,
+    /// Text extent from the last [`crate::measure::measure_tree`].
+    #[serde(skip)]
+    measured: Option<MeasuredText>,
   },
 
   /// Interactive button widget.
@@ -208,6 +358,10 @@ pub enum Widget {
     bounds: WidgetBounds,
     #[serde(default)]
     style: ButtonStyle,
+    /// Style classes, resolved against
+    /// [`crate::style_context::StyleContext::classes`] when rendering.
+    #[serde(default)]
+    classes: Vec<String>,
     #[serde(default)]
     width: Option<f32>,
     #[serde(default)]
@@ -227,7 +381,22 @@ pub enum Widget {
     #[serde(default = "default_span_one")]
     row_span: usize,
     #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+    /// Extra margin around the bounds that still hits this control;
+    /// `None` uses `InteractionState::hit_padding`.
+    #[serde(default)]
+    hit_padding: Option<f32>,
+    #[serde(default)]
     font: Option<String>,
+    /// Text extent from the last [`crate::measure::measure_tree`].
+    #[serde(skip)]
+    measured: Option<MeasuredText>,
   },
 
   /// List/Menu widget.
@@ -235,10 +404,38 @@ pub enum Widget {
       #[serde(default)]
       id: String,
       items: Vec<String>,
+      /// Rows as widgets, e.g. an icon with a title and subtitle. When
+      /// non-empty these replace `items`; each is laid out to fill its
+      /// row slot and only visible rows are drawn and hit-tested.
+      #[serde(default)]
+      item_widgets: Vec<Widget>,
+      /// Per-item heights. Items past the end of this list are
+      /// `style.item_height` tall.
+      #[serde(default)]
+      item_heights: Vec<f32>,
       #[serde(default)]
       selected_index: Option<usize>,
+      /// Select several rows at once, see [`crate::list_view`]. The
+      /// selection lives in `selected_indices`; `selected_index` is unused.
+      #[serde(default)]
+      multi_select: bool,
+      #[serde(default)]
+      selected_indices: std::collections::HashSet<usize>,
+      /// Draw a checkbox at the start of each row in multi-select mode.
+      #[serde(default)]
+      show_checkboxes: bool,
       #[serde(default)]
       style: ListViewStyle,
+      /// Shown centered in place of the items when the list is empty.
+      #[serde(default)]
+      empty_message: Option<String>,
+      /// Texture name drawn above `empty_message`.
+      #[serde(default)]
+      empty_icon: Option<String>,
+      #[serde(default)]
+      scrollbar: crate::scrollbar::ScrollbarPolicy,
+      #[serde(default)]
+      scrollbar_mode: crate::scrollbar::ScrollbarMode,
 
       #[serde(default)]
       bounds: WidgetBounds,
@@ -258,9 +455,21 @@ pub enum Widget {
       col_span: usize,
       #[serde(default = "default_span_one")]
       row_span: usize,
+      #[serde(default)]
+      grid_area: Option<String>,
+      #[serde(default)]
+      align_self: AlignSelf,
+      #[serde(default)]
+      justify_self: AlignSelf,
+      #[serde(default)]
+      order: i32,
 
       #[serde(skip)]
       scroll_offset: f32,
+      /// Last clicked row: the start of Shift ranges and the row Space
+      /// toggles.
+      #[serde(skip)]
+      selection_anchor: Option<usize>,
   },
 
   /// Tree Widget
@@ -295,11 +504,22 @@ pub enum Widget {
       col_span: usize,
       #[serde(default = "default_span_one")]
       row_span: usize,
+      #[serde(default)]
+      grid_area: Option<String>,
+      #[serde(default)]
+      align_self: AlignSelf,
+      #[serde(default)]
+      justify_self: AlignSelf,
+      #[serde(default)]
+      order: i32,
   },
 
   /// Toggle switch widget.
   ToggleSwitch {
     id: String,
+    /// Value-store key this input reads from and writes back to.
+    #[serde(default)]
+    bind: Option<String>,
     checked: bool,
     #[serde(default)]
     style: ToggleSwitchStyle,
@@ -317,6 +537,22 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+    /// Extra margin around the bounds that still hits this control;
+    /// `None` uses `InteractionState::hit_padding`.
+    #[serde(default)]
+    hit_padding: Option<f32>,
+    /// Frame clock time `handle_interactions` last flipped `checked`,
+    /// for the thumb slide (`style.animation_secs`).
+    #[serde(skip)]
+    toggled_at: Option<f32>,
   },
 
   /// Progress bar widget.
@@ -326,6 +562,11 @@ pub enum Widget {
     min: f32,
     #[serde(default)]
     max: f32,
+    /// Shows a looping segment instead of a fill when progress is unknown.
+    /// Animates from `RenderContext::time`, so the app must keep
+    /// requesting redraws; determinate mode ignores the clock.
+    #[serde(default)]
+    indeterminate: bool,
     #[serde(default)]
     style: ProgressBarStyle,
     #[serde(default)]
@@ -346,6 +587,370 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+  },
+
+  /// Ring-shaped progress indicator.
+  CircularProgress {
+    value: f32,
+    #[serde(default)]
+    min: f32,
+    #[serde(default)]
+    max: f32,
+    /// Spins a fixed-length arc instead of showing `value`. Animates from
+    /// `RenderContext::time`, so the app must keep requesting redraws.
+    #[serde(default)]
+    indeterminate: bool,
+    /// Draws the percentage in the middle of the ring.
+    #[serde(default)]
+    show_label: bool,
+    #[serde(default)]
+    style: CircularProgressStyle,
+    /// Diameter; defaults to 48.
+    #[serde(default)]
+    size: Option<f32>,
+    #[serde(default)]
+    bounds: WidgetBounds,
+    #[serde(default)]
+    layout: Layout,
+    #[serde(default)]
+    flex: f32,
+    #[serde(default)]
+    grid_col: Option<usize>,
+    #[serde(default)]
+    grid_row: Option<usize>,
+    #[serde(default = "default_span_one")]
+    col_span: usize,
+    #[serde(default = "default_span_one")]
+    row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+  },
+
+  /// Gray placeholder shaped like content that is still loading, with a
+  /// highlight sweeping across it.
+  ///
+  /// The shimmer animates from `RenderContext::time`, so the app must keep
+  /// requesting redraws (return true from `on_tick`) while it is shown.
+  Skeleton {
+    #[serde(default)]
+    shape: SkeletonShape,
+    #[serde(default)]
+    style: SkeletonStyle,
+    /// Stops the shimmer and draws only the placeholder.
+    #[serde(default)]
+    paused: bool,
+    #[serde(default)]
+    width: Option<f32>,
+    #[serde(default)]
+    height: Option<f32>,
+    #[serde(default)]
+    bounds: WidgetBounds,
+    #[serde(default)]
+    layout: Layout,
+    #[serde(default)]
+    flex: f32,
+    #[serde(default)]
+    grid_col: Option<usize>,
+    #[serde(default)]
+    grid_row: Option<usize>,
+    #[serde(default = "default_span_one")]
+    col_span: usize,
+    #[serde(default = "default_span_one")]
+    row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+  },
+
+  /// Scrolling grid of equally sized items that wrap to the width, for
+  /// galleries with thousands of entries.
+  ///
+  /// Items come from the [`crate::virtual_flow::ItemBuilder`] registered
+  /// under `builder_id` and only those in view are built, see
+  /// [`crate::virtual_flow`]. Scrolls by `scroll_offsets[id]`; clicking
+  /// an item outside its interactive children emits `{id}:{index}`.
+  VirtualFlow {
+    id: String,
+    item_count: usize,
+    /// Width and height of every item.
+    item_size: (f32, f32),
+    /// Gap between items, both across and down.
+    #[serde(default)]
+    spacing: f32,
+    builder_id: String,
+    #[serde(default)]
+    width: Option<f32>,
+    #[serde(default)]
+    height: Option<f32>,
+    #[serde(default)]
+    bounds: WidgetBounds,
+    #[serde(default)]
+    layout: Layout,
+    #[serde(default)]
+    flex: f32,
+    #[serde(default)]
+    grid_col: Option<usize>,
+    #[serde(default)]
+    grid_row: Option<usize>,
+    #[serde(default = "default_span_one")]
+    col_span: usize,
+    #[serde(default = "default_span_one")]
+    row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+    /// Items built for the current viewport, by index.
+    #[serde(skip)]
+    realized: Vec<(usize, Widget)>,
+  },
+
+  /// Row of stars showing a score, e.g. for reviews.
+  ///
+  /// Clicking a star emits `{id}:star:{i}` (or `{id}:half_star:{i}` on the
+  /// left half of a star when `allow_half` is set) and sets `value` to
+  /// `i + 1` (or `i + 0.5`). Hovering previews the value. A fractional
+  /// `value` is drawn as a partially filled star either way.
+  Rating {
+    id: String,
+    #[serde(default)]
+    value: f32,
+    /// Number of stars.
+    #[serde(default = "default_rating_max")]
+    max: usize,
+    #[serde(default)]
+    allow_half: bool,
+    /// Display only: no hit regions, hover or clicks.
+    #[serde(default)]
+    read_only: bool,
+    #[serde(default)]
+    style: RatingStyle,
+    #[serde(default)]
+    width: Option<f32>,
+    #[serde(default)]
+    height: Option<f32>,
+    #[serde(default)]
+    bounds: WidgetBounds,
+    #[serde(default)]
+    layout: Layout,
+    #[serde(default)]
+    flex: f32,
+    #[serde(default)]
+    grid_col: Option<usize>,
+    #[serde(default)]
+    grid_row: Option<usize>,
+    #[serde(default = "default_span_one")]
+    col_span: usize,
+    #[serde(default = "default_span_one")]
+    row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+  },
+
+  /// Tag editor: removable chips followed by an inline text field.
+  ///
+  /// While focused, Enter turns the typed `value` into a chip and Backspace
+  /// on an empty field removes the last chip. A chip's "×" emits
+  /// `{id}:remove:{i}`. Chips wrap onto new rows when they overflow.
+  ChipInput {
+    id: String,
+    #[serde(default)]
+    chips: Vec<String>,
+    #[serde(default)]
+    value: String,
+    #[serde(default)]
+    placeholder: String,
+    #[serde(default)]
+    style: ChipInputStyle,
+    #[serde(default)]
+    width: Option<f32>,
+    /// Defaults to the height the chips need at `width` (or 240).
+    #[serde(default)]
+    height: Option<f32>,
+    #[serde(default)]
+    bounds: WidgetBounds,
+    #[serde(default)]
+    layout: Layout,
+    #[serde(default)]
+    flex: f32,
+    #[serde(default)]
+    grid_col: Option<usize>,
+    #[serde(default)]
+    grid_row: Option<usize>,
+    #[serde(default = "default_span_one")]
+    col_span: usize,
+    #[serde(default = "default_span_one")]
+    row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+  },
+
+  /// Multi-step progress indicator: numbered circles joined by lines.
+  ///
+  /// Steps before `current` are completed, later ones are upcoming. Clicking
+  /// a step emits `{id}:step:{i}` and moves `current` there. Going back to a
+  /// completed step is always allowed; jumping ahead only with `allow_skip`.
+  Stepper {
+    id: String,
+    steps: Vec<String>,
+    #[serde(default)]
+    current: usize,
+    #[serde(default)]
+    orientation: Orientation,
+    /// Lets upcoming steps be clicked as well.
+    #[serde(default)]
+    allow_skip: bool,
+    #[serde(default)]
+    style: StepperStyle,
+    #[serde(default)]
+    width: Option<f32>,
+    #[serde(default)]
+    height: Option<f32>,
+    #[serde(default)]
+    bounds: WidgetBounds,
+    #[serde(default)]
+    layout: Layout,
+    #[serde(default)]
+    flex: f32,
+    #[serde(default)]
+    grid_col: Option<usize>,
+    #[serde(default)]
+    grid_row: Option<usize>,
+    #[serde(default = "default_span_one")]
+    col_span: usize,
+    #[serde(default = "default_span_one")]
+    row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+  },
+
+  /// Horizontal trail of clickable path segments.
+  ///
+  /// Clicking a segment emits `{id}:crumb:{i}`. Middle segments collapse
+  /// into an ellipsis when the trail is wider than the widget.
+  Breadcrumb {
+    id: String,
+    segments: Vec<String>,
+    #[serde(default = "default_crumb_separator")]
+    separator: String,
+    #[serde(default)]
+    style: BreadcrumbStyle,
+    #[serde(default)]
+    width: Option<f32>,
+    #[serde(default)]
+    height: Option<f32>,
+    #[serde(default)]
+    bounds: WidgetBounds,
+    #[serde(default)]
+    layout: Layout,
+    #[serde(default)]
+    flex: f32,
+    #[serde(default)]
+    grid_col: Option<usize>,
+    #[serde(default)]
+    grid_row: Option<usize>,
+    #[serde(default = "default_span_one")]
+    col_span: usize,
+    #[serde(default = "default_span_one")]
+    row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+
+    /// Slots laid out by the last render, reused by hit testing.
+    #[serde(skip)]
+    crumb_slots: RefCell<Vec<CrumbSlot>>,
+  },
+
+  /// Application menu bar.
+  ///
+  /// Clicking a title emits `{id}:menu:{i}` and toggles that menu; the
+  /// open menu drops down as an overlay and clicking an item emits the
+  /// item's own action. While a menu is open, arrows move between items
+  /// and menus, Enter chooses, Escape closes.
+  MenuBar {
+    id: String,
+    menus: Vec<Menu>,
+    /// Index of the dropped-down menu.
+    #[serde(default)]
+    open: Option<usize>,
+    /// Keyboard highlight within the open menu.
+    #[serde(default)]
+    highlighted: Option<usize>,
+    #[serde(default)]
+    style: MenuBarStyle,
+    #[serde(default)]
+    width: Option<f32>,
+    #[serde(default)]
+    height: Option<f32>,
+    #[serde(default)]
+    bounds: WidgetBounds,
+    #[serde(default)]
+    layout: Layout,
+    #[serde(default)]
+    flex: f32,
+    #[serde(default)]
+    grid_col: Option<usize>,
+    #[serde(default)]
+    grid_row: Option<usize>,
+    #[serde(default = "default_span_one")]
+    col_span: usize,
+    #[serde(default = "default_span_one")]
+    row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
   },
 
   /// Radio button widget.
@@ -370,6 +975,18 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+    /// Extra margin around the bounds that still hits this control;
+    /// `None` uses `InteractionState::hit_padding`.
+    #[serde(default)]
+    hit_padding: Option<f32>,
   },
 
   /// Dropdown widget.
@@ -400,6 +1017,14 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
   },
 
   /// Horizontal spacer.
@@ -416,6 +1041,14 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
   },
 
   /// Visual divider for separating content.
@@ -430,6 +1063,12 @@ pub enum Widget {
     color: Color,
     #[serde(default = "default_divider_margin")]
     margin: f32,
+    /// Text centered on the line, which is broken around it. Only
+    /// horizontal dividers draw labels; vertical ones ignore it.
+    #[serde(default)]
+    label: Option<String>,
+    #[serde(default = "default_divider_label_size")]
+    label_size: f32,
     #[serde(default)]
     flex: f32,
     #[serde(default)]
@@ -440,6 +1079,14 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
   },
 
   /// Scrollbar for indicating scroll position.
@@ -463,6 +1110,14 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
   },
 
   /// Data grid for displaying tabular data.
@@ -478,6 +1133,22 @@ pub enum Widget {
     header_height: f32,
     #[serde(default)]
     row_height: f32,
+    /// Grow rows to fit their wrapped cell text, from `row_height` up to
+    /// `max_row_height`. Heights are measured by
+    /// [`crate::datagrid::fit_datagrid_rows`].
+    #[serde(default)]
+    auto_row_height: bool,
+    #[serde(default = "default_max_row_height")]
+    max_row_height: f32,
+    #[serde(skip)]
+    fitted_rows: Option<crate::datagrid::FittedRows>,
+    /// Data source version last seen by [`crate::datagrid::sync_datagrids`].
+    #[serde(skip)]
+    data_version: Option<u64>,
+    /// Height of all rows as of the last render, for hit testing the
+    /// scrollbar.
+    #[serde(skip)]
+    content_height: std::cell::Cell<f32>,
     #[serde(default)]
     striped: bool,
     #[serde(default)]
@@ -492,8 +1163,23 @@ pub enum Widget {
     sort_column: Option<usize>,
     #[serde(default)]
     sort_direction: Option<crate::data_source::SortDirection>,
+    /// Sort keys in priority order when sorting by several columns; takes
+    /// precedence over `sort_column` / `sort_direction` when not empty.
+    /// Updated by [`crate::datagrid::handle_header_sort`].
+    #[serde(default)]
+    sort_keys: Vec<(usize, crate::data_source::SortDirection)>,
     #[serde(default)]
     style: crate::datagrid::DataGridStyle,
+    /// Shown centered in the body when there are no rows.
+    #[serde(default)]
+    empty_message: Option<String>,
+    /// Texture name drawn above `empty_message`.
+    #[serde(default)]
+    empty_icon: Option<String>,
+    #[serde(default)]
+    scrollbar: crate::scrollbar::ScrollbarPolicy,
+    #[serde(default)]
+    scrollbar_mode: crate::scrollbar::ScrollbarMode,
     #[serde(default)]
     flex: f32,
     #[serde(default)]
@@ -504,6 +1190,14 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
   },
 
   /// KPI Card for analytics.
@@ -530,6 +1224,14 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
   },
 
   /// Text input field.
@@ -539,6 +1241,9 @@ pub enum Widget {
     #[serde(default)]
     placeholder: String,
     id: String,
+    /// Value-store key this input reads from and writes back to.
+    #[serde(default)]
+    bind: Option<String>,
     #[serde(default)]
     font_size: f32,
     #[serde(default)]
@@ -547,8 +1252,16 @@ pub enum Widget {
     bounds: WidgetBounds,
     #[serde(default)]
     validation: Option<Vec<ValidationRule>>,
+    /// Input mask such as `"(###) ###-####"`, see [`crate::mask`]. With a
+    /// mask, `value` holds only the typed characters.
+    #[serde(default)]
+    mask: Option<String>,
     #[serde(default)]
     style: TextInputStyle,
+    /// Style classes, resolved against
+    /// [`crate::style_context::StyleContext::classes`] when rendering.
+    #[serde(default)]
+    classes: Vec<String>,
     #[serde(default)]
     width: f32,
     #[serde(default)]
@@ -563,11 +1276,22 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
   },
 
   /// Numeric input with optional spinner buttons.
   NumberInput {
     id: String,
+    /// Value-store key this input reads from and writes back to.
+    #[serde(default)]
+    bind: Option<String>,
     #[serde(default)]
     value: f64,
     #[serde(default)]
@@ -578,8 +1302,14 @@ pub enum Widget {
     step: f64,
     #[serde(default)]
     precision: usize,
+    /// Grouping and decimal separators for the displayed value.
+    #[serde(default)]
+    number_format: NumberFormat,
     #[serde(default = "default_true")]
     show_spinner: bool,
+    /// Press-and-hold timing of the spinner arrows.
+    #[serde(default)]
+    repeat: SpinRepeatConfig,
     #[serde(default)]
     bounds: WidgetBounds,
     #[serde(default)]
@@ -600,6 +1330,14 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
   },
 
   /// Autocomplete dropdown input.
@@ -609,8 +1347,22 @@ pub enum Widget {
       value: String,
       #[serde(default)]
       placeholder: String,
+      /// Static suggestions, or the last results from `provider`.
       #[serde(default)]
       suggestions: Vec<String>,
+      /// Id of a [`SuggestionProvider`](crate::suggestion::SuggestionProvider)
+      /// that refills `suggestions` when the value changes.
+      #[serde(default)]
+      provider: Option<String>,
+      /// Delay after the last change before the provider is queried.
+      #[serde(default)]
+      debounce_ms: u64,
+      #[serde(skip)]
+      suggest_state: crate::suggestion::SuggestionState,
+      /// Highlight the characters of each suggestion that fuzzy-match the
+      /// typed value. Does not reorder; see [`crate::fuzzy::rank`].
+      #[serde(default)]
+      fuzzy: bool,
       #[serde(default = "default_max_visible")]
       max_visible: usize,
       #[serde(default)]
@@ -633,6 +1385,14 @@ pub enum Widget {
       col_span: usize,
       #[serde(default = "default_span_one")]
       row_span: usize,
+      #[serde(default)]
+      grid_area: Option<String>,
+      #[serde(default)]
+      align_self: AlignSelf,
+      #[serde(default)]
+      justify_self: AlignSelf,
+      #[serde(default)]
+      order: i32,
   },
 
   /// Date picker widget with calendar dropdown.
@@ -640,6 +1400,13 @@ pub enum Widget {
       id: String,
       #[serde(default)]
       value: Option<NaiveDate>,
+      /// Pick a `start`..=`end` range instead of a single `value`.
+      #[serde(default)]
+      range: bool,
+      #[serde(default)]
+      start: Option<NaiveDate>,
+      #[serde(default)]
+      end: Option<NaiveDate>,
       #[serde(default)]
       placeholder: String,
       #[serde(default)]
@@ -648,6 +1415,13 @@ pub enum Widget {
       max_date: Option<NaiveDate>,
       #[serde(default = "default_date_format")]
       format: String,
+      /// First day of the week in the calendar grid, e.g. `"Sun"`.
+      #[serde(default = "default_week_start")]
+      week_start: Weekday,
+      /// Localized day-name abbreviations, Monday first. Falls back to
+      /// English two-letter names when absent or not exactly seven.
+      #[serde(default)]
+      day_names: Option<Vec<String>>,
       #[serde(default)]
       bounds: WidgetBounds,
       #[serde(default)]
@@ -668,11 +1442,72 @@ pub enum Widget {
       col_span: usize,
       #[serde(default = "default_span_one")]
       row_span: usize,
+      #[serde(default)]
+      grid_area: Option<String>,
+      #[serde(default)]
+      align_self: AlignSelf,
+      #[serde(default)]
+      justify_self: AlignSelf,
+      #[serde(default)]
+      order: i32,
+  },
+
+  /// Time picker with an hour/minute selection overlay.
+  ///
+  /// Overlay cells emit `{id}:time:{HH:MM}`; times outside
+  /// `min_time`/`max_time` are drawn dimmed and not applied.
+  TimePicker {
+      id: String,
+      #[serde(default)]
+      value: Option<NaiveTime>,
+      #[serde(default)]
+      placeholder: String,
+      /// Spacing of the minute slots offered in the overlay.
+      #[serde(default = "default_step_minutes")]
+      step_minutes: u32,
+      /// 24-hour display; otherwise 12-hour with AM/PM.
+      #[serde(default = "default_true")]
+      use_24h: bool,
+      #[serde(default)]
+      min_time: Option<NaiveTime>,
+      #[serde(default)]
+      max_time: Option<NaiveTime>,
+      #[serde(default)]
+      bounds: WidgetBounds,
+      #[serde(default)]
+      style: TimePickerStyle,
+      #[serde(default)]
+      validation: Option<Vec<ValidationRule>>,
+      #[serde(default)]
+      width: f32,
+      #[serde(default)]
+      height: f32,
+      #[serde(default)]
+      flex: f32,
+      #[serde(default)]
+      grid_col: Option<usize>,
+      #[serde(default)]
+      grid_row: Option<usize>,
+      #[serde(default = "default_span_one")]
+      col_span: usize,
+      #[serde(default = "default_span_one")]
+      row_span: usize,
+      #[serde(default)]
+      grid_area: Option<String>,
+      #[serde(default)]
+      align_self: AlignSelf,
+      #[serde(default)]
+      justify_self: AlignSelf,
+      #[serde(default)]
+      order: i32,
   },
 
   /// Checkbox toggle.
   Checkbox {
     id: String,
+    /// Value-store key this input reads from and writes back to.
+    #[serde(default)]
+    bind: Option<String>,
     #[serde(default)]
     checked: bool,
     #[serde(default = "default_checkbox_size")]
@@ -691,11 +1526,26 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+    /// Extra margin around the bounds that still hits this control;
+    /// `None` uses `InteractionState::hit_padding`.
+    #[serde(default)]
+    hit_padding: Option<f32>,
   },
 
   /// Slider range input.
   Slider {
     id: String,
+    /// Value-store key this input reads from and writes back to.
+    #[serde(default)]
+    bind: Option<String>,
     #[serde(default)]
     value: f32,
     #[serde(default = "default_slider_min")]
@@ -718,6 +1568,18 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
+    /// Extra margin around the bounds that still hits this control;
+    /// `None` uses `InteractionState::hit_padding`.
+    #[serde(default)]
+    hit_padding: Option<f32>,
   },
   /// Image widget.
   Image {
@@ -741,6 +1603,14 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
   },
   Icon {
     id: String,
@@ -760,6 +1630,14 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
   },
 
 
@@ -791,11 +1669,28 @@ pub enum Widget {
     col_span: usize,
     #[serde(default = "default_span_one")]
     row_span: usize,
+    #[serde(default)]
+    grid_area: Option<String>,
+    #[serde(default)]
+    align_self: AlignSelf,
+    #[serde(default)]
+    justify_self: AlignSelf,
+    #[serde(default)]
+    order: i32,
 
     #[serde(skip)]
     // We wrap in RefCell to allow mutation during rendering (draw calls)
     backend: RefCell<GloomyPlotBackend>,
   },
+
+  /// Placeholder that splices in another RON file at load time.
+  ///
+  /// The path is resolved relative to the directory of the file that
+  /// contains the include. `load_ui` replaces every `Include` with the
+  /// loaded widget, so it never reaches layout or rendering.
+  Include {
+    path: String,
+  },
 }
 
 // Wrapper for PlotBackend to handle Clone/Debug/Serde
@@ -857,20 +1752,48 @@ pub struct TabStyle {
     pub border: Option<Border>,
     /// Optional shadow for the tab bar.
     pub shadow: Option<Shadow>,
+    /// Narrowest a horizontal tab may get. When the tabs don't fit at this
+    /// width the header scrolls and shows chevrons at both ends.
+    #[serde(default = "default_min_tab_width")]
+    pub min_tab_width: f32,
 }
 
 fn default_tab_background() -> Color { (0.15, 0.15, 0.18, 1.0) }
 fn default_tab_selected() -> Color { (0.3, 0.6, 1.0, 1.0) }
 fn default_tab_unselected() -> Color { (0.5, 0.5, 0.5, 1.0) }
+fn default_min_tab_width() -> f32 { 80.0 }
 
 impl Default for TabStyle {
+    /// Derived from the current theme, see [`crate::theme::set_current_theme`].
     fn default() -> Self {
+        themed_default(Self::fallback)
+    }
+}
+
+impl Themed for TabStyle {
+    fn themed(theme: &Theme) -> Self {
+        let c = &theme.colors;
+        TabStyle {
+            background: c.surface,
+            selected_color: c.primary,
+            unselected_color: c.text_secondary,
+            border: None,
+            shadow: None,
+            min_tab_width: default_min_tab_width(),
+        }
+    }
+}
+
+impl TabStyle {
+    /// Built-in dark style, used when no theme is current.
+    fn fallback() -> Self {
         TabStyle {
             background: default_tab_background(),
             selected_color: default_tab_selected(),
             unselected_color: default_tab_unselected(),
             border: None,
             shadow: None,
+            min_tab_width: default_min_tab_width(),
         }
     }
 }
@@ -887,6 +1810,7 @@ impl Widget {
             id: Some(id.into()),
             tabs,
             selected: 0,
+            header_scroll: 0.0,
             orientation,
             style,
             bounds: WidgetBounds::default(),
@@ -898,24 +1822,171 @@ impl Widget {
             grid_row: None,
             col_span: 1,
             row_span: 1,
+            grid_area: None,
+            align_self: Default::default(), justify_self: Default::default(),
+            order: 0,
             layout_cache: None,
             render_cache: RefCell::new(None),
         }
     }
+
+    /// A Button styled from `theme` rather than the current theme.
+    pub fn button_themed(theme: &Theme, text: impl Into<String>, action: impl Into<String>) -> Self {
+        Widget::Button {
+            text: text.into(),
+            action: action.into(),
+            bounds: WidgetBounds::default(),
+            style: ButtonStyle::themed(theme),
+            classes: Vec::new(),
+            width: None,
+            height: None,
+            disabled: false,
+            layout: Layout::default(),
+            flex: 0.0,
+            grid_col: None,
+            grid_row: None,
+            col_span: 1,
+            row_span: 1,
+            grid_area: None,
+            align_self: Default::default(), justify_self: Default::default(),
+            order: 0,
+            hit_padding: None,
+            font: None,
+            measured: None,
+        }
+    }
+
+    /// A TextInput styled from `theme` rather than the current theme.
+    pub fn text_input_themed(theme: &Theme, id: impl Into<String>, placeholder: impl Into<String>) -> Self {
+        Widget::TextInput {
+            value: String::new(),
+            placeholder: placeholder.into(),
+            id: id.into(),
+            bind: None,
+            font_size: 0.0,
+            text_align: TextAlign::default(),
+            bounds: WidgetBounds::default(),
+            validation: None,
+            mask: None,
+            style: TextInputStyle::themed(theme),
+            classes: Vec::new(),
+            width: 0.0,
+            height: 0.0,
+            flex: 0.0,
+            grid_col: None,
+            grid_row: None,
+            col_span: 1,
+            row_span: 1,
+            grid_area: None,
+            align_self: Default::default(), justify_self: Default::default(),
+            order: 0,
+        }
+    }
+}
+
+pub(crate) fn default_font_size() -> f32 {
+  16.0
+}
+
+fn default_color() -> Color {
+  (1.0, 1.0, 1.0, 1.0)
+}
+
+/// Accepts a bare value as well as `Some(..)`/`None`, so properties that
+/// became optional keep reading older files.
+fn deserialize_maybe<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+  D: serde::Deserializer<'de>,
+  T: Deserialize<'de>,
+{
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum MaybeRepr<T> {
+    Plain(T),
+    Optional(Option<T>),
+  }
+
+  Ok(match MaybeRepr::deserialize(deserializer)? {
+    MaybeRepr::Plain(value) => Some(value),
+    MaybeRepr::Optional(value) => value,
+  })
+}
+
+/// Size, color and font a Label with the given properties is drawn with
+/// under `inherited`. Unset properties are inherited, falling back to the
+/// defaults.
+pub(crate) fn label_text(size: Option<f32>, color: Option<Color>, font: &Option<String>, inherited: &TextDefaults) -> (f32, Color, Option<String>) {
+  let size = size.or(inherited.font_size).unwrap_or_else(default_font_size);
+  let color = color.or(inherited.color).unwrap_or_else(default_color);
+  (size, color, font.clone().or_else(|| inherited.font.clone()))
+}
+
+/// Returns `children` in paint order: ascending `z_index`, declaration
+/// order within equal values. Hit testing walks this in reverse.
+///
+/// Children that are already in order (the usual case, all `z_index: 0`)
+/// are iterated in place; only out-of-order siblings allocate a sorted list.
+pub fn z_ordered(children: &[Widget]) -> ZOrdered<'_> {
+  if children.windows(2).all(|pair| pair[0].z_index() <= pair[1].z_index()) {
+    return ZOrdered::Declared(children.iter());
+  }
+  let mut ordered: Vec<&Widget> = children.iter().collect();
+  // Stable sort keeps declaration order as the tiebreaker.
+  ordered.sort_by_key(|child| child.z_index());
+  ZOrdered::Sorted(ordered.into_iter())
+}
+
+/// Iterator returned by [`z_ordered`].
+pub enum ZOrdered<'a> {
+  Declared(std::slice::Iter<'a, Widget>),
+  Sorted(std::vec::IntoIter<&'a Widget>),
+}
+
+impl<'a> Iterator for ZOrdered<'a> {
+  type Item = &'a Widget;
+
+  fn next(&mut self) -> Option<&'a Widget> {
+    match self {
+      ZOrdered::Declared(iter) => iter.next(),
+      ZOrdered::Sorted(iter) => iter.next(),
+    }
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    match self {
+      ZOrdered::Declared(iter) => iter.size_hint(),
+      ZOrdered::Sorted(iter) => iter.size_hint(),
+    }
+  }
 }
 
-fn default_font_size() -> f32 {
-  16.0
+impl DoubleEndedIterator for ZOrdered<'_> {
+  fn next_back(&mut self) -> Option<Self::Item> {
+    match self {
+      ZOrdered::Declared(iter) => iter.next_back(),
+      ZOrdered::Sorted(iter) => iter.next_back(),
+    }
+  }
 }
 
-fn default_color() -> Color {
-  (1.0, 1.0, 1.0, 1.0)
+impl ExactSizeIterator for ZOrdered<'_> {}
+
+fn default_crumb_separator() -> String {
+  "/".to_string()
+}
+
+fn default_opacity() -> f32 {
+  1.0
 }
 
 fn default_spacer_size() -> f32 {
   10.0
 }
 
+fn default_max_row_height() -> f32 {
+  160.0
+}
+
 fn default_divider_thickness() -> f32 {
   1.0
 }
@@ -928,6 +1999,10 @@ fn default_divider_margin() -> f32 {
   8.0
 }
 
+fn default_divider_label_size() -> f32 {
+  14.0
+}
+
 fn default_checkbox_size() -> f32 {
   20.0
 }
@@ -966,54 +2041,141 @@ impl Widget {
     Widget::Container {
       id: None,
       scrollable: false,
+      overflow: Overflow::Visible,
+      clip_to_shape: false,
       bounds: WidgetBounds::default(),
       width: None,
       height: None,
       style: BoxStyle::default(),
-      padding: 0.0,
+      text_defaults: Default::default(),
+      opacity: 1.0,
+      z_index: 0,
+      padding: Padding::uniform(0.0),
+      focus_trap: false,
+      live_region: None,
+      static_cache: false,
+      scrollbar: Default::default(),
+      scrollbar_mode: Default::default(),
       layout: Layout::default(),
       flex: 0.0,
       grid_col: None,
       grid_row: None,
       col_span: 1,
       row_span: 1,
+      grid_area: None,
+      align_self: Default::default(), justify_self: Default::default(),
+      order: 0,
       children: Vec::new(),
       layout_cache: None,
       render_cache: RefCell::new(None),
+      generation: 0,
+    }
+  }
+
+  /// Returns the paint order of this widget among its siblings.
+  ///
+  /// Only containers carry a `z_index`; wrap other widgets in a container
+  /// to layer them.
+  pub fn z_index(&self) -> i32 {
+    match self {
+      Widget::Container { z_index, .. } => *z_index,
+      _ => 0,
     }
   }
 
+  /// Calls `f` on each subtree that can hold form widgets: container
+  /// children, all tab pages and ListView item widgets.
+  pub(crate) fn for_each_child(&self, f: &mut dyn FnMut(&Widget)) {
+      match self {
+          Widget::Container { children, .. } => children.iter().for_each(f),
+          Widget::Tab { tabs, .. } => tabs.iter().for_each(|tab| f(&tab.content)),
+          Widget::ListView { item_widgets, .. } => item_widgets.iter().for_each(f),
+          _ => {}
+      }
+  }
+
+  /// True while a ToggleSwitch in this subtree is still sliding its
+  /// thumb; with `on_tick` in gloomy-app, keep returning true from the
+  /// tick until this (and `InteractionState::has_active_ripples`) is false.
+  pub fn is_animating(&self) -> bool {
+      if let Widget::ToggleSwitch { style, toggled_at: Some(at), .. } = self {
+          if crate::ui::frame_clock() - at < style.animation_secs {
+              return true;
+          }
+      }
+      let mut animating = false;
+      self.for_each_child(&mut |child| animating = animating || child.is_animating());
+      animating
+  }
+
+  /// Mutable variant of [`Widget::for_each_child`].
+  pub(crate) fn for_each_child_mut(&mut self, f: &mut dyn FnMut(&mut Widget)) {
+      match self {
+          Widget::Container { children, .. } => children.iter_mut().for_each(f),
+          Widget::Tab { tabs, .. } => tabs.iter_mut().for_each(|tab| f(&mut tab.content)),
+          Widget::ListView { item_widgets, .. } => item_widgets.iter_mut().for_each(f),
+          _ => {}
+      }
+  }
+
   /// Explicitly invalidates the layout cache for this widget and its subtree.
   /// Should be called whenever the widget structure or style changes.
   pub fn mark_dirty(&mut self) {
+      if let Widget::Container { generation, .. } = self {
+          *generation += 1;
+      }
+      self.clear_caches();
+  }
+
+  fn clear_caches(&mut self) {
       if let Widget::Container { layout_cache, render_cache, children, .. } = self {
           *layout_cache = None;
           *render_cache.borrow_mut() = None;
           for child in children {
-              child.mark_dirty();
+              child.clear_caches();
           }
       }
       // For other widgets (leaves), there is no cache to clear, 
       // but if we add caching to leaf nodes later, we'd clear it here.
   }
 
+  /// How often [`Widget::mark_dirty`] was called on this container; 0
+  /// for other widgets.
+  pub(crate) fn generation(&self) -> u64 {
+      match self {
+          Widget::Container { generation, .. } => *generation,
+          _ => 0,
+      }
+  }
+
   /// Creates a new label widget.
   pub fn label(text: impl Into<String>) -> Self {
     Widget::Label {
       text: text.into(),
+      id: None,
+      bind: None,
       x: 0.0,
       y: 0.0,
       width: 0.0,
       height: 0.0,
-      size: 16.0,
-      color: (1.0, 1.0, 1.0, 1.0),
+      size: None,
+      color: None,
       text_align: TextAlign::Left,
       flex: 0.0,
       grid_col: None,
       grid_row: None,
       col_span: 1,
       row_span: 1,
+      grid_area: None,
+      align_self: Default::default(), justify_self: Default::default(),
+      order: 0,
       font: None,
+      tab_width: TabWidth::default(),
+      underline: false,
+      strikethrough: false,
+      selectable: false,
+      char_stops: RefCell::new(Vec::new()),
+      measured: None,
     }
   }
 
@@ -1026,6 +2188,7 @@ impl Widget {
           Widget::NumberInput { bounds, .. } => *bounds,
           Widget::Autocomplete { bounds, .. } => *bounds,
           Widget::DatePicker { bounds, .. } => *bounds,
+          Widget::TimePicker { bounds, .. } => *bounds,
           Widget::Checkbox { bounds, .. } => *bounds,
           Widget::Slider { bounds, .. } => *bounds,
           Widget::Image { bounds, .. } => *bounds,
@@ -1044,6 +2207,15 @@ impl Widget {
           Widget::ListView { bounds, .. } => *bounds,
           Widget::Tab { bounds, .. } => *bounds,
           Widget::Chart { bounds, .. } => *bounds,
+          Widget::VirtualFlow { bounds, .. } => *bounds,
+          Widget::Skeleton { bounds, .. } => *bounds,
+          Widget::ChipInput { bounds, .. } => *bounds,
+          Widget::Rating { bounds, .. } => *bounds,
+          Widget::MenuBar { bounds, .. } => *bounds,
+          Widget::Breadcrumb { bounds, .. } => *bounds,
+          Widget::Stepper { bounds, .. } => *bounds,
+          Widget::CircularProgress { bounds, .. } => *bounds,
+          Widget::Include { .. } => WidgetBounds::default(),
       }
   }
 
@@ -1055,6 +2227,8 @@ impl Widget {
           Widget::NumberInput { id, .. } => Some(id),
           Widget::Autocomplete { id, .. } => Some(id),
           Widget::DatePicker { id, .. } => Some(id),
+          Widget::TimePicker { id, .. } => Some(id),
+          Widget::ChipInput { id, .. } => Some(id),
           Widget::Checkbox { id, .. } => Some(id),
           Widget::Slider { id, .. } => Some(id),
           Widget::ToggleSwitch { id, .. } => Some(id),
@@ -1066,6 +2240,23 @@ impl Widget {
       }
   }
 
+  /// Returns the identifier used for by-id lookups.
+  ///
+  /// Containers use their optional `id`; interactive widgets use the same
+  /// id as [`Widget::get_focusable_id`].
+  pub fn id(&self) -> Option<&str> {
+      match self {
+          Widget::Container { id, .. } => id.as_deref(),
+          Widget::Stepper { id, .. } => Some(id),
+          Widget::Breadcrumb { id, .. } => Some(id),
+          Widget::MenuBar { id, .. } => Some(id),
+          Widget::Rating { id, .. } => Some(id),
+          Widget::VirtualFlow { id, .. } => Some(id),
+          Widget::DataGrid { id, .. } => id.as_deref(),
+          _ => self.get_focusable_id(),
+      }
+  }
+
   /// Validates the widget's current value against its rules.
   pub fn validate(&self) -> Vec<String> {
       let mut errors = Vec::new();
@@ -1110,6 +2301,24 @@ impl Widget {
                   }
               }
           }
+          Widget::TimePicker { value, min_time, max_time, validation, .. } => {
+              if let Some(val) = value {
+                  if let Some(min) = min_time {
+                      if *val < *min { errors.push(format!("Time must be after {}", min.format("%H:%M"))); }
+                  }
+                  if let Some(max) = max_time {
+                      if *val > *max { errors.push(format!("Time must be before {}", max.format("%H:%M"))); }
+                  }
+              }
+              if let Some(rules) = validation {
+                  let val_str = value.map(|t| t.format("%H:%M").to_string()).unwrap_or_default();
+                  for rule in rules {
+                       if let Err(e) = rule.validate(&val_str) {
+                           errors.push(e);
+                       }
+                  }
+              }
+          }
           _ => {}
       }
       errors
@@ -1142,7 +2351,32 @@ pub struct NumberInputStyle {
 }
 
 impl Default for NumberInputStyle {
+    /// Derived from the current theme, see [`crate::theme::set_current_theme`].
     fn default() -> Self {
+        themed_default(Self::fallback)
+    }
+}
+
+impl Themed for NumberInputStyle {
+    fn themed(theme: &Theme) -> Self {
+        let c = &theme.colors;
+        Self {
+            background: Some(c.surface),
+            background_focused: Some(c.surface),
+            border: Some(Border { width: 1.0, color: c.border, ..Default::default() }),
+            border_focused: Some(Border { width: 1.0, color: c.primary, ..Default::default() }),
+            text_color: c.text,
+            spinner_color: c.text_secondary,
+            spinner_hover_color: c.text,
+            corner_radius: 4.0,
+            font: None,
+        }
+    }
+}
+
+impl NumberInputStyle {
+    /// Built-in dark style, used when no theme is current.
+    fn fallback() -> Self {
         Self {
             background: Some((0.15, 0.15, 0.18, 1.0)),
             background_focused: Some((0.18, 0.18, 0.22, 1.0)),
@@ -1192,10 +2426,42 @@ pub struct AutocompleteStyle {
     pub dropdown_text_color: Color,
     #[serde(default = "default_highlight_color")]
     pub dropdown_highlight_color: Color,
+    /// Color of fuzzy-matched characters, which are also drawn bold.
+    #[serde(default = "default_match_color")]
+    pub match_color: Color,
 }
 
 impl Default for AutocompleteStyle {
+    /// Derived from the current theme, see [`crate::theme::set_current_theme`].
     fn default() -> Self {
+        themed_default(Self::fallback)
+    }
+}
+
+impl Themed for AutocompleteStyle {
+    fn themed(theme: &Theme) -> Self {
+        let c = &theme.colors;
+        Self {
+            background: Some(c.surface),
+            background_focused: Some(c.surface),
+            border: Some(Border { width: 1.0, color: c.border, ..Default::default() }),
+            border_focused: Some(Border { width: 1.0, color: c.primary, ..Default::default() }),
+            text_color: c.text,
+            cursor_color: c.primary,
+            corner_radius: 4.0,
+            font: None,
+            dropdown_background: Some(c.surface),
+            dropdown_border: Some(Border { width: 1.0, color: c.border, ..Default::default() }),
+            dropdown_text_color: c.text,
+            dropdown_highlight_color: c.hover,
+            match_color: c.primary,
+        }
+    }
+}
+
+impl AutocompleteStyle {
+    /// Built-in dark style, used when no theme is current.
+    fn fallback() -> Self {
         Self {
             background: Some((0.15, 0.15, 0.18, 1.0)),
             background_focused: Some((0.18, 0.18, 0.22, 1.0)),
@@ -1221,6 +2487,7 @@ impl Default for AutocompleteStyle {
             }),
             dropdown_text_color: (0.9, 0.9, 0.9, 1.0),
             dropdown_highlight_color: (0.25, 0.35, 0.5, 1.0),
+            match_color: default_match_color(),
         }
     }
 }
@@ -1259,10 +2526,45 @@ pub struct DatePickerStyle {
     pub day_hover_color: Color,
     #[serde(default = "default_month_header_color")]
     pub month_header_color: Color,
+    /// Band behind the days between a range's start and end.
+    #[serde(default = "default_range_color")]
+    pub range_color: Color,
 }
 
 impl Default for DatePickerStyle {
+    /// Derived from the current theme, see [`crate::theme::set_current_theme`].
     fn default() -> Self {
+        themed_default(Self::fallback)
+    }
+}
+
+impl Themed for DatePickerStyle {
+    fn themed(theme: &Theme) -> Self {
+        let c = &theme.colors;
+        Self {
+            background: Some(c.surface),
+            background_focused: Some(c.surface),
+            border: Some(Border { width: 1.0, color: c.border, ..Default::default() }),
+            border_focused: Some(Border { width: 1.0, color: c.primary, ..Default::default() }),
+            text_color: c.text,
+            placeholder_color: c.text_disabled,
+            corner_radius: 4.0,
+            font: None,
+            calendar_background: Some(c.surface),
+            calendar_border: Some(Border { width: 1.0, color: c.border, ..Default::default() }),
+            day_text_color: c.text,
+            selected_day_color: c.primary,
+            today_color: with_alpha(c.success, 0.5),
+            day_hover_color: c.hover,
+            month_header_color: c.text_secondary,
+            range_color: with_alpha(c.primary, 0.3),
+        }
+    }
+}
+
+impl DatePickerStyle {
+    /// Built-in dark style, used when no theme is current.
+    fn fallback() -> Self {
         Self {
             background: Some((0.15, 0.15, 0.18, 1.0)),
             background_focused: Some((0.18, 0.18, 0.22, 1.0)),
@@ -1291,6 +2593,100 @@ impl Default for DatePickerStyle {
             today_color: default_today_color(),
             day_hover_color: default_day_hover_color(),
             month_header_color: default_month_header_color(),
+            range_color: default_range_color(),
+        }
+    }
+}
+
+/// Style for TimePicker widget.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TimePickerStyle {
+    #[serde(default)]
+    pub background: Option<Color>,
+    #[serde(default)]
+    pub background_focused: Option<Color>,
+    #[serde(default)]
+    pub border: Option<Border>,
+    #[serde(default)]
+    pub border_focused: Option<Border>,
+    #[serde(default = "default_text_color")]
+    pub text_color: Color,
+    #[serde(default = "default_placeholder_color")]
+    pub placeholder_color: Color,
+    #[serde(default)]
+    pub corner_radius: f32,
+    #[serde(default)]
+    pub font: Option<String>,
+    // Overlay Styles
+    #[serde(default)]
+    pub panel_background: Option<Color>,
+    #[serde(default)]
+    pub panel_border: Option<Border>,
+    #[serde(default = "default_text_color")]
+    pub cell_text_color: Color,
+    #[serde(default = "default_highlight_color")]
+    pub selected_color: Color,
+    #[serde(default = "default_day_hover_color")]
+    pub hover_color: Color,
+}
+
+impl Default for TimePickerStyle {
+    /// Derived from the current theme, see [`crate::theme::set_current_theme`].
+    fn default() -> Self {
+        themed_default(Self::fallback)
+    }
+}
+
+impl Themed for TimePickerStyle {
+    fn themed(theme: &Theme) -> Self {
+        let c = &theme.colors;
+        Self {
+            background: Some(c.surface),
+            background_focused: Some(c.surface),
+            border: Some(Border { width: 1.0, color: c.border, ..Default::default() }),
+            border_focused: Some(Border { width: 1.0, color: c.primary, ..Default::default() }),
+            text_color: c.text,
+            placeholder_color: c.text_disabled,
+            corner_radius: 4.0,
+            font: None,
+            panel_background: Some(c.surface),
+            panel_border: Some(Border { width: 1.0, color: c.border, ..Default::default() }),
+            cell_text_color: c.text,
+            selected_color: c.primary,
+            hover_color: c.hover,
+        }
+    }
+}
+
+impl TimePickerStyle {
+    /// Built-in dark style, used when no theme is current.
+    fn fallback() -> Self {
+        Self {
+            background: Some((0.15, 0.15, 0.18, 1.0)),
+            background_focused: Some((0.18, 0.18, 0.22, 1.0)),
+            border: Some(Border {
+                width: 1.0,
+                color: (0.3, 0.3, 0.35, 1.0),
+                ..Default::default()
+            }),
+            border_focused: Some(Border {
+                width: 1.0,
+                color: (0.4, 0.6, 1.0, 1.0),
+                ..Default::default()
+            }),
+            text_color: (0.9, 0.9, 0.9, 1.0),
+            placeholder_color: default_placeholder_color(),
+            corner_radius: 4.0,
+            font: None,
+            panel_background: Some((0.12, 0.12, 0.15, 1.0)),
+            panel_border: Some(Border {
+                width: 1.0,
+                color: (0.25, 0.25, 0.3, 1.0),
+                ..Default::default()
+            }),
+            cell_text_color: (0.9, 0.9, 0.9, 1.0),
+            selected_color: (0.25, 0.5, 0.8, 1.0),
+            hover_color: default_day_hover_color(),
         }
     }
 }
@@ -1337,8 +2733,13 @@ fn default_spinner_color() -> Color { (0.5, 0.5, 0.55, 1.0) }
 fn default_spinner_hover_color() -> Color { (0.7, 0.7, 0.75, 1.0) }
 fn default_max_visible() -> usize { 5 }
 fn default_highlight_color() -> Color { (0.25, 0.35, 0.5, 1.0) }
+fn default_match_color() -> Color { (0.45, 0.65, 1.0, 1.0) }
 fn default_date_format() -> String { "%Y-%m-%d".to_string() }
+fn default_week_start() -> Weekday { Weekday::Mon }
+fn default_step_minutes() -> u32 { 15 }
+fn default_rating_max() -> usize { 5 }
 fn default_today_color() -> Color { (0.3, 0.5, 0.3, 1.0) }
+fn default_range_color() -> Color { (0.25, 0.5, 0.8, 0.3) }
 fn default_day_hover_color() -> Color { (0.2, 0.2, 0.25, 1.0) }
 fn default_month_header_color() -> Color { (0.8, 0.8, 0.85, 1.0) }
 
@@ -1357,6 +2758,10 @@ pub struct ToggleSwitchStyle {
     pub track_height: f32,
     #[serde(default)]
     pub width: f32,
+    /// Seconds the thumb takes to slide across after a click; 0 moves it
+    /// at once. Keep requesting redraws while `Widget::is_animating` holds.
+    #[serde(default)]
+    pub animation_secs: f32,
 }
 
 /// Style configuration for scrollbars.
@@ -1370,7 +2775,28 @@ pub struct ScrollbarStyle {
 }
 
 impl Default for ScrollbarStyle {
+    /// Derived from the current theme, see [`crate::theme::set_current_theme`].
     fn default() -> Self {
+        themed_default(Self::fallback)
+    }
+}
+
+impl Themed for ScrollbarStyle {
+    fn themed(theme: &Theme) -> Self {
+        let c = &theme.colors;
+        Self {
+            track_color: c.background,
+            thumb_color: c.border,
+            thumb_hover_color: c.text_disabled,
+            width: 12.0,
+            corner_radius: 6.0,
+        }
+    }
+}
+
+impl ScrollbarStyle {
+    /// Built-in dark style, used when no theme is current.
+    fn fallback() -> Self {
         Self {
             track_color: (0.1, 0.1, 0.1, 1.0),
             thumb_color: (0.3, 0.3, 0.3, 1.0),
@@ -1393,6 +2819,188 @@ pub struct ProgressBarStyle {
     pub corner_radius: f32,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CircularProgressStyle {
+    #[serde(default)]
+    pub track_color: Option<Color>,
+    #[serde(default)]
+    pub fill_color: Option<Color>,
+    #[serde(default)]
+    pub text_color: Option<Color>,
+    /// Ring thickness; 0 uses 4.
+    #[serde(default)]
+    pub thickness: f32,
+    /// Label size; 0 scales with the diameter.
+    #[serde(default)]
+    pub font_size: f32,
+}
+
+/// Outline of a `Skeleton` placeholder.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SkeletonShape {
+    /// A line of text: fully rounded ends, 12px tall by default.
+    #[default]
+    Line,
+    /// A block such as an image or card; corners use `corner_radius`.
+    Rect,
+    /// An avatar or icon; fits the smaller side.
+    Circle,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SkeletonStyle {
+    #[serde(default)]
+    pub color: Option<Color>,
+    #[serde(default)]
+    pub shimmer_color: Option<Color>,
+    /// Seconds per sweep; 0 uses 1.5.
+    #[serde(default)]
+    pub shimmer_period: f32,
+    /// Corner radius of `Rect` skeletons.
+    #[serde(default)]
+    pub corner_radius: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ChipInputStyle {
+    #[serde(default)]
+    pub background: Option<Color>,
+    #[serde(default)]
+    pub border: Option<Border>,
+    #[serde(default)]
+    pub border_focused: Option<Border>,
+    #[serde(default)]
+    pub text_color: Option<Color>,
+    #[serde(default)]
+    pub placeholder_color: Option<Color>,
+    #[serde(default)]
+    pub chip_background: Option<Color>,
+    #[serde(default)]
+    pub chip_text_color: Option<Color>,
+    #[serde(default)]
+    pub corner_radius: f32,
+    /// 0 uses 14.
+    #[serde(default)]
+    pub font_size: f32,
+}
+
+impl ChipInputStyle {
+    pub fn text_size(&self) -> f32 {
+        if self.font_size > 0.0 { self.font_size } else { 14.0 }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RatingStyle {
+    /// Star diameter; 0 uses 24.
+    #[serde(default)]
+    pub star_size: f32,
+    /// Gap between stars; 0 uses 4.
+    #[serde(default)]
+    pub spacing: f32,
+    #[serde(default)]
+    pub filled_color: Option<Color>,
+    #[serde(default)]
+    pub empty_color: Option<Color>,
+    /// Fill of the previewed stars while hovering.
+    #[serde(default)]
+    pub hover_color: Option<Color>,
+    /// Draw empty stars as outlines of this width instead of filled.
+    #[serde(default)]
+    pub outline_width: f32,
+}
+
+impl RatingStyle {
+    pub fn size(&self) -> f32 {
+        if self.star_size > 0.0 { self.star_size } else { 24.0 }
+    }
+
+    pub fn gap(&self) -> f32 {
+        if self.spacing > 0.0 { self.spacing } else { 4.0 }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MenuBarStyle {
+    #[serde(default)]
+    pub background: Option<Color>,
+    #[serde(default)]
+    pub text_color: Option<Color>,
+    /// Open title and highlighted/hovered item.
+    #[serde(default)]
+    pub highlight_color: Option<Color>,
+    #[serde(default)]
+    pub panel_background: Option<Color>,
+    #[serde(default)]
+    pub disabled_color: Option<Color>,
+    /// 0 uses 14.
+    #[serde(default)]
+    pub font_size: f32,
+    /// Width of a dropped-down panel; 0 uses 200.
+    #[serde(default)]
+    pub panel_width: f32,
+}
+
+impl MenuBarStyle {
+    pub fn text_size(&self) -> f32 {
+        if self.font_size > 0.0 { self.font_size } else { 14.0 }
+    }
+
+    pub fn panel_width_or_default(&self) -> f32 {
+        if self.panel_width > 0.0 { self.panel_width } else { 200.0 }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BreadcrumbStyle {
+    /// Segment text; the last segment uses `current_color`.
+    #[serde(default)]
+    pub text_color: Option<Color>,
+    #[serde(default)]
+    pub current_color: Option<Color>,
+    #[serde(default)]
+    pub separator_color: Option<Color>,
+    /// 0 uses 14.
+    #[serde(default)]
+    pub font_size: f32,
+}
+
+/// Colors for the Stepper widget.
+///
+/// Completed steps are filled with `completed_color`, the current step with
+/// `current_color`, and upcoming steps are drawn as an outline in
+/// `upcoming_color`. Connectors behind a completed step use
+/// `completed_color`, the rest `line_color`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct StepperStyle {
+    #[serde(default)]
+    pub completed_color: Option<Color>,
+    #[serde(default)]
+    pub current_color: Option<Color>,
+    #[serde(default)]
+    pub upcoming_color: Option<Color>,
+    #[serde(default)]
+    pub line_color: Option<Color>,
+    #[serde(default)]
+    pub text_color: Option<Color>,
+    /// Step circle radius; 0 uses 14.
+    #[serde(default)]
+    pub circle_radius: f32,
+    /// Label size; 0 uses 13.
+    #[serde(default)]
+    pub font_size: f32,
+}
+
+impl StepperStyle {
+    pub fn radius(&self) -> f32 {
+        if self.circle_radius > 0.0 { self.circle_radius } else { 14.0 }
+    }
+
+    pub fn text_size(&self) -> f32 {
+        if self.font_size > 0.0 { self.font_size } else { 13.0 }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct RadioButtonStyle {
     #[serde(default)]
@@ -0,0 +1,249 @@
+//! Id → path index for fast widget lookup.
+//!
+//! Resolving ids with a recursive walk is O(n) per lookup. Apps that
+//! resolve many ids per frame can build a [`WidgetIndex`] once (after
+//! layout, or whenever the tree is rebuilt) and look widgets up by the
+//! stored child path instead.
+//!
+//! Paths step through `Container` children, `Tab` pages (all pages,
+//! not only the selected one) and `ListView` item widgets. Calling
+//! [`Widget::mark_dirty`] on the root marks the indexes built from it as
+//! stale; lookups on a stale index, or on a path that no longer leads to
+//! the requested id, fall back to the recursive search.
+
+use crate::widget::Widget;
+use std::collections::HashMap;
+
+/// Maps widget ids to their child-index path from the root.
+#[derive(Debug, Clone, Default)]
+pub struct WidgetIndex {
+    paths: HashMap<String, Vec<usize>>,
+    /// The root's [`Widget::generation`] when the index was built.
+    generation: u64,
+}
+
+impl WidgetIndex {
+    /// Builds an index for the given tree.
+    ///
+    /// When ids are duplicated, the first widget in depth-first order wins,
+    /// matching the recursive search.
+    pub fn build(root: &Widget) -> Self {
+        let mut index = Self::default();
+        index.rebuild(root);
+        index
+    }
+
+    /// Rebuilds the index in place.
+    pub fn rebuild(&mut self, root: &Widget) {
+        self.paths.clear();
+        let mut path = Vec::new();
+        collect_paths(root, &mut path, &mut self.paths);
+        self.generation = root.generation();
+    }
+
+    /// Returns true if `root` was marked dirty since the index was built.
+    pub fn is_stale(&self, root: &Widget) -> bool {
+        self.generation != root.generation()
+    }
+
+    /// Number of indexed ids.
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Returns true if no ids are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Returns the stored child path for `id`.
+    pub fn path(&self, id: &str) -> Option<&[usize]> {
+        self.paths.get(id).map(|p| p.as_slice())
+    }
+
+    /// Looks up a widget by id.
+    pub fn get<'a>(&self, root: &'a Widget, id: &str) -> Option<&'a Widget> {
+        if let Some(widget) = self.resolve(root, id) {
+            return Some(widget);
+        }
+        find_widget(root, id)
+    }
+
+    /// Looks up a widget by id for mutation.
+    pub fn get_mut<'a>(
+        &self,
+        root: &'a mut Widget,
+        id: &str,
+    ) -> Option<&'a mut Widget> {
+        if self.resolve(root, id).is_none() {
+            return find_widget_by_id_mut(root, id);
+        }
+        let mut current = root;
+        for &step in self.paths.get(id)? {
+            current = child_at_mut(current, step)?;
+        }
+        Some(current)
+    }
+
+    /// Follows the stored path, returning the widget only if it still
+    /// carries the requested id.
+    fn resolve<'a>(&self, root: &'a Widget, id: &str) -> Option<&'a Widget> {
+        if self.is_stale(root) {
+            return None;
+        }
+        let mut current = root;
+        for &step in self.paths.get(id)? {
+            current = child_at(current, step)?;
+        }
+        (current.id() == Some(id)).then_some(current)
+    }
+}
+
+/// Recursively searches the tree for a widget with the given id.
+///
+/// This is the fallback used for trees without an index.
+pub fn find_widget<'a>(root: &'a Widget, id: &str) -> Option<&'a Widget> {
+    if root.id() == Some(id) {
+        return Some(root);
+    }
+    let mut i = 0;
+    while let Some(child) = child_at(root, i) {
+        if let Some(found) = find_widget(child, id) {
+            return Some(found);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Mutable variant of [`find_widget`].
+pub fn find_widget_by_id_mut<'a>(
+    root: &'a mut Widget,
+    id: &str,
+) -> Option<&'a mut Widget> {
+    if root.id() == Some(id) {
+        return Some(root);
+    }
+    match root {
+        Widget::Container { children, .. } => children
+            .iter_mut()
+            .find_map(|child| find_widget_by_id_mut(child, id)),
+        Widget::Tab { tabs, .. } => tabs
+            .iter_mut()
+            .find_map(|tab| find_widget_by_id_mut(&mut tab.content, id)),
+        Widget::ListView { item_widgets, .. } => item_widgets
+            .iter_mut()
+            .find_map(|row| find_widget_by_id_mut(row, id)),
+        _ => None,
+    }
+}
+
+fn child_at(widget: &Widget, index: usize) -> Option<&Widget> {
+    match widget {
+        Widget::Container { children, .. } => children.get(index),
+        Widget::Tab { tabs, .. } => tabs.get(index).map(|t| t.content.as_ref()),
+        Widget::ListView { item_widgets, .. } => item_widgets.get(index),
+        _ => None,
+    }
+}
+
+fn child_at_mut(widget: &mut Widget, index: usize) -> Option<&mut Widget> {
+    match widget {
+        Widget::Container { children, .. } => children.get_mut(index),
+        Widget::Tab { tabs, .. } => {
+            tabs.get_mut(index).map(|t| t.content.as_mut())
+        }
+        Widget::ListView { item_widgets, .. } => item_widgets.get_mut(index),
+        _ => None,
+    }
+}
+
+fn collect_paths(
+    widget: &Widget,
+    path: &mut Vec<usize>,
+    paths: &mut HashMap<String, Vec<usize>>,
+) {
+    if let Some(id) = widget.id() {
+        paths.entry(id.to_string()).or_insert_with(|| path.clone());
+    }
+    let mut i = 0;
+    while let Some(child) = child_at(widget, i) {
+        path.push(i);
+        collect_paths(child, path, paths);
+        path.pop();
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widget::{Orientation, TabItem, TabStyle};
+
+    fn tree() -> Widget {
+        let mut root = Widget::container();
+        let mut panel = Widget::container();
+        if let Widget::Container { id, children, .. } = &mut panel {
+            *id = Some("panel".into());
+            children.push(Widget::tab(
+                "tabs",
+                vec![
+                    TabItem { title: "A".into(), content: Box::new(Widget::label("a")) },
+                    TabItem {
+                        title: "B".into(),
+                        content: Box::new({
+                            let mut inner = Widget::container();
+                            if let Widget::Container { id, .. } = &mut inner {
+                                *id = Some("inner".into());
+                            }
+                            inner
+                        }),
+                    },
+                ],
+                Orientation::Horizontal,
+                TabStyle::default(),
+            ));
+        }
+        if let Widget::Container { children, .. } = &mut root {
+            children.push(Widget::label("title"));
+            children.push(panel);
+        }
+        root
+    }
+
+    #[test]
+    fn test_index_paths() {
+        let root = tree();
+        let index = WidgetIndex::build(&root);
+        assert_eq!(index.path("panel"), Some(&[1][..]));
+        assert_eq!(index.path("tabs"), Some(&[1, 0][..]));
+        assert_eq!(index.path("inner"), Some(&[1, 0, 1][..]));
+        assert!(index.get(&root, "inner").is_some());
+        assert!(index.get(&root, "missing").is_none());
+    }
+
+    #[test]
+    fn test_get_mut_and_fallback_after_change() {
+        let mut root = tree();
+        let index = WidgetIndex::build(&root);
+
+        // Insert a sibling in front so the stored paths are outdated.
+        if let Widget::Container { children, .. } = &mut root {
+            children.insert(0, Widget::label("new"));
+        }
+        let other = tree();
+        let other_index = WidgetIndex::build(&other);
+        assert!(!index.is_stale(&root));
+        root.mark_dirty();
+        assert!(index.is_stale(&root));
+        // Marking one tree dirty leaves indexes of other trees alone.
+        assert!(!other_index.is_stale(&other));
+
+        // Lookup still succeeds through the recursive fallback.
+        let panel = index.get_mut(&mut root, "panel").unwrap();
+        assert_eq!(panel.id(), Some("panel"));
+
+        let index = WidgetIndex::build(&root);
+        assert_eq!(index.path("panel"), Some(&[2][..]));
+    }
+}
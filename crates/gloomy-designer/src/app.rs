@@ -1,10 +1,26 @@
 //! Application state and logic for the designer.
 
 use crate::model::{DesignDocument, WidgetPath};
+use crate::panels::canvas::{snap_rect, Guide, SnapSettings, Snapped};
 use crate::panels::palette::{WidgetType, create_palette_item};
 use crate::panels::inspector::create_property_widgets;
-use gloomy_core::layout::{Direction, Layout};
+use gloomy_core::layout::{Direction, Layout, Padding};
+use gloomy_core::layout_engine::set_pos;
 use gloomy_core::widget::{Widget, WidgetBounds};
+use gloomy_core::Vec2;
+use std::collections::HashMap;
+
+/// Child indices from the designer root to the canvas container.
+const CANVAS_PATH: [usize; 2] = [1, 0];
+
+/// A design widget being dragged on the canvas.
+#[derive(Debug, Clone, Copy)]
+pub struct CanvasDrag {
+    /// Index in design.root.children
+    pub index: usize,
+    /// Pointer position relative to the widget's top-left corner
+    pub grab: Vec2,
+}
 
 /// Main application state for the designer.
 pub struct DesignerApp {
@@ -19,6 +35,15 @@ pub struct DesignerApp {
     
     /// Designer UI root
     pub ui_root: Widget,
+    
+    /// Grid and alignment snapping for canvas drags
+    pub snap: SnapSettings,
+    
+    /// Drag in progress on the canvas
+    pub drag: Option<CanvasDrag>,
+    
+    /// Alignment guides to draw for the current drag (canvas-local)
+    pub guides: Vec<Guide>,
 }
 
 impl DesignerApp {
@@ -31,6 +56,9 @@ impl DesignerApp {
             selected_index: None,
             clipboard: None,
             ui_root,
+            snap: SnapSettings::default(),
+            drag: None,
+            guides: Vec::new(),
         }
     }
     
@@ -70,6 +98,13 @@ impl DesignerApp {
     /// Adds a widget to the current design.
     fn add_widget_to_design(&mut self, widget: Widget) {
         if let Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None), children, .. } = &mut self.design.root {
+            // Stack below the last widget; the user drags it from there.
+            let y = children.last().map_or(0.0, |last| {
+                let b = last.bounds();
+                b.y + b.height + self.snap.grid_size
+            });
+            let mut widget = widget;
+            set_pos(&mut widget, 0.0, y);
             children.push(widget);
             // Select the newly added widget
             self.selected_index = Some(children.len() - 1);
@@ -88,6 +123,72 @@ impl DesignerApp {
         }
     }
     
+    /// Converts a window position to canvas-local coordinates.
+    pub fn canvas_point(&self, mouse: Vec2, scroll_offsets: &HashMap<String, Vec2>) -> Vec2 {
+        let scroll = scroll_offsets.get("canvas").copied().unwrap_or(Vec2::ZERO);
+        mouse - self.canvas_origin() + scroll
+    }
+    
+    /// Window position of the canvas' top-left corner after the last
+    /// layout pass.
+    pub fn canvas_origin(&self) -> Vec2 {
+        let mut node = &self.ui_root;
+        let mut origin = Vec2::new(node.bounds().x, node.bounds().y);
+        for index in CANVAS_PATH {
+            let Widget::Container { children, .. } = node else { break };
+            let Some(child) = children.get(index) else { break };
+            node = child;
+            origin += Vec2::new(node.bounds().x, node.bounds().y);
+        }
+        origin
+    }
+    
+    /// Starts dragging design widget `index`, grabbed at canvas-local `point`.
+    pub fn begin_drag(&mut self, index: usize, point: Vec2) {
+        if let Widget::Container { children, .. } = &self.design.root {
+            if let Some(widget) = children.get(index) {
+                let b = widget.bounds();
+                self.drag = Some(CanvasDrag { index, grab: point - Vec2::new(b.x, b.y) });
+            }
+        }
+    }
+    
+    /// Moves the dragged widget so its grab point follows `point`.
+    ///
+    /// With `snapping` off (the snap modifier is held) the widget follows
+    /// the pointer exactly. Returns true if a widget moved.
+    pub fn drag_to(&mut self, point: Vec2, snapping: bool) -> bool {
+        let Some(drag) = self.drag else { return false };
+        let Widget::Container { children, .. } = &mut self.design.root else { return false };
+        let Some(mut rect) = children.get(drag.index).map(Widget::bounds) else { return false };
+        
+        let target = point - drag.grab;
+        rect.x = target.x;
+        rect.y = target.y;
+        let snapped = if snapping {
+            let siblings: Vec<WidgetBounds> = children.iter().enumerate()
+                .filter(|(i, _)| *i != drag.index)
+                .map(|(_, w)| w.bounds())
+                .collect();
+            snap_rect(rect, &siblings, &self.snap)
+        } else {
+            Snapped::unsnapped(target)
+        };
+        
+        set_pos(&mut children[drag.index], snapped.position.x, snapped.position.y);
+        self.guides = snapped.guides;
+        self.update_canvas_children();
+        true
+    }
+    
+    /// Drops the dragged widget and hides the guides.
+    pub fn end_drag(&mut self) {
+        if self.drag.take().is_some() {
+            self.guides.clear();
+            self.refresh_ui();
+        }
+    }
+    
     /// Returns the currently selected widget.
     fn get_selected_widget(&self) -> Option<&Widget> {
         if let Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None), children, .. } = &self.design.root {
@@ -142,6 +243,14 @@ impl DesignerApp {
         selected_index: Option<usize>
     ) -> Widget {
         let is_selected = selected_index == Some(index);
+        // The canvas places wrappers absolutely, at the widget's position.
+        let b = widget.bounds();
+        let wrapper_bounds = WidgetBounds {
+            x: b.x - 4.0,
+            y: b.y - 4.0,
+            width: if b.width > 0.0 { b.width + 8.0 } else { 200.0 },
+            height: if b.height > 0.0 { b.height + 8.0 } else { 40.0 },
+        };
         let bg_color = if is_selected {
             Some((0.4, 0.4, 0.4, 0.3)) // Gray highlight
         } else {
@@ -149,9 +258,10 @@ impl DesignerApp {
         };
         
         Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
             id: Some(format!("wrapper_{}", index)),
             scrollable: false,
-            bounds: WidgetBounds::default(),
+            bounds: wrapper_bounds,
             width: None,
             height: None,
             background: bg_color,
@@ -168,12 +278,24 @@ impl DesignerApp {
             shadow: None,
             corner_radius: 4.0,
             corner_radii: None,
-            padding: 4.0,
+            padding: Padding::uniform(4.0),
+            focus_trap: false,
+            live_region: None,
+            static_cache: false,
+            scrollbar: Default::default(),
+            scrollbar_mode: Default::default(),
+            z_index: 0,
+            clip_to_shape: false,
+            overflow: Default::default(),
+            opacity: 1.0,
             flex: 0.0,
             grid_col: None,
             grid_row: None,
             col_span: 1,
             row_span: 1,
+            grid_area: None,
+            align_self: Default::default(), justify_self: Default::default(),
+            order: 0,
             layout: Layout::default(),
             children: vec![
                 widget,
@@ -198,8 +320,15 @@ impl DesignerApp {
                     grid_row: None,
                     col_span: 1,
                     row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
+                    hit_padding: None,
+                    measured: None,
+                    classes: Vec::new(),
                 },
             ],
+            text_defaults: Default::default(),
         }
     }
 
@@ -258,6 +387,7 @@ impl DesignerApp {
     /// Creates the designer's own UI layout.
     fn create_designer_ui() -> Widget {
         Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
             id: Some("designer_root".to_string()),
             scrollable: false,
             bounds: WidgetBounds {
@@ -272,12 +402,24 @@ impl DesignerApp {
             shadow: None,
             corner_radius: 0.0,
             corner_radii: None,
-            padding: 0.0,
+            padding: Padding::uniform(0.0),
+            focus_trap: false,
+            live_region: None,
+            static_cache: false,
+            scrollbar: Default::default(),
+            scrollbar_mode: Default::default(),
+            z_index: 0,
+            clip_to_shape: false,
+            overflow: Default::default(),
+            opacity: 1.0,
             flex: 0.0,
             grid_col: None,
             grid_row: None,
             col_span: 1,
             row_span: 1,
+            grid_area: None,
+            align_self: Default::default(), justify_self: Default::default(),
+            order: 0,
             layout: Layout {
                 direction: Direction::Row,
                 ..Default::default()
@@ -287,6 +429,7 @@ impl DesignerApp {
                 Self::create_center_panel(),
                 Self::create_inspector_panel(),
             ],
+            text_defaults: Default::default(),
         }
     }
     
@@ -301,6 +444,7 @@ impl DesignerApp {
         }
         
         Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
             id: Some("palette".to_string()),
             scrollable: true,
             bounds: WidgetBounds::default(),
@@ -312,23 +456,37 @@ impl DesignerApp {
             shadow: None,
             corner_radius: 0.0,
             corner_radii: None,
-            padding: 8.0,
+            padding: Padding::uniform(8.0),
+            focus_trap: false,
+            live_region: None,
+            static_cache: false,
+            scrollbar: Default::default(),
+            scrollbar_mode: Default::default(),
+            z_index: 0,
+            clip_to_shape: false,
+            overflow: Default::default(),
+            opacity: 1.0,
             flex: 0.0,
             grid_col: None,
             grid_row: None,
             col_span: 1,
             row_span: 1,
+            grid_area: None,
+            align_self: Default::default(), justify_self: Default::default(),
+            order: 0,
             layout: Layout {
                 direction: Direction::Column,
                 spacing: 4.0,
                 ..Default::default()
             },
             children: palette_children,
+            text_defaults: Default::default(),
         }
     }
     
     fn create_center_panel() -> Widget {
         Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
             id: Some("center".to_string()),
             scrollable: false,
             bounds: WidgetBounds::default(),
@@ -340,18 +498,31 @@ impl DesignerApp {
             shadow: None,
             corner_radius: 0.0,
             corner_radii: None,
-            padding: 0.0,
+            padding: Padding::uniform(0.0),
+            focus_trap: false,
+            live_region: None,
+            static_cache: false,
+            scrollbar: Default::default(),
+            scrollbar_mode: Default::default(),
+            z_index: 0,
+            clip_to_shape: false,
+            overflow: Default::default(),
+            opacity: 1.0,
             flex: 1.0,
             grid_col: None,
             grid_row: None,
             col_span: 1,
             row_span: 1,
+            grid_area: None,
+            align_self: Default::default(), justify_self: Default::default(),
+            order: 0,
             layout: Layout {
                 direction: Direction::Column,
                 ..Default::default()
             },
             children: vec![
                 Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
                     id: Some("canvas".to_string()),
                     scrollable: true,
                     bounds: WidgetBounds::default(),
@@ -363,22 +534,36 @@ impl DesignerApp {
                     shadow: None,
                     corner_radius: 0.0,
                     corner_radii: None,
-                    padding: 16.0,
+                    padding: Padding::uniform(16.0),
+                    focus_trap: false,
+                    live_region: None,
+                    static_cache: false,
+                    scrollbar: Default::default(),
+                    scrollbar_mode: Default::default(),
+                    z_index: 0,
+                    clip_to_shape: false,
+                    overflow: Default::default(),
+                    opacity: 1.0,
                     flex: 1.0,
                     grid_col: None,
                     grid_row: None,
                     col_span: 1,
                     row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
                     layout: Layout {
-                        direction: Direction::Column,
-                        spacing: 8.0,
+                        // Widgets are dragged freely; see begin_drag.
+                        direction: Direction::None,
                         ..Default::default()
                     },
                     children: vec![
                         Widget::label("Click widgets in palette to add"),
                     ],
+                    text_defaults: Default::default(),
                 },
                 Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
                     id: Some("tree".to_string()),
                     scrollable: true,
                     bounds: WidgetBounds::default(),
@@ -390,12 +575,24 @@ impl DesignerApp {
                     shadow: None,
                     corner_radius: 0.0,
                     corner_radii: None,
-                    padding: 8.0,
+                    padding: Padding::uniform(8.0),
+                    focus_trap: false,
+                    live_region: None,
+                    static_cache: false,
+                    scrollbar: Default::default(),
+                    scrollbar_mode: Default::default(),
+                    z_index: 0,
+                    clip_to_shape: false,
+                    overflow: Default::default(),
+                    opacity: 1.0,
                     flex: 0.0,
                     grid_col: None,
                     grid_row: None,
                     col_span: 1,
                     row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
                     layout: Layout {
                         direction: Direction::Column,
                         spacing: 2.0,
@@ -405,13 +602,16 @@ impl DesignerApp {
                         Widget::label("WIDGET TREE"),
                         Widget::label("▼ Container (root) [0]"),
                     ],
+                    text_defaults: Default::default(),
                 },
             ],
+            text_defaults: Default::default(),
         }
     }
     
     fn create_inspector_panel() -> Widget {
         Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
             id: Some("inspector".to_string()),
             scrollable: true,
             bounds: WidgetBounds::default(),
@@ -423,12 +623,24 @@ impl DesignerApp {
             shadow: None,
             corner_radius: 0.0,
             corner_radii: None,
-            padding: 8.0,
+            padding: Padding::uniform(8.0),
+            focus_trap: false,
+            live_region: None,
+            static_cache: false,
+            scrollbar: Default::default(),
+            scrollbar_mode: Default::default(),
+            z_index: 0,
+            clip_to_shape: false,
+            overflow: Default::default(),
+            opacity: 1.0,
             flex: 0.0,
             grid_col: None,
             grid_row: None,
             col_span: 1,
             row_span: 1,
+            grid_area: None,
+            align_self: Default::default(), justify_self: Default::default(),
+            order: 0,
             layout: Layout {
                 direction: Direction::Column,
                 spacing: 8.0,
@@ -439,6 +651,7 @@ impl DesignerApp {
                 Widget::label("─────────────"),
                 Widget::label("No selection"),
             ],
+            text_defaults: Default::default(),
         }
     }
 }
@@ -11,6 +11,7 @@ mod panels;
 mod ron_export;
 
 use app::DesignerApp;
+use panels::canvas::draw_guides;
 use gloomy_app::GloomyApp;
 use gloomy_core::interaction::InteractionState;
 use gloomy_core::layout_engine::compute_layout;
@@ -31,6 +32,7 @@ fn main() -> anyhow::Result<()> {
     let state_move = state.clone();
     let state_input = state.clone();
     let state_draw = state.clone();
+    let state_mods = state.clone();
 
     GloomyApp::new()
         .on_cursor_move(move |win, x, y| {
@@ -38,6 +40,13 @@ fn main() -> anyhow::Result<()> {
             let pos = Vec2::new(x as f32, y as f32);
             s.interaction.update_mouse(pos);
             
+            // Drag the grabbed widget; Alt temporarily disables snapping
+            if s.interaction.is_pressed && s.app.drag.is_some() {
+                let point = s.app.canvas_point(pos, &s.interaction.scroll_offsets);
+                let snapping = !s.snap_bypass;
+                s.app.drag_to(point, snapping);
+            }
+            
             // Perform hit test for hover state
             let scroll_offsets = s.interaction.scroll_offsets.clone();
             if let Some(res) = hit_test(
@@ -66,6 +75,12 @@ fn main() -> anyhow::Result<()> {
                 ) {
                     let action = res.action.to_string();
                     log::debug!("Hit: {}", action);
+                    
+                    // Pressing a canvas widget also grabs it for dragging
+                    if let Some(index) = action.strip_prefix("select_").and_then(|i| i.parse().ok()) {
+                        let point = s.app.canvas_point(mouse_pos, &scroll_offsets);
+                        s.app.begin_drag(index, point);
+                    }
                     s.interaction.set_active(Some(action.clone()));
                     s.interaction.set_clicked(Some(action));
                 } else {
@@ -91,6 +106,7 @@ fn main() -> anyhow::Result<()> {
                     }
                 }
                 
+                s.app.end_drag();
                 s.interaction.set_pressed(false);
                 s.interaction.set_active(None);
                 s.interaction.set_clicked(None);
@@ -98,6 +114,9 @@ fn main() -> anyhow::Result<()> {
             // Trigger redraw for click state changes
             win.window.request_redraw();
         })
+        .on_modifiers_changed(move |_win, modifiers| {
+            state_mods.borrow_mut().snap_bypass = modifiers.state().alt_key();
+        })
         .on_draw(move |win, ctx| {
             let mut s = state_draw.borrow_mut();
             
@@ -125,6 +144,10 @@ fn main() -> anyhow::Result<()> {
                 ctx.queue,
                 Some(&s.interaction),
             );
+            
+            let origin = s.app.canvas_origin()
+                - s.interaction.scroll_offsets.get("canvas").copied().unwrap_or(Vec2::ZERO);
+            draw_guides(win.renderer.primitives(), &s.app.guides, origin);
         })
         .run()
 }
@@ -133,6 +156,8 @@ fn main() -> anyhow::Result<()> {
 struct DesignerState {
     app: DesignerApp,
     interaction: InteractionState,
+    /// Alt is held: drag without snapping
+    snap_bypass: bool,
 }
 
 impl DesignerState {
@@ -140,6 +165,7 @@ impl DesignerState {
         Self {
             app: DesignerApp::new(),
             interaction: InteractionState::default(),
+            snap_bypass: false,
         }
     }
 }
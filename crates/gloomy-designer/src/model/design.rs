@@ -1,5 +1,6 @@
 //! Design document model.
 
+use gloomy_core::layout::Direction;
 use gloomy_core::widget::Widget;
 
 /// A design document being edited.
@@ -22,8 +23,14 @@ pub struct DesignMetadata {
 impl DesignDocument {
     /// Creates a new empty design document.
     pub fn new() -> Self {
+        let mut root = Widget::container();
+        // Widgets are placed by dragging them on the canvas, so the root
+        // must not lay them out again.
+        if let Widget::Container { layout, .. } = &mut root {
+            layout.direction = Direction::None;
+        }
         Self {
-            root: Widget::container(),
+            root,
             metadata: DesignMetadata {
                 name: "Untitled".to_string(),
                 version: "1.0".to_string(),
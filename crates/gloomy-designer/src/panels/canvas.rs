@@ -1,3 +1,214 @@
 //! Design canvas panel.
 //!
 //! Renders the current design and handles widget selection/positioning.
+//!
+//! Dragging a widget snaps it to the grid, or to a sibling when one of
+//! its edges or its center comes within `threshold` of the same line on
+//! that sibling. Sibling snaps win over the grid and leave guide lines
+//! behind for the canvas to draw. All coordinates are canvas-local.
+
+use gloomy_core::primitives::PrimitiveRenderer;
+use gloomy_core::widget::WidgetBounds;
+use gloomy_core::{Vec2, Vec4};
+
+/// Color of alignment guide lines.
+const GUIDE_COLOR: Vec4 = Vec4::new(0.95, 0.35, 0.75, 0.9);
+
+/// Snapping configuration for canvas drags.
+#[derive(Debug, Clone, Copy)]
+pub struct SnapSettings {
+    /// Grid cell size in pixels. 0 disables the grid.
+    pub grid_size: f32,
+    /// Maximum distance, in pixels, at which a sibling edge or center
+    /// pulls the dragged widget into line.
+    pub threshold: f32,
+    /// Whether to snap to siblings and show alignment guides.
+    pub guides: bool,
+}
+
+impl Default for SnapSettings {
+    fn default() -> Self {
+        Self {
+            grid_size: 8.0,
+            threshold: 6.0,
+            guides: true,
+        }
+    }
+}
+
+/// An alignment guide, spanning the dragged widget and the siblings it
+/// lines up with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Guide {
+    Vertical { x: f32, top: f32, bottom: f32 },
+    Horizontal { y: f32, left: f32, right: f32 },
+}
+
+/// Result of snapping a dragged widget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapped {
+    /// Where the widget's top-left corner should go.
+    pub position: Vec2,
+    pub guides: Vec<Guide>,
+}
+
+impl Snapped {
+    /// Leaves the widget where the pointer put it, e.g. while the snap
+    /// modifier is held.
+    pub fn unsnapped(position: Vec2) -> Self {
+        Self { position, guides: Vec::new() }
+    }
+}
+
+/// Snaps `rect` (the dragged widget at its unsnapped position) against
+/// the grid and `siblings`.
+pub fn snap_rect(rect: WidgetBounds, siblings: &[WidgetBounds], settings: &SnapSettings) -> Snapped {
+    let mut guides = Vec::new();
+    let x = snap_axis(
+        rect.x,
+        rect.width,
+        siblings.iter().map(|s| (s.x, s.width)),
+        settings,
+    );
+    let y = snap_axis(
+        rect.y,
+        rect.height,
+        siblings.iter().map(|s| (s.y, s.height)),
+        settings,
+    );
+
+    if settings.guides {
+        let snapped = WidgetBounds { x, y, ..rect };
+        for sibling in siblings {
+            for line in aligned_lines(snapped.x, snapped.width, sibling.x, sibling.width) {
+                guides.push(Guide::Vertical {
+                    x: line,
+                    top: snapped.y.min(sibling.y),
+                    bottom: (snapped.y + snapped.height).max(sibling.y + sibling.height),
+                });
+            }
+            for line in aligned_lines(snapped.y, snapped.height, sibling.y, sibling.height) {
+                guides.push(Guide::Horizontal {
+                    y: line,
+                    left: snapped.x.min(sibling.x),
+                    right: (snapped.x + snapped.width).max(sibling.x + sibling.width),
+                });
+            }
+        }
+    }
+
+    Snapped { position: Vec2::new(x, y), guides }
+}
+
+/// Start, center and end of a span.
+fn lines(start: f32, len: f32) -> [f32; 3] {
+    [start, start + len * 0.5, start + len]
+}
+
+/// Snaps one axis: to the nearest sibling line within the threshold,
+/// otherwise to the grid.
+fn snap_axis(
+    start: f32,
+    len: f32,
+    siblings: impl Iterator<Item = (f32, f32)>,
+    settings: &SnapSettings,
+) -> f32 {
+    if settings.guides {
+        let mut best: Option<f32> = None;
+        for (s_start, s_len) in siblings {
+            for target in lines(s_start, s_len) {
+                for line in lines(start, len) {
+                    let delta = target - line;
+                    if delta.abs() <= settings.threshold
+                        && best.map_or(true, |b| delta.abs() < b.abs())
+                    {
+                        best = Some(delta);
+                    }
+                }
+            }
+        }
+        if let Some(delta) = best {
+            return start + delta;
+        }
+    }
+    if settings.grid_size > 0.0 {
+        (start / settings.grid_size).round() * settings.grid_size
+    } else {
+        start
+    }
+}
+
+/// Lines shared by two spans, each reported once.
+fn aligned_lines(start: f32, len: f32, other_start: f32, other_len: f32) -> Vec<f32> {
+    let mut shared: Vec<f32> = Vec::new();
+    for line in lines(start, len) {
+        let matches = lines(other_start, other_len)
+            .iter()
+            .any(|other| (other - line).abs() < 0.5);
+        if matches && !shared.iter().any(|s| (s - line).abs() < 0.5) {
+            shared.push(line);
+        }
+    }
+    shared
+}
+
+/// Draws guide lines, offset by the canvas origin in window coordinates.
+pub fn draw_guides(primitives: &mut PrimitiveRenderer, guides: &[Guide], origin: Vec2) {
+    for guide in guides {
+        let (start, end) = match *guide {
+            Guide::Vertical { x, top, bottom } => (Vec2::new(x, top), Vec2::new(x, bottom)),
+            Guide::Horizontal { y, left, right } => (Vec2::new(left, y), Vec2::new(right, y)),
+        };
+        primitives.draw_line(origin + start, origin + end, 1.0, GUIDE_COLOR);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f32, y: f32, width: f32, height: f32) -> WidgetBounds {
+        WidgetBounds { x, y, width, height }
+    }
+
+    #[test]
+    fn test_snaps_to_grid_without_siblings() {
+        let snapped = snap_rect(rect(13.0, 21.0, 50.0, 20.0), &[], &SnapSettings::default());
+        assert_eq!(snapped.position, Vec2::new(16.0, 24.0));
+        assert!(snapped.guides.is_empty());
+    }
+
+    #[test]
+    fn test_sibling_edges_win_over_grid() {
+        let sibling = rect(100.0, 0.0, 60.0, 30.0);
+        // Left edge 3px off the sibling's left edge, far below it.
+        let snapped = snap_rect(rect(103.0, 200.0, 40.0, 20.0), &[sibling], &SnapSettings::default());
+        assert_eq!(snapped.position, Vec2::new(100.0, 200.0));
+        assert_eq!(
+            snapped.guides,
+            vec![Guide::Vertical { x: 100.0, top: 0.0, bottom: 220.0 }]
+        );
+    }
+
+    #[test]
+    fn test_centers_align() {
+        let sibling = rect(0.0, 100.0, 200.0, 40.0);
+        // Centers at y = 120 and y = 118.
+        let settings = SnapSettings { grid_size: 0.0, ..Default::default() };
+        let snapped = snap_rect(rect(300.0, 108.0, 50.0, 20.0), &[sibling], &settings);
+        assert_eq!(snapped.position, Vec2::new(300.0, 110.0));
+        assert_eq!(
+            snapped.guides,
+            vec![Guide::Horizontal { y: 120.0, left: 0.0, right: 350.0 }]
+        );
+    }
+
+    #[test]
+    fn test_out_of_threshold_falls_back_to_grid() {
+        let sibling = rect(100.0, 0.0, 60.0, 30.0);
+        let settings = SnapSettings { threshold: 2.0, ..Default::default() };
+        let snapped = snap_rect(rect(105.0, 200.0, 40.0, 20.0), &[sibling], &settings);
+        assert_eq!(snapped.position, Vec2::new(104.0, 200.0));
+        assert!(snapped.guides.is_empty());
+    }
+}
@@ -51,16 +51,22 @@ pub fn create_property_widgets(
                             bg.0, bg.1, bg.2
                         )));
                     }
-                    children.push(Widget::label(format!("Padding: {}", padding)));
+                    children.push(Widget::label(format!("Padding: {:?}", padding)));
                 }
                 Widget::Label { text, size, color, .. } => {
                     children.push(Widget::label("Type: Label"));
                     children.push(Widget::label(format!("Text: {}", text)));
-                    children.push(Widget::label(format!("Size: {}", size)));
-                    children.push(Widget::label(format!(
-                        "Color: ({:.1},{:.1},{:.1})",
-                        color.0, color.1, color.2
-                    )));
+                    match size {
+                        Some(size) => children.push(Widget::label(format!("Size: {}", size))),
+                        None => children.push(Widget::label("Size: inherited")),
+                    }
+                    match color {
+                        Some(color) => children.push(Widget::label(format!(
+                            "Color: ({:.1},{:.1},{:.1})",
+                            color.0, color.1, color.2
+                        ))),
+                        None => children.push(Widget::label("Color: inherited")),
+                    }
                 }
                 Widget::Button { text, action, .. } => {
                     children.push(Widget::label("Type: Button"));
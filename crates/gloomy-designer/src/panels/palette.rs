@@ -2,7 +2,7 @@
 //! 
 //! Displays a list of available widgets that can be dragged onto the canvas.
 
-use gloomy_core::layout::{Direction, Layout};
+use gloomy_core::layout::{Direction, Layout, Padding};
 use gloomy_core::widget::{Widget, WidgetBounds, TextAlign};
 
 /// Widget type that can be created.
@@ -51,6 +51,7 @@ impl WidgetType {
     pub fn create(&self) -> Widget {
         match self {
             WidgetType::Container => Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
                 id: Some("new_container".to_string()),
                 scrollable: false,
                 bounds: WidgetBounds { 
@@ -65,30 +66,54 @@ impl WidgetType {
                 shadow: None,
                 corner_radius: 4.0,
                 corner_radii: None,
-                padding: 8.0,
+                padding: Padding::uniform(8.0),
+                focus_trap: false,
+                live_region: None,
+                static_cache: false,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
+                z_index: 0,
+                clip_to_shape: false,
+                overflow: Default::default(),
+                opacity: 1.0,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 layout: Layout {
                     direction: Direction::Column,
                     ..Default::default()
                 },
                 children: Vec::new(),
+                text_defaults: Default::default(),
             },
             WidgetType::Label => Widget::Label {
                 text: "New Label".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
-                size: 16.0,
-                color: (1.0, 1.0, 1.0, 1.0),
+                size: None,
+                color: None,
                 text_align: TextAlign::Left,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                measured: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
             },
             WidgetType::Button => Widget::Button {
                 text: "Button".to_string(),
@@ -111,9 +136,16 @@ impl WidgetType {
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                hit_padding: None,
+                measured: None,
+                classes: Vec::new(),
             },
             WidgetType::TextInput => Widget::TextInput {
                 id: "new_input".to_string(),
+                bind: None,
                 value: String::new(),
                 placeholder: "Enter text...".to_string(),
                 font_size: 14.0,
@@ -131,6 +163,7 @@ impl WidgetType {
                     }),
                     ..Default::default()
                 },
+                classes: Vec::new(),
                 width: 200.0,
                 height: 32.0,
                 flex: 0.0,
@@ -138,9 +171,14 @@ impl WidgetType {
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                mask: None,
             },
             WidgetType::Checkbox => Widget::Checkbox {
                 id: "new_checkbox".to_string(),
+                bind: None,
                 checked: false,
                 size: 20.0,
                 style: gloomy_core::widget::CheckboxStyle {
@@ -154,9 +192,14 @@ impl WidgetType {
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                hit_padding: None,
             },
             WidgetType::Slider => Widget::Slider {
                 id: "new_slider".to_string(),
+                bind: None,
                 value: 0.5,
                 min: 0.0,
                 max: 1.0,
@@ -174,6 +217,10 @@ impl WidgetType {
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                hit_padding: None,
             },
             WidgetType::Spacer => Widget::Spacer {
                 size: 16.0,
@@ -182,6 +229,9 @@ impl WidgetType {
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
             },
             WidgetType::Icon => Widget::Icon {
                 id: "new_icon".to_string(),
@@ -194,6 +244,9 @@ impl WidgetType {
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
             },
         }
     }
@@ -220,5 +273,11 @@ pub fn create_palette_item(widget_type: WidgetType) -> Widget {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
+        hit_padding: None,
+        measured: None,
+        classes: Vec::new(),
     }
 }
@@ -1,7 +1,8 @@
 //! Automation API for testing Gloomy UIs.
 
 use gloomy_core::widget::{Widget, WidgetBounds};
-use gloomy_core::{InteractionState, hit_test, compute_layout, load_ui, parse_ui};
+use gloomy_core::{InteractionState, Rect, hit_test, compute_root_layout, load_ui, parse_ui};
+use gloomy_core::layout::SafeArea;
 use glam::Vec2;
 
 /// A headless driver for interacting with a Gloomy UI tree.
@@ -17,18 +18,16 @@ impl GloomyDriver {
     ///
     /// This performs an initial layout calculation.
     pub fn new(mut root: Widget, width: f32, height: f32) -> Self {
-        // Set root dimensions if it's a container
-        if let Widget::Container { bounds, .. } = &mut root {
-            bounds.width = width;
-            bounds.height = height;
-        }
-        
         // Initial layout
-        compute_layout(&mut root, 0.0, 0.0, width, height);
+        compute_root_layout(&mut root, width, height, SafeArea::default());
+
+        // Overlays near the edges flip as they would in a window this size.
+        let interaction = InteractionState::default();
+        interaction.overlay_area.set(Some(Rect::from_size(width, height)));
 
         Self {
             root,
-            interaction: InteractionState::default(),
+            interaction,
             width,
             height,
         }
@@ -164,8 +163,10 @@ mod tests {
                      action: "my_action".to_string(),
                      bounds: WidgetBounds { x: 0.0, y: 0.0, width: 100.0, height: 50.0 }, 
                      style: ButtonStyle::default(),
+                     classes: Vec::new(),
                      width: Some(100.0), height: Some(50.0), disabled: false, layout: Default::default(),
-                     flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, font: None,
+                     flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, hit_padding: None, font: None,
+                     measured: None,
                  },
              ];
         } else {
@@ -1,6 +1,6 @@
 use gloomy_app::GloomyApp;
 use gloomy_core::{
-    layout::{Direction, Layout},
+    layout::{Direction, Layout, Padding},
     layout_engine::compute_layout,
     ui::{render_ui, handle_interactions, handle_keyboard_event, hit_test},
     widget::{Widget, WidgetBounds, TextAlign, TextInputStyle, CheckboxStyle, SliderStyle, Border},
@@ -92,7 +92,7 @@ fn main() -> anyhow::Result<()> {
             let mut s = state_key.borrow_mut();
             let AppState { ui_root, interaction, input_value, .. } = &mut *s;
             
-             if handle_keyboard_event(ui_root, interaction, &event) {
+             if handle_keyboard_event(ui_root, interaction, &event, winit::keyboard::ModifiersState::empty(), None, None).changed {
                 // Fetch updated values back to state if needed (two-way binding simulation)
                 if let Widget::Container { children, .. } = ui_root {
                     // Extract text input value (this is hacky for basic example, normally use ID map)
@@ -176,6 +176,7 @@ impl AppState {
     fn rebuild_ui(&mut self) {
         // Simple immediate mode style: recreate UI on state change
         self.ui_root = Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
             id: None,
             scrollable: false,
             bounds: WidgetBounds::default(),
@@ -187,12 +188,24 @@ impl AppState {
             border: None,
             corner_radius: 0.0,
             corner_radii: None,
-            padding: 20.0,
+            padding: Padding::uniform(20.0),
+            focus_trap: false,
+            live_region: None,
+            static_cache: false,
+            scrollbar: Default::default(),
+            scrollbar_mode: Default::default(),
+            z_index: 0,
+            clip_to_shape: false,
+            overflow: Default::default(),
+            opacity: 1.0,
             flex: 0.0,
             grid_col: None,
             grid_row: None,
             col_span: 1,
             row_span: 1,
+            grid_area: None,
+            align_self: Default::default(), justify_self: Default::default(),
+            order: 0,
             layout: Layout {
                 direction: Direction::Column,
                 spacing: 12.0,
@@ -200,7 +213,7 @@ impl AppState {
             },
             children: vec![
                 Widget::label("Basic Widget Test"),
-                Widget::Spacer { size: 10.0, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1 },
+                Widget::Spacer { size: 10.0, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1  grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0,},
                 
                 Widget::Button {
                     text: format!("Clicked: {}", self.counter),
@@ -220,11 +233,18 @@ impl AppState {
                     grid_row: None,
                     col_span: 1,
                     row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
+                    hit_padding: None,
                     font: None,
+                    measured: None,
+                    classes: Vec::new(),
                 },
                 
                 Widget::TextInput {
                     id: "text_input".to_string(),
+                    bind: None,
                     value: self.input_value.clone(),
                     placeholder: "Type something...".to_string(),
                     font_size: 16.0,
@@ -239,6 +259,7 @@ impl AppState {
                         }),
                         ..Default::default()
                     },
+                    classes: Vec::new(),
                     width: 200.0,
                     height: 40.0,
                     flex: 0.0,
@@ -246,10 +267,15 @@ impl AppState {
                     grid_row: None,
                     col_span: 1,
                     row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
+                    mask: None,
                 },
                 
                 Widget::Checkbox {
                     id: "chk_toggle".to_string(),
+                    bind: None,
                     checked: self.checked,
                     size: 24.0,
                     style: CheckboxStyle {
@@ -263,10 +289,15 @@ impl AppState {
                     grid_row: None,
                     col_span: 1,
                     row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
+                    hit_padding: None,
                 },
                 
                 Widget::Slider {
                     id: "slider".to_string(),
+                    bind: None,
                     value: self.slider_val,
                     min: 0.0,
                     max: 1.0,
@@ -284,11 +315,15 @@ impl AppState {
                     grid_row: None,
                     col_span: 1,
                     row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
+                    hit_padding: None,
                 },
                 
                 Widget::label(format!("Slider: {:.2}", self.slider_val)),
                 
-                Widget::Spacer { size: 20.0, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1 },
+                Widget::Spacer { size: 20.0, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1  grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0,},
                 
                 Widget::Icon {
                     id: "my_icon".to_string(),
@@ -301,15 +336,19 @@ impl AppState {
                     grid_row: None,
                     col_span: 1,
                     row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
                 },
             ],
+            text_defaults: Default::default(),
         }
     }
 }
 
 fn create_ui() -> Widget {
     let mut state = AppState {
-        ui_root: Widget::Spacer { size: 0.0, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1 },
+        ui_root: Widget::Spacer { size: 0.0, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1  grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0,},
         interaction: InteractionState::default(),
         counter: 0,
         input_value: String::new(),
@@ -5,7 +5,7 @@ use gloomy_core::{
         Border, BorderStyle, Gradient, Shadow, TextInputStyle,
         Widget, WidgetBounds, TextAlign,
     },
-    layout::{Layout, Direction, Align, Justify},
+    layout::{Layout, Direction, Align, Justify, Padding},
     layout_engine::compute_layout,
     interaction::InteractionState,
     Vec2,
@@ -36,6 +36,7 @@ fn main() -> anyhow::Result<()> {
             
             // Define UI
             let mut ui = Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
                 id: Some("root".to_string()),
                 scrollable: true,
                 bounds: WidgetBounds { x: 0.0, y: 0.0, width: w, height: h },
@@ -47,12 +48,24 @@ fn main() -> anyhow::Result<()> {
                 shadow: None,
                 corner_radius: 0.0,
                 corner_radii: None,
-                padding: 20.0,
+                padding: Padding::uniform(20.0),
+                focus_trap: false,
+                live_region: None,
+                static_cache: false,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
+                z_index: 0,
+                clip_to_shape: false,
+                overflow: Default::default(),
+                opacity: 1.0,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 layout: Layout {
                     direction: Direction::Column,
                     spacing: 30.0,
@@ -64,6 +77,7 @@ fn main() -> anyhow::Result<()> {
                     
                     // --- 1. Gradient Borders ---
                     Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
                          id: None,
                          scrollable: false,
                          bounds: WidgetBounds::default(),
@@ -84,9 +98,21 @@ fn main() -> anyhow::Result<()> {
                          shadow: None,
                          corner_radius: 16.0,
                          corner_radii: None,
-                         padding: 0.0,
+                         padding: Padding::uniform(0.0),
+                         focus_trap: false,
+                         live_region: None,
+                         static_cache: false,
+                         scrollbar: Default::default(),
+                         scrollbar_mode: Default::default(),
+                         z_index: 0,
+                         clip_to_shape: false,
+                         overflow: Default::default(),
+                         opacity: 1.0,
                          flex: 0.0, 
                          grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                         grid_area: None,
+                         align_self: Default::default(), justify_self: Default::default(),
+                         order: 0,
                          layout: Layout { justify_content: Justify::Center, align_items: Align::Center, ..Default::default() },
                          children: vec![{
                              let mut w = Widget::label("Gradient Border (4px)");
@@ -95,10 +121,12 @@ fn main() -> anyhow::Result<()> {
                              }
                              w
                          }],
+                         text_defaults: Default::default(),
                     },
 
                     // --- 2. Neon Glow Effect ---
                     Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
                          id: None,
                          scrollable: false,
                          bounds: WidgetBounds::default(),
@@ -120,9 +148,21 @@ fn main() -> anyhow::Result<()> {
                          }),
                          corner_radius: 8.0,
                          corner_radii: None,
-                         padding: 0.0,
+                         padding: Padding::uniform(0.0),
+                         focus_trap: false,
+                         live_region: None,
+                         static_cache: false,
+                         scrollbar: Default::default(),
+                         scrollbar_mode: Default::default(),
+                         z_index: 0,
+                         clip_to_shape: false,
+                         overflow: Default::default(),
+                         opacity: 1.0,
                          flex: 0.0, 
                          grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                         grid_area: None,
+                         align_self: Default::default(), justify_self: Default::default(),
+                         order: 0,
                          layout: Layout { justify_content: Justify::Center, align_items: Align::Center, ..Default::default() },
                          children: vec![{
                              let mut w = Widget::label("Neon Glow (Border + Shadow)");
@@ -131,11 +171,13 @@ fn main() -> anyhow::Result<()> {
                              }
                              w
                          }],
+                         text_defaults: Default::default(),
                     },
 
                      // --- 3. Custom Styled Input ---
                     Widget::TextInput {
                         id: "styled_input".to_string(),
+                        bind: None,
                         value: "Custom Input Style".to_string(),
                         placeholder: "Type here...".to_string(),
                         font_size: 16.0,
@@ -144,6 +186,9 @@ fn main() -> anyhow::Result<()> {
                         width: 400.0,
                         height: 40.0,
                         flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                         style: TextInputStyle {
                             background: Some((0.05, 0.05, 0.05, 1.0).into()),
                             border: Some(Border {
@@ -160,7 +205,9 @@ fn main() -> anyhow::Result<()> {
                             }),
                             // selection_color: (0.2, 0.8, 0.2, 0.3).into(),
                             ..Default::default()
-                        }
+                        },
+                        classes: Vec::new(),
+                        mask: None,
                     },
                     
                     // --- 4. Gradient Button with Shadow ---
@@ -189,8 +236,15 @@ fn main() -> anyhow::Result<()> {
                         corner_radii: None,
                         layout: Layout::default(),
                         flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
+                        hit_padding: None,
+                        measured: None,
+                        classes: Vec::new(),
                     },
-                ]
+                ],
+                text_defaults: Default::default(),
             };
             
             compute_layout(&mut ui, 0.0, 0.0, w, h);
@@ -40,6 +40,9 @@ fn main() -> anyhow::Result<()> {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         backend: Default::default(),
     };
 
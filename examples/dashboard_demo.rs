@@ -1,6 +1,6 @@
 use gloomy_app::GloomyApp;
 use gloomy_core::{
-    Align, Container, Direction, GloomyRenderer, InteractionState, Justify, Layout,
+    Align, Container, Direction, GloomyRenderer, InteractionState, Justify, Layout, Padding,
     RenderContext, Widget, WidgetBounds, KpiCard, KpiCardStyle, KpiTrend, TrendDirection, BoxStyle,
 };
 use winit::keyboard::{Key, NamedKey};
@@ -98,7 +98,17 @@ fn create_ui() -> Widget {
         scrollable: true,
         bounds: WidgetBounds::default(),
         style: BoxStyle::fill((0.12, 0.12, 0.14, 1.0)),
-        padding: 30.0,
+        text_defaults: Default::default(),
+        padding: Padding::uniform(30.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         layout: Layout {
             direction: Direction::Column,
             justify_content: Justify::Start,
@@ -108,15 +118,27 @@ fn create_ui() -> Widget {
         },
         flex: 1.0,
         grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         corner_radii: None, layout_cache: None, render_cache: RefCell::new(None),
+        generation: 0,
         children: vec![
             Widget::Label {
                 text: "Analytics Dashboard".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0, y: 0.0,
-                width: 300.0, height: 40.0, size: 24.0,
-                color: (1.0, 1.0, 1.0, 1.0),
+                width: 300.0, height: 40.0, size: Some(24.0),
+                color: Some((1.0, 1.0, 1.0, 1.0)),
                 text_align: gloomy_core::widget::TextAlign::Left,
-                flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, font: None,
+                flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             // KPI Grid
@@ -125,8 +147,17 @@ fn create_ui() -> Widget {
                 bounds: WidgetBounds::default(),
                 width: None, height: None,
                 width: None, height: None,
-                style: BoxStyle::default(),
-                padding: 0.0,
+                style: BoxStyle::default(), text_defaults: Default::default(),
+                padding: Padding::uniform(0.0),
+                focus_trap: false,
+                live_region: None,
+                static_cache: false,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
+                z_index: 0,
+                clip_to_shape: false,
+                overflow: Default::default(),
+                opacity: 1.0,
                 layout: Layout {
                     direction: Direction::Grid { columns: 3 },
                     justify_content: Justify::Start,
@@ -141,7 +172,11 @@ fn create_ui() -> Widget {
                 },
                 flex: 0.0,
                 grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 corner_radii: None, layout_cache: None, render_cache: RefCell::new(None),
+                generation: 0,
                 children: vec![
                     Widget::KpiCard {
                         id: Some("kpi1".to_string()),
@@ -151,6 +186,9 @@ fn create_ui() -> Widget {
                         style: create_kpi_style(true),
                         bounds: WidgetBounds { width: 0.0, height: 120.0, ..Default::default() },
                         flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                     },
                     Widget::KpiCard {
                         id: Some("kpi2".to_string()),
@@ -160,6 +198,9 @@ fn create_ui() -> Widget {
                         style: create_kpi_style(false),
                         bounds: WidgetBounds { width: 0.0, height: 120.0, ..Default::default() },
                         flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                     },
                     Widget::KpiCard {
                         id: Some("kpi3".to_string()),
@@ -169,6 +210,9 @@ fn create_ui() -> Widget {
                         style: create_kpi_style(true),
                         bounds: WidgetBounds { width: 0.0, height: 120.0, ..Default::default() },
                         flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                     },
                 ],
             },
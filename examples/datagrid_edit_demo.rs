@@ -9,11 +9,11 @@
 /// Run with: cargo run --example datagrid_edit_demo
 
 use gloomy_core::{
-    layout::{Direction, Layout},
+    layout::{Direction, Layout, Padding},
     layout_engine::compute_layout,
     ui::{render_ui, hit_test},
     widget::{Widget, WidgetBounds, TextAlign},
-    datagrid::{ColumnDef, ColumnWidth, DataGridStyle, SelectionMode},
+    datagrid::{handle_cell_edit_key, sync_datagrids, ColumnDef, ColumnWidth, DataGridStyle, SelectionMode},
     data_source::{VecDataSource, CellValue, MapDataProvider, DataProvider},
     interaction::InteractionState,
     Vec2,
@@ -21,7 +21,6 @@ use gloomy_core::{
 use std::{cell::RefCell, rc::Rc};
 use std::time::{SystemTime, UNIX_EPOCH};
 use winit::event::{ElementState, MouseButton};
-use winit::keyboard::{Key, NamedKey};
 
 struct AppState {
     interaction: InteractionState,
@@ -151,55 +150,10 @@ fn main() -> anyhow::Result<()> {
             
             let mut s = state_key.borrow_mut();
             
-            // Handle editing keys
-            if s.interaction.editing_grid_cell.is_some() {
-                match &event.logical_key {
-                    Key::Named(NamedKey::Enter) => {
-                        // Commit
-                        if let Some((grid_id, row, col, value_str)) = s.interaction.commit_grid_edit() {
-                            println!("Committed: ({}, {}) = {}", row, col, value_str);
-                            if let Some(source) = s.provider.get_source_mut("data") {
-                                // Try to preserve original type
-                                let current_val = source.cell_value(row, col);
-                                let new_val = match current_val {
-                                    CellValue::Integer(_) => {
-                                        value_str.parse::<i64>().map(CellValue::Integer).unwrap_or(CellValue::Text(value_str.clone()))
-                                    },
-                                    CellValue::Number(_) => {
-                                        value_str.parse::<f64>().map(CellValue::Number).unwrap_or(CellValue::Text(value_str.clone()))
-                                    },
-                                    CellValue::Boolean(_) => {
-                                        value_str.parse::<bool>().map(CellValue::Boolean).unwrap_or(CellValue::Text(value_str.clone()))
-                                    },
-                                    _ => CellValue::Text(value_str.clone()),
-                                };
-                                
-                                if source.set_cell(row, col, new_val) {
-                                    s.interaction.mark_dirty(&grid_id, row, col);
-                                }
-                            }
-                        }
-                        win.window.request_redraw();
-                    }
-                    Key::Named(NamedKey::Escape) => {
-                        s.interaction.cancel_grid_edit();
-                        win.window.request_redraw();
-                    }
-                    Key::Named(NamedKey::Backspace) => {
-                        s.interaction.grid_edit_buffer.pop();
-                        win.window.request_redraw();
-                    }
-                    Key::Character(ch) => {
-                        if !ch.chars().any(|c| c.is_control()) {
-                            s.interaction.grid_edit_buffer.push_str(ch);
-                            win.window.request_redraw();
-                        }
-                    }
-                    _ => {}
-                }
-            } else {
-                // Navigation / Shortcuts (outside edit mode)
-                // e.g. Delete key to delete row?
+            // Typing, Backspace, Enter (commit) and Escape (cancel) while a cell is edited
+            let app_state = &mut *s;
+            if handle_cell_edit_key(&app_state.ui_root, &mut app_state.interaction, &event.logical_key, Some(&mut app_state.provider)) {
+                win.window.request_redraw();
             }
         })
         .on_draw(move |win, ctx| {
@@ -224,6 +178,10 @@ fn main() -> anyhow::Result<()> {
             }
             
             compute_layout(&mut s.ui_root, 0.0, 0.0, width, height);
+
+            // Deleting rows can leave the grid scrolled past its end
+            let app_state = &mut *s;
+            sync_datagrids(&mut app_state.ui_root, &app_state.provider, &mut app_state.interaction);
             
             // Hover logic
             if let Some(hit) = hit_test(&s.ui_root, s.interaction.mouse_pos, Some(&s.interaction)) {
@@ -274,7 +232,16 @@ fn create_ui(columns: &[ColumnDef], selected_row: Option<usize>) -> Widget {
         corner_radius: 0.0,
         shadow: None,
         gradient: None,
-        padding: 30.0,
+        padding: Padding::uniform(30.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         layout: Layout {
             direction: Direction::Column,
             justify_content: gloomy_core::Justify::Start,
@@ -287,21 +254,36 @@ fn create_ui(columns: &[ColumnDef], selected_row: Option<usize>) -> Widget {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         corner_radii: None,
         layout_cache: None,
         render_cache: RefCell::new(None),
+        generation: 0,
         children: vec![
             Widget::Label {
                 text: "DataGrid Editing Demo".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0, y: 0.0,
                 width: 500.0,
                 height: 40.0,
-                size: 24.0,
-                color: (1.0, 1.0, 1.0, 1.0), // Pure white
+                size: Some(24.0),
+                color: Some((1.0, 1.0, 1.0, 1.0)), // Pure white
                 text_align: TextAlign::Left,
                 flex: 0.0,
                 grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             // Toolbar
@@ -316,7 +298,16 @@ fn create_ui(columns: &[ColumnDef], selected_row: Option<usize>) -> Widget {
                 corner_radius: 0.0,
                 shadow: None,
                 gradient: None,
-                padding: 0.0,
+                padding: Padding::uniform(0.0),
+                focus_trap: false,
+                live_region: None,
+                static_cache: false,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
+                z_index: 0,
+                clip_to_shape: false,
+                overflow: Default::default(),
+                opacity: 1.0,
                 layout: Layout {
                     direction: Direction::Row,
                     justify_content: gloomy_core::Justify::Start,
@@ -326,9 +317,13 @@ fn create_ui(columns: &[ColumnDef], selected_row: Option<usize>) -> Widget {
                 },
                 flex: 0.0,
                 grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 corner_radii: None,
                 layout_cache: None,
                 render_cache: RefCell::new(None),
+                generation: 0,
                 children: vec![
                     Widget::Button {
                         text: "Add Row".to_string(),
@@ -345,7 +340,13 @@ fn create_ui(columns: &[ColumnDef], selected_row: Option<usize>) -> Widget {
                         layout: Default::default(),
                         flex: 0.0,
                         grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
+                        hit_padding: None,
                         font: None,
+                        measured: None,
+                        classes: Vec::new(),
                     },
                     Widget::Button {
                         text: "Delete Row".to_string(),
@@ -362,21 +363,39 @@ fn create_ui(columns: &[ColumnDef], selected_row: Option<usize>) -> Widget {
                         layout: Default::default(),
                         flex: 0.0,
                         grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
+                        hit_padding: None,
                         font: None,
+                        measured: None,
+                        classes: Vec::new(),
                     },
                     Widget::Label {
                         text: "Select a row to delete.".to_string(),
+                        id: None,
+                        bind: None,
                         x: 0.0, y: 0.0,
                         width: 200.0,
                         height: 25.0,
-                        size: 14.0,
-                        color: (0.7, 0.7, 0.75, 1.0),
+                        size: Some(14.0),
+                        color: Some((0.7, 0.7, 0.75, 1.0)),
                         text_align: TextAlign::Left,
                         flex: 0.0,
                         grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                         font: None,
+                        tab_width: Default::default(),
+                        underline: false,
+                        strikethrough: false,
+                        selectable: false,
+                        char_stops: Default::default(),
+                        measured: None,
                     },
                 ],
+                text_defaults: Default::default(),
             },
             
             Widget::DataGrid {
@@ -386,20 +405,34 @@ fn create_ui(columns: &[ColumnDef], selected_row: Option<usize>) -> Widget {
                 data_source_id: Some("data".to_string()),
                 header_height: 40.0,
                 row_height: 36.0,
+                auto_row_height: false,
+                max_row_height: 160.0,
+                fitted_rows: None,
+                data_version: None,
+                content_height: Default::default(),
                 striped: true,
                 selection_mode: SelectionMode::Single,
                 selected_rows: selected_row.into_iter().collect(),
                 sort_column: None,
                 sort_direction: None,
+                sort_keys: Vec::new(),
                 show_vertical_lines: true,
                 show_horizontal_lines: true,
                 style: DataGridStyle::default(),
+                empty_message: None,
+                empty_icon: None,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
                 flex: 1.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
             },
         ],
+        text_defaults: Default::default(),
     }
 }
@@ -1,6 +1,7 @@
 use gloomy_app::GloomyApp;
 use gloomy_core::ui::{render_ui, hit_test, find_widget_mut};
 use gloomy_core::layout_engine::compute_layout;
+use gloomy_core::layout::Padding;
 use gloomy_core::interaction::InteractionState;
 use gloomy_core::widget::{Widget, WidgetBounds, DatePickerStyle};
 use gloomy_core::Vec2;
@@ -21,10 +22,15 @@ fn main() -> anyhow::Result<()> {
     let date_input = Widget::DatePicker {
         id: "date_input".to_string(),
         value: None,
+        range: false,
+        start: None,
+        end: None,
         placeholder: "Select Date".to_string(),
         min_date: Some(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()),
         max_date: Some(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()),
         format: "%Y-%m-%d".to_string(),
+        week_start: chrono::Weekday::Mon,
+        day_names: None,
         bounds: WidgetBounds { width: 300.0, height: 40.0, ..Default::default() },
         style: DatePickerStyle::default(),
         validation: None,
@@ -35,6 +41,9 @@ fn main() -> anyhow::Result<()> {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
     };
 
     let container = Widget::Container {
@@ -43,15 +52,26 @@ fn main() -> anyhow::Result<()> {
         children: vec![
             Widget::Label { 
                 text: "DatePicker Demo".to_string(), 
-                size: 24.0, 
+                id: None,
+                bind: None,
+                size: Some(24.0), 
                 width: 300.0, 
                 height: 40.0, 
-                color: (1.0, 1.0, 1.0, 1.0),
+                color: Some((1.0, 1.0, 1.0, 1.0)),
                 text_align: gloomy_core::widget::TextAlign::Center,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
                 flex: 0.0,
                 grid_col: None, grid_row: None, col_span:1, row_span:1,
-                x:0.0, y:0.0 
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                x:0.0, y:0.0,
+                measured: None, 
             },
             date_input,
         ],
@@ -66,12 +86,24 @@ fn main() -> anyhow::Result<()> {
             spacing: 15.0,
             ..Default::default()
         },
-        padding: 40.0,
+        padding: Padding::uniform(40.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         flex: 1.0,
         grid_col: None,
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         border: None,
         corner_radius: 0.0,
         shadow: None,
@@ -79,6 +111,8 @@ fn main() -> anyhow::Result<()> {
         corner_radii: None,
         layout_cache: None,
         render_cache: RefCell::new(None),
+        generation: 0,
+        text_defaults: Default::default(),
     };
   
     let state = Rc::new(RefCell::new(AppState {
@@ -3,7 +3,7 @@ use gloomy_core::{
     ui::render_ui,
     widget::{Widget, WidgetBounds, TabItem, TabStyle, Orientation, ButtonStyle},
     ui::{hit_test},
-    layout::{Layout, Direction, Align},
+    layout::{Layout, Direction, Align, Padding},
     interaction::InteractionState,
     layout_engine::compute_layout,
     Vec2,
@@ -72,18 +72,33 @@ fn main() -> anyhow::Result<()> {
                 bounds: WidgetBounds { x: 0.0, y: 0.0, width: w, height: h },
                 width: Some(w), height: Some(h),
                 style: Default::default(),
-                padding: 10.0,
+                text_defaults: Default::default(),
+                padding: Padding::uniform(10.0),
+                focus_trap: false,
+                live_region: None,
+                static_cache: false,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
+                z_index: 0,
+                clip_to_shape: false,
+                overflow: Default::default(),
+                opacity: 1.0,
                 layout: Layout { direction: Direction::Column, spacing: 10.0, ..Default::default() },
                 flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 children: vec![
                     Widget::Button { 
                         text: format!("Toggle Orientation (Current: {:?})", s.orientation), 
                         action: "btn_toggle".into(), 
                         bounds: WidgetBounds::default(), 
                         style: ButtonStyle::default(), 
+                        classes: Vec::new(),
                         width: None, height: Some(40.0), 
                         disabled: false, layout: Layout::default(), flex: 0.0, 
-                        grid_col: None, grid_row: None, col_span: 1, row_span: 1, font: None 
+                        grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, hit_padding: None, font: None,
+                        measured: None, 
                     },
                     Widget::tab(
                         "debug_tabs",
@@ -92,20 +107,26 @@ fn main() -> anyhow::Result<()> {
                                 title: "Tab A".into(), 
                                 content: Box::new(Widget::Container {
                                     id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, 
-                                    style: Default::default(), padding: 20.0, layout: Layout::default(), flex: 0.0, 
+                                    style: Default::default(), text_defaults: Default::default(), padding: Padding::uniform(20.0), focus_trap: false, live_region: None, static_cache: false, scrollbar: Default::default(), scrollbar_mode: Default::default(), z_index: 0, clip_to_shape: false, overflow: Default::default(), opacity: 1.0, layout: Layout::default(), flex: 0.0, 
                                     grid_col: None, grid_row: None, col_span: 1, row_span: 1, 
+                                    grid_area: None,
+                                    align_self: Default::default(), justify_self: Default::default(),
+                                    order: 0,
                                     children: vec![Widget::label("Content A")],
-                                    layout_cache: None, render_cache: std::cell::RefCell::new(None)
+                                    layout_cache: None, render_cache: std::cell::RefCell::new(None), generation: 0,
                                 })
                             },
                             TabItem { 
                                 title: "Tab B".into(), 
                                 content: Box::new(Widget::Container {
                                     id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, 
-                                    style: Default::default(), padding: 20.0, layout: Layout::default(), flex: 0.0, 
+                                    style: Default::default(), text_defaults: Default::default(), padding: Padding::uniform(20.0), focus_trap: false, live_region: None, static_cache: false, scrollbar: Default::default(), scrollbar_mode: Default::default(), z_index: 0, clip_to_shape: false, overflow: Default::default(), opacity: 1.0, layout: Layout::default(), flex: 0.0, 
                                     grid_col: None, grid_row: None, col_span: 1, row_span: 1, 
+                                    grid_area: None,
+                                    align_self: Default::default(), justify_self: Default::default(),
+                                    order: 0,
                                     children: vec![Widget::label("Content B")],
-                                    layout_cache: None, render_cache: std::cell::RefCell::new(None)
+                                    layout_cache: None, render_cache: std::cell::RefCell::new(None), generation: 0,
                                 })
                             },
                         ],
@@ -113,7 +134,7 @@ fn main() -> anyhow::Result<()> {
                         TabStyle::default()
                     )
                 ],
-                layout_cache: None, render_cache: std::cell::RefCell::new(None)
+                layout_cache: None, render_cache: std::cell::RefCell::new(None), generation: 0,
             };
 
             compute_layout(&mut ui, 0.0, 0.0, w, h);
@@ -9,7 +9,7 @@
 /// Run with: cargo run --example divider_demo
 
 use gloomy_core::{
-    layout::{Direction, Layout},
+    layout::{Direction, Layout, Padding},
     layout_engine::compute_layout,
     ui::{render_ui, handle_interactions},
     widget::{Widget, WidgetBounds, Orientation},
@@ -62,6 +62,7 @@ impl AppState {
 
 fn create_ui() -> Widget {
     Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
         id: Some("root".to_string()),
         scrollable: false,
         bounds: WidgetBounds::default(),
@@ -72,7 +73,16 @@ fn create_ui() -> Widget {
         corner_radius: 0.0,
         shadow: None,
         gradient: None,
-        padding: 30.0,
+        padding: Padding::uniform(30.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         layout: Layout {
             direction: Direction::Column,
             spacing: 0.0,
@@ -83,24 +93,38 @@ fn create_ui() -> Widget {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         corner_radii: None,
         children: vec![
             // Title
             Widget::Label {
                 text: "Divider Widget Demo".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 0.0,
                 height: 0.0,
-                size: 32.0,
-                color: (0.9, 0.9, 0.9, 1.0),
+                size: Some(32.0),
+                color: Some((0.9, 0.9, 0.9, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             // Horizontal divider
@@ -110,46 +134,73 @@ fn create_ui() -> Widget {
                 thickness: 2.0,
                 color: (0.3, 0.3, 0.3, 1.0),
                 margin: 16.0,
+                label: None,
+                label_size: 14.0,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
             },
             
             // Section 1
             Widget::Label {
                 text: "Section 1: Horizontal Dividers".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 0.0,
                 height: 0.0,
-                size: 20.0,
-                color: (0.8, 0.8, 0.8, 1.0),
+                size: Some(20.0),
+                color: Some((0.8, 0.8, 0.8, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             Widget::Label {
                 text: "Horizontal dividers are great for separating content vertically.".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 0.0,
                 height: 0.0,
-                size: 14.0,
-                color: (0.6, 0.6, 0.6, 1.0),
+                size: Some(14.0),
+                color: Some((0.6, 0.6, 0.6, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             // Thin divider
@@ -159,33 +210,50 @@ fn create_ui() -> Widget {
                 thickness: 1.0,
                 color: (0.25, 0.25, 0.25, 1.0),
                 margin: 12.0,
+                label: None,
+                label_size: 14.0,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
             },
             
             // Section 2
             Widget::Label {
                 text: "Section 2: Vertical Dividers".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 0.0,
                 height: 0.0,
-                size: 20.0,
-                color: (0.8, 0.8, 0.8, 1.0),
+                size: Some(20.0),
+                color: Some((0.8, 0.8, 0.8, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             // Row container with vertical dividers
             Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
                 id: Some("row".to_string()),
                 scrollable: false,
                 bounds: WidgetBounds::default(),
@@ -196,7 +264,16 @@ fn create_ui() -> Widget {
                 corner_radius: 8.0,
                 shadow: None,
                 gradient: None,
-                padding: 15.0,
+                padding: Padding::uniform(15.0),
+                focus_trap: false,
+                live_region: None,
+                static_cache: false,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
+                z_index: 0,
+                clip_to_shape: false,
+                overflow: Default::default(),
+                opacity: 1.0,
                 layout: Layout {
                     direction: Direction::Row,
                     spacing: 0.0,
@@ -207,6 +284,9 @@ fn create_ui() -> Widget {
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 corner_radii: None,
                 children: vec![
                     create_text_box("Column 1", "First column of content"),
@@ -218,11 +298,16 @@ fn create_ui() -> Widget {
                         thickness: 2.0,
                         color: (0.4, 0.4, 0.4, 1.0),
                         margin: 10.0,
+                        label: None,
+                        label_size: 14.0,
                         flex: 0.0,
                         grid_col: None,
                         grid_row: None,
                         col_span: 1,
                         row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                     },
                     
                     create_text_box("Column 2", "Second column of content"),
@@ -234,22 +319,30 @@ fn create_ui() -> Widget {
                         thickness: 2.0,
                         color: (0.4, 0.4, 0.4, 1.0),
                         margin: 10.0,
+                        label: None,
+                        label_size: 14.0,
                         flex: 0.0,
                         grid_col: None,
                         grid_row: None,
                         col_span: 1,
                         row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                     },
                     
                     create_text_box("Column 3", "Third column of content"),
                 ],
+                text_defaults: Default::default(),
             },
         ],
+        text_defaults: Default::default(),
     }
 }
 
 fn create_text_box(title: &str, description: &str) -> Widget {
     Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
         id: None,
         scrollable: false,
         bounds: WidgetBounds::default(),
@@ -260,7 +353,16 @@ fn create_text_box(title: &str, description: &str) -> Widget {
         corner_radius: 0.0,
         shadow: None,
         gradient: None,
-        padding: 10.0,
+        padding: Padding::uniform(10.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         layout: Layout {
             direction: Direction::Column,
             spacing: 8.0,
@@ -271,40 +373,66 @@ fn create_text_box(title: &str, description: &str) -> Widget {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         corner_radii: None,
         children: vec![
             Widget::Label {
                 text: title.to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 0.0,
                 height: 0.0,
-                size: 16.0,
-                color: (0.9, 0.9, 0.9, 1.0),
+                size: Some(16.0),
+                color: Some((0.9, 0.9, 0.9, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             Widget::Label {
                 text: description.to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 0.0,
                 height: 0.0,
-                size: 12.0,
-                color: (0.6, 0.6, 0.6, 1.0),
+                size: Some(12.0),
+                color: Some((0.6, 0.6, 0.6, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
         ],
+        text_defaults: Default::default(),
     }
 }
@@ -0,0 +1,78 @@
+//! DPI Demo - Rendering that stays correct across monitors.
+//!
+//! Drag the window to a display with a different scale factor. Text and
+//! layout re-render at the new DPI and the label reports the factor.
+//!
+//! Press 'q' or Escape to quit.
+
+use gloomy_app::GloomyApp;
+use gloomy_core::layout_engine::compute_root_layout;
+use gloomy_core::ui::{parse_ui, render_ui};
+use gloomy_core::widget::Widget;
+use gloomy_core::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const UI: &str = r#"
+Container(
+    id: Some("root"),
+    padding: 24.0,
+    layout: (direction: Column, spacing: 12.0),
+    children: [
+        Label(text: "Move this window between displays", size: 24.0),
+        Label(id: Some("scale"), text: "Scale factor: ?", size: 16.0),
+        Label(text: "The quick brown fox jumps over the lazy dog", size: 14.0),
+        Button(text: "Crisp at any DPI", action: "noop"),
+    ],
+)
+"#;
+
+fn set_scale_label(root: &mut Widget, scale_factor: f64) {
+    if let Widget::Container { children, .. } = root {
+        for child in children {
+            if let Widget::Label { id: Some(id), text, .. } = child {
+                if id == "scale" {
+                    *text = format!("Scale factor: {:.2}", scale_factor);
+                }
+            }
+        }
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    env_logger::init();
+
+    let ui = Rc::new(RefCell::new(parse_ui(UI)?));
+    let ui_scale = ui.clone();
+    let ui_draw = ui.clone();
+    // Logical size the tree was last laid out at.
+    let laid_out = Rc::new(RefCell::new(None::<Vec2>));
+    let laid_out_scale = laid_out.clone();
+
+    GloomyApp::new()
+        .with_title("DPI Demo")
+        .on_scale_factor_changed(move |win, scale_factor| {
+            log::info!("Scale factor changed to {}", scale_factor);
+            // Cached layouts were computed for the old logical size; lay
+            // out again at the new one before the next frame.
+            let mut root = ui_scale.borrow_mut();
+            set_scale_label(&mut root, scale_factor);
+            root.mark_dirty();
+            let size = win.renderer.size();
+            compute_root_layout(&mut root, size.x, size.y, win.safe_area());
+            *laid_out_scale.borrow_mut() = Some(size);
+        })
+        .on_draw(move |win, ctx| {
+            let mut root = ui_draw.borrow_mut();
+            set_scale_label(&mut root, win.window.scale_factor());
+
+            // Layout works in logical pixels; the renderer scales to physical.
+            let size = win.renderer.size();
+            if *laid_out.borrow() != Some(size) {
+                compute_root_layout(&mut root, size.x, size.y, win.safe_area());
+                *laid_out.borrow_mut() = Some(size);
+            }
+            render_ui(&root, &mut win.renderer, ctx.device, ctx.queue, None, None);
+        })
+        .run()
+}
@@ -1,9 +1,11 @@
 use gloomy_app::GloomyApp;
 use gloomy_core::ui::{render_ui, hit_test, find_widget_mut};
 use gloomy_core::layout_engine::compute_layout;
+use gloomy_core::layout::Padding;
 use gloomy_core::interaction::InteractionState;
 use gloomy_core::widget::{Widget, WidgetBounds, NumberInputStyle, AutocompleteStyle};
 use gloomy_core::validation::ValidationRule;
+use gloomy_core::{step_number_input, tick_spinners, NumberFormat, SpinRepeatConfig};
 use gloomy_core::Vec2;
 use winit::event::ElementState;
 use std::rc::Rc;
@@ -20,12 +22,15 @@ fn main() -> anyhow::Result<()> {
     // Create UI programmatically
     let number_input = Widget::NumberInput {
         id: "num_input".to_string(),
+        bind: None,
         value: 10.0,
         min: Some(0.0),
         max: Some(100.0),
         step: 0.5,
         precision: 1,
+        number_format: NumberFormat::default(),
         show_spinner: true,
+        repeat: SpinRepeatConfig::default(),
         bounds: WidgetBounds { width: 200.0, height: 40.0, ..Default::default() },
         style: NumberInputStyle::default(),
         width: 200.0,
@@ -35,6 +40,9 @@ fn main() -> anyhow::Result<()> {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         validation: None,
     };
 
@@ -62,6 +70,9 @@ fn main() -> anyhow::Result<()> {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
     };
 
     let container = Widget::Container {
@@ -79,12 +90,24 @@ fn main() -> anyhow::Result<()> {
             spacing: 20.0,
             ..Default::default()
         },
-        padding: 20.0,
+        padding: Padding::uniform(20.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         flex: 1.0,
         grid_col: None,
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         border: None,
         corner_radius: 0.0,
         shadow: None,
@@ -92,6 +115,8 @@ fn main() -> anyhow::Result<()> {
         corner_radii: None,
         layout_cache: None,
         render_cache: RefCell::new(None),
+        generation: 0,
+        text_defaults: Default::default(),
     };
   
     let state = Rc::new(RefCell::new(AppState {
@@ -144,18 +169,8 @@ fn main() -> anyhow::Result<()> {
                          }
                      }
 
-                     // Handle NumberInput actions
-                     if action == "num_input:up" {
-                         if let Some(Widget::NumberInput { value, step, max, .. }) = find_widget_mut(ui, "num_input") {
-                             *value += *step;
-                             if let Some(m) = max { *value = value.min(*m); }
-                         }
-                     } else if action == "num_input:down" {
-                         if let Some(Widget::NumberInput { value, step, min, .. }) = find_widget_mut(ui, "num_input") {
-                             *value -= *step;
-                             if let Some(m) = min { *value = value.max(*m); }
-                         }
-                     }
+                     // Handle NumberInput actions; holding repeats in on_draw
+                     step_number_input(ui, &action);
                  } else {
                      interaction.focused_id = None;
                  }
@@ -180,6 +195,16 @@ fn main() -> anyhow::Result<()> {
               s.interaction.hovered_action = None;
           }
 
+          let now_ms = std::time::SystemTime::now()
+              .duration_since(std::time::UNIX_EPOCH)
+              .map(|d| d.as_millis() as u64)
+              .unwrap_or(0);
+          let app_state = &mut *s;
+          tick_spinners(&mut app_state.ui, &mut app_state.interaction, now_ms);
+          if s.interaction.spin_repeat.is_some() {
+              win.window.request_redraw();
+          }
+
           render_ui(
             &s.ui, 
             &mut win.renderer,
@@ -1,8 +1,10 @@
 use gloomy_app::GloomyApp;
 use gloomy_core::{
-    layout::{Direction, Layout},
+    layout::{Direction, Layout, Padding},
     layout_engine::compute_layout,
     ui::{render_ui, hit_test},
+    scroll::route_scroll,
+    scrollbar::{begin_scrollbar_drag, drag_scrollbar, end_scrollbar_drag},
     widget::{Widget, WidgetBounds, TextAlign},
     datagrid::{ColumnDef, ColumnWidth},
     data_source::{VecDataSource, CellValue, MapDataProvider, DataProvider, SortDirection},
@@ -18,6 +20,8 @@ struct AppState {
     provider: MapDataProvider,
     ui_root: Widget,
     row_count: usize,
+    /// Text render passes of the previous frame, logged when it changes.
+    text_passes: usize,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -41,6 +45,7 @@ fn main() -> anyhow::Result<()> {
         provider,
         ui_root,
         row_count,
+        text_passes: 0,
     }));
     
     let state_move = state.clone();
@@ -59,13 +64,10 @@ fn main() -> anyhow::Result<()> {
             };
             
             if d != Vec2::ZERO {
-                 // Only scroll if hovering over a scrollable widget
-                 let target = s.interaction.hovered_action.as_ref()
-                    .and_then(|h| h.split(":").next())
-                    .map(|s| s.to_string());
-                 
-                 if let Some(id) = target {
-                      s.interaction.handle_scroll(&id, d);
+                 // Scroll the innermost scrollable under the cursor
+                 let s = &mut *s;
+                 let pos = s.interaction.mouse_pos;
+                 if route_scroll(&s.ui_root, pos, d, &mut s.interaction).is_some() {
                       win.window.request_redraw();
                  }
             }
@@ -74,6 +76,10 @@ fn main() -> anyhow::Result<()> {
             let mut s = state_move.borrow_mut();
             let pos = Vec2::new(x as f32, y as f32);
             s.interaction.update_mouse(pos);
+            if drag_scrollbar(&mut s.interaction, pos) {
+                win.window.request_redraw();
+                return;
+            }
             
             // Hit Test
             let hit_action = hit_test(&s.ui_root, pos, Some(&s.interaction))
@@ -97,6 +103,13 @@ fn main() -> anyhow::Result<()> {
              if button == MouseButton::Left {
                 let mut s = state_click.borrow_mut();
                 let pressed = state == ElementState::Pressed;
+                let s = &mut *s;
+                if pressed {
+                    let pos = s.interaction.mouse_pos;
+                    begin_scrollbar_drag(&s.ui_root, &mut s.interaction, pos);
+                } else {
+                    end_scrollbar_drag(&mut s.interaction);
+                }
                 s.interaction.set_pressed(pressed);
                 win.window.request_redraw();
              }
@@ -118,6 +131,12 @@ fn main() -> anyhow::Result<()> {
 
             // Update FPS in title
             // Note: In real app use a proper FPS counter
+
+            let text_passes = win.renderer.text().draw_calls();
+            if text_passes != s.text_passes {
+                log::info!("Text render passes per frame: {}", text_passes);
+                s.text_passes = text_passes;
+            }
             
             render_ui(
                 &s.ui_root,
@@ -158,6 +177,7 @@ fn create_large_provider(count: usize) -> MapDataProvider {
 
 fn create_ui(columns: &[ColumnDef]) -> Widget {
     Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
         id: Some("root".to_string()),
         scrollable: false,
         bounds: WidgetBounds::default(),
@@ -168,7 +188,16 @@ fn create_ui(columns: &[ColumnDef]) -> Widget {
         corner_radius: 0.0,
         shadow: None,
         gradient: None,
-        padding: 20.0,
+        padding: Padding::uniform(20.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         layout: Layout {
             direction: Direction::Column,
             align_items: gloomy_core::Align::Stretch,
@@ -179,23 +208,37 @@ fn create_ui(columns: &[ColumnDef]) -> Widget {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         corner_radii: None,
         children: vec![
             Widget::Label {
                 text: "Large DataGrid Virtualization Benchmark (100k rows)".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 0.0,
                 height: 30.0,
-                size: 24.0,
-                color: (1.0, 1.0, 1.0, 1.0),
+                size: Some(24.0),
+                color: Some((1.0, 1.0, 1.0, 1.0)),
                 text_align: TextAlign::Left,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             Widget::DataGrid {
@@ -205,20 +248,34 @@ fn create_ui(columns: &[ColumnDef]) -> Widget {
                 data_source_id: Some("large_data".to_string()),
                 header_height: 32.0,
                 row_height: 28.0,
+                auto_row_height: false,
+                max_row_height: 160.0,
+                fitted_rows: None,
+                data_version: None,
+                content_height: Default::default(),
                 striped: true,
                 selection_mode: gloomy_core::datagrid::SelectionMode::Single,
                 selected_rows: Vec::new(),
                 sort_column: None,
                 sort_direction: Some(SortDirection::Ascending),
+                sort_keys: Vec::new(),
                 show_vertical_lines: true,
                 show_horizontal_lines: false,
                 style: gloomy_core::datagrid::DataGridStyle::default(),
+                empty_message: None,
+                empty_icon: None,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
                 flex: 1.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
             }
         ],
+        text_defaults: Default::default(),
     }
 }
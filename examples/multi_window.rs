@@ -0,0 +1,129 @@
+//! Multi Window - Two windows rendering different roots from shared state.
+//!
+//! The main window has a counter; "Open Inspector" spawns a second window
+//! that shows the same count. Both windows share one `Rc<RefCell<State>>`
+//! but keep their own widget tree and `InteractionState`.
+//!
+//! Press 'q' or Escape to quit.
+
+use gloomy_app::{GloomyApp, WindowConfig, MAIN_WINDOW};
+use gloomy_core::interaction::InteractionState;
+use gloomy_core::layout_engine::compute_layout;
+use gloomy_core::ui::{hit_test, parse_ui, render_ui};
+use gloomy_core::widget::Widget;
+use gloomy_core::Vec2;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use winit::event::{ElementState, MouseButton};
+
+const INSPECTOR: &str = "inspector";
+
+const MAIN_UI: &str = r#"
+Container(
+    padding: 24.0,
+    layout: (direction: Column, spacing: 12.0),
+    children: [
+        Label(text: "Main window", size: 24.0),
+        Button(text: "Increment", action: "increment"),
+        Button(text: "Open Inspector", action: "open_inspector"),
+    ],
+)
+"#;
+
+const INSPECTOR_UI: &str = r#"
+Container(
+    padding: 16.0,
+    layout: (direction: Column, spacing: 8.0),
+    children: [
+        Label(text: "Inspector", size: 20.0),
+        Label(id: Some("count"), text: "", size: 16.0),
+    ],
+)
+"#;
+
+/// One window's view of the app.
+struct View {
+    root: Widget,
+    interaction: InteractionState,
+}
+
+/// State shared by every window.
+struct State {
+    count: u32,
+    views: HashMap<String, View>,
+}
+
+impl State {
+    fn view(&mut self, label: &str) -> Option<&mut View> {
+        if !self.views.contains_key(label) {
+            let ron = match label {
+                MAIN_WINDOW => MAIN_UI,
+                INSPECTOR => INSPECTOR_UI,
+                _ => return None,
+            };
+            let root = parse_ui(ron).expect("valid UI");
+            self.views.insert(label.to_string(), View { root, interaction: InteractionState::default() });
+        }
+        self.views.get_mut(label)
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    env_logger::init();
+
+    let state = Rc::new(RefCell::new(State { count: 0, views: HashMap::new() }));
+    let state_move = state.clone();
+    let state_input = state.clone();
+    let state_draw = state.clone();
+
+    let app = GloomyApp::new().with_title("Multi Window");
+    let spawner = app.spawner();
+
+    app.on_cursor_move(move |win, x, y| {
+            let mut s = state_move.borrow_mut();
+            if let Some(view) = s.view(&win.label) {
+                view.interaction.update_mouse(Vec2::new(x, y));
+            }
+            win.window.request_redraw();
+        })
+        .on_mouse_input(move |win, elem_state, button| {
+            if elem_state != ElementState::Pressed || button != MouseButton::Left {
+                return;
+            }
+            let mut s = state_input.borrow_mut();
+            let Some(view) = s.view(&win.label) else { return };
+            let action = hit_test(&view.root, view.interaction.mouse_pos, Some(&view.interaction))
+                .map(|hit| hit.action.to_string());
+
+            match action.as_deref() {
+                Some("increment") => s.count += 1,
+                Some("open_inspector") if !s.views.contains_key(INSPECTOR) => {
+                    spawner.open(WindowConfig::new(INSPECTOR, "Inspector").with_size(320, 200));
+                }
+                _ => {}
+            }
+            win.window.request_redraw();
+        })
+        .on_draw(move |win, ctx| {
+            let mut s = state_draw.borrow_mut();
+            let count = s.count;
+            let Some(view) = s.view(&win.label) else { return };
+
+            if let Widget::Container { children, .. } = &mut view.root {
+                for child in children {
+                    if let Widget::Label { id: Some(id), text, .. } = child {
+                        if id == "count" {
+                            *text = format!("Count: {}", count);
+                        }
+                    }
+                }
+            }
+
+            // Each window lays out at its own size with its own renderer.
+            let size = win.renderer.size();
+            compute_layout(&mut view.root, 0.0, 0.0, size.x, size.y);
+            render_ui(&view.root, &mut win.renderer, ctx.device, ctx.queue, Some(&view.interaction), None);
+        })
+        .run()
+}
@@ -15,7 +15,7 @@
 /// Run with: cargo run --example rich_text_showcase
 
 use gloomy_core::{
-    layout::Layout,
+    layout::{Layout, Padding},
     layout_engine::compute_layout,
     ui::render_ui,
     widget::{Widget, WidgetBounds, TextAlign},
@@ -81,6 +81,7 @@ struct AppState {
 
 fn create_ui() -> Widget {
     Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
         bounds: WidgetBounds::default(),
         background: Some((0.10, 0.10, 0.12, 1.0)),
         border: None,
@@ -88,7 +89,16 @@ fn create_ui() -> Widget {
         gradient: None,
         corner_radius: 0.0,
         corner_radii: None,
-        padding: 30.0,
+        padding: Padding::uniform(30.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         children: vec![
             // Title
             Widget::Label {
@@ -98,15 +108,26 @@ fn create_ui() -> Widget {
                 height: 40.0,
                 text: "<size=\"28\"><bold>Rich Text \
                        Showcase</bold></size>".to_string(),
-                size: 24.0,
-                color: (0.95, 0.95, 0.97, 1.0),
+                id: None,
+                bind: None,
+                size: Some(24.0),
+                color: Some((0.95, 0.95, 0.97, 1.0)),
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
                 text_align: TextAlign::Center,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                measured: None,
             },
             
             // Color examples
@@ -120,15 +141,26 @@ fn create_ui() -> Widget {
                        <color=\"#00FF00\">Green</color> \
                        <color=\"#0000FF\">Blue</color> \
                        <color=\"#FF00FF\">Magenta</color>".to_string(),
-                size: 16.0,
-                color: (0.9, 0.9, 0.92, 1.0),
+                id: None,
+                bind: None,
+                size: Some(16.0),
+                color: Some((0.9, 0.9, 0.92, 1.0)),
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
                 text_align: TextAlign::Left,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                measured: None,
             },
             
             // Size examples
@@ -142,15 +174,26 @@ fn create_ui() -> Widget {
                        <size=\"16\">Medium</size> \
                        <size=\"24\">Large</size> \
                        <size=\"32\">Huge</size>".to_string(),
-                size: 16.0,
-                color: (0.9, 0.9, 0.92, 1.0),
+                id: None,
+                bind: None,
+                size: Some(16.0),
+                color: Some((0.9, 0.9, 0.92, 1.0)),
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
                 text_align: TextAlign::Left,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                measured: None,
             },
             
             // Style examples
@@ -163,15 +206,26 @@ fn create_ui() -> Widget {
                        <bold>Bold</bold> \
                        <italic>Italic</italic> \
                        <underline>Underline</underline>".to_string(),
-                size: 16.0,
-                color: (0.9, 0.9, 0.92, 1.0),
+                id: None,
+                bind: None,
+                size: Some(16.0),
+                color: Some((0.9, 0.9, 0.92, 1.0)),
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
                 text_align: TextAlign::Left,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                measured: None,
             },
             
             // Nested examples
@@ -185,15 +239,26 @@ fn create_ui() -> Widget {
                        Orange</color></bold> \
                        <italic><size=\"18\">Big \
                        Italic</size></italic>".to_string(),
-                size: 16.0,
-                color: (0.9, 0.9, 0.92, 1.0),
+                id: None,
+                bind: None,
+                size: Some(16.0),
+                color: Some((0.9, 0.9, 0.92, 1.0)),
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
                 text_align: TextAlign::Left,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                measured: None,
             },
             
             // Span tag examples
@@ -205,15 +270,26 @@ fn create_ui() -> Widget {
                 text: "<bold>Combined:</bold> \
                        <span color=\"#00FFFF\" size=\"20\" \
                        bold>Cyan Bold 20px</span>".to_string(),
-                size: 16.0,
-                color: (0.9, 0.9, 0.92, 1.0),
+                id: None,
+                bind: None,
+                size: Some(16.0),
+                color: Some((0.9, 0.9, 0.92, 1.0)),
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
                 text_align: TextAlign::Left,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                measured: None,
             },
             
             // Button with rich text
@@ -242,6 +318,10 @@ fn create_ui() -> Widget {
                     align_items: gloomy_core::layout::Align::Center,
                     justify_content: gloomy_core::layout::Justify::Center,
                     template_columns: vec![],
+                    breakpoints: vec![],
+                    row_gap: None,
+                    column_gap: None,
+                    areas: Default::default(),
                 },
                 font: None,
                 flex: 0.0,
@@ -249,6 +329,12 @@ fn create_ui() -> Widget {
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                hit_padding: None,
+                measured: None,
+                classes: Vec::new(),
             },
             
             // Tree with rich text
@@ -285,6 +371,9 @@ fn create_ui() -> Widget {
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
             },
         ],
         layout: Layout {
@@ -293,6 +382,10 @@ fn create_ui() -> Widget {
             align_items: gloomy_core::layout::Align::Start,
             justify_content: gloomy_core::layout::Justify::Start,
             template_columns: vec![],
+            breakpoints: vec![],
+            row_gap: None,
+            column_gap: None,
+            areas: Default::default(),
         },
         width: None,
         height: None,
@@ -301,7 +394,11 @@ fn create_ui() -> Widget {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         id: None,
         scrollable: false,
+        text_defaults: Default::default(),
     }
 }
@@ -3,7 +3,7 @@ use gloomy_core::ui::{load_ui, render_ui, hit_test};
 use gloomy_core::layout_engine::compute_layout;
 use gloomy_core::interaction::InteractionState;
 use gloomy_core::widget::Widget;
-use winit::event::{ElementState, MouseButton, MouseScrollDelta};
+use winit::event::{ElementState, MouseButton};
 use gloomy_core::Vec2;
 use std::rc::Rc;
 use std::cell::RefCell;
@@ -48,19 +48,13 @@ fn main() -> anyhow::Result<()> {
              }
         }
     })
-    .on_scroll(move |_win, delta, _phase| {
+    .on_scroll_pixels(move |_win, _dx, dy, _phase| {
         let mut s = state_clone_scroll.borrow_mut();
         
         // Simple logic: scroll "scroll_view" if it exists
         // In real app, check hit test or hovered element
         if let Some(offset) = s.interaction.scroll_offsets.get_mut("scroll_view") {
-             // Treat delta y
-             let d = match delta {
-                 MouseScrollDelta::LineDelta(_x, y) => y * 30.0,
-                 MouseScrollDelta::PixelDelta(pos) => pos.y as f32,
-             };
-             
-             offset.y -= d;
+             offset.y -= dy;
              // Ensure not negative
              if offset.y < 0.0 { offset.y = 0.0; }
              // Max scroll? We need content height. 
@@ -102,19 +102,30 @@ fn create_ui(vertical_scroll: f32, horizontal_scroll: f32) -> Widget {
             // Title
             Widget::Label {
                 text: "Scrollbar Widget Demo".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 0.0,
                 height: 0.0,
-                size: 28.0,
-                color: (0.9, 0.9, 0.9, 1.0),
+                size: Some(28.0),
+                color: Some((0.9, 0.9, 0.9, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             // Vertical scrollbar section
@@ -143,19 +154,30 @@ fn create_ui(vertical_scroll: f32, horizontal_scroll: f32) -> Widget {
                 children: vec![
                     Widget::Label {
                         text: "Vertical Scrollbar".to_string(),
+                        id: None,
+                        bind: None,
                         x: 0.0,
                         y: 0.0,
                         width: 0.0,
                         height: 0.0,
-                        size: 18.0,
-                        color: (0.8, 0.8, 0.8, 1.0),
+                        size: Some(18.0),
+                        color: Some((0.8, 0.8, 0.8, 1.0)),
                         text_align: Default::default(),
                         flex: 0.0,
                         grid_col: None,
                         grid_row: None,
                         col_span: 1,
                         row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                         font: None,
+                        tab_width: Default::default(),
+                        underline: false,
+                        strikethrough: false,
+                        selectable: false,
+                        char_stops: Default::default(),
+                        measured: None,
                     },
                     
                     // Scrollable area container
@@ -219,19 +241,30 @@ fn create_ui(vertical_scroll: f32, horizontal_scroll: f32) -> Widget {
                                             viewport_height,
                                             vertical_scroll
                                         ),
+                                        id: None,
+                                        bind: None,
                                         x: 0.0,
                                         y: 0.0,
                                         width: 0.0,
                                         height: 0.0,
-                                        size: 14.0,
-                                        color: (0.7, 0.7, 0.7, 1.0),
+                                        size: Some(14.0),
+                                        color: Some((0.7, 0.7, 0.7, 1.0)),
                                         text_align: Default::default(),
                                         flex: 0.0,
                                         grid_col: None,
                                         grid_row: None,
                                         col_span: 1,
                                         row_span: 1,
+                                        grid_area: None,
+                                        align_self: Default::default(), justify_self: Default::default(),
+                                        order: 0,
                                         font: None,
+                                        tab_width: Default::default(),
+                                        underline: false,
+                                        strikethrough: false,
+                                        selectable: false,
+                                        char_stops: Default::default(),
+                                        measured: None,
                                     },
                                 ],
                             },
@@ -255,6 +288,9 @@ fn create_ui(vertical_scroll: f32, horizontal_scroll: f32) -> Widget {
                                 grid_row: None,
                                 col_span: 1,
                                 row_span: 1,
+                                grid_area: None,
+                                align_self: Default::default(), justify_self: Default::default(),
+                                order: 0,
                             },
                         ],
                     },
@@ -287,19 +323,30 @@ fn create_ui(vertical_scroll: f32, horizontal_scroll: f32) -> Widget {
                 children: vec![
                     Widget::Label {
                         text: "Horizontal Scrollbar".to_string(),
+                        id: None,
+                        bind: None,
                         x: 0.0,
                         y: 0.0,
                         width: 0.0,
                         height: 0.0,
-                        size: 18.0,
-                        color: (0.8, 0.8, 0.8, 1.0),
+                        size: Some(18.0),
+                        color: Some((0.8, 0.8, 0.8, 1.0)),
                         text_align: Default::default(),
                         flex: 0.0,
                         grid_col: None,
                         grid_row: None,
                         col_span: 1,
                         row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                         font: None,
+                        tab_width: Default::default(),
+                        underline: false,
+                        strikethrough: false,
+                        selectable: false,
+                        char_stops: Default::default(),
+                        measured: None,
                     },
                     
                     // Horizontal scrollbar container
@@ -333,19 +380,30 @@ fn create_ui(vertical_scroll: f32, horizontal_scroll: f32) -> Widget {
                                     viewport_width,
                                     horizontal_scroll
                                 ),
+                                id: None,
+                                bind: None,
                                 x: 0.0,
                                 y: 0.0,
                                 width: 0.0,
                                 height: 0.0,
-                                size: 12.0,
-                                color: (0.7, 0.7, 0.7, 1.0),
+                                size: Some(12.0),
+                                color: Some((0.7, 0.7, 0.7, 1.0)),
                                 text_align: Default::default(),
                                 flex: 1.0,
                                 grid_col: None,
                                 grid_row: None,
                                 col_span: 1,
                                 row_span: 1,
+                                grid_area: None,
+                                align_self: Default::default(), justify_self: Default::default(),
+                                order: 0,
                                 font: None,
+                                tab_width: Default::default(),
+                                underline: false,
+                                strikethrough: false,
+                                selectable: false,
+                                char_stops: Default::default(),
+                                measured: None,
                             },
                             
                             // Horizontal scrollbar
@@ -361,6 +419,9 @@ fn create_ui(vertical_scroll: f32, horizontal_scroll: f32) -> Widget {
                                 grid_row: None,
                                 col_span: 1,
                                 row_span: 1,
+                                grid_area: None,
+                                align_self: Default::default(), justify_self: Default::default(),
+                                order: 0,
                             },
                         ],
                     },
@@ -6,7 +6,7 @@ use gloomy_core::{
     Vec2, 
     widget::{Widget, WidgetBounds},
     style::{BoxStyle, ButtonStyle, Border, ListViewStyle},
-    layout::{Layout, Direction, Align},
+    layout::{Layout, Direction, Align, Padding},
     data_source::{VecDataSource, CellValue, MapDataProvider, DataProvider},
 };
 use std::{rc::Rc, cell::RefCell};
@@ -25,19 +25,33 @@ fn make_spacer(size: f32) -> Widget {
         size,
         flex: 0.0,
         grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
     }
 }
 
 fn make_label(text: &str, size: f32) -> Widget {
     Widget::Label {
         text: text.to_string(),
-        size,
-        color: (1.0, 1.0, 1.0, 1.0),
+        id: None,
+        bind: None,
+        size: Some(size),
+        color: Some((1.0, 1.0, 1.0, 1.0)),
         text_align: glob::widget::TextAlign::Center,
         x: 0.0, y: 0.0, width: 0.0, height: 0.0,
         flex: 0.0,
         grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         font: None,
+        tab_width: Default::default(),
+        underline: false,
+        strikethrough: false,
+        selectable: false,
+        char_stops: Default::default(),
+        measured: None,
     }
 }
 
@@ -48,12 +62,18 @@ fn make_button(text: &str, action: &str) -> Widget {
         height: Some(40.0),
         width: None,
         style: ButtonStyle::default(),
+        classes: Vec::new(),
         bounds: WidgetBounds::default(),
         disabled: false,
         layout: Layout::default(),
         flex: 0.0,
         grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
+        hit_padding: None,
         font: None,
+        measured: None,
     }
 }
 
@@ -84,29 +104,56 @@ fn create_sidebar() -> Widget {
             border: Some(Border { width: 1.0, color: (0.2, 0.2, 0.25, 1.0), radius: [0.0; 4] }),
             ..BoxStyle::default()
         },
+        text_defaults: Default::default(),
         children: vec![
             make_label("Showcase", 24.0),
             make_spacer(30.0),
             Widget::ListView {
                 id: "menu".to_string(),
                 items: vec!["Dashboard".to_string(), "Inputs".to_string(), "All Widgets".to_string()],
+                item_widgets: Vec::new(),
+                item_heights: Vec::new(),
                 selected_index: Some(0), // Will be updated by state
+                multi_select: false,
+                selected_indices: Default::default(),
+                show_checkboxes: false,
                 style: ListViewStyle::default(),
+                empty_message: None,
+                empty_icon: None,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
                 width: None, // Auto width (fill parent due to Align::Stretch)
                 height: None, // Auto height
                 bounds: WidgetBounds::default(),
                 layout: Layout::default(),
                 flex: 0.0,
                 grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                selection_anchor: None,
             }
         ],
         bounds: WidgetBounds::default(),
         scrollable: false,
-        padding: 20.0,
+        padding: Padding::uniform(20.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         flex: 0.0,
         grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         layout_cache: None,
         render_cache: RefCell::new(None),
+        generation: 0,
     }
 }
 
@@ -154,16 +201,30 @@ fn main() -> anyhow::Result<()> {
             background: Some((0.05, 0.05, 0.08, 1.0)),
             ..Default::default()
         },
+        text_defaults: Default::default(),
         children: vec![
             create_sidebar(),
             load_page(Page::Dashboard),
         ],
         scrollable: false,
-        padding: 0.0,
+        padding: Padding::uniform(0.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         flex: 0.0,
         grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         layout_cache: None,
         render_cache: RefCell::new(None),
+        generation: 0,
     };
     
     let root_widget = Rc::new(RefCell::new(root));
@@ -13,7 +13,7 @@ use gloomy_core::{
     layout_engine::compute_layout,
     ui::{render_ui, hit_test},
     widget::{Widget, WidgetBounds, TextAlign},
-    datagrid::{ColumnDef, ColumnWidth},
+    datagrid::{next_sort_keys, ColumnDef, ColumnWidth},
     data_source::{VecDataSource, CellValue, MapDataProvider, DataProvider, SortDirection},
     interaction::InteractionState,
     Vec2,
@@ -27,8 +27,7 @@ struct AppState {
     provider: MapDataProvider,
     ui_root: Widget,
     selected_rows: Vec<usize>,
-    sort_col: Option<usize>,
-    sort_dir: Option<SortDirection>,
+    sort_keys: Vec<(usize, SortDirection)>,
     col_specs: Vec<ColumnDef>,
     resizing_col: Option<usize>,
     drag_start_x: f32,
@@ -61,8 +60,7 @@ fn main() -> anyhow::Result<()> {
         provider,
         ui_root: initial_ui,
         selected_rows: Vec::new(),
-        sort_col: None,
-        sort_dir: None,
+        sort_keys: Vec::new(),
         col_specs: columns,
         resizing_col: None,
         drag_start_x: 0.0,
@@ -185,24 +183,14 @@ fn main() -> anyhow::Result<()> {
                             if let Ok(col_idx) = action.split(":").nth(2).unwrap_or("0").parse::<usize>() {
                                 println!("Sort column: {}", col_idx);
                                 
-                                // Update Sort State
-                                let (new_col, new_dir) = if s.sort_col == Some(col_idx) {
-                                    (Some(col_idx), match s.sort_dir {
-                                        Some(SortDirection::Ascending) => Some(SortDirection::Descending),
-                                        _ => Some(SortDirection::Ascending),
-                                    })
-                                } else {
-                                    (Some(col_idx), Some(SortDirection::Ascending))
-                                };
-                                
-                                s.sort_col = new_col;
-                                s.sort_dir = new_dir;
+                                // Update Sort State: Shift+click adds a secondary key
+                                let add = s.modifiers.state().shift_key();
+                                s.sort_keys = next_sort_keys(&s.sort_keys, col_idx, add);
                                 
                                 // Perform Sort
+                                let keys = s.sort_keys.clone();
                                 if let Some(source) = s.provider.get_source_mut("users") { 
-                                     if let Some(dir) = new_dir {
-                                          source.sort(col_idx, dir);
-                                     }
+                                     source.sort_by_keys(&keys);
                                 }
                                 
                                 win.window.request_redraw();
@@ -272,7 +260,7 @@ fn main() -> anyhow::Result<()> {
             let mut s = state_draw.borrow_mut();
             
             // Rebuild UI with current selection
-            let ui_root_new = create_ui(&s.selected_rows, s.sort_col, s.sort_dir, &s.col_specs);
+            let ui_root_new = create_ui(&s.selected_rows, &s.sort_keys, &s.col_specs);
             s.ui_root = ui_root_new; // Update the stored root for layout and rendering
             
             let window_size = win.window.inner_size();
@@ -328,7 +316,7 @@ fn create_sample_data() -> VecDataSource {
     VecDataSource::new(columns, rows)
 }
 
-fn create_ui(selected_rows: &[usize], sort_col: Option<usize>, sort_dir: Option<SortDirection>, columns: &[ColumnDef]) -> Widget {
+fn create_ui(selected_rows: &[usize], sort_keys: &[(usize, SortDirection)], columns: &[ColumnDef]) -> Widget {
         id: Some("root".to_string()),
         scrollable: false,
         bounds: WidgetBounds::default(),
@@ -357,37 +345,59 @@ fn create_ui(selected_rows: &[usize], sort_col: Option<usize>, sort_dir: Option<
             // Title
             Widget::Label {
                 text: "Interactive DataGrid Example".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 1140.0,
                 height: 40.0,
-                size: 28.0,
-                color: (0.9, 0.9, 0.9, 1.0),
+                size: Some(28.0),
+                color: Some((0.9, 0.9, 0.9, 1.0)),
                 text_align: TextAlign::Left,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             // Instruction Label
             Widget::Label {
                 text: "Scroll with mouse wheel. Click rows to select.".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 1140.0,
                 height: 25.0,
-                size: 14.0,
-                color: (0.7, 0.7, 0.7, 1.0),
+                size: Some(14.0),
+                color: Some((0.7, 0.7, 0.7, 1.0)),
                 text_align: TextAlign::Left,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             // DataGrid
@@ -398,11 +408,17 @@ fn create_ui(selected_rows: &[usize], sort_col: Option<usize>, sort_dir: Option<
                 data_source_id: Some("users".to_string()),
                 header_height: 40.0,
                 row_height: 36.0,
+                auto_row_height: false,
+                max_row_height: 160.0,
+                fitted_rows: None,
+                data_version: None,
+                content_height: Default::default(),
                 striped: true,
                 selection_mode: gloomy_core::datagrid::SelectionMode::Multiple,
                 selected_rows: selected_rows.to_vec(),
-                sort_column: sort_col,
-                sort_direction: sort_dir,
+                sort_column: None,
+                sort_direction: None,
+                sort_keys: sort_keys.to_vec(),
                 show_vertical_lines: true,
                 show_horizontal_lines: true,
                 style: gloomy_core::datagrid::DataGridStyle {
@@ -416,12 +432,20 @@ fn create_ui(selected_rows: &[usize], sort_col: Option<usize>, sort_dir: Option<
                     hover_background: (0.5, 0.5, 1.0, 0.5),  // Blue Hover
                     selected_background: (0.2, 0.8, 0.2, 1.0), // Green Selection
                     cell_padding: 8.0,
+                    ..Default::default()
                 },
+                empty_message: None,
+                empty_icon: None,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
                 flex: 1.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
             },
         ],
     }
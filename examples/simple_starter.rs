@@ -9,7 +9,7 @@
 /// Run with: cargo run --example simple_starter
 
 use gloomy_core::{
-    layout::{Direction, Layout},
+    layout::{Direction, Layout, Padding},
     layout_engine::compute_layout,
     ui::{render_ui, handle_interactions, hit_test},
     widget::{Widget, WidgetBounds},
@@ -127,6 +127,7 @@ impl AppState {
 
 fn create_ui(counter: i32) -> Widget {
     Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
         id: Some("main".to_string()),
         scrollable: false,
         bounds: WidgetBounds::default(),
@@ -137,7 +138,16 @@ fn create_ui(counter: i32) -> Widget {
         corner_radius: 0.0,
         shadow: None,
         gradient: None,
-        padding: 30.0,
+        padding: Padding::uniform(30.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         layout: Layout {
             direction: Direction::Column,
             spacing: 20.0,
@@ -148,60 +158,96 @@ fn create_ui(counter: i32) -> Widget {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         corner_radii: None,
         children: vec![
             // Title
             Widget::Label {
                 text: "Gloomy UI - Simple Starter".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 0.0,
                 height: 0.0,
-                size: 32.0,
-                color: (0.9, 0.9, 0.9, 1.0),
+                size: Some(32.0),
+                color: Some((0.9, 0.9, 0.9, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             // Description
             Widget::Label {
                 text: "Click the button to increment the counter".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 0.0,
                 height: 0.0,
-                size: 16.0,
-                color: (0.7, 0.7, 0.7, 1.0),
+                size: Some(16.0),
+                color: Some((0.7, 0.7, 0.7, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             // Counter display
             Widget::Label {
                 text: format!("Counter: {}", counter),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 0.0,
                 height: 0.0,
-                size: 24.0,
-                color: (1.0, 1.0, 1.0, 1.0),
+                size: Some(24.0),
+                color: Some((1.0, 1.0, 1.0, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             // Increment button
@@ -228,8 +274,15 @@ fn create_ui(counter: i32) -> Widget {
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                hit_padding: None,
                 font: None,
+                measured: None,
+                classes: Vec::new(),
             },
         ],
+        text_defaults: Default::default(),
     }
 }
@@ -266,6 +266,9 @@ fn create_ui(
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
     };
     
         bounds: WidgetBounds::default(),
@@ -286,15 +289,26 @@ fn create_ui(
                 text: "Tree Widget - Click ► to Expand, \
                        Click Label to Select"
                     .to_string(),
-                size: 18.0,
-                color: (0.9, 0.9, 0.95, 1.0),
+                id: None,
+                bind: None,
+                size: Some(18.0),
+                color: Some((0.9, 0.9, 0.95, 1.0)),
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
                 text_align: TextAlign::Left,
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                measured: None,
             },
             tree_widget,
         ],
@@ -305,6 +319,10 @@ fn create_ui(
             justify_content: 
                 gloomy_core::layout::Justify::Start,
             template_columns: vec![],
+            breakpoints: vec![],
+            row_gap: None,
+            column_gap: None,
+            areas: Default::default(),
         },
         width: None,
         height: None,
@@ -9,7 +9,7 @@ use gloomy_core::{
 
     kpi::{KpiCardStyle, KpiTrend, TrendDirection},
 
-    layout::{Layout, Direction, Align, Justify},
+    layout::{Layout, Direction, Align, Justify, Padding},
     layout_engine::compute_layout,
     interaction::InteractionState,
     Vec2,
@@ -96,7 +96,7 @@ fn main() -> anyhow::Result<()> {
             
             // 1. DataGrid Page
             let page_datagrid = Widget::Container {
-                id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, style: Default::default(), padding: 10.0, layout: Layout { direction: Direction::Column, align_items: Align::Stretch, spacing: 10.0, ..Default::default() }, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, children: vec![
+                id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, style: Default::default(), text_defaults: Default::default(), padding: Padding::uniform(10.0), focus_trap: false, live_region: None, static_cache: false, scrollbar: Default::default(), scrollbar_mode: Default::default(), z_index: 0, clip_to_shape: false, overflow: Default::default(), opacity: 1.0, layout: Layout { direction: Direction::Column, align_items: Align::Stretch, spacing: 10.0, ..Default::default() }, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, children: vec![
                     Widget::label("DataGrid Example"),
                     Widget::DataGrid {
                         id: Some("dg1".to_string()),
@@ -107,11 +107,23 @@ fn main() -> anyhow::Result<()> {
                         ],
                         data_source_id: None,
                         style: DataGridStyle::default(),
+                        empty_message: None,
+                        empty_icon: None,
+                        scrollbar: Default::default(),
+                        scrollbar_mode: Default::default(),
                         bounds: WidgetBounds::default(), flex: 1.0,
                         grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                         // Defaults for remaining fields
                         header_height: 40.0,
                         row_height: 32.0,
+                        auto_row_height: false,
+                        max_row_height: 160.0,
+                        fitted_rows: None,
+                        data_version: None,
+                        content_height: Default::default(),
                         striped: true,
 
                         selection_mode: SelectionMode::Single,
@@ -120,39 +132,40 @@ fn main() -> anyhow::Result<()> {
                         selected_rows: s.selected_rows.iter().cloned().collect(),
                         sort_column: None,
                         sort_direction: None,
+                        sort_keys: Vec::new(),
                     }
 
 
                 ], 
-                layout_cache: None, render_cache: std::cell::RefCell::new(None)
+                layout_cache: None, render_cache: std::cell::RefCell::new(None), generation: 0,
             };
             
             // 2. Form Page
             let page_form = Widget::Container {
-                 id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, style: Default::default(), padding: 20.0, layout: Layout { direction: Direction::Column, align_items: Align::Stretch, spacing: 15.0, ..Default::default() }, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, children: vec![
+                 id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, style: Default::default(), text_defaults: Default::default(), padding: Padding::uniform(20.0), focus_trap: false, live_region: None, static_cache: false, scrollbar: Default::default(), scrollbar_mode: Default::default(), z_index: 0, clip_to_shape: false, overflow: Default::default(), opacity: 1.0, layout: Layout { direction: Direction::Column, align_items: Align::Stretch, spacing: 15.0, ..Default::default() }, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, children: vec![
                      Widget::label("User Form"),
-                     Widget::TextInput { id: "fname".into(), value: "John".into(), placeholder: "First Name".into(), validation: None, style: TextInputStyle::default(), bounds: WidgetBounds::default(), width: 2.5.into(), height: 0.0.into(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, font_size: 14.0, text_align: TextAlign::Left },
-                     Widget::TextInput { id: "lname".into(), value: "Doe".into(), placeholder: "Last Name".into(), validation: None, style: TextInputStyle::default(), bounds: WidgetBounds::default(), width: 250.0.into(), height: 0.0.into(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, font_size: 14.0, text_align: TextAlign::Left },
-                     Widget::Button { text: "Submit".into(), action: "submit".into(), bounds: WidgetBounds::default(), style: ButtonStyle::default(), width: Some(100.0), height: None, disabled: false, layout: Layout::default(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, font: None },
+                     Widget::TextInput { id: "fname".into(), bind: None, value: "John".into(), placeholder: "First Name".into(), validation: None, mask: None, style: TextInputStyle::default(), classes: Vec::new(), bounds: WidgetBounds::default(), width: 2.5.into(), height: 0.0.into(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, font_size: 14.0, text_align: TextAlign::Left },
+                     Widget::TextInput { id: "lname".into(), bind: None, value: "Doe".into(), placeholder: "Last Name".into(), validation: None, mask: None, style: TextInputStyle::default(), classes: Vec::new(), bounds: WidgetBounds::default(), width: 250.0.into(), height: 0.0.into(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, font_size: 14.0, text_align: TextAlign::Left },
+                     Widget::Button { text: "Submit".into(), action: "submit".into(), bounds: WidgetBounds::default(), style: ButtonStyle::default(), classes: Vec::new(), width: Some(100.0), height: None, disabled: false, layout: Layout::default(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, hit_padding: None, font: None, measured: None, },
 
 
                  ],
-                 layout_cache: None, render_cache: std::cell::RefCell::new(None)
+                 layout_cache: None, render_cache: std::cell::RefCell::new(None), generation: 0,
             };
 
             // 3. TextInput Demo
             let page_text = Widget::Container {
-                 id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, style: Default::default(), padding: 20.0, layout: Layout { direction: Direction::Column, align_items: Align::Stretch, spacing: 10.0, ..Default::default() }, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, children: vec![
+                 id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, style: Default::default(), text_defaults: Default::default(), padding: Padding::uniform(20.0), focus_trap: false, live_region: None, static_cache: false, scrollbar: Default::default(), scrollbar_mode: Default::default(), z_index: 0, clip_to_shape: false, overflow: Default::default(), opacity: 1.0, layout: Layout { direction: Direction::Column, align_items: Align::Stretch, spacing: 10.0, ..Default::default() }, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, children: vec![
                      Widget::label("Text Input Demo"),
-                     Widget::TextInput { id: "demo_input".into(), value: s.input_text.clone(), placeholder: "Type here...".into(), validation: None, style: TextInputStyle::default(), bounds: WidgetBounds::default(), width: 300.0.into(), height: 0.0.into(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, font_size: 14.0, text_align: TextAlign::Left },
+                     Widget::TextInput { id: "demo_input".into(), bind: None, value: s.input_text.clone(), placeholder: "Type here...".into(), validation: None, mask: None, style: TextInputStyle::default(), classes: Vec::new(), bounds: WidgetBounds::default(), width: 300.0.into(), height: 0.0.into(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, font_size: 14.0, text_align: TextAlign::Left },
                      Widget::label(format!("You typed: {}", s.input_text)),
                  ],
-                 layout_cache: None, render_cache: std::cell::RefCell::new(None)
+                 layout_cache: None, render_cache: std::cell::RefCell::new(None), generation: 0,
             };
 
             // 4. Chart Placeholder
             let page_chart = Widget::Container {
-                 id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, style: Default::default(), padding: 20.0, layout: Layout { direction: Direction::Column, align_items: Align::Stretch, spacing: 10.0, ..Default::default() }, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, children: vec![
+                 id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, style: Default::default(), text_defaults: Default::default(), padding: Padding::uniform(20.0), focus_trap: false, live_region: None, static_cache: false, scrollbar: Default::default(), scrollbar_mode: Default::default(), z_index: 0, clip_to_shape: false, overflow: Default::default(), opacity: 1.0, layout: Layout { direction: Direction::Column, align_items: Align::Stretch, spacing: 10.0, ..Default::default() }, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, children: vec![
                      Widget::label("Analytics Chart"),
                      Widget::KpiCard {
                          id: Some("kpi1".into()),
@@ -161,27 +174,37 @@ fn main() -> anyhow::Result<()> {
                          trend: Some(KpiTrend { value: "+12%".into(), direction: TrendDirection::Up }),
                          style: KpiCardStyle::default(),
                          bounds: WidgetBounds { width: 200.0, height: 120.0, ..Default::default() },
-                         flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1
-                     },
+                         flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                      grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0,},
                      // Colored rect as chart placeholder
                      Widget::Container {
                          id: None, scrollable: false, bounds: WidgetBounds { width: 400.0, height: 200.0, ..Default::default() }, width: Some(400.0), height: Some(200.0), 
-                         style: Default::default(), // Need to set background color here
+                         style: Default::default(), text_defaults: Default::default(), // Need to set background color here
                          // Check BoxStyle: background is Option<Color>
                          // Wait, in widget.rs style: BoxStyle.
-                         padding: 0.0, layout: Layout::default(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, children: vec![],
-                         layout_cache: None, render_cache: std::cell::RefCell::new(None)
+                         padding: Padding::uniform(0.0), layout: Layout::default(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, children: vec![],
+                         layout_cache: None, render_cache: std::cell::RefCell::new(None),
+                         generation: 0,
+                         focus_trap: false,
+                         live_region: None,
+                         static_cache: false,
+                         scrollbar: Default::default(),
+                         scrollbar_mode: Default::default(),
+                         z_index: 0,
+                         clip_to_shape: false,
+                         overflow: Default::default(),
+                         opacity: 1.0,
                      }
                  ],
-                 layout_cache: None, render_cache: std::cell::RefCell::new(None)
+                 layout_cache: None, render_cache: std::cell::RefCell::new(None), generation: 0,
             };
 
             // 5. Static Label
             let page_label = Widget::Container {
-                 id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, style: Default::default(), padding: 20.0, layout: Layout::default(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, children: vec![
+                 id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, style: Default::default(), text_defaults: Default::default(), padding: Padding::uniform(20.0), focus_trap: false, live_region: None, static_cache: false, scrollbar: Default::default(), scrollbar_mode: Default::default(), z_index: 0, clip_to_shape: false, overflow: Default::default(), opacity: 1.0, layout: Layout::default(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, children: vec![
                      Widget::label("Just a simple label page."),
                  ],
-                 layout_cache: None, render_cache: std::cell::RefCell::new(None)
+                 layout_cache: None, render_cache: std::cell::RefCell::new(None), generation: 0,
             };
 
             // --- Root UI ---
@@ -191,17 +214,30 @@ fn main() -> anyhow::Result<()> {
                 bounds: WidgetBounds { x: 0.0, y: 0.0, width: w, height: h },
                 width: Some(w), height: Some(h),
                 style: Default::default(),
-                padding: 10.0,
+                text_defaults: Default::default(),
+                padding: Padding::uniform(10.0),
+                focus_trap: false,
+                live_region: None,
+                static_cache: false,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
+                z_index: 0,
+                clip_to_shape: false,
+                overflow: Default::default(),
+                opacity: 1.0,
                 layout: Layout { direction: Direction::Column, align_items: Align::Stretch, spacing: 10.0, ..Default::default() },
                 flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 children: vec![
                     // Top Bar
                     Widget::Container {
-                        id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: Some(40.0), style: Default::default(), padding: 0.0, layout: Layout { direction: Direction::Row, align_items: Align::Center, justify_content: Justify::SpaceBetween, ..Default::default() }, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, children: vec![
+                        id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: Some(40.0), style: Default::default(), text_defaults: Default::default(), padding: Padding::uniform(0.0), focus_trap: false, live_region: None, static_cache: false, scrollbar: Default::default(), scrollbar_mode: Default::default(), z_index: 0, clip_to_shape: false, overflow: Default::default(), opacity: 1.0, layout: Layout { direction: Direction::Row, align_items: Align::Center, justify_content: Justify::SpaceBetween, ..Default::default() }, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, children: vec![
                              Widget::label("Tab Component Showcase"),
-                             Widget::Button { text: "Toggle Orientation".into(), action: "toggle_orient".into(), bounds: WidgetBounds::default(), style: ButtonStyle::default(), width: None, height: None, disabled: false, layout: Layout::default(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, font: None }
+                             Widget::Button { text: "Toggle Orientation".into(), action: "toggle_orient".into(), bounds: WidgetBounds::default(), style: ButtonStyle::default(), classes: Vec::new(), width: None, height: None, disabled: false, layout: Layout::default(), flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, hit_padding: None, font: None, measured: None, }
                         ],
-                        layout_cache: None, render_cache: std::cell::RefCell::new(None)
+                        layout_cache: None, render_cache: std::cell::RefCell::new(None), generation: 0,
                     },
                     // Tab Widget
                     Widget::tab(
@@ -217,7 +253,7 @@ fn main() -> anyhow::Result<()> {
                         TabStyle::default()
                     ) // removed with_selected since it doesn't exist on enum
                 ],
-                layout_cache: None, render_cache: std::cell::RefCell::new(None)
+                layout_cache: None, render_cache: std::cell::RefCell::new(None), generation: 0,
             };
 
 
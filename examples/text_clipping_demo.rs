@@ -9,7 +9,7 @@
 /// Run with: cargo run --example text_clipping_demo
 
 use gloomy_core::{
-    layout::{Direction, Layout},
+    layout::{Direction, Layout, Padding},
     layout_engine::compute_layout,
     ui::{render_ui, handle_interactions},
     widget::{Widget, WidgetBounds},
@@ -65,6 +65,7 @@ fn create_ui() -> Widget {
     let medium_text = "This text is moderately long and should be clipped at the edge of its container.";
     
     Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
         id: Some("root".to_string()),
         scrollable: false,
         bounds: WidgetBounds::default(),
@@ -75,7 +76,16 @@ fn create_ui() -> Widget {
         corner_radius: 0.0,
         shadow: None,
         gradient: None,
-        padding: 30.0,
+        padding: Padding::uniform(30.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         layout: Layout {
             direction: Direction::Column,
             spacing: 20.0,
@@ -86,24 +96,38 @@ fn create_ui() -> Widget {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         corner_radii: None,
         children: vec![
             // Title
             Widget::Label {
                 text: "Text Clipping Demo".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 800.0,
                 height: 40.0,
-                size: 32.0,
-                color: (0.9, 0.9, 0.9, 1.0),
+                size: Some(32.0),
+                color: Some((0.9, 0.9, 0.9, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             
             // Example 1: Wide container, long text
@@ -138,6 +162,7 @@ fn create_ui() -> Widget {
             
             // Example 4: Height clipping
             Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
                 id: None,
                 scrollable: false,
                 bounds: WidgetBounds::default(),
@@ -148,7 +173,16 @@ fn create_ui() -> Widget {
                 corner_radius: 8.0,
                 shadow: None,
                 gradient: None,
-                padding: 15.0,
+                padding: Padding::uniform(15.0),
+                focus_trap: false,
+                live_region: None,
+                static_cache: false,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
+                z_index: 0,
+                clip_to_shape: false,
+                overflow: Default::default(),
+                opacity: 1.0,
                 layout: Layout {
                     direction: Direction::Column,
                     spacing: 8.0,
@@ -159,61 +193,99 @@ fn create_ui() -> Widget {
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 corner_radii: None,
                 children: vec![
                     Widget::Label {
                         text: "Example 4: Height Clipping (Fixed 30px Height)".to_string(),
+                        id: None,
+                        bind: None,
                         x: 0.0,
                         y: 0.0,
                         width: 670.0,
                         height: 25.0,
-                        size: 14.0,
-                        color: (0.6, 0.6, 0.7, 1.0),
+                        size: Some(14.0),
+                        color: Some((0.6, 0.6, 0.7, 1.0)),
                         text_align: Default::default(),
                         flex: 0.0,
                         grid_col: None,
                         grid_row: None,
                         col_span: 1,
                         row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                         font: None,
+                        tab_width: Default::default(),
+                        underline: false,
+                        strikethrough: false,
+                        selectable: false,
+                        char_stops: Default::default(),
+                        measured: None,
                     },
                     Widget::Label {
                         text: "This text has a very tall font size (32px) but is clipped to 30px height".to_string(),
+                        id: None,
+                        bind: None,
                         x: 0.0,
                         y: 0.0,
                         width: 670.0,
                         height: 30.0,  // Smaller than text size!
-                        size: 32.0,     // Will be clipped
-                        color: (0.9, 0.7, 0.7, 1.0),
+                        size: Some(32.0),     // Will be clipped
+                        color: Some((0.9, 0.7, 0.7, 1.0)),
                         text_align: Default::default(),
                         flex: 0.0,
                         grid_col: None,
                         grid_row: None,
                         col_span: 1,
                         row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                         font: None,
+                        tab_width: Default::default(),
+                        underline: false,
+                        strikethrough: false,
+                        selectable: false,
+                        char_stops: Default::default(),
+                        measured: None,
                     },
                 ],
+                text_defaults: Default::default(),
             },
             
             // Info
             Widget::Label {
                 text: "✓ All text is clipped to container/label bounds\n✓ No text overflows beyond boundaries".to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 800.0,
                 height: 60.0,
-                size: 14.0,
-                color: (0.5, 0.7, 0.5, 1.0),
+                size: Some(14.0),
+                color: Some((0.5, 0.7, 0.5, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
         ],
+        text_defaults: Default::default(),
     }
 }
 
@@ -226,6 +298,7 @@ fn create_clip_example(
     bg_color: (f32, f32, f32, f32)
 ) -> Widget {
     Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
         id: None,
         scrollable: false,
         bounds: WidgetBounds::default(),
@@ -236,7 +309,16 @@ fn create_clip_example(
         corner_radius: 8.0,
         shadow: None,
         gradient: None,
-        padding: 15.0,
+        padding: Padding::uniform(15.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         layout: Layout {
             direction: Direction::Column,
             spacing: 8.0,
@@ -247,40 +329,66 @@ fn create_clip_example(
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         corner_radii: None,
         children: vec![
             Widget::Label {
                 text: title.to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: width,
                 height: 20.0,
-                size: 12.0,
-                color: (0.6, 0.6, 0.6, 1.0),
+                size: Some(12.0),
+                color: Some((0.6, 0.6, 0.6, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
             Widget::Label {
                 text: text.to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: width,
                 height: height,
-                size: font_size,
-                color: (0.9, 0.9, 0.9, 1.0),
+                size: Some(font_size),
+                color: Some((0.9, 0.9, 0.9, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
         ],
+        text_defaults: Default::default(),
     }
 }
@@ -0,0 +1,47 @@
+use gloomy_core::TextRenderer;
+use std::time::{Duration, Instant};
+
+const FONT: &[u8] = include_bytes!("../crates/gloomy-core/src/fonts/Inter-Regular.ttf");
+
+/// Header labels of a wide DataGrid, measured once per column per frame.
+const HEADERS: [&str; 8] = [
+    "Customer ID", "Company Name", "Contact Person", "Email Address",
+    "Phone Number", "Billing Country", "Outstanding Balance", "Last Order Date",
+];
+const COLUMNS: usize = 64;
+const FRAMES: usize = 500;
+
+fn run(text: &TextRenderer) -> Duration {
+    let start = Instant::now();
+    let mut total = 0.0;
+    for _ in 0..FRAMES {
+        for col in 0..COLUMNS {
+            total += text.measure(HEADERS[col % HEADERS.len()], 14.0, None).x;
+        }
+    }
+    assert!(total > 0.0);
+    start.elapsed()
+}
+
+fn main() {
+    println!("=== Text Measurement Cache Benchmark ===");
+
+    let instance = wgpu::Instance::default();
+    let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+        .expect("No GPU adapter available");
+    let (device, _queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default(), None))
+        .expect("Failed to create device");
+
+    let mut text = TextRenderer::new(&device, wgpu::TextureFormat::Rgba8UnormSrgb, 800, 600, FONT);
+    let measurements = FRAMES * COLUMNS;
+
+    text.set_measure_cache_capacity(0);
+    let uncached = run(&text);
+    println!("Uncached ({} measurements): {:?}", measurements, uncached);
+
+    text.set_measure_cache_capacity(gloomy_core::text::DEFAULT_MEASURE_CACHE_CAPACITY);
+    let cached = run(&text);
+    println!("Cached   ({} measurements): {:?}", measurements, cached);
+
+    println!("Speedup: {:.1}x", uncached.as_secs_f64() / cached.as_secs_f64().max(f64::EPSILON));
+}
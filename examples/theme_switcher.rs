@@ -9,7 +9,7 @@
 /// Run with: cargo run --example theme_switcher
 
 use gloomy_core::{
-    layout::{Direction, Layout},
+    layout::{Direction, Layout, Padding},
     layout_engine::compute_layout,
     ui::{render_ui, handle_interactions, hit_test},
     widget::{Widget, WidgetBounds},
@@ -27,6 +27,7 @@ fn main() -> anyhow::Result<()> {
     
     let state = Rc::new(RefCell::new(AppState {
         ui_root: Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
             id: Some("root".to_string()),
             scrollable: false,
             bounds: WidgetBounds::default(),
@@ -37,15 +38,28 @@ fn main() -> anyhow::Result<()> {
             corner_radius: 0.0,
             shadow: None,
             gradient: None,
-            padding: 0.0,
+            padding: Padding::uniform(0.0),
+            focus_trap: false,
+            live_region: None,
+            static_cache: false,
+            scrollbar: Default::default(),
+            scrollbar_mode: Default::default(),
+            z_index: 0,
+            clip_to_shape: false,
+            overflow: Default::default(),
+            opacity: 1.0,
             layout: Layout::default(),
             flex: 0.0,
             grid_col: None,
             grid_row: None,
             col_span: 1,
             row_span: 1,
+            grid_area: None,
+            align_self: Default::default(), justify_self: Default::default(),
+            order: 0,
             corner_radii: None,
             children: vec![],
+            text_defaults: Default::default(),
         },
         interaction: InteractionState::default(),
         style_ctx: StyleContext::default(),
@@ -173,6 +187,7 @@ impl AppState {
         let error = theme.colors.error;
         
         self.ui_root = Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
             id: Some("root".to_string()),
             scrollable: false,
             bounds: WidgetBounds::default(),
@@ -183,7 +198,16 @@ impl AppState {
             corner_radius: 0.0,
             shadow: None,
             gradient: None,
-            padding: style.spacing_large,
+            padding: Padding::uniform(style.spacing_large),
+            focus_trap: false,
+            live_region: None,
+            static_cache: false,
+            scrollbar: Default::default(),
+            scrollbar_mode: Default::default(),
+            z_index: 0,
+            clip_to_shape: false,
+            overflow: Default::default(),
+            opacity: 1.0,
             layout: Layout {
                 direction: Direction::Column,
                 spacing: style.spacing_medium,
@@ -194,42 +218,67 @@ impl AppState {
             grid_row: None,
             col_span: 1,
             row_span: 1,
+            grid_area: None,
+            align_self: Default::default(), justify_self: Default::default(),
+            order: 0,
             corner_radii: None,
             children: vec![
                 // Title
                 Widget::Label {
                     text: format!("Theme Switcher - Current: {}", theme.name),
+                    id: None,
+                    bind: None,
                     x: 0.0,
                     y: 0.0,
                     width: 0.0,
                     height: 0.0,
-                    size: style.font_size_heading,
-                    color: text,
+                    size: Some(style.font_size_heading),
+                    color: Some(text),
                     text_align: Default::default(),
                     flex: 0.0,
                     grid_col: None,
                     grid_row: None,
                     col_span: 1,
                     row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
                     font: None,
+                    tab_width: Default::default(),
+                    underline: false,
+                    strikethrough: false,
+                    selectable: false,
+                    char_stops: Default::default(),
+                    measured: None,
                 },
                 
                 // Description
                 Widget::Label {
                     text: "Click the button below to cycle through themes".to_string(),
+                    id: None,
+                    bind: None,
                     x: 0.0,
                     y: 0.0,
                     width: 0.0,
                     height: 0.0,
-                    size: style.font_size_normal,
-                    color: text_secondary,
+                    size: Some(style.font_size_normal),
+                    color: Some(text_secondary),
                     text_align: Default::default(),
                     flex: 0.0,
                     grid_col: None,
                     grid_row: None,
                     col_span: 1,
                     row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
                     font: None,
+                    tab_width: Default::default(),
+                    underline: false,
+                    strikethrough: false,
+                    selectable: false,
+                    char_stops: Default::default(),
+                    measured: None,
                 },
                 
                 // Switch button
@@ -256,11 +305,18 @@ impl AppState {
                     grid_row: None,
                     col_span: 1,
                     row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
+                    hit_padding: None,
                     font: None,
+                    measured: None,
+                    classes: Vec::new(),
                 },
                 
                 // Color palette display
                 Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
                     id: Some("palette".to_string()),
                     scrollable: false,
                     bounds: WidgetBounds::default(),
@@ -271,7 +327,16 @@ impl AppState {
                     corner_radius: style.corner_radius_medium,
                     shadow: style.shadow_small.clone(),
                     gradient: None,
-                    padding: style.spacing_medium,
+                    padding: Padding::uniform(style.spacing_medium),
+                    focus_trap: false,
+                    live_region: None,
+                    static_cache: false,
+                    scrollbar: Default::default(),
+                    scrollbar_mode: Default::default(),
+                    z_index: 0,
+                    clip_to_shape: false,
+                    overflow: Default::default(),
+                    opacity: 1.0,
                     layout: Layout {
                         direction: Direction::Column,
                         spacing: style.spacing_small,
@@ -282,36 +347,53 @@ impl AppState {
                     grid_row: None,
                     col_span: 1,
                     row_span: 1,
+                    grid_area: None,
+                    align_self: Default::default(), justify_self: Default::default(),
+                    order: 0,
                     corner_radii: None,
                     children: vec![
                         Widget::Label {
                             text: "Semantic Colors:".to_string(),
+                            id: None,
+                            bind: None,
                             x: 0.0,
                             y: 0.0,
                             width: 0.0,
                             height: 0.0,
-                            size: style.font_size_large,
-                            color: text,
+                            size: Some(style.font_size_large),
+                            color: Some(text),
                             text_align: Default::default(),
                             flex: 0.0,
                             grid_col: None,
                             grid_row: None,
                             col_span: 1,
                             row_span: 1,
+                            grid_area: None,
+                            align_self: Default::default(), justify_self: Default::default(),
+                            order: 0,
                             font: None,
+                            tab_width: Default::default(),
+                            underline: false,
+                            strikethrough: false,
+                            selectable: false,
+                            char_stops: Default::default(),
+                            measured: None,
                         },
                         create_color_box("Success", success, style),
                         create_color_box("Warning", warning, style),
                         create_color_box("Error", error, style),
                     ],
+                    text_defaults: Default::default(),
                 },
             ],
+            text_defaults: Default::default(),
         };
     }
 }
 
 fn create_color_box(label: &str, color: (f32, f32, f32, f32), style: &GlobalStyle) -> Widget {
     Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
         id: None,
         scrollable: false,
         bounds: WidgetBounds::default(),
@@ -322,31 +404,55 @@ fn create_color_box(label: &str, color: (f32, f32, f32, f32), style: &GlobalStyl
         corner_radius: style.corner_radius_small,
         shadow: None,
         gradient: None,
-        padding: style.spacing_small,
+        padding: Padding::uniform(style.spacing_small),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         layout: Layout::default(),
         flex: 0.0,
         grid_col: None,
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         corner_radii: None,
         children: vec![
             Widget::Label {
                 text: label.to_string(),
+                id: None,
+                bind: None,
                 x: 0.0,
                 y: 0.0,
                 width: 0.0,
                 height: 0.0,
-                size: style.font_size_normal,
-                color: (1.0, 1.0, 1.0, 1.0),
+                size: Some(style.font_size_normal),
+                color: Some((1.0, 1.0, 1.0, 1.0)),
                 text_align: Default::default(),
                 flex: 0.0,
                 grid_col: None,
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
+                measured: None,
             },
         ],
+        text_defaults: Default::default(),
     }
 }
@@ -1,9 +1,11 @@
 use gloomy_app::GloomyApp;
 use gloomy_core::ui::{render_ui, hit_test, find_widget_mut};
 use gloomy_core::layout_engine::compute_layout;
+use gloomy_core::layout::Padding;
 use gloomy_core::interaction::InteractionState;
 use gloomy_core::widget::{Widget, WidgetBounds, TextInputStyle, NumberInputStyle, AutocompleteStyle, Border};
 use gloomy_core::validation::ValidationRule;
+use gloomy_core::{NumberFormat, SpinRepeatConfig};
 use gloomy_core::Vec2;
 use winit::event::ElementState;
 use std::rc::Rc;
@@ -22,16 +24,19 @@ fn main() -> anyhow::Result<()> {
     // Name Input (Required, MinLength 3)
     let name_input = Widget::TextInput {
         id: "name_input".to_string(),
+        bind: None,
         value: "".to_string(),
         placeholder: "Enter Name (Min 3 chars)".to_string(),
         font_size: 16.0,
         text_align: gloomy_core::widget::TextAlign::Left,
         bounds: WidgetBounds { width: 300.0, height: 40.0, ..Default::default() },
         style: TextInputStyle::default(),
+        classes: Vec::new(),
         validation: Some(vec![
             ValidationRule::Required,
             ValidationRule::MinLength(3)
         ]),
+        mask: None,
         width: 300.0,
         height: 40.0,
         flex: 0.0,
@@ -39,17 +44,23 @@ fn main() -> anyhow::Result<()> {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
     };
 
     // Age Input (Min 18, Max 120)
     let age_input = Widget::NumberInput {
         id: "age_input".to_string(),
+        bind: None,
         value: 18.0,
         min: Some(0.0),
         max: Some(120.0),
         step: 1.0,
         precision: 0,
+        number_format: NumberFormat::default(),
         show_spinner: true,
+        repeat: SpinRepeatConfig::default(),
         bounds: WidgetBounds { width: 300.0, height: 40.0, ..Default::default() },
         style: NumberInputStyle::default(),
         validation: Some(vec![
@@ -63,6 +74,9 @@ fn main() -> anyhow::Result<()> {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
     };
     
     // Country Autocomplete (Required, Must be in list)
@@ -85,6 +99,10 @@ fn main() -> anyhow::Result<()> {
         bounds: WidgetBounds { width: 300.0, height: 40.0, ..Default::default() },
         style: AutocompleteStyle::default(),
         validation: Some(vec![ValidationRule::Required]),
+        provider: None,
+        debounce_ms: 0,
+        suggest_state: Default::default(),
+        fuzzy: false,
         width: 300.0,
         height: 40.0,
         flex: 0.0,
@@ -92,6 +110,9 @@ fn main() -> anyhow::Result<()> {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
     };
 
     // Submit Button
@@ -118,7 +139,13 @@ fn main() -> anyhow::Result<()> {
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
+        hit_padding: None,
         font: None,
+        measured: None,
+        classes: Vec::new(),
     };
     
     let container = Widget::Container {
@@ -127,20 +154,31 @@ fn main() -> anyhow::Result<()> {
         children: vec![
             Widget::Label { 
                 text: "Validation Demo".to_string(), 
-                size: 24.0, 
+                id: None,
+                bind: None,
+                size: Some(24.0), 
                 width: 300.0, 
                 height: 40.0, 
-                color: (1.0, 1.0, 1.0, 1.0),
+                color: Some((1.0, 1.0, 1.0, 1.0)),
                 text_align: gloomy_core::widget::TextAlign::Center,
                 font: None,
+                tab_width: Default::default(),
+                underline: false,
+                strikethrough: false,
+                selectable: false,
+                char_stops: Default::default(),
                 flex: 0.0,
                 grid_col: None, grid_row: None, col_span:1, row_span:1,
-                x:0.0, y:0.0 
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
+                x:0.0, y:0.0,
+                measured: None, 
             },
             name_input, 
             age_input, 
             country_input,
-            Widget::Spacer { size: 20.0, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1 },
+            Widget::Spacer { size: 20.0, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1  grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0,},
             submit_btn
         ],
         bounds: WidgetBounds::default(),
@@ -154,12 +192,24 @@ fn main() -> anyhow::Result<()> {
             spacing: 15.0,
             ..Default::default()
         },
-        padding: 40.0,
+        padding: Padding::uniform(40.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         flex: 1.0,
         grid_col: None,
         grid_row: None,
         col_span: 1,
         row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         border: None,
         corner_radius: 0.0,
         shadow: None,
@@ -167,6 +217,8 @@ fn main() -> anyhow::Result<()> {
         corner_radii: None,
         layout_cache: None,
         render_cache: RefCell::new(None),
+        generation: 0,
+        text_defaults: Default::default(),
     };
   
     let state = Rc::new(RefCell::new(AppState {
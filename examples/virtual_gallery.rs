@@ -0,0 +1,114 @@
+//! Virtual Gallery - Ten thousand cards, only the visible ones built.
+//!
+//! A `VirtualFlow` wraps fixed-size cards to the window width. Scroll to
+//! move through the gallery and resize the window to change the number
+//! of columns. Clicking a card logs its index.
+//!
+//! Press 'q' or Escape to quit.
+
+use gloomy_app::GloomyApp;
+use gloomy_core::interaction::InteractionState;
+use gloomy_core::layout_engine::compute_layout;
+use gloomy_core::ui::{hit_test, parse_ui, render_ui};
+use gloomy_core::virtual_flow::{refresh_virtual_flows, FlowGeometry, ItemBuilders};
+use gloomy_core::widget::Widget;
+use gloomy_core::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+use winit::event::{ElementState, MouseButton};
+
+const ITEM_COUNT: usize = 10_000;
+const ITEM_SIZE: Vec2 = Vec2::new(160.0, 120.0);
+const SPACING: f32 = 12.0;
+
+const UI: &str = r#"
+VirtualFlow(
+    id: "gallery",
+    item_count: 10000,
+    item_size: (160.0, 120.0),
+    spacing: 12.0,
+    builder_id: "cards",
+)
+"#;
+
+const CARD: &str = r#"
+Container(
+    padding: 12.0,
+    style: (background: Some((0.18, 0.2, 0.26, 1.0)), corner_radii: (8.0, 8.0, 8.0, 8.0)),
+    layout: (direction: Column, spacing: 6.0),
+    children: [
+        Label(id: Some("title"), text: "", size: 18.0),
+        Label(text: "Gallery item", size: 12.0, color: (0.6, 0.65, 0.7, 1.0)),
+    ],
+)
+"#;
+
+fn card(index: usize) -> Widget {
+    let mut card = parse_ui(CARD).expect("valid card");
+    if let Widget::Container { children, .. } = &mut card {
+        if let Some(Widget::Label { text, .. }) = children.first_mut() {
+            *text = format!("#{}", index + 1);
+        }
+    }
+    card
+}
+
+struct State {
+    root: Widget,
+    interaction: InteractionState,
+    builders: ItemBuilders,
+}
+
+fn main() -> anyhow::Result<()> {
+    env_logger::init();
+
+    let mut builders = ItemBuilders::new();
+    builders.register("cards", card);
+    let state = Rc::new(RefCell::new(State {
+        root: parse_ui(UI)?,
+        interaction: InteractionState::default(),
+        builders,
+    }));
+    let state_move = state.clone();
+    let state_click = state.clone();
+    let state_scroll = state.clone();
+    let state_draw = state.clone();
+
+    GloomyApp::new()
+        .with_title("Virtual Gallery")
+        .on_cursor_move(move |_win, x, y| {
+            state_move.borrow_mut().interaction.update_mouse(Vec2::new(x, y));
+        })
+        .on_mouse_input(move |_win, elem_state, button| {
+            if elem_state != ElementState::Pressed || button != MouseButton::Left {
+                return;
+            }
+            let s = state_click.borrow();
+            if let Some(hit) = hit_test(&s.root, s.interaction.mouse_pos, Some(&s.interaction)) {
+                log::info!("Clicked {}", hit.action);
+            }
+        })
+        .on_scroll_pixels(move |win, _dx, dy, _phase| {
+            let mut s = state_scroll.borrow_mut();
+            let size = win.renderer.size();
+            let geometry = FlowGeometry::new(size.x, ITEM_SIZE, SPACING);
+            let max_scroll = (geometry.content_height(ITEM_COUNT) - size.y).max(0.0);
+            let offset = s.interaction.scroll_offsets.entry("gallery".to_string()).or_default();
+            offset.y = (offset.y - dy).clamp(0.0, max_scroll);
+            win.window.request_redraw();
+        })
+        .on_draw(move |win, ctx| {
+            let s = &mut *state_draw.borrow_mut();
+            // The flow is the root, so it takes the whole window; a resize
+            // changes the column count on this layout pass.
+            let size = win.renderer.size();
+            if let Widget::VirtualFlow { bounds, .. } = &mut s.root {
+                bounds.width = size.x;
+                bounds.height = size.y;
+            }
+            compute_layout(&mut s.root, 0.0, 0.0, size.x, size.y);
+            refresh_virtual_flows(&mut s.root, &s.builders, &s.interaction);
+            render_ui(&s.root, &mut win.renderer, ctx.device, ctx.queue, Some(&s.interaction), None);
+        })
+        .run()
+}
@@ -4,11 +4,12 @@ use gloomy_app::GloomyApp;
 use gloomy_core::{
     widget::{Widget, WidgetBounds},
     style::{ListViewStyle, BoxStyle},
-    layout::{Layout, Direction, Align, Justify},
+    layout::{Layout, Direction, Align, Justify, Padding},
     ui::render_ui,
     compute_layout,
     InteractionState,
     hit_test,
+    route_scroll,
     Vec2,
 };
 
@@ -28,7 +29,17 @@ fn main() -> anyhow::Result<()> {
             background: Some((0.1, 0.1, 0.12, 1.0)),
             ..Default::default()
         },
-        padding: 20.0,
+        text_defaults: Default::default(),
+        padding: Padding::uniform(20.0),
+        focus_trap: false,
+        live_region: None,
+        static_cache: false,
+        scrollbar: Default::default(),
+        scrollbar_mode: Default::default(),
+        z_index: 0,
+        clip_to_shape: false,
+        overflow: Default::default(),
+        opacity: 1.0,
         layout: Layout {
             direction: Direction::Column,
             align_items: Align::Stretch,
@@ -37,6 +48,9 @@ fn main() -> anyhow::Result<()> {
             ..Default::default()
         },
         width: None, height: None, flex: 0.0, grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+        grid_area: None,
+        align_self: Default::default(), justify_self: Default::default(),
+        order: 0,
         children: vec![
             Widget::label("Virtual ListView Demo (10,000 items)"),
             
@@ -45,6 +59,9 @@ fn main() -> anyhow::Result<()> {
                 id: "my_list".to_string(),
                 items,
                 selected_index: None,
+                multi_select: false,
+                selected_indices: Default::default(),
+                show_checkboxes: false,
                 style: ListViewStyle {
                     item_height: 30.0,
                     idle: BoxStyle {
@@ -62,6 +79,10 @@ fn main() -> anyhow::Result<()> {
                     text_color_idle: (0.9, 0.9, 0.9, 1.0),
                     text_color_selected: (1.0, 1.0, 1.0, 1.0),
                 },
+                empty_message: None,
+                empty_icon: None,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
                 bounds: WidgetBounds::default(),
                 // Fix height to force scrolling
                 width: None,
@@ -72,13 +93,20 @@ fn main() -> anyhow::Result<()> {
                 grid_row: None,
                 col_span: 1,
                 row_span: 1,
+                grid_area: None,
+                align_self: Default::default(), justify_self: Default::default(),
+                order: 0,
                 scroll_offset: 0.0,
+                selection_anchor: None,
+                item_heights: Vec::new(),
+                item_widgets: Vec::new(),
             },
             
             Widget::label("Try scrolling the list!"),
         ],
         layout_cache: None,
         render_cache: std::cell::RefCell::new(None),
+        generation: 0,
     };
 
     // Shared State
@@ -104,24 +132,14 @@ fn main() -> anyhow::Result<()> {
              let mut int = int_scroll.borrow_mut();
              let ui = ui_scroll.borrow();
              
-             // Hit test to identify scroll target
-             if let Some(hit) = hit_test(&ui, int.mouse_pos, Some(&int)) {
-                 let action = hit.action;
-                 // Extract ID from action (e.g. "my_list:5" -> "my_list")
-                 let id = if let Some(idx) = action.find(':') {
-                     &action[0..idx]
-                 } else {
-                     &action
-                 };
-                 
-                 let (dx, dy) = match delta {
-                     winit::event::MouseScrollDelta::LineDelta(x, y) => (x * 30.0, y * 30.0),
-                     winit::event::MouseScrollDelta::PixelDelta(p) => (p.x as f32, p.y as f32),
-                 };
-                 
-                 // Pass Vec2 to handle_scroll
-                 int.handle_scroll(id, Vec2::new(dx, dy));
-             }
+             let (dx, dy) = match delta {
+                 winit::event::MouseScrollDelta::LineDelta(x, y) => (x * 30.0, y * 30.0),
+                 winit::event::MouseScrollDelta::PixelDelta(p) => (p.x as f32, p.y as f32),
+             };
+
+             // Scroll the innermost scrollable under the cursor
+             let pos = int.mouse_pos;
+             route_scroll(&ui, pos, Vec2::new(dx, dy), &mut int);
         })
         .on_draw(move |win, ctx| {
             let size = win.renderer.size();
@@ -4,7 +4,7 @@ use gloomy_core::{
     widget::{
         Widget, WidgetBounds, ToggleSwitchStyle, ProgressBarStyle, RadioButtonStyle, DropdownStyle,
     },
-    layout::{Layout, Direction, Align},
+    layout::{Layout, Direction, Align, Padding},
     layout_engine::compute_layout,
     interaction::InteractionState,
     Vec2,
@@ -77,19 +77,29 @@ fn main() -> anyhow::Result<()> {
             // --- UI Definition ---
             let header_lbl = {
                 let mut l = Widget::label("Widgets Showcase");
-                if let Widget::Label { size, .. } = &mut l { *size = 24.0; }
+                if let Widget::Label { size, .. } = &mut l { *size = Some(24.0); }
                 l
             };
 
             let mut ui = Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
                 id: Some("root".to_string()),
                 scrollable: true,
                 bounds: WidgetBounds { x: 0.0, y: 0.0, width: w, height: h }, 
                 width: Some(w), height: Some(h),
                 background: Some((0.1, 0.1, 0.12, 1.0)),
-                border: None, corner_radius: 0.0, shadow: None, gradient: None, padding: 20.0,
+                border: None, corner_radius: 0.0, shadow: None, gradient: None, padding: Padding::uniform(20.0),
+                focus_trap: false,
+                live_region: None,
+                static_cache: false,
+                scrollbar: Default::default(),
+                scrollbar_mode: Default::default(),
+                z_index: 0,
+                clip_to_shape: false,
+                overflow: Default::default(),
+                opacity: 1.0,
                 corner_radii: None,
-                grid_col: None, grid_row: None, col_span: 1, row_span: 1, flex: 0.0,
+                grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, flex: 0.0,
                 layout: Layout {
                     direction: Direction::Column,
                     spacing: 20.0,
@@ -102,19 +112,27 @@ fn main() -> anyhow::Result<()> {
                     // --- Toggles ---
                     Widget::label("1. Toggle Switches"),
                     Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
                         layout: Layout { direction: Direction::Row, spacing: 20.0, align_items: Align::Center, ..Default::default() },
                         children: vec![
                             Widget::ToggleSwitch {
                                 id: "toggle_1".to_string(),
+                                bind: None,
                                 checked: s.toggle_1,
                                 style: ToggleSwitchStyle { width: 50.0, ..Default::default() },
                                 bounds: WidgetBounds::default(), layout: Layout::default(), flex: 0.0,
                                 grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                                grid_area: None,
+                                align_self: Default::default(), justify_self: Default::default(),
+                                order: 0,
+                                hit_padding: None,
+                                toggled_at: None,
                             },
                             Widget::label(if s.toggle_1 { "On" } else { "Off" }),
                             
                             Widget::ToggleSwitch {
                                 id: "toggle_2".to_string(),
+                                bind: None,
                                 checked: s.toggle_2,
                                 style: ToggleSwitchStyle { 
                                     width: 60.0, 
@@ -124,9 +142,15 @@ fn main() -> anyhow::Result<()> {
                                 },
                                 bounds: WidgetBounds::default(), layout: Layout::default(), flex: 0.0,
                                 grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                                grid_area: None,
+                                align_self: Default::default(), justify_self: Default::default(),
+                                order: 0,
+                                hit_padding: None,
+                                toggled_at: None,
                             },
                         ],
-                         id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, background: None, border: None, corner_radius: 0.0, shadow: None, gradient: None, padding: 0.0, corner_radii: None, grid_col: None, grid_row: None, col_span: 1, row_span: 1, flex: 0.0,
+                         id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, background: None, border: None, corner_radius: 0.0, shadow: None, gradient: None, padding: Padding::uniform(0.0), focus_trap: false, live_region: None, static_cache: false, scrollbar: Default::default(), scrollbar_mode: Default::default(), z_index: 0, clip_to_shape: false, overflow: Default::default(), opacity: 1.0, corner_radii: None, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, flex: 0.0,
+                        text_defaults: Default::default(),
                     },
                     
                     // --- Progress Bar ---
@@ -134,6 +158,7 @@ fn main() -> anyhow::Result<()> {
                     Widget::ProgressBar {
                         value: s.progress,
                         min: 0.0, max: 1.0,
+                        indeterminate: false,
                         style: ProgressBarStyle {
                             fill_color: Some((0.3, 0.6, 1.0, 1.0)),
                             background_color: Some((0.2, 0.2, 0.2, 1.0)),
@@ -144,11 +169,15 @@ fn main() -> anyhow::Result<()> {
                         height: Some(10.0),
                         bounds: WidgetBounds::default(), layout: Layout::default(), flex: 0.0,
                         grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                     },
                     
                     // --- Radio Buttons ---
                     Widget::label("3. Radio Buttons"),
                     Widget::Container { layout_cache: None, render_cache: std::cell::RefCell::new(None),
+ generation: 0,
                         layout: Layout { direction: Direction::Row, spacing: 10.0, align_items: Align::Center, ..Default::default() },
                         children: vec![
                              Widget::RadioButton {
@@ -159,6 +188,10 @@ fn main() -> anyhow::Result<()> {
                                  label: "Option 1".to_string(),
                                  bounds: WidgetBounds::default(), layout: Layout::default(), flex: 0.0,
                                  grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                                 grid_area: None,
+                                 align_self: Default::default(), justify_self: Default::default(),
+                                 order: 0,
+                                 hit_padding: None,
                              },
                              Widget::label("Option 1"),
                              
@@ -170,10 +203,15 @@ fn main() -> anyhow::Result<()> {
                                  label: "Option 2".to_string(),
                                  bounds: WidgetBounds::default(), layout: Layout::default(), flex: 0.0,
                                  grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                                 grid_area: None,
+                                 align_self: Default::default(), justify_self: Default::default(),
+                                 order: 0,
+                                 hit_padding: None,
                              },
                              Widget::label("Option 2"),
                         ],
-                         id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, background: None, border: None, corner_radius: 0.0, shadow: None, gradient: None, padding: 0.0, corner_radii: None, grid_col: None, grid_row: None, col_span: 1, row_span: 1, flex: 0.0,
+                         id: None, scrollable: false, bounds: WidgetBounds::default(), width: None, height: None, background: None, border: None, corner_radius: 0.0, shadow: None, gradient: None, padding: Padding::uniform(0.0), focus_trap: false, live_region: None, static_cache: false, scrollbar: Default::default(), scrollbar_mode: Default::default(), z_index: 0, clip_to_shape: false, overflow: Default::default(), opacity: 1.0, corner_radii: None, grid_col: None, grid_row: None, col_span: 1, row_span: 1, grid_area: None, align_self: Default::default(), justify_self: Default::default(), order: 0, flex: 0.0,
+                        text_defaults: Default::default(),
                     },
                     
                     // --- Dropdown ---
@@ -188,9 +226,13 @@ fn main() -> anyhow::Result<()> {
                         height: Some(36.0),
                         bounds: WidgetBounds::default(), layout: Layout::default(), flex: 0.0,
                         grid_col: None, grid_row: None, col_span: 1, row_span: 1,
+                        grid_area: None,
+                        align_self: Default::default(), justify_self: Default::default(),
+                        order: 0,
                     },
                     
                 ],
+                text_defaults: Default::default(),
             };
             
             compute_layout(&mut ui, 0.0, 0.0, w, h);